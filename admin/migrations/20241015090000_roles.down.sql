@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS users_roles;
+DROP TABLE IF EXISTS roles_capabilities;
+DROP TABLE IF EXISTS capabilities;
+DROP TABLE IF EXISTS roles;
@@ -0,0 +1,103 @@
+-- Add up migration script here
+CREATE TABLE roles (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    slug VARCHAR(200) NOT NULL UNIQUE,
+    description VARCHAR(1000) DEFAULT NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+CREATE TABLE capabilities (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) NOT NULL UNIQUE,
+    description VARCHAR(1000) DEFAULT NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+CREATE TABLE roles_capabilities (
+    id SERIAL PRIMARY KEY,
+    role_id INTEGER NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    capability_id INTEGER NOT NULL REFERENCES capabilities(id) ON DELETE CASCADE,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    UNIQUE (role_id, capability_id)
+);
+CREATE TABLE users_roles (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role_id INTEGER NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    UNIQUE (user_id, role_id)
+);
+INSERT INTO roles (name, slug, description)
+VALUES (
+        'Administrator',
+        'administrator',
+        'Full access to every administration feature'
+    ),
+    (
+        'Editor',
+        'editor',
+        'Publishes and manages posts including the posts of other users'
+    ),
+    (
+        'Author',
+        'author',
+        'Publishes and manages their own posts'
+    ),
+    (
+        'Contributor',
+        'contributor',
+        'Writes and manages their own posts but cannot publish them'
+    );
+INSERT INTO capabilities (name, description)
+VALUES ('read', 'Access the administration'),
+    ('edit_posts', 'Create and edit own posts'),
+    ('edit_others_posts', 'Edit posts of other users'),
+    ('publish_posts', 'Publish and schedule posts'),
+    ('delete_posts', 'Delete own posts'),
+    ('delete_others_posts', 'Delete posts of other users'),
+    ('manage_categories', 'Create, edit and delete categories'),
+    ('manage_tags', 'Create, edit and delete tags'),
+    ('list_users', 'List users'),
+    ('create_users', 'Create users'),
+    ('edit_users', 'Edit other users'),
+    ('delete_users', 'Delete users'),
+    ('promote_users', 'Assign roles to users'),
+    ('manage_roles', 'Create, edit and delete roles');
+INSERT INTO roles_capabilities (role_id, capability_id)
+SELECT r.id,
+    c.id
+FROM roles r
+    CROSS JOIN capabilities c
+WHERE r.slug = 'administrator'
+    OR (
+        r.slug = 'editor'
+        AND c.name IN (
+            'read',
+            'edit_posts',
+            'edit_others_posts',
+            'publish_posts',
+            'delete_posts',
+            'delete_others_posts',
+            'manage_categories',
+            'manage_tags'
+        )
+    )
+    OR (
+        r.slug = 'author'
+        AND c.name IN (
+            'read',
+            'edit_posts',
+            'publish_posts',
+            'delete_posts'
+        )
+    )
+    OR (
+        r.slug = 'contributor'
+        AND c.name IN ('read', 'edit_posts', 'delete_posts')
+    );
+-- Existing accounts keep full access
+INSERT INTO users_roles (user_id, role_id)
+SELECT u.id,
+    r.id
+FROM users u
+    CROSS JOIN roles r
+WHERE r.slug = 'administrator';
@@ -0,0 +1,6 @@
+-- Add up migration script here
+ALTER TABLE posts
+ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', coalesce(title, '')), 'A') || setweight(to_tsvector('english', coalesce(content, '')), 'B')
+    ) STORED;
+CREATE INDEX posts_search_vector_idx ON posts USING GIN (search_vector);
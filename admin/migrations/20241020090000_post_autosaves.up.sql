@@ -0,0 +1,12 @@
+-- Add up migration script here
+CREATE TABLE post_autosaves (
+    id SERIAL PRIMARY KEY,
+    post_id INTEGER NULL REFERENCES posts(id) ON DELETE CASCADE,
+    author_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    title VARCHAR(200) NOT NULL,
+    content TEXT NOT NULL,
+    date_modified TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+-- One autosave per user and post, the draft of a new post having no post
+CREATE UNIQUE INDEX post_autosaves_author_id_post_id_idx
+    ON post_autosaves (author_id, (COALESCE(post_id, 0)));
@@ -0,0 +1,5 @@
+-- Add down migration script here
+DELETE FROM capabilities
+WHERE name = 'upload_files';
+ALTER TABLE posts DROP COLUMN IF EXISTS featured_media_id;
+DROP TABLE IF EXISTS media;
@@ -0,0 +1,22 @@
+-- Add up migration script here
+CREATE TABLE media (
+    id SERIAL PRIMARY KEY,
+    author_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    filename VARCHAR(255) NOT NULL,
+    path VARCHAR(255) NOT NULL UNIQUE,
+    mime_type VARCHAR(100) NOT NULL,
+    size BIGINT NOT NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+ALTER TABLE posts
+ADD COLUMN featured_media_id INTEGER NULL REFERENCES media(id) ON DELETE
+SET NULL;
+INSERT INTO capabilities (name, description)
+VALUES ('upload_files', 'Upload files and delete own media');
+INSERT INTO roles_capabilities (role_id, capability_id)
+SELECT r.id,
+    c.id
+FROM roles r
+    CROSS JOIN capabilities c
+WHERE r.slug IN ('administrator', 'editor', 'author')
+    AND c.name = 'upload_files';
@@ -0,0 +1,6 @@
+-- Add down migration script here
+DELETE FROM capabilities
+WHERE name = 'manage_options';
+ALTER TABLE media DROP COLUMN IF EXISTS sizes,
+    DROP COLUMN IF EXISTS height,
+    DROP COLUMN IF EXISTS width;
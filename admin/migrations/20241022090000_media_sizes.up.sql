@@ -0,0 +1,14 @@
+-- Add up migration script here
+ALTER TABLE media
+ADD COLUMN width INTEGER NULL,
+    ADD COLUMN height INTEGER NULL,
+    ADD COLUMN sizes JSONB NOT NULL DEFAULT '{}'::jsonb;
+INSERT INTO capabilities (name, description)
+VALUES ('manage_options', 'Manage the settings of the site');
+INSERT INTO roles_capabilities (role_id, capability_id)
+SELECT r.id,
+    c.id
+FROM roles r
+    CROSS JOIN capabilities c
+WHERE r.slug = 'administrator'
+    AND c.name = 'manage_options';
@@ -0,0 +1,5 @@
+-- Add down migration script here
+DELETE FROM capabilities
+WHERE name = 'moderate_comments';
+DROP TABLE IF EXISTS comments;
+DROP TYPE IF EXISTS comments_status;
@@ -0,0 +1,39 @@
+-- Add up migration script here
+DO $$ BEGIN IF NOT EXISTS (
+    SELECT 1
+    FROM pg_type
+    WHERE typname = 'comments_status'
+) THEN CREATE TYPE comments_status AS ENUM (
+    'Pending',
+    'Approved',
+    'Spam',
+    'Trash'
+);
+END IF;
+END $$;
+CREATE TABLE comments (
+    id SERIAL PRIMARY KEY,
+    post_id INTEGER NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    parent_id INTEGER NULL REFERENCES comments(id) ON DELETE CASCADE,
+    -- The author of a reply from the administration, the name and email
+    -- being copied from the account
+    user_id INTEGER NULL REFERENCES users(id) ON DELETE SET NULL,
+    author_name VARCHAR(100) NOT NULL,
+    author_email VARCHAR(200) NOT NULL,
+    content TEXT NOT NULL,
+    status comments_status NOT NULL DEFAULT 'Pending',
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+CREATE INDEX comments_post_id_status_idx ON comments (post_id, status);
+INSERT INTO capabilities (name, description)
+VALUES (
+        'moderate_comments',
+        'Approve, reply to and delete comments'
+    );
+INSERT INTO roles_capabilities (role_id, capability_id)
+SELECT r.id,
+    c.id
+FROM roles r
+    CROSS JOIN capabilities c
+WHERE r.slug IN ('administrator', 'editor')
+    AND c.name = 'moderate_comments';
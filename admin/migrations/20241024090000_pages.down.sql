@@ -0,0 +1,11 @@
+-- Add down migration script here
+DELETE FROM capabilities
+WHERE name = 'edit_pages';
+DELETE FROM posts
+WHERE post_type = 'Page';
+DROP INDEX IF EXISTS posts_post_type_parent_id_idx;
+ALTER TABLE posts DROP COLUMN IF EXISTS template,
+    DROP COLUMN IF EXISTS menu_order,
+    DROP COLUMN IF EXISTS parent_id,
+    DROP COLUMN IF EXISTS post_type;
+DROP TYPE IF EXISTS posts_type;
@@ -0,0 +1,27 @@
+-- Add up migration script here
+DO $$ BEGIN IF NOT EXISTS (
+    SELECT 1
+    FROM pg_type
+    WHERE typname = 'posts_type'
+) THEN CREATE TYPE posts_type AS ENUM ('Post', 'Page');
+END IF;
+END $$;
+-- Pages are stored with the posts, the type telling them apart
+ALTER TABLE posts
+ADD COLUMN post_type posts_type NOT NULL DEFAULT 'Post',
+    ADD COLUMN parent_id INTEGER NULL REFERENCES posts(id) ON DELETE SET NULL,
+    ADD COLUMN menu_order INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN template VARCHAR(100) NULL;
+CREATE INDEX posts_post_type_parent_id_idx ON posts (post_type, parent_id);
+INSERT INTO capabilities (name, description)
+VALUES (
+        'edit_pages',
+        'Create, edit and delete pages'
+    );
+INSERT INTO roles_capabilities (role_id, capability_id)
+SELECT r.id,
+    c.id
+FROM roles r
+    CROSS JOIN capabilities c
+WHERE r.slug IN ('administrator', 'editor')
+    AND c.name = 'edit_pages';
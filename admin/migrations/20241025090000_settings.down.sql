@@ -0,0 +1,4 @@
+-- Add down migration script here
+DELETE FROM capabilities
+WHERE name = 'switch_themes';
+DROP TABLE IF EXISTS settings;
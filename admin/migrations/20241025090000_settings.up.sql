@@ -0,0 +1,17 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS settings (
+    name VARCHAR(100) PRIMARY KEY,
+    value JSONB NOT NULL,
+    date_updated TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+INSERT INTO settings (name, value)
+VALUES ('active_theme', '"Default"');
+INSERT INTO capabilities (name, description)
+VALUES ('switch_themes', 'Change the theme of the site');
+INSERT INTO roles_capabilities (role_id, capability_id)
+SELECT r.id,
+    c.id
+FROM roles r
+    CROSS JOIN capabilities c
+WHERE r.slug = 'administrator'
+    AND c.name = 'switch_themes';
@@ -0,0 +1,7 @@
+-- Add down migration script here
+DELETE FROM capabilities
+WHERE name = 'edit_theme_options';
+DROP TABLE IF EXISTS menu_locations;
+DROP TABLE IF EXISTS menu_items;
+DROP TABLE IF EXISTS menus;
+DROP TYPE IF EXISTS menu_items_type;
@@ -0,0 +1,48 @@
+-- Add up migration script here
+DO $$ BEGIN IF NOT EXISTS (
+    SELECT 1
+    FROM pg_type
+    WHERE typname = 'menu_items_type'
+) THEN CREATE TYPE menu_items_type AS ENUM ('Post', 'Page', 'Category', 'Tag', 'Custom');
+END IF;
+END $$;
+CREATE TABLE IF NOT EXISTS menus (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    slug VARCHAR(100) NOT NULL UNIQUE,
+    date_created TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+-- Items point to a post, a page, a category or a tag through object_id, or
+-- to a custom URL
+CREATE TABLE IF NOT EXISTS menu_items (
+    id SERIAL PRIMARY KEY,
+    menu_id INTEGER NOT NULL REFERENCES menus(id) ON DELETE CASCADE,
+    parent_id INTEGER NULL REFERENCES menu_items(id) ON DELETE CASCADE,
+    item_type menu_items_type NOT NULL,
+    object_id INTEGER NULL,
+    url VARCHAR(2048) NULL,
+    title VARCHAR(255) NULL,
+    menu_order INTEGER NOT NULL DEFAULT 0,
+    CHECK (
+        (item_type = 'Custom') = (url IS NOT NULL)
+        AND (item_type = 'Custom') = (object_id IS NULL)
+    )
+);
+CREATE INDEX menu_items_menu_id_idx ON menu_items (menu_id);
+-- Theme locations, each showing a menu
+CREATE TABLE IF NOT EXISTS menu_locations (
+    location VARCHAR(100) PRIMARY KEY,
+    menu_id INTEGER NOT NULL REFERENCES menus(id) ON DELETE CASCADE
+);
+INSERT INTO capabilities (name, description)
+VALUES (
+        'edit_theme_options',
+        'Edit the menus and their theme locations'
+    );
+INSERT INTO roles_capabilities (role_id, capability_id)
+SELECT r.id,
+    c.id
+FROM roles r
+    CROSS JOIN capabilities c
+WHERE r.slug = 'administrator'
+    AND c.name = 'edit_theme_options';
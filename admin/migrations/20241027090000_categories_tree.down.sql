@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS categories_parent_id_idx;
+ALTER TABLE categories DROP CONSTRAINT IF EXISTS categories_parent_id_check,
+    DROP CONSTRAINT IF EXISTS categories_parent_id_fkey;
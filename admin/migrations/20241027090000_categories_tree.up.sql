@@ -0,0 +1,44 @@
+-- Add up migration script here
+-- Categories pointing at themselves or at a missing category become
+-- top-level categories
+UPDATE categories
+SET parent_id = NULL
+WHERE parent_id = id
+    OR parent_id NOT IN (
+        SELECT id
+        FROM categories
+    );
+-- Every cycle of the hierarchy is broken at its category of lowest ID
+WITH RECURSIVE ancestors(category_id, ancestor_id, path) AS (
+    SELECT id,
+        parent_id,
+        ARRAY [id]
+    FROM categories
+    WHERE parent_id IS NOT NULL
+    UNION ALL
+    SELECT a.category_id,
+        c.parent_id,
+        a.path || c.id
+    FROM ancestors a
+        JOIN categories c ON c.id = a.ancestor_id
+    WHERE c.parent_id IS NOT NULL
+        AND NOT c.id = ANY(a.path)
+)
+UPDATE categories
+SET parent_id = NULL
+WHERE id IN (
+        SELECT MIN(category_id)
+        FROM ancestors
+        WHERE ancestor_id = category_id
+        GROUP BY (
+                SELECT ARRAY_AGG(
+                        id
+                        ORDER BY id
+                    )
+                FROM UNNEST(path) AS id
+            )
+    );
+ALTER TABLE categories
+ADD CONSTRAINT categories_parent_id_fkey FOREIGN KEY (parent_id) REFERENCES categories(id) ON DELETE SET NULL,
+    ADD CONSTRAINT categories_parent_id_check CHECK (parent_id <> id);
+CREATE INDEX categories_parent_id_idx ON categories (parent_id);
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS tags_slug_key;
+DROP INDEX IF EXISTS categories_slug_key;
+DROP INDEX IF EXISTS posts_slug_key;
@@ -0,0 +1,21 @@
+-- Add up migration script here
+-- Duplicated slugs keep their first row, the others take their ID as suffix
+UPDATE posts p
+SET slug = LEFT(p.slug, 199 - LENGTH(p.id::TEXT)) || '-' || p.id
+FROM posts d
+WHERE d.slug = p.slug
+    AND d.id < p.id;
+UPDATE categories c
+SET slug = LEFT(c.slug, 199 - LENGTH(c.id::TEXT)) || '-' || c.id
+FROM categories d
+WHERE d.slug = c.slug
+    AND d.id < c.id;
+UPDATE tags t
+SET slug = LEFT(t.slug, 199 - LENGTH(t.id::TEXT)) || '-' || t.id
+FROM tags d
+WHERE d.slug = t.slug
+    AND d.id < t.id;
+-- Posts and pages are both served at /{slug}, they share their slugs
+CREATE UNIQUE INDEX posts_slug_key ON posts (slug);
+CREATE UNIQUE INDEX categories_slug_key ON categories (slug);
+CREATE UNIQUE INDEX tags_slug_key ON tags (slug);
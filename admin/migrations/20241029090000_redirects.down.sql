@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS redirects;
+DROP TABLE IF EXISTS slug_history;
@@ -0,0 +1,24 @@
+-- Add up migration script here
+-- Former slugs of the contents, to redirect their old permalinks
+CREATE TABLE slug_history (
+    id SERIAL PRIMARY KEY,
+    post_id INTEGER NULL REFERENCES posts(id) ON DELETE CASCADE,
+    category_id INTEGER NULL REFERENCES categories(id) ON DELETE CASCADE,
+    tag_id INTEGER NULL REFERENCES tags(id) ON DELETE CASCADE,
+    slug VARCHAR(200) NOT NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    CONSTRAINT slug_history_content_check CHECK (
+        num_nonnulls(post_id, category_id, tag_id) = 1
+    )
+);
+CREATE INDEX slug_history_slug_idx ON slug_history (slug);
+-- Redirects managed by hand, from a path of the site to a path or a URL
+CREATE TABLE redirects (
+    id SERIAL PRIMARY KEY,
+    source_path VARCHAR(2000) NOT NULL UNIQUE,
+    target VARCHAR(2000) NOT NULL,
+    status_code INTEGER NOT NULL DEFAULT 301 CHECK (status_code IN (301, 302, 307, 308)),
+    hits INTEGER NOT NULL DEFAULT 0,
+    last_hit TIMESTAMP NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
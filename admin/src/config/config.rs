@@ -1,5 +1,7 @@
 use std::env;
 
+use crate::handlers::image_handler::{parse_image_sizes, ImageSize};
+
 pub fn get_api_url() -> String {
     env::var("API_URL").expect("API_URL must be set")
 }
@@ -25,6 +27,47 @@ pub fn get_cors_allowed_url() -> String {
     env::var("CORS_ALLOWED_URL").expect("CORS_ALLOWED_URL must be set")
 }
 
+pub fn get_scheduled_posts_interval() -> u64 {
+    match env::var("SCHEDULED_POSTS_INTERVAL") {
+        Ok(interval_str) => match interval_str.parse::<u64>() {
+            Ok(interval) if interval > 0 => interval,
+            _ => panic!("SCHEDULED_POSTS_INTERVAL must be a positive u64"),
+        },
+        Err(_) => 60,
+    }
+}
+
+pub fn get_media_dir() -> String {
+    env::var("MEDIA_DIR").unwrap_or_else(|_| String::from("uploads"))
+}
+
+pub fn get_media_url() -> String {
+    env::var("MEDIA_URL").unwrap_or_else(|_| {
+        format!("http://{}:{}/uploads", get_api_url(), get_api_port())
+    })
+}
+
+pub fn get_media_max_size() -> usize {
+    match env::var("MEDIA_MAX_SIZE") {
+        Ok(size_str) => match size_str.parse::<usize>() {
+            Ok(size) if size > 0 => size,
+            _ => panic!("MEDIA_MAX_SIZE must be a positive usize"),
+        },
+        Err(_) => 10 * 1024 * 1024,
+    }
+}
+
+pub fn get_media_sizes() -> Vec<ImageSize> {
+    let sizes = env::var("MEDIA_SIZES").unwrap_or_else(|_| {
+        String::from("thumbnail=150x150:crop,medium=300x300,large=1024x1024")
+    });
+
+    match parse_image_sizes(&sizes) {
+        Ok(sizes) => sizes,
+        Err(e) => panic!("MEDIA_SIZES must be a valid list of sizes: {}", e),
+    }
+}
+
 pub fn get_backtrace() -> u16 {
     let backtrace_str =
         env::var("RUST_BACKTRACE").expect("RUST_BACKTRACE must be set");
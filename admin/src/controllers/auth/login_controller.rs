@@ -7,7 +7,9 @@ use sqlx::PgPool;
 
 use crate::{
     dtos::auth_dtos::LoginRequestDTO,
-    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    handlers::error_to_response_handler::{
+        convert_anyhow_to_ntex, UnauthorizedError,
+    },
     services::auth_service::login_service,
 };
 
@@ -29,7 +31,12 @@ pub async fn login_controller(
 ) -> Result<HttpResponse, web::Error> {
     match login_service(pool.get_ref(), &login.email, &login.password).await {
         Ok(Some(token)) => Ok(HttpResponse::Ok().json(&token)),
-        Ok(None) => Ok(HttpResponse::Unauthorized().finish()),
+        Ok(None) => Err(convert_anyhow_to_ntex(
+            UnauthorizedError {
+                message: String::from("Invalid credentials"),
+            }
+            .into(),
+        )),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
 }
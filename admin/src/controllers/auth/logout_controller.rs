@@ -0,0 +1,93 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::{ClaimsDTO, LogoutRequestDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::auth_service::logout_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/auth/logout",
+    tag = "Auth",
+    request_body = LogoutRequestDTO,
+    responses(
+        (status = 204, description = "Logout successful"),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/auth/logout")]
+pub async fn logout_controller(
+    pool: State<PgPool>,
+    claims: ClaimsDTO,
+    logout: Json<LogoutRequestDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match logout_service(
+        pool.get_ref(),
+        &claims,
+        logout.refresh_token.as_deref(),
+    )
+    .await
+    {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
+
+    #[ntex::test]
+    async fn test_logout_revokes_access_token() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "contributor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(logout_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/auth/logout")
+            .set_json(&LogoutRequestDTO {
+                refresh_token: None,
+            })
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let req = test::TestRequest::post()
+            .uri("/auth/logout")
+            .set_json(&LogoutRequestDTO {
+                refresh_token: None,
+            })
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+
+        let body = test::read_body(resp).await;
+        let error: serde_json::Value =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(error["message"], "Token has been revoked");
+    }
+}
@@ -1 +1,3 @@
 pub mod login_controller;
+pub mod logout_controller;
+pub mod refresh_controller;
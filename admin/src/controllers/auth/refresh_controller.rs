@@ -0,0 +1,150 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::RefreshTokenRequestDTO,
+    handlers::error_to_response_handler::{
+        convert_anyhow_to_ntex, UnauthorizedError,
+    },
+    services::auth_service::refresh_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/auth/refresh",
+    tag = "Auth",
+    request_body = RefreshTokenRequestDTO,
+    responses(
+        (status = 200, description = "Tokens refreshed", body = TokenDTO),
+        (status = 401, description = "Invalid or expired refresh token", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/auth/refresh")]
+pub async fn refresh_controller(
+    pool: State<PgPool>,
+    refresh: Json<RefreshTokenRequestDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match refresh_service(pool.get_ref(), &refresh.refresh_token).await {
+        Ok(Some(token)) => Ok(HttpResponse::Ok().json(&token)),
+        Ok(None) => Err(convert_anyhow_to_ntex(
+            UnauthorizedError {
+                message: String::from("Invalid or expired refresh token"),
+            }
+            .into(),
+        )),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::Value;
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+    use crate::dtos::auth_dtos::TokenDTO;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_refresh_rotates_refresh_token() {
+        // Arrange
+        let pool = setup_test_db().await;
+        setup_test_auth(&pool, "author").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(refresh_controller),
+        )
+        .await;
+
+        let refresh_token = "test-refresh-rotates-refresh-token";
+        let token_hash = hex::encode(Sha256::digest(refresh_token.as_bytes()));
+        clean_data_test(&pool, "refresh_tokens", "token_hash", &token_hash)
+            .await
+            .expect("Failed to clean up test data");
+        sqlx::query(
+            "INSERT INTO refresh_tokens (user_id, token_hash, expires_at) \
+            SELECT id, $1, NOW() + INTERVAL '1 day' FROM users \
+            WHERE username = 'test-author'",
+        )
+        .bind(&token_hash)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test data");
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/auth/refresh")
+            .set_json(&RefreshTokenRequestDTO {
+                refresh_token: refresh_token.to_string(),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let token: TokenDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_ne!(token.refresh_token, refresh_token);
+        assert_eq!(token.expires_in, 900);
+
+        // The rotated token cannot be used twice
+        let req = test::TestRequest::post()
+            .uri("/auth/refresh")
+            .set_json(&RefreshTokenRequestDTO {
+                refresh_token: refresh_token.to_string(),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+
+        // Reusing it revoked the whole token family
+        let req = test::TestRequest::post()
+            .uri("/auth/refresh")
+            .set_json(&RefreshTokenRequestDTO {
+                refresh_token: token.refresh_token,
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[ntex::test]
+    async fn test_refresh_unknown_token() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(refresh_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/auth/refresh")
+            .set_json(&RefreshTokenRequestDTO {
+                refresh_token: String::from("unknown-refresh-token"),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+        let body = test::read_body(resp).await;
+        let body: Value =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(body["message"], "Invalid or expired refresh token");
+    }
+}
@@ -0,0 +1,99 @@
+use ntex::web::{self, types::State, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::capabilities_service::get_all_capabilities_service,
+};
+
+#[utoipa::path(
+  get,
+  path = "/capabilities",
+  tag = "Capabilities",
+  responses(
+    (status = 200, description = "Get all capabilities", body = [CapabilityDTO]),
+    (status = 401, description = "Unauthorized", body = Error),
+    (status = 403, description = "Forbidden", body = Error),
+    (status = 500, description = "Internal Server Error", body = Error)
+  ),
+)]
+#[web::get("/capabilities")]
+pub async fn get_all_capabilities_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageRoles)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match get_all_capabilities_service(pool.get_ref()).await {
+        Ok(capabilities) => Ok(HttpResponse::Ok().json(&capabilities)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::capability_dto::CapabilityDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
+
+    #[ntex::test]
+    async fn test_get_all_capabilities_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_all_capabilities_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/capabilities")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let capabilities: Vec<CapabilityDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert!(capabilities.iter().any(|c| c.name == "manage_roles"));
+    }
+
+    #[ntex::test]
+    async fn test_get_all_capabilities_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "contributor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_all_capabilities_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/capabilities")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
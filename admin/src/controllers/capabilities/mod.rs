@@ -0,0 +1 @@
+pub mod get_all_capabilities_controller;
@@ -2,8 +2,10 @@ use ntex::web::types::{Json, State};
 use ntex::web::{self, HttpResponse};
 use sqlx::PgPool;
 
+use crate::dtos::auth_dtos::CurrentUserDTO;
 use crate::dtos::category_dto::CreateCategoryDTO;
 use crate::handlers::error_to_response_handler::convert_anyhow_to_ntex;
+use crate::models::capabilities_model::Capability;
 use crate::services::categories_service::create_category_service;
 
 #[utoipa::path(
@@ -14,14 +16,21 @@ use crate::services::categories_service::create_category_service;
     responses(
         (status = 201, description = "Category created successfully", body = CategoryDTO),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     ),
 )]
 #[web::post("/categories")]
 pub async fn create_category_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     category_dto: Json<CreateCategoryDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageCategories)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match create_category_service(pool.get_ref(), category_dto.into_inner())
         .await
     {
@@ -39,23 +48,26 @@ mod tests {
 
     use super::*;
     use crate::dtos::category_dto::CreateCategoryDTO;
-    use crate::tests::helpers::setup::setup_test_db;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
 
     #[ntex::test]
     async fn test_create_category_success() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(create_category_controller),
         )
         .await;
 
         let category = CreateCategoryDTO {
-            parent_id: Some(10),
+            parent_id: None,
             name: String::from("New Category"),
-            slug: String::from("new-category"),
+            slug: Some(String::from("new-category")),
             description: Some(String::from("New category description")),
         };
 
@@ -63,6 +75,7 @@ mod tests {
         let req = test::TestRequest::post()
             .uri("/categories")
             .set_json(&category)
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -85,17 +98,19 @@ mod tests {
     async fn test_create_category_validation_failure() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(create_category_controller),
         )
         .await;
 
         let category = CreateCategoryDTO {
-            parent_id: Some(10),
+            parent_id: None,
             name: String::new(), // Empty name, should trigger validation failure
-            slug: String::from("new-category"),
+            slug: Some(String::from("new-category")),
             description: Some(String::from("New category description")),
         };
 
@@ -103,11 +118,48 @@ mod tests {
         let req = test::TestRequest::post()
             .uri("/categories")
             .set_json(&category)
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[ntex::test]
+    async fn test_create_category_unknown_parent() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_category_controller),
+        )
+        .await;
+
+        let category = CreateCategoryDTO {
+            parent_id: Some(i32::MAX),
+            name: String::from("Orphan Category"),
+            slug: Some(String::from("orphan-category")),
+            description: None,
+        };
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/categories")
+            .set_json(&category)
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
         // Assert
         assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+        let body: serde_json::Value =
+            serde_json::from_slice(&test::read_body(resp).await)
+                .expect("Failed to parse the response");
+        assert!(body.to_string().contains("Unknown category in parent_id"));
     }
 
     #[ntex::test]
@@ -122,9 +174,9 @@ mod tests {
         .await;
 
         let category = CreateCategoryDTO {
-            parent_id: Some(10),
+            parent_id: None,
             name: String::from("New Category"),
-            slug: String::from("new-category"),
+            slug: Some(String::from("new-category")),
             description: Some(String::from("New category description")),
         };
 
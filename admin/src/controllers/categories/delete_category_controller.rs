@@ -7,8 +7,9 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::category_dto::DeleteCategoryIdsDTO,
+    dtos::{auth_dtos::CurrentUserDTO, category_dto::DeleteCategoryIdsDTO},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::categories_service::delete_category_service,
 };
 
@@ -20,14 +21,21 @@ use crate::{
     responses(
         (status = 200, description = "Categories deleted", body = i32),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::delete("/categories")]
 pub async fn delete_category_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     delete_category_ids_dto: Json<DeleteCategoryIdsDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageCategories)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match delete_category_service(
         pool.get_ref(),
         delete_category_ids_dto.into_inner(),
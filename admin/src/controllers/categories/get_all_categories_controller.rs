@@ -6,8 +6,12 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::pagination_dto::PaginationParamsDTO,
+    dtos::{
+        auth_dtos::CurrentUserDTO, category_dto::CategoryFiltersDTO,
+        pagination_dto::PaginationParamsDTO,
+    },
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::categories_service::get_all_categories_service,
 };
 
@@ -19,21 +23,30 @@ use crate::{
     ("page" = Option<i32>, Query, description = "The page number for pagination"),
     ("limit" = Option<i32>, Query, description = "The number of items per page"),
     ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'id', 'name')"),
-    ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')")
+    ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')"),
+    CategoryFiltersDTO
   ),
   security(
     ("api_key" = [])
 ),
   responses(
     (status = 200, description = "Get all categories", body = CategoryDTO),
+    (status = 401, description = "Unauthorized", body = Error),
+    (status = 403, description = "Forbidden", body = Error),
     (status = 500, description = "Internal Server Error", body = Error)
   ),
 )]
 #[web::get("/categories")]
 pub async fn get_all_categories_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     params: Query<PaginationParamsDTO>,
+    filters: Query<CategoryFiltersDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::Read)
+        .map_err(convert_anyhow_to_ntex)?;
+
     let page = params.page.unwrap_or(1);
     let limit = params.limit.unwrap_or(25);
     let sort_column = params.sort_column.as_deref().unwrap_or("id");
@@ -41,6 +54,7 @@ pub async fn get_all_categories_controller(
 
     match get_all_categories_service(
         pool.get_ref(),
+        &filters,
         page,
         limit,
         sort_column,
@@ -56,8 +70,13 @@ pub async fn get_all_categories_controller(
 #[cfg(test)]
 mod tests {
     use crate::controllers::categories::get_all_categories_controller::get_all_categories_controller;
-    use crate::dtos::category_dto::CategoryDTO;
-    use crate::tests::helpers::setup::setup_test_db;
+    use crate::dtos::{
+        category_dto::CategoryDTO, pagination_dto::PaginationDTO,
+    };
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
     use ntex::http;
     use ntex::web::{self, test};
 
@@ -65,13 +84,18 @@ mod tests {
     async fn test_get_all_categories_controller_success() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(get_all_categories_controller),
         )
         .await;
 
+        clean_data_test(&pool, "categories", "slug", "test-category")
+            .await
+            .expect("Failed to clean up test data");
         sqlx::query!(
             r#"
             INSERT INTO categories (parent_id, name, slug, description) 
@@ -87,18 +111,22 @@ mod tests {
         .expect("Failed to insert test data");
 
         // Act
-        let req = test::TestRequest::get().uri("/categories").to_request();
+        let req = test::TestRequest::get()
+            .uri("/categories")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
         let resp = test::call_service(&app, req).await;
 
         // Assert
         assert_eq!(resp.status(), http::StatusCode::OK);
 
         let body = test::read_body(resp).await;
-        let response_body: Vec<CategoryDTO> =
+        let response_body: PaginationDTO<CategoryDTO> =
             serde_json::from_slice(&body).expect("Failed to parse JSON");
 
-        let category = response_body.iter().find(|&cat| {
-            cat.name == "Test Category" && cat.slug == "test-category"
+        let category = response_body.data.iter().find(|&cat| {
+            cat.name == "Test Category"
+                && cat.slug.as_deref() == Some("test-category")
         });
 
         assert!(category.is_some(), "Category not found in the response");
@@ -0,0 +1,113 @@
+use anyhow::Result;
+use ntex::web::{self, types::State, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::categories_service::get_categories_tree_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/categories/tree",
+    tag = "Categories",
+    responses(
+        (status = 200, description = "Tree of categories retrieved", body = Vec<CategoryTreeDTO>),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/categories/tree")]
+pub async fn get_categories_tree_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::Read)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match get_categories_tree_service(pool.get_ref()).await {
+        Ok(categories) => Ok(HttpResponse::Ok().json(&categories)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::controllers::categories::get_categories_tree_controller::get_categories_tree_controller;
+    use crate::dtos::category_dto::CategoryTreeDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    #[ntex::test]
+    async fn test_get_categories_tree_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_categories_tree_controller),
+        )
+        .await;
+
+        let parent_id: i32 = sqlx::query_scalar(
+            r#"
+            INSERT INTO categories (name, slug)
+            VALUES ('Tree Parent', 'tree-parent')
+            RETURNING id
+            "#,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test data");
+        sqlx::query(
+            r#"
+            INSERT INTO categories (parent_id, name, slug)
+            VALUES ($1, 'Tree Child', 'tree-child')
+            "#,
+        )
+        .bind(parent_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test data");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/categories/tree")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let response_body: Vec<CategoryTreeDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+
+        let parent = response_body
+            .iter()
+            .find(|category| category.id == parent_id)
+            .expect("Category not found in the response");
+        assert_eq!(parent.children.len(), 1);
+        assert_eq!(parent.children[0].slug, "tree-child");
+        assert_eq!(parent.children[0].post_count, 0);
+
+        // Clean Data
+        sqlx::query(
+            r#"
+            DELETE FROM categories WHERE slug IN ('tree-parent', 'tree-child')
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to clean up test data");
+    }
+}
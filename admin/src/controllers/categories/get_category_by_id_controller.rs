@@ -7,7 +7,9 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::categories_service::get_category_by_id_service,
 };
 
@@ -21,14 +23,21 @@ use crate::{
     responses(
         (status = 200, description = "Category retrieved", body = CategoryDTO),
         (status = 404, description = "Category not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::get("/categories/{id}")]
 pub async fn get_category_by_id_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     category_id: Path<i32>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::Read)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match get_category_by_id_service(pool.get_ref(), category_id.into_inner())
         .await
     {
@@ -0,0 +1,47 @@
+use anyhow::Result;
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::categories_service::get_category_path_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/categories/{id}/path",
+    tag = "Categories",
+    params(
+        ("id" = i32, description = "ID of the category")
+    ),
+    responses(
+        (status = 200, description = "Breadcrumb path of the category, from its top-level category", body = Vec<CategoryDTO>),
+        (status = 404, description = "Category not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/categories/{id}/path")]
+pub async fn get_category_path_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    category_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::Read)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match get_category_path_service(pool.get_ref(), category_id.into_inner())
+        .await
+    {
+        Ok(categories) => Ok(HttpResponse::Ok().json(&categories)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
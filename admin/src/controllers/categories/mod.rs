@@ -1,5 +1,7 @@
 pub mod create_category_controller;
 pub mod delete_category_controller;
 pub mod get_all_categories_controller;
+pub mod get_categories_tree_controller;
 pub mod get_category_by_id_controller;
+pub mod get_category_path_controller;
 pub mod update_category_controller;
@@ -7,8 +7,12 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::category_dto::{CategoryDTO, CreateCategoryDTO},
+    dtos::{
+        auth_dtos::CurrentUserDTO,
+        category_dto::{CategoryDTO, CreateCategoryDTO},
+    },
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::categories_service::update_category_service,
 };
 
@@ -24,15 +28,22 @@ use crate::{
         (status = 200, description = "Category updated", body = CategoryDTO),
         (status = 400, description = "Validation Error", body = Error),
         (status = 404, description = "Category not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::put("/categories/{id}")]
 pub async fn update_category_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     category_id: Path<i32>,
     category_dto: Json<CreateCategoryDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageCategories)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match update_category_service(
         pool.get_ref(),
         category_id.into_inner(),
@@ -0,0 +1,191 @@
+use ntex::web::{
+    self,
+    types::{Json, Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::comment_dto::CreateCommentDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::comments_service::create_comment_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/posts/{id}/comments",
+    tag = "Comments",
+    params(
+        ("id" = i32, description = "ID of the commented post")
+    ),
+    request_body = CreateCommentDTO,
+    responses(
+        (status = 201, description = "Comment held for moderation", body = CommentDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 403, description = "Comments are closed", body = Error),
+        (status = 404, description = "Post not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/posts/{id}/comments")]
+pub async fn create_comment_controller(
+    pool: State<PgPool>,
+    post_id: Path<i32>,
+    comment: Json<CreateCommentDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match create_comment_service(
+        pool.get_ref(),
+        post_id.into_inner(),
+        comment.into_inner(),
+    )
+    .await
+    {
+        Ok(comment) => Ok(HttpResponse::Created().json(&comment)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::dtos::comment_dto::CommentDTO;
+    use crate::models::{
+        comments_model::CommentsStatus, posts_model::PostsStatus,
+    };
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    /// Inserts a post of the test editor, returning its ID.
+    async fn insert_post(
+        pool: &PgPool,
+        slug: &str,
+        status: PostsStatus,
+    ) -> i32 {
+        setup_test_auth(pool, "editor").await;
+        clean_data_test(pool, "posts", "slug", slug)
+            .await
+            .expect("Failed to clean up test data");
+
+        sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            SELECT 'Test Comment', 'Content', $1, id, $2::posts_status
+            FROM users WHERE username = 'test-editor'
+            RETURNING id
+            "#,
+            slug,
+            status as _,
+        )
+        .fetch_one(pool)
+        .await
+        .expect("Failed to insert test post")
+    }
+
+    #[ntex::test]
+    async fn test_create_comment_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(create_comment_controller),
+        )
+        .await;
+
+        let post_id =
+            insert_post(&pool, "test-create-comment", PostsStatus::Published)
+                .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri(&format!("/posts/{}/comments", post_id))
+            .set_json(&json!({
+                "author_name": "Reader",
+                "author_email": "reader@rustpress.test",
+                "content": "Nice post!"
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let body = test::read_body(resp).await;
+        let comment: CommentDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(comment.post_id, post_id);
+        assert_eq!(comment.status, CommentsStatus::Pending);
+        assert_eq!(comment.user_id, None);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-create-comment")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_create_comment_controller_unpublished_post() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(create_comment_controller),
+        )
+        .await;
+
+        let post_id =
+            insert_post(&pool, "test-create-comment-draft", PostsStatus::Draft)
+                .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri(&format!("/posts/{}/comments", post_id))
+            .set_json(&json!({
+                "author_name": "Reader",
+                "author_email": "reader@rustpress.test",
+                "content": "Nice post!"
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-create-comment-draft")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_create_comment_controller_invalid_email() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(create_comment_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/posts/1/comments")
+            .set_json(&json!({
+                "author_name": "Reader",
+                "author_email": "not-an-email",
+                "content": "Nice post!"
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,141 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, comment_dto::DeleteCommentIdsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::comments_service::delete_comments_service,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/comments",
+    tag = "Comments",
+    request_body = DeleteCommentIdsDTO,
+    responses(
+        (status = 200, description = "Comments deleted", body = [i32]),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/comments")]
+pub async fn delete_comments_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    comment_ids: Json<DeleteCommentIdsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ModerateComments)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match delete_comments_service(pool.get_ref(), comment_ids.into_inner())
+        .await
+    {
+        Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_delete_comments_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(delete_comments_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-delete-comments")
+            .await
+            .expect("Failed to clean up test data");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            SELECT 'Test Delete Comments', 'Content',
+                'test-delete-comments', id, $1::posts_status
+            FROM users WHERE username = 'test-editor'
+            RETURNING id
+            "#,
+            PostsStatus::Published as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        let parent_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO comments (post_id, author_name, author_email, content)
+            VALUES ($1, 'Reader', 'reader@rustpress.test', 'Question?')
+            RETURNING id
+            "#,
+            post_id,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test comment");
+
+        sqlx::query!(
+            r#"
+            INSERT INTO comments
+                (post_id, parent_id, author_name, author_email, content)
+            VALUES ($1, $2, 'Reader', 'reader@rustpress.test', 'Answer.')
+            "#,
+            post_id,
+            parent_id,
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test reply");
+
+        // Act
+        let req = test::TestRequest::delete()
+            .uri("/comments")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "ids": [parent_id] }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        // The replies are deleted with the comment
+        let remaining: Option<i64> = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM comments WHERE post_id = $1",
+            post_id
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test comments");
+        assert_eq!(remaining, Some(0));
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-delete-comments")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,174 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{
+        auth_dtos::CurrentUserDTO, comment_dto::CommentFiltersDTO,
+        pagination_dto::PaginationParamsDTO,
+    },
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::comments_service::get_all_comments_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/comments",
+    tag = "Comments",
+    params(
+        ("page" = Option<i32>, Query, description = "The page number for pagination"),
+        ("limit" = Option<i32>, Query, description = "The number of items per page"),
+        ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'id', 'date_created')"),
+        ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')"),
+        CommentFiltersDTO
+    ),
+    responses(
+        (status = 200, description = "Get all comments", body = [CommentDTO]),
+        (status = 400, description = "Bad Request", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/comments")]
+pub async fn get_all_comments_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    params: Query<PaginationParamsDTO>,
+    filters: Query<CommentFiltersDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ModerateComments)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(25);
+    let sort_column = params.sort_column.as_deref().unwrap_or("id");
+    let sort_order = params.sort_order.as_deref().unwrap_or("desc");
+
+    match get_all_comments_service(
+        pool.get_ref(),
+        &filters,
+        page,
+        limit,
+        sort_column,
+        sort_order,
+    )
+    .await
+    {
+        Ok(comments) => Ok(HttpResponse::Ok().json(&comments)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::{comment_dto::CommentDTO, pagination_dto::PaginationDTO};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::{
+        comments_model::CommentsStatus, posts_model::PostsStatus,
+    };
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_get_all_comments_controller_filters() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_all_comments_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-get-all-comments")
+            .await
+            .expect("Failed to clean up test data");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            SELECT 'Test Get All Comments', 'Content',
+                'test-get-all-comments', id, $1::posts_status
+            FROM users WHERE username = 'test-editor'
+            RETURNING id
+            "#,
+            PostsStatus::Published as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        sqlx::query!(
+            r#"
+            INSERT INTO comments
+                (post_id, author_name, author_email, content, status)
+            VALUES
+                ($1, 'Reader', 'reader@rustpress.test', 'First', 'Pending'),
+                ($1, 'Reader', 'reader@rustpress.test', 'Second', 'Spam')
+            "#,
+            post_id,
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test comments");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri(&format!("/comments?post_id={}&status=Pending", post_id))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let comments: PaginationDTO<CommentDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(comments.total_items, 1);
+        assert_eq!(comments.data[0].content, "First");
+        assert_eq!(comments.data[0].status, CommentsStatus::Pending);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-get-all-comments")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_get_all_comments_controller_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "author").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_all_comments_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/comments")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
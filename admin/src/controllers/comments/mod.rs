@@ -0,0 +1,5 @@
+pub mod create_comment_controller;
+pub mod delete_comments_controller;
+pub mod get_all_comments_controller;
+pub mod reply_comment_controller;
+pub mod update_comments_status_controller;
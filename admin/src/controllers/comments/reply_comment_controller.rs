@@ -0,0 +1,163 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, comment_dto::ReplyCommentDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::comments_service::reply_comment_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/comments",
+    tag = "Comments",
+    request_body = ReplyCommentDTO,
+    responses(
+        (status = 201, description = "Reply created", body = CommentDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Post not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/comments")]
+pub async fn reply_comment_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    reply: Json<ReplyCommentDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ModerateComments)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match reply_comment_service(
+        pool.get_ref(),
+        &current_user,
+        reply.into_inner(),
+    )
+    .await
+    {
+        Ok(comment) => Ok(HttpResponse::Created().json(&comment)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::dtos::comment_dto::CommentDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::{
+        comments_model::CommentsStatus, posts_model::PostsStatus,
+    };
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_reply_comment_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(reply_comment_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-reply-comment")
+            .await
+            .expect("Failed to clean up test data");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            SELECT 'Test Reply Comment', 'Content', 'test-reply-comment', id,
+                $1::posts_status
+            FROM users WHERE username = 'test-editor'
+            RETURNING id
+            "#,
+            PostsStatus::Published as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        let parent_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO comments (post_id, author_name, author_email, content)
+            VALUES ($1, 'Reader', 'reader@rustpress.test', 'Question?')
+            RETURNING id
+            "#,
+            post_id,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test comment");
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/comments")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "post_id": post_id,
+                "parent_id": parent_id,
+                "content": "Answer."
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let body = test::read_body(resp).await;
+        let comment: CommentDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(comment.parent_id, Some(parent_id));
+        assert_eq!(comment.status, CommentsStatus::Approved);
+        assert!(comment.user_id.is_some());
+        assert_eq!(comment.author_email, "test-editor@rustpress.test");
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-reply-comment")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_reply_comment_controller_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "author").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(reply_comment_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/comments")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "post_id": 1, "content": "Answer." }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
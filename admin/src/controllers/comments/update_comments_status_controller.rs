@@ -0,0 +1,138 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, comment_dto::UpdateCommentsStatusDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::comments_service::update_comments_status_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/comments/status",
+    tag = "Comments",
+    request_body = UpdateCommentsStatusDTO,
+    responses(
+        (status = 200, description = "Comments moderated", body = [i32]),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/comments/status")]
+pub async fn update_comments_status_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    update_status: Json<UpdateCommentsStatusDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ModerateComments)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match update_comments_status_service(
+        pool.get_ref(),
+        update_status.into_inner(),
+    )
+    .await
+    {
+        Ok(updated_ids) => Ok(HttpResponse::Ok().json(&updated_ids)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::posts_model::PostsStatus;
+    use crate::repositories::posts_repository::select_post_by_id;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_update_comments_status_controller_approve() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_comments_status_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-approve-comments")
+            .await
+            .expect("Failed to clean up test data");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            SELECT 'Test Approve Comments', 'Content',
+                'test-approve-comments', id, $1::posts_status
+            FROM users WHERE username = 'test-editor'
+            RETURNING id
+            "#,
+            PostsStatus::Published as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        let comment_ids: Vec<i32> = sqlx::query_scalar!(
+            r#"
+            INSERT INTO comments (post_id, author_name, author_email, content)
+            VALUES
+                ($1, 'Reader', 'reader@rustpress.test', 'First'),
+                ($1, 'Reader', 'reader@rustpress.test', 'Second')
+            RETURNING id
+            "#,
+            post_id,
+        )
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to insert test comments");
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/comments/status")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "ids": [comment_ids[0]],
+                "status": "Approved"
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let updated_ids: Vec<i32> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(updated_ids, vec![comment_ids[0]]);
+
+        // Only the approved comment is counted
+        let post = select_post_by_id(&pool, post_id)
+            .await
+            .expect("Failed to query test post");
+        assert_eq!(post.comment_count, Some(1));
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-approve-comments")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
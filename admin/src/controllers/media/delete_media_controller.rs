@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, media_dto::DeleteMediaIdsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::media_service::delete_media_service,
+    storage::StorageBackend,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/media",
+    tag = "Media",
+    request_body = DeleteMediaIdsDTO,
+    responses(
+        (status = 200, description = "Media deleted", body = [i32]),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/media")]
+pub async fn delete_media_controller(
+    pool: State<PgPool>,
+    storage: State<Arc<dyn StorageBackend>>,
+    current_user: CurrentUserDTO,
+    media_ids: Json<DeleteMediaIdsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::UploadFiles)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match delete_media_service(
+        pool.get_ref(),
+        storage.get_ref().as_ref(),
+        &current_user,
+        media_ids.into_inner(),
+    )
+    .await
+    {
+        Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db, setup_test_storage,
+    };
+
+    #[ntex::test]
+    async fn test_delete_media_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let storage = setup_test_storage();
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .state(storage.clone())
+                .service(delete_media_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-delete-media")
+            .await
+            .expect("Failed to clean up test data");
+
+        let path = "test/test-delete-media.png";
+        storage
+            .save(path, b"\x89PNG\r\n\x1a\n")
+            .await
+            .expect("Failed to save test file");
+
+        let media_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO media (author_id, filename, path, mime_type, size)
+            SELECT id, 'test-delete-media.png', $1, 'image/png', 8
+            FROM users WHERE username = 'test-editor'
+            RETURNING id
+            "#,
+            path,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test media");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts
+                (title, content, slug, author_id, status, featured_media_id)
+            SELECT 'Test Delete Media', 'Content', 'test-delete-media', id,
+                $1::posts_status, $2
+            FROM users WHERE username = 'test-editor'
+            RETURNING id
+            "#,
+            PostsStatus::Draft as _,
+            media_id,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        // Act
+        let req = test::TestRequest::delete()
+            .uri("/media")
+            .set_json(&json!({ "ids": [media_id] }))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let deleted_ids: Vec<i32> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(deleted_ids, vec![media_id]);
+
+        let file = std::env::temp_dir().join("rustpress-test-media").join(path);
+        assert!(!file.exists(), "File was not deleted");
+
+        let featured_media_id: Option<i32> = sqlx::query_scalar!(
+            "SELECT featured_media_id FROM posts WHERE id = $1",
+            post_id
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test post");
+        assert_eq!(featured_media_id, None);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-delete-media")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, pagination_dto::PaginationParamsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::media_service::get_all_media_service,
+    storage::StorageBackend,
+};
+
+#[utoipa::path(
+  get,
+  path = "/media",
+  tag = "Media",
+  params(
+    ("page" = Option<i32>, Query, description = "The page number for pagination"),
+    ("limit" = Option<i32>, Query, description = "The number of items per page"),
+    ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'id', 'filename')"),
+    ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')")
+  ),
+  responses(
+    (status = 200, description = "Get the media library", body = [MediaDTO]),
+    (status = 400, description = "Invalid sort parameters", body = Error),
+    (status = 401, description = "Unauthorized", body = Error),
+    (status = 403, description = "Forbidden", body = Error),
+    (status = 500, description = "Internal Server Error", body = Error)
+  ),
+)]
+#[web::get("/media")]
+pub async fn get_all_media_controller(
+    pool: State<PgPool>,
+    storage: State<Arc<dyn StorageBackend>>,
+    current_user: CurrentUserDTO,
+    params: Query<PaginationParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::UploadFiles)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(25);
+    let sort_column = params.sort_column.as_deref().unwrap_or("id");
+    let sort_order = params.sort_order.as_deref().unwrap_or("desc");
+
+    match get_all_media_service(
+        pool.get_ref(),
+        storage.get_ref().as_ref(),
+        page,
+        limit,
+        sort_column,
+        sort_order,
+    )
+    .await
+    {
+        Ok(media) => Ok(HttpResponse::Ok().json(&media)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::{media_dto::MediaDTO, pagination_dto::PaginationDTO};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db, setup_test_storage,
+    };
+
+    #[ntex::test]
+    async fn test_get_all_media_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .state(setup_test_storage())
+                .service(get_all_media_controller),
+        )
+        .await;
+
+        let media_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO media (author_id, filename, path, mime_type, size)
+            SELECT id, $1, $2, 'image/png', 42
+            FROM users WHERE username = 'test-editor'
+            RETURNING id
+            "#,
+            "test-get-all-media.png",
+            "test/test-get-all-media.png",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test media");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/media?sort_column=id&sort_order=desc")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let response_body: PaginationDTO<MediaDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        let media = response_body
+            .data
+            .iter()
+            .find(|media| media.id == media_id)
+            .expect("Media not found in the response");
+        assert_eq!(
+            media.url,
+            "http://127.0.0.1/uploads/test/test-get-all-media.png"
+        );
+
+        // Clean up test data
+        clean_data_test(&pool, "media", "filename", "test-get-all-media.png")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_get_all_media_controller_invalid_sort_column() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .state(setup_test_storage())
+                .service(get_all_media_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/media?sort_column=path")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::media_service::get_media_by_id_service, storage::StorageBackend,
+};
+
+#[utoipa::path(
+    get,
+    path = "/media/{id}",
+    tag = "Media",
+    params(
+        ("id" = i32, description = "ID of the media")
+    ),
+    responses(
+        (status = 200, description = "Media retrieved", body = MediaDTO),
+        (status = 404, description = "Media not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/media/{id}")]
+pub async fn get_media_by_id_controller(
+    pool: State<PgPool>,
+    storage: State<Arc<dyn StorageBackend>>,
+    current_user: CurrentUserDTO,
+    media_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::UploadFiles)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match get_media_by_id_service(
+        pool.get_ref(),
+        storage.get_ref().as_ref(),
+        media_id.into_inner(),
+    )
+    .await
+    {
+        Ok(media) => Ok(HttpResponse::Ok().json(&media)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        setup_test_auth, setup_test_db, setup_test_storage,
+    };
+
+    #[ntex::test]
+    async fn test_get_media_by_id_controller_not_found() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .state(setup_test_storage())
+                .service(get_media_by_id_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/media/2147483647")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    #[ntex::test]
+    async fn test_get_media_by_id_controller_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "contributor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .state(setup_test_storage())
+                .service(get_media_by_id_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/media/1")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
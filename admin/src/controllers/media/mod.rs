@@ -0,0 +1,5 @@
+pub mod delete_media_controller;
+pub mod get_all_media_controller;
+pub mod get_media_by_id_controller;
+pub mod regenerate_media_controller;
+pub mod upload_media_controller;
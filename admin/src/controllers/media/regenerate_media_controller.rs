@@ -0,0 +1,263 @@
+use std::sync::Arc;
+
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    config::config::get_media_sizes,
+    dtos::{auth_dtos::CurrentUserDTO, media_dto::RegenerateMediaDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::media_service::regenerate_media_sizes_service,
+    storage::StorageBackend,
+};
+
+#[utoipa::path(
+    post,
+    path = "/media/regenerate",
+    tag = "Media",
+    request_body = RegenerateMediaDTO,
+    responses(
+        (status = 200, description = "Image sizes regenerated", body = [MediaDTO]),
+        (status = 400, description = "Invalid image", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/media/regenerate")]
+pub async fn regenerate_media_controller(
+    pool: State<PgPool>,
+    storage: State<Arc<dyn StorageBackend>>,
+    current_user: CurrentUserDTO,
+    regenerate_media_dto: Json<RegenerateMediaDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match regenerate_media_sizes_service(
+        pool.get_ref(),
+        storage.get_ref().as_ref(),
+        &get_media_sizes(),
+        regenerate_media_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(media) => Ok(HttpResponse::Ok().json(&media)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::dtos::media_dto::MediaDTO;
+    use crate::handlers::image_handler::parse_image_sizes;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::repositories::media_repository::insert_media;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db, setup_test_storage,
+    };
+
+    #[ntex::test]
+    async fn test_regenerate_media_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let storage = setup_test_storage();
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .state(storage.clone())
+                .service(regenerate_media_controller),
+        )
+        .await;
+
+        // An image uploaded before the sizes were defined
+        let mut data = std::io::Cursor::new(Vec::new());
+        image::RgbImage::new(300, 600)
+            .write_to(&mut data, image::ImageFormat::Png)
+            .expect("Failed to encode test image");
+        let path = "test-regenerate-media.png";
+        storage
+            .save(path, data.get_ref())
+            .await
+            .expect("Failed to save test file");
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+        let media = insert_media(
+            &pool,
+            author_id,
+            "test-regenerate-media.png",
+            path,
+            "image/png",
+            data.get_ref().len() as i64,
+            None,
+        )
+        .await
+        .expect("Failed to insert test media");
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/media/regenerate")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "ids": [media.id] }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let regenerated: Vec<MediaDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(regenerated.len(), 1);
+        assert_eq!(
+            (regenerated[0].width, regenerated[0].height),
+            (Some(300), Some(600))
+        );
+        let medium = &regenerated[0].sizes["medium"];
+        assert_eq!((medium.width, medium.height), (150, 300));
+
+        // Clean up test data
+        for size_path in [
+            path,
+            "test-regenerate-media-150x150.png",
+            "test-regenerate-media-150x300.png",
+        ] {
+            storage
+                .delete(size_path)
+                .await
+                .expect("Failed to clean up test file");
+        }
+        clean_data_test(
+            &pool,
+            "media",
+            "filename",
+            "test-regenerate-media.png",
+        )
+        .await
+        .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_regenerate_media_sizes_of_the_same_dimensions() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let storage = setup_test_storage();
+
+        let mut data = std::io::Cursor::new(Vec::new());
+        image::RgbImage::new(300, 300)
+            .write_to(&mut data, image::ImageFormat::Png)
+            .expect("Failed to encode test image");
+        let path = "test-regenerate-media-shared.png";
+        storage
+            .save(path, data.get_ref())
+            .await
+            .expect("Failed to save test file");
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+        let media = insert_media(
+            &pool,
+            author_id,
+            "test-regenerate-media-shared.png",
+            path,
+            "image/png",
+            data.get_ref().len() as i64,
+            None,
+        )
+        .await
+        .expect("Failed to insert test media");
+        let sizes = parse_image_sizes("square=150x150:crop,small=150x150")
+            .expect("Failed to parse test sizes");
+        let regenerate = || RegenerateMediaDTO {
+            ids: Some(vec![media.id]),
+        };
+
+        // Act
+        let regenerated = regenerate_media_sizes_service(
+            &pool,
+            storage.as_ref(),
+            &sizes,
+            regenerate(),
+        )
+        .await
+        .expect("Failed to regenerate the sizes");
+        // The size left keeps the copy it shared
+        let regenerated_again = regenerate_media_sizes_service(
+            &pool,
+            storage.as_ref(),
+            &sizes[..1],
+            regenerate(),
+        )
+        .await
+        .expect("Failed to regenerate the sizes again");
+
+        // Assert
+        let shared_path = "test-regenerate-media-shared-150x150.png";
+        let image_sizes = &regenerated[0].sizes;
+        assert_eq!(image_sizes["square"].url, image_sizes["small"].url);
+        assert!(regenerated_again[0].sizes.contains_key("square"));
+        assert!(storage.read(shared_path).await.is_ok());
+
+        // Clean up test data
+        for size_path in [path, shared_path] {
+            storage
+                .delete(size_path)
+                .await
+                .expect("Failed to clean up test file");
+        }
+        clean_data_test(
+            &pool,
+            "media",
+            "filename",
+            "test-regenerate-media-shared.png",
+        )
+        .await
+        .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_regenerate_media_controller_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .state(setup_test_storage())
+                .service(regenerate_media_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/media/regenerate")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use ntex::web::{self, types::State, HttpResponse};
+use ntex_multipart::Multipart;
+use sqlx::PgPool;
+
+use crate::{
+    config::config::{get_media_max_size, get_media_sizes},
+    dtos::auth_dtos::CurrentUserDTO,
+    handlers::{
+        error_to_response_handler::convert_anyhow_to_ntex,
+        multipart_handler::read_file_field,
+    },
+    models::capabilities_model::Capability,
+    services::media_service::upload_media_service,
+    storage::StorageBackend,
+};
+
+#[utoipa::path(
+    post,
+    path = "/media",
+    tag = "Media",
+    request_body(
+        content = String,
+        content_type = "multipart/form-data",
+        description = "Form with the uploaded file in a `file` field"
+    ),
+    responses(
+        (status = 201, description = "File uploaded", body = MediaDTO),
+        (status = 400, description = "Missing, too large or not allowed file", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/media")]
+pub async fn upload_media_controller(
+    pool: State<PgPool>,
+    storage: State<Arc<dyn StorageBackend>>,
+    current_user: CurrentUserDTO,
+    multipart: Multipart,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::UploadFiles)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    let upload = read_file_field(multipart, "file", get_media_max_size())
+        .await
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match upload_media_service(
+        pool.get_ref(),
+        storage.get_ref().as_ref(),
+        &get_media_sizes(),
+        &current_user,
+        upload,
+    )
+    .await
+    {
+        Ok(media) => Ok(HttpResponse::Created().json(&media)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::media_dto::MediaDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db, setup_test_storage,
+    };
+
+    const BOUNDARY: &str = "rustpress-test-boundary";
+
+    /// Encodes a blank PNG image of the given dimensions.
+    fn png_image(width: u32, height: u32) -> Vec<u8> {
+        let mut data = std::io::Cursor::new(Vec::new());
+        image::RgbImage::new(width, height)
+            .write_to(&mut data, image::ImageFormat::Png)
+            .expect("Failed to encode test image");
+        data.into_inner()
+    }
+
+    /// Builds a `multipart/form-data` body holding a single file.
+    fn multipart_body(field: &str, filename: &str, data: &[u8]) -> Vec<u8> {
+        let mut body = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"; \
+            filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+            BOUNDARY, field, filename
+        )
+        .into_bytes();
+        body.extend_from_slice(data);
+        body.extend_from_slice(format!("\r\n--{}--\r\n", BOUNDARY).as_bytes());
+        body
+    }
+
+    #[ntex::test]
+    async fn test_upload_media_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let storage = setup_test_storage();
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .state(storage.clone())
+                .service(upload_media_controller),
+        )
+        .await;
+
+        let data = png_image(400, 200);
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/media")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .header(
+                http::header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={}", BOUNDARY),
+            )
+            .set_payload(multipart_body("file", "test-upload-media.png", &data))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let body = test::read_body(resp).await;
+        let media: MediaDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(media.filename, "test-upload-media.png");
+        assert_eq!(media.mime_type, "image/png");
+        assert_eq!(media.size, data.len() as i64);
+        assert_eq!((media.width, media.height), (Some(400), Some(200)));
+        let thumbnail = &media.sizes["thumbnail"];
+        assert_eq!((thumbnail.width, thumbnail.height), (150, 150));
+
+        let path: String = sqlx::query_scalar!(
+            "SELECT path FROM media WHERE id = $1",
+            media.id
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test media");
+        assert!(media.url.ends_with(&path));
+        let saved = tokio::fs::read(
+            std::env::temp_dir()
+                .join("rustpress-test-media")
+                .join(&path),
+        )
+        .await
+        .expect("Failed to read uploaded file");
+        assert_eq!(saved, data);
+
+        // Clean up test data
+        let sizes: serde_json::Value = sqlx::query_scalar!(
+            "SELECT sizes FROM media WHERE id = $1",
+            media.id
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test media sizes");
+        let sizes_paths = sizes
+            .as_object()
+            .into_iter()
+            .flat_map(|sizes| sizes.values())
+            .filter_map(|size| size["path"].as_str());
+        for size_path in sizes_paths.chain([path.as_str()]) {
+            storage
+                .delete(size_path)
+                .await
+                .expect("Failed to clean up test file");
+        }
+        clean_data_test(&pool, "media", "filename", "test-upload-media.png")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_upload_media_controller_type_not_allowed() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .state(setup_test_storage())
+                .service(upload_media_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/media")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .header(
+                http::header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={}", BOUNDARY),
+            )
+            .set_payload(multipart_body(
+                "file",
+                "test-upload-media.png",
+                b"<?php echo 'Not an image'; ?>",
+            ))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[ntex::test]
+    async fn test_upload_media_controller_missing_file() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .state(setup_test_storage())
+                .service(upload_media_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/media")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .header(
+                http::header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={}", BOUNDARY),
+            )
+            .set_payload(multipart_body("image", "test-upload-media.png", b""))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,141 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, menu_dto::CreateMenuDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::menus_service::create_menu_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/menus",
+    tag = "Menus",
+    request_body = CreateMenuDTO,
+    responses(
+        (status = 201, description = "Menu created", body = MenuDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/menus")]
+pub async fn create_menu_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    menu_dto: Json<CreateMenuDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditThemeOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match create_menu_service(pool.get_ref(), menu_dto.into_inner()).await {
+        Ok(created_menu) => Ok(HttpResponse::Created().json(&created_menu)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::dtos::menu_dto::MenuDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_create_menu_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_menu_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/menus")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "name": "Test Create Menu Success" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let body = test::read_body(resp).await;
+        let menu: MenuDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(menu.slug.as_deref(), Some("test-create-menu-success"));
+
+        // Clean up test data
+        clean_data_test(&pool, "menus", "slug", "test-create-menu-success")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_create_menu_controller_validation_failure() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_menu_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/menus")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "name": "Test", "slug": "test-menu-*/!" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[ntex::test]
+    async fn test_create_menu_controller_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_menu_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/menus")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "name": "Test Create Menu Forbidden" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,94 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, menu_dto::DeleteMenuIdsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::menus_service::delete_menus_service,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/menus",
+    tag = "Menus",
+    request_body = DeleteMenuIdsDTO,
+    responses(
+        (status = 200, description = "Menus deleted", body = Vec<i32>),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/menus")]
+pub async fn delete_menus_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    menu_ids: Json<DeleteMenuIdsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditThemeOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match delete_menus_service(pool.get_ref(), menu_ids.into_inner()).await {
+        Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::dtos::menu_dto::CreateMenuDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::services::menus_service::create_menu_service;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
+
+    #[ntex::test]
+    async fn test_delete_menus_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let menu = create_menu_service(
+            &pool,
+            CreateMenuDTO {
+                name: String::from("Test Delete Menus"),
+                slug: None,
+            },
+        )
+        .await
+        .expect("Failed to create the menu");
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(delete_menus_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::delete()
+            .uri("/menus")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "ids": [menu.id.unwrap()] }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let deleted_ids: Vec<i32> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(deleted_ids, vec![menu.id.unwrap()]);
+    }
+}
@@ -0,0 +1,94 @@
+use ntex::web::{self, types::State, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::menus_service::get_all_menus_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/menus",
+    tag = "Menus",
+    responses(
+        (status = 200, description = "Menus, sorted by name", body = Vec<MenuDTO>),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/menus")]
+pub async fn get_all_menus_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditThemeOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match get_all_menus_service(pool.get_ref()).await {
+        Ok(menus) => Ok(HttpResponse::Ok().json(&menus)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::menu_dto::{CreateMenuDTO, MenuDTO};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::services::menus_service::create_menu_service;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_get_all_menus_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        create_menu_service(
+            &pool,
+            CreateMenuDTO {
+                name: String::from("Test Get All Menus"),
+                slug: None,
+            },
+        )
+        .await
+        .expect("Failed to create the menu");
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_all_menus_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/menus")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let menus: Vec<MenuDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert!(menus
+            .iter()
+            .any(|menu| menu.slug.as_deref() == Some("test-get-all-menus")));
+
+        // Clean up test data
+        clean_data_test(&pool, "menus", "slug", "test-get-all-menus")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
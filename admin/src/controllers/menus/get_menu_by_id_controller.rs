@@ -0,0 +1,78 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::menus_service::get_menu_by_id_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/menus/{id}",
+    tag = "Menus",
+    params(
+        ("id" = i32, description = "ID of the menu")
+    ),
+    responses(
+        (status = 200, description = "Menu and the tree of its items", body = MenuTreeDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Menu not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/menus/{id}")]
+pub async fn get_menu_by_id_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    menu_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditThemeOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match get_menu_by_id_service(pool.get_ref(), menu_id.into_inner()).await {
+        Ok(menu) => Ok(HttpResponse::Ok().json(&menu)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
+
+    #[ntex::test]
+    async fn test_get_menu_by_id_controller_not_found() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_menu_by_id_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/menus/2147483647")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,69 @@
+use ntex::web::{self, types::State, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::menus_service::get_menu_locations_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/menus/locations",
+    tag = "Menus",
+    responses(
+        (status = 200, description = "Menus shown at the theme locations", body = MenuLocationsDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/menus/locations")]
+pub async fn get_menu_locations_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditThemeOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match get_menu_locations_service(pool.get_ref()).await {
+        Ok(locations) => Ok(HttpResponse::Ok().json(&locations)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
+
+    #[ntex::test]
+    async fn test_get_menu_locations_controller_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "author").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_menu_locations_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/menus/locations")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod create_menu_controller;
+pub mod delete_menus_controller;
+pub mod get_all_menus_controller;
+pub mod get_menu_by_id_controller;
+pub mod get_menu_locations_controller;
+pub mod update_menu_controller;
+pub mod update_menu_items_controller;
+pub mod update_menu_locations_controller;
@@ -0,0 +1,178 @@
+use ntex::web::{
+    self,
+    types::{Json, Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, menu_dto::UpdateMenuItemsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::menus_service::update_menu_items_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/menus/{id}/items",
+    tag = "Menus",
+    params(
+        ("id" = i32, description = "ID of the menu")
+    ),
+    request_body = UpdateMenuItemsDTO,
+    responses(
+        (status = 200, description = "Items of the menu replaced", body = MenuTreeDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Menu not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/menus/{id}/items")]
+pub async fn update_menu_items_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    menu_id: Path<i32>,
+    items_dto: Json<UpdateMenuItemsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditThemeOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match update_menu_items_service(
+        pool.get_ref(),
+        menu_id.into_inner(),
+        items_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(menu) => Ok(HttpResponse::Ok().json(&menu)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::dtos::menu_dto::{CreateMenuDTO, MenuTreeDTO};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::services::menus_service::create_menu_service;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_update_menu_items_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let menu = create_menu_service(
+            &pool,
+            CreateMenuDTO {
+                name: String::from("Test Update Menu Items"),
+                slug: None,
+            },
+        )
+        .await
+        .expect("Failed to create the menu");
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_menu_items_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri(&format!("/menus/{}/items", menu.id.unwrap()))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "items": [
+                    {
+                        "item_type": "Custom",
+                        "url": "/",
+                        "title": "Home",
+                        "children": [{
+                            "item_type": "Custom",
+                            "url": "https://example.com",
+                            "title": "Example"
+                        }]
+                    },
+                    { "item_type": "Custom", "url": "/about", "title": "About" }
+                ]
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let tree: MenuTreeDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(tree.items.len(), 2);
+        assert_eq!(tree.items[0].title.as_deref(), Some("Home"));
+        assert_eq!(tree.items[0].children.len(), 1);
+        assert_eq!(tree.items[1].title.as_deref(), Some("About"));
+
+        // Clean up test data
+        clean_data_test(&pool, "menus", "slug", "test-update-menu-items")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_update_menu_items_controller_invalid_item() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let menu = create_menu_service(
+            &pool,
+            CreateMenuDTO {
+                name: String::from("Test Update Menu Items Invalid"),
+                slug: None,
+            },
+        )
+        .await
+        .expect("Failed to create the menu");
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_menu_items_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri(&format!("/menus/{}/items", menu.id.unwrap()))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "items": [
+                    { "item_type": "Custom", "title": "No URL" },
+                    { "item_type": "Category", "object_id": 2147483647 }
+                ]
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        // Clean up test data
+        clean_data_test(
+            &pool,
+            "menus",
+            "slug",
+            "test-update-menu-items-invalid",
+        )
+        .await
+        .expect("Failed to clean up test data");
+    }
+}
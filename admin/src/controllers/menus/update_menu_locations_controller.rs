@@ -0,0 +1,83 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, menu_dto::MenuLocationsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::menus_service::update_menu_locations_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/menus/locations",
+    tag = "Menus",
+    request_body = MenuLocationsDTO,
+    responses(
+        (status = 200, description = "Menus assigned to the theme locations", body = MenuLocationsDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/menus/locations")]
+pub async fn update_menu_locations_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    locations_dto: Json<MenuLocationsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditThemeOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match update_menu_locations_service(
+        pool.get_ref(),
+        locations_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(locations) => Ok(HttpResponse::Ok().json(&locations)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
+
+    #[ntex::test]
+    async fn test_update_menu_locations_controller_unknown_menu() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_menu_locations_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/menus/locations")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "locations": { "primary": 2147483647 } }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+}
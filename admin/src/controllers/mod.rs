@@ -1,6 +1,21 @@
 pub mod auth;
+pub mod capabilities;
 pub mod categories;
+pub mod comments;
+pub mod media;
+pub mod menus;
+pub mod pages;
+pub mod post_autosaves;
+pub mod post_revisions;
 pub mod posts;
 pub mod posts_categories;
+pub mod posts_tags;
+pub mod public;
+pub mod redirects;
+pub mod roles;
+pub mod settings;
+pub mod slugs;
 pub mod tags;
+pub mod themes;
 pub mod users;
+pub mod users_roles;
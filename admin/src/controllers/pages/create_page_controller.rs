@@ -0,0 +1,291 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, page_dto::CreatePageDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::pages_service::create_page_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/pages",
+    tag = "Pages",
+    request_body = CreatePageDTO,
+    responses(
+        (status = 201, description = "Page created", body = PageDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/pages")]
+pub async fn create_page_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    page: Json<CreatePageDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPages)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match create_page_service(pool.get_ref(), &current_user, page.into_inner())
+        .await
+    {
+        Ok(page) => Ok(HttpResponse::Created().json(&page)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::dtos::page_dto::PageDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_create_page_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_page_controller),
+        )
+        .await;
+
+        for slug in ["test-create-page-child", "test-create-page-parent"] {
+            clean_data_test(&pool, "posts", "slug", slug)
+                .await
+                .expect("Failed to clean up test data");
+        }
+
+        let (author_id, parent_id): (i32, i32) = sqlx::query_as(
+            "INSERT INTO posts (title, content, slug, author_id, post_type) \
+            SELECT 'Test Parent Page', 'Content', 'test-create-page-parent', \
+            id, 'Page' FROM users WHERE username = 'test-editor' \
+            RETURNING author_id, id",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test page");
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/pages")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "title": "Test Create Page Child",
+                "content": "Content",
+                "slug": "test-create-page-child",
+                "author_id": author_id,
+                "status": "Published",
+                "date_published": null,
+                "parent_id": parent_id,
+                "menu_order": 2
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let body = test::read_body(resp).await;
+        let page: PageDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(page.parent_id, Some(parent_id));
+        assert_eq!(page.menu_order, 2);
+        assert_eq!(page.template, None);
+
+        // The page is not listed among the posts
+        let post_type: String = sqlx::query_scalar(
+            "SELECT post_type::text FROM posts WHERE id = $1",
+        )
+        .bind(page.id)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test page");
+        assert_eq!(post_type, "Page");
+
+        // Clean up test data
+        for slug in ["test-create-page-child", "test-create-page-parent"] {
+            clean_data_test(&pool, "posts", "slug", slug)
+                .await
+                .expect("Failed to clean up test data");
+        }
+    }
+
+    #[ntex::test]
+    async fn test_create_page_controller_unknown_parent() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_page_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-create-page-post-parent")
+            .await
+            .expect("Failed to clean up test data");
+
+        // A post cannot be the parent of a page
+        let (author_id, post_id): (i32, i32) = sqlx::query_as(
+            "INSERT INTO posts (title, content, slug, author_id) \
+            SELECT 'Test Parent Post', 'Content', \
+            'test-create-page-post-parent', id \
+            FROM users WHERE username = 'test-editor' \
+            RETURNING author_id, id",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/pages")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "title": "Test Create Page Orphan",
+                "content": "Content",
+                "author_id": author_id,
+                "status": "Draft",
+                "date_published": null,
+                "parent_id": post_id
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-create-page-post-parent")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_create_page_controller_post_slug() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_page_controller),
+        )
+        .await;
+
+        let slugs = ["test-create-page-shared", "test-create-page-shared-2"];
+        for slug in slugs {
+            clean_data_test(&pool, "posts", "slug", slug)
+                .await
+                .expect("Failed to clean up test data");
+        }
+
+        // Posts and pages are both served at /{slug}
+        let (author_id,): (i32,) = sqlx::query_as(
+            "INSERT INTO posts (title, content, slug, author_id) \
+            SELECT 'Test Create Page Shared', 'Content', \
+            'test-create-page-shared', id \
+            FROM users WHERE username = 'test-editor' \
+            RETURNING author_id",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/pages")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "title": "Test Create Page Shared",
+                "content": "Content",
+                "author_id": author_id,
+                "status": "Draft",
+                "date_published": null
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        let explicit_req = test::TestRequest::post()
+            .uri("/pages")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "title": "Test Create Page Shared",
+                "content": "Content",
+                "slug": "test-create-page-shared",
+                "author_id": author_id,
+                "status": "Draft",
+                "date_published": null
+            }))
+            .to_request();
+        let explicit_resp = test::call_service(&app, explicit_req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+        let body = test::read_body(resp).await;
+        let page: PageDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(page.slug.as_deref(), Some("test-create-page-shared-2"));
+        assert_eq!(explicit_resp.status(), http::StatusCode::CONFLICT);
+
+        // Clean up test data
+        for slug in slugs {
+            clean_data_test(&pool, "posts", "slug", slug)
+                .await
+                .expect("Failed to clean up test data");
+        }
+    }
+
+    #[ntex::test]
+    async fn test_create_page_controller_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "author").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_page_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/pages")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "title": "Test Create Page Forbidden",
+                "content": "Content",
+                "author_id": 1,
+                "status": "Draft",
+                "date_published": null
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,114 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, page_dto::DeletePageIdsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::pages_service::delete_pages_service,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/pages",
+    tag = "Pages",
+    request_body = DeletePageIdsDTO,
+    responses(
+        (status = 200, description = "Pages deleted", body = [i32]),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/pages")]
+pub async fn delete_pages_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    delete_page_ids_dto: Json<DeletePageIdsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPages)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match delete_pages_service(pool.get_ref(), delete_page_ids_dto.into_inner())
+        .await
+    {
+        Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_delete_pages_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(delete_pages_controller),
+        )
+        .await;
+
+        for slug in ["test-delete-page", "test-delete-page-post"] {
+            clean_data_test(&pool, "posts", "slug", slug)
+                .await
+                .expect("Failed to clean up test data");
+        }
+
+        let ids: Vec<i32> = sqlx::query_scalar(
+            "INSERT INTO posts (title, content, slug, author_id, post_type) \
+            SELECT v.title, 'Content', v.slug, users.id, \
+            v.post_type::posts_type \
+            FROM users, (VALUES \
+                ('Page', 'test-delete-page', 'Page'), \
+                ('Post', 'test-delete-page-post', 'Post')\
+            ) AS v(title, slug, post_type) \
+            WHERE users.username = 'test-editor' \
+            RETURNING id",
+        )
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to insert test data");
+
+        // Act
+        let req = test::TestRequest::delete()
+            .uri("/pages")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "ids": ids }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        // The post is left untouched
+        let body = test::read_body(resp).await;
+        let deleted_ids: Vec<i32> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(deleted_ids, vec![ids[0]]);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-delete-page-post")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
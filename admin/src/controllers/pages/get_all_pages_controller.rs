@@ -0,0 +1,184 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{
+        auth_dtos::CurrentUserDTO, page_dto::PageFiltersDTO,
+        pagination_dto::PaginationParamsDTO,
+    },
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::pages_service::get_all_pages_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/pages",
+    tag = "Pages",
+    params(
+        ("page" = Option<i32>, Query, description = "The page number for pagination"),
+        ("limit" = Option<i32>, Query, description = "The number of items per page"),
+        ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'menu_order', 'title')"),
+        ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')"),
+        PageFiltersDTO
+    ),
+    responses(
+        (status = 200, description = "Get all pages", body = PageDTO),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[web::get("/pages")]
+pub async fn get_all_pages_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    params: Query<PaginationParamsDTO>,
+    filters: Query<PageFiltersDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPages)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(25);
+    let sort_column = params.sort_column.as_deref().unwrap_or("menu_order");
+    let sort_order = params.sort_order.as_deref().unwrap_or("asc");
+
+    match get_all_pages_service(
+        pool.get_ref(),
+        &filters,
+        page,
+        limit,
+        sort_column,
+        sort_order,
+    )
+    .await
+    {
+        Ok(pages) => Ok(HttpResponse::Ok().json(&pages)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::{page_dto::PageDTO, pagination_dto::PaginationDTO};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    const SLUGS: [&str; 4] = [
+        "test-get-all-pages-second",
+        "test-get-all-pages-first",
+        "test-get-all-pages-post",
+        "test-get-all-pages-parent",
+    ];
+
+    #[ntex::test]
+    async fn test_get_all_pages_controller_children() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_all_pages_controller),
+        )
+        .await;
+
+        for slug in SLUGS {
+            clean_data_test(&pool, "posts", "slug", slug)
+                .await
+                .expect("Failed to clean up test data");
+        }
+
+        let parent_id: i32 = sqlx::query_scalar(
+            "INSERT INTO posts (title, content, slug, author_id, post_type) \
+            SELECT 'Test Parent', 'Content', 'test-get-all-pages-parent', \
+            id, 'Page' FROM users WHERE username = 'test-editor' \
+            RETURNING id",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test page");
+
+        // Two children in reverse menu order, and a post under the same
+        // parent which must not be listed
+        sqlx::query(
+            "INSERT INTO posts \
+            (title, content, slug, author_id, post_type, parent_id, menu_order) \
+            SELECT v.title, 'Content', v.slug, users.id, \
+            v.post_type::posts_type, $1, v.menu_order \
+            FROM users, (VALUES \
+                ('Second', 'test-get-all-pages-second', 'Page', 2), \
+                ('First', 'test-get-all-pages-first', 'Page', 1), \
+                ('Post', 'test-get-all-pages-post', 'Post', 0)\
+            ) AS v(title, slug, post_type, menu_order) \
+            WHERE users.username = 'test-editor'",
+        )
+        .bind(parent_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test pages");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri(&format!("/pages?parent_id={}", parent_id))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let pages: PaginationDTO<PageDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        let titles: Vec<&str> =
+            pages.data.iter().map(|page| page.title.as_str()).collect();
+        assert_eq!(titles, vec!["First", "Second"]);
+        assert_eq!(pages.total_items, 2);
+
+        // Clean up test data
+        for slug in SLUGS {
+            clean_data_test(&pool, "posts", "slug", slug)
+                .await
+                .expect("Failed to clean up test data");
+        }
+    }
+
+    #[ntex::test]
+    async fn test_get_all_pages_controller_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "author").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_all_pages_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/pages")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
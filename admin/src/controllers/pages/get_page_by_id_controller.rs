@@ -0,0 +1,151 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::pages_service::get_page_by_id_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/pages/{id}",
+    tag = "Pages",
+    params(
+        ("id" = i32, description = "ID of the page")
+    ),
+    responses(
+        (status = 200, description = "Page retrieved", body = PageDTO),
+        (status = 404, description = "Page not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/pages/{id}")]
+pub async fn get_page_by_id_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    page_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPages)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match get_page_by_id_service(pool.get_ref(), page_id.into_inner()).await {
+        Ok(page) => Ok(HttpResponse::Ok().json(&page)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::page_dto::PageDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_get_page_by_id_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_page_by_id_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-get-page-by-id")
+            .await
+            .expect("Failed to clean up test data");
+
+        let page_id: i32 = sqlx::query_scalar(
+            "INSERT INTO posts \
+            (title, content, slug, author_id, post_type, template) \
+            SELECT 'Test Get Page', 'Content', 'test-get-page-by-id', id, \
+            'Page', 'full-width' FROM users WHERE username = 'test-editor' \
+            RETURNING id",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test page");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri(&format!("/pages/{}", page_id))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let page: PageDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(page.id, Some(page_id));
+        assert_eq!(page.template.as_deref(), Some("full-width"));
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-get-page-by-id")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_get_page_by_id_controller_post() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_page_by_id_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-get-page-by-id-post")
+            .await
+            .expect("Failed to clean up test data");
+
+        let post_id: i32 = sqlx::query_scalar(
+            "INSERT INTO posts (title, content, slug, author_id) \
+            SELECT 'Test Get Page Post', 'Content', \
+            'test-get-page-by-id-post', id \
+            FROM users WHERE username = 'test-editor' \
+            RETURNING id",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri(&format!("/pages/{}", post_id))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-get-page-by-id-post")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
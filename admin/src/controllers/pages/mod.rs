@@ -0,0 +1,5 @@
+pub mod create_page_controller;
+pub mod delete_pages_controller;
+pub mod get_all_pages_controller;
+pub mod get_page_by_id_controller;
+pub mod update_page_controller;
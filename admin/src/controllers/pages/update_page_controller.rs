@@ -0,0 +1,190 @@
+use ntex::web::{
+    self,
+    types::{Json, Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, page_dto::CreatePageDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::pages_service::update_page_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/pages/{id}",
+    tag = "Pages",
+    request_body = CreatePageDTO,
+    params(
+        ("id" = i32, description = "ID of the page")
+    ),
+    responses(
+        (status = 200, description = "Page updated", body = PageDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 404, description = "Page not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/pages/{id}")]
+pub async fn update_page_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    page_id: Path<i32>,
+    page: Json<CreatePageDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPages)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match update_page_service(
+        pool.get_ref(),
+        &current_user,
+        page_id.into_inner(),
+        page.into_inner(),
+    )
+    .await
+    {
+        Ok(page) => Ok(HttpResponse::Ok().json(&page)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::dtos::page_dto::PageDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    /// Inserts a page of the test editor, returning its author and its ID.
+    async fn insert_page(
+        pool: &PgPool,
+        slug: &str,
+        parent_id: Option<i32>,
+    ) -> (i32, i32) {
+        clean_data_test(pool, "posts", "slug", slug)
+            .await
+            .expect("Failed to clean up test data");
+
+        sqlx::query_as(
+            "INSERT INTO posts \
+            (title, content, slug, author_id, post_type, parent_id) \
+            SELECT 'Test Update Page', 'Content', $1, id, 'Page', $2 \
+            FROM users WHERE username = 'test-editor' \
+            RETURNING author_id, id",
+        )
+        .bind(slug)
+        .bind(parent_id)
+        .fetch_one(pool)
+        .await
+        .expect("Failed to insert test page")
+    }
+
+    #[ntex::test]
+    async fn test_update_page_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_page_controller),
+        )
+        .await;
+
+        let (author_id, page_id) =
+            insert_page(&pool, "test-update-page", None).await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri(&format!("/pages/{}", page_id))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "title": "Test Update Page Updated",
+                "content": "Updated content",
+                "slug": "test-update-page",
+                "author_id": author_id,
+                "status": "Published",
+                "date_published": null,
+                "menu_order": 3,
+                "template": "full-width"
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let page: PageDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(page.title, "Test Update Page Updated");
+        assert_eq!(page.menu_order, 3);
+        assert_eq!(page.template.as_deref(), Some("full-width"));
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-update-page")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_update_page_controller_descendant_parent() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_page_controller),
+        )
+        .await;
+
+        let (author_id, parent_id) =
+            insert_page(&pool, "test-update-page-cycle-parent", None).await;
+        let (_, child_id) =
+            insert_page(&pool, "test-update-page-cycle-child", Some(parent_id))
+                .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri(&format!("/pages/{}", parent_id))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "title": "Test Update Page Cycle",
+                "content": "Content",
+                "slug": "test-update-page-cycle-parent",
+                "author_id": author_id,
+                "status": "Draft",
+                "date_published": null,
+                "parent_id": child_id
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        // Clean up test data
+        for slug in [
+            "test-update-page-cycle-child",
+            "test-update-page-cycle-parent",
+        ] {
+            clean_data_test(&pool, "posts", "slug", slug)
+                .await
+                .expect("Failed to clean up test data");
+        }
+    }
+}
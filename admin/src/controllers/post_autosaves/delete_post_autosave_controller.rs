@@ -0,0 +1,137 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{
+        auth_dtos::CurrentUserDTO, post_autosave_dto::PostAutosaveParamsDTO,
+    },
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::post_autosaves_service::delete_post_autosave_service,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/autosaves",
+    tag = "Posts Autosaves",
+    params(PostAutosaveParamsDTO),
+    responses(
+        (status = 204, description = "Unsaved changes discarded"),
+        (status = 404, description = "No unsaved changes", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/autosaves")]
+pub async fn delete_post_autosave_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    params: Query<PostAutosaveParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match delete_post_autosave_service(
+        pool.get_ref(),
+        &current_user,
+        params.post_id,
+    )
+    .await
+    {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    #[ntex::test]
+    async fn test_delete_post_autosave_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(delete_post_autosave_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-post-autosave-delete")
+            .await
+            .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-editor'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            VALUES ($1, $2, $3, $4, $5::posts_status)
+            RETURNING id
+            "#,
+            "Test Post Autosave Delete",
+            "Saved content",
+            "test-post-autosave-delete",
+            author_id,
+            PostsStatus::Draft as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        sqlx::query!(
+            r#"
+            INSERT INTO post_autosaves (post_id, author_id, title, content)
+            VALUES ($1, $2, 'Test Post Autosave Delete', 'Unsaved content')
+            "#,
+            post_id,
+            author_id,
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test autosave");
+
+        // Act
+        let uri = format!("/autosaves?post_id={}", post_id);
+        let req = test::TestRequest::delete()
+            .uri(&uri)
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        let req = test::TestRequest::delete()
+            .uri(&uri)
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let second_resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+        assert_eq!(second_resp.status(), http::StatusCode::NOT_FOUND);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-post-autosave-delete")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,250 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{
+        auth_dtos::CurrentUserDTO, post_autosave_dto::PostAutosaveParamsDTO,
+    },
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::post_autosaves_service::get_post_autosave_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/autosaves",
+    tag = "Posts Autosaves",
+    params(PostAutosaveParamsDTO),
+    responses(
+        (status = 200, description = "Unsaved changes newer than the saved post", body = PostAutosaveDTO),
+        (status = 404, description = "No unsaved changes", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/autosaves")]
+pub async fn get_post_autosave_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    params: Query<PostAutosaveParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match get_post_autosave_service(
+        pool.get_ref(),
+        &current_user,
+        params.post_id,
+    )
+    .await
+    {
+        Ok(autosave) => Ok(HttpResponse::Ok().json(&autosave)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controllers::post_autosaves::save_post_autosave_controller::save_post_autosave_controller;
+    use crate::controllers::posts::update_post_controller::update_post_controller;
+    use crate::dtos::post_autosave_dto::{
+        PostAutosaveDTO, SavePostAutosaveDTO,
+    };
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    #[ntex::test]
+    async fn test_get_post_autosave_controller_until_post_saved() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(save_post_autosave_controller)
+                .service(get_post_autosave_controller)
+                .service(update_post_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-post-autosave-edit")
+            .await
+            .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-editor'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            VALUES ($1, $2, $3, $4, $5::posts_status)
+            RETURNING id
+            "#,
+            "Test Post Autosave Edit",
+            "Saved content",
+            "test-post-autosave-edit",
+            author_id,
+            PostsStatus::Draft as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        let req = test::TestRequest::put()
+            .uri("/autosaves")
+            .set_json(&SavePostAutosaveDTO {
+                post_id: Some(post_id),
+                title: "Test Post Autosave Edit".to_string(),
+                content: "Unsaved content".to_string(),
+            })
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri(&format!("/autosaves?post_id={}", post_id))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let response_body: PostAutosaveDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(response_body.post_id, Some(post_id));
+        assert_eq!(response_body.content, "Unsaved content");
+
+        // Saving the post discards its autosave
+        let req = test::TestRequest::put()
+            .uri(&format!("/posts/{}", post_id))
+            .set_json(&serde_json::json!({
+                "title": "Test Post Autosave Edit",
+                "content": "Unsaved content",
+                "slug": "test-post-autosave-edit",
+                "author_id": author_id,
+                "status": "Draft",
+                "date_published": null,
+                "categories_ids": [],
+            }))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/autosaves?post_id={}", post_id))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-post-autosave-edit")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_get_post_autosave_controller_older_than_revision() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_post_autosave_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-post-autosave-stale")
+            .await
+            .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-editor'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            VALUES ($1, $2, $3, $4, $5::posts_status)
+            RETURNING id
+            "#,
+            "Test Post Autosave Stale",
+            "Saved content",
+            "test-post-autosave-stale",
+            author_id,
+            PostsStatus::Draft as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        // The post was saved by someone else after the autosave
+        sqlx::query!(
+            r#"
+            INSERT INTO post_autosaves
+                (post_id, author_id, title, content, date_modified)
+            VALUES ($1, $2, 'Test Post Autosave Stale', 'Unsaved content',
+                CURRENT_TIMESTAMP - INTERVAL '1 hour')
+            "#,
+            post_id,
+            author_id,
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test autosave");
+        sqlx::query!(
+            r#"
+            INSERT INTO post_revisions
+                (post_id, author_id, title, content, status)
+            VALUES ($1, $2, 'Test Post Autosave Stale', 'Saved content', 'Draft')
+            "#,
+            post_id,
+            author_id,
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test revision");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri(&format!("/autosaves?post_id={}", post_id))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-post-autosave-stale")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
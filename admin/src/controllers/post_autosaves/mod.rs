@@ -0,0 +1,3 @@
+pub mod delete_post_autosave_controller;
+pub mod get_post_autosave_controller;
+pub mod save_post_autosave_controller;
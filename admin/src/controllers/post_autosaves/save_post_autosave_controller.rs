@@ -0,0 +1,179 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, post_autosave_dto::SavePostAutosaveDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::post_autosaves_service::save_post_autosave_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/autosaves",
+    tag = "Posts Autosaves",
+    request_body = SavePostAutosaveDTO,
+    responses(
+        (status = 200, description = "Unsaved changes stored", body = PostAutosaveDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 404, description = "Post not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/autosaves")]
+pub async fn save_post_autosave_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    autosave_dto: Json<SavePostAutosaveDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match save_post_autosave_service(
+        pool.get_ref(),
+        &current_user,
+        autosave_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(autosave) => Ok(HttpResponse::Ok().json(&autosave)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controllers::post_autosaves::get_post_autosave_controller::get_post_autosave_controller;
+    use crate::controllers::posts::create_post_controller::create_post_controller;
+    use crate::dtos::post_autosave_dto::PostAutosaveDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    #[ntex::test]
+    async fn test_save_post_autosave_controller_new_post_until_created() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(save_post_autosave_controller)
+                .service(get_post_autosave_controller)
+                .service(create_post_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-post-autosave-new")
+            .await
+            .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-editor'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        // Act
+        for content in ["First draft", "Second draft"] {
+            let req = test::TestRequest::put()
+                .uri("/autosaves")
+                .set_json(&SavePostAutosaveDTO {
+                    post_id: None,
+                    title: "Test Post Autosave New".to_string(),
+                    content: content.to_string(),
+                })
+                .header(http::header::AUTHORIZATION, auth.clone())
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), http::StatusCode::OK);
+        }
+
+        let req = test::TestRequest::get()
+            .uri("/autosaves")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let response_body: PostAutosaveDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(response_body.post_id, None);
+        assert_eq!(response_body.author_id, author_id);
+        assert_eq!(response_body.content, "Second draft");
+
+        // Saving the post discards its autosave
+        let req = test::TestRequest::post()
+            .uri("/posts")
+            .set_json(&serde_json::json!({
+                "title": "Test Post Autosave New",
+                "content": "Second draft",
+                "slug": "test-post-autosave-new",
+                "author_id": author_id,
+                "status": "Draft",
+                "date_published": null,
+                "categories_ids": [],
+            }))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let req = test::TestRequest::get()
+            .uri("/autosaves")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-post-autosave-new")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_save_post_autosave_controller_validation_error() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(save_post_autosave_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/autosaves")
+            .set_json(&SavePostAutosaveDTO {
+                post_id: None,
+                title: "a".repeat(201),
+                content: String::new(),
+            })
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,167 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::post_revisions_service::get_post_revisions_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/posts/{id}/revisions",
+    tag = "Posts Revisions",
+    params(
+        ("id" = i32, description = "ID of the post")
+    ),
+    responses(
+        (status = 200, description = "Get the revisions of a post, latest first", body = [PostRevisionDTO]),
+        (status = 404, description = "Post not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts/{id}/revisions")]
+pub async fn get_post_revisions_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    post_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match get_post_revisions_service(pool.get_ref(), post_id.into_inner()).await
+    {
+        Ok(revisions) => Ok(HttpResponse::Ok().json(&revisions)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controllers::posts::update_post_controller::update_post_controller;
+    use crate::dtos::post_revision_dto::PostRevisionDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    #[ntex::test]
+    async fn test_get_post_revisions_controller_update_creates_revision() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_post_controller)
+                .service(get_post_revisions_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-post-revisions")
+            .await
+            .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            VALUES ($1, $2, $3, $4, $5::posts_status)
+            RETURNING id
+            "#,
+            "Test Post Revisions",
+            "Test Post Revisions Content",
+            "test-post-revisions",
+            author_id,
+            PostsStatus::Draft as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/posts/{}", post_id))
+            .set_json(&serde_json::json!({
+                "title": "Test Post Revisions Updated",
+                "content": "Test Post Revisions Updated Content",
+                "slug": "test-post-revisions",
+                "author_id": author_id,
+                "status": "Draft",
+                "date_published": null,
+                "categories_ids": [],
+            }))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri(&format!("/posts/{}/revisions", post_id))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let response_body: Vec<PostRevisionDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(response_body.len(), 1);
+        assert_eq!(response_body[0].title, "Test Post Revisions Updated");
+        assert_eq!(
+            response_body[0].content,
+            "Test Post Revisions Updated Content"
+        );
+        assert_eq!(response_body[0].author_id, author_id);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-post-revisions")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_get_post_revisions_controller_post_not_found() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_post_revisions_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/posts/999999/revisions")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+}
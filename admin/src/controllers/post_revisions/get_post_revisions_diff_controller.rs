@@ -0,0 +1,190 @@
+use ntex::web::{
+    self,
+    types::{Path, Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{
+        auth_dtos::CurrentUserDTO, post_revision_dto::PostRevisionDiffParamsDTO,
+    },
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::post_revisions_service::get_post_revisions_diff_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/posts/{id}/revisions/diff",
+    tag = "Posts Revisions",
+    params(
+        ("id" = i32, description = "ID of the post"),
+        PostRevisionDiffParamsDTO
+    ),
+    responses(
+        (status = 200, description = "Line-level differences between two revisions", body = PostRevisionDiffDTO),
+        (status = 400, description = "Bad Request", body = Error),
+        (status = 404, description = "Revision not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts/{id}/revisions/diff")]
+pub async fn get_post_revisions_diff_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    post_id: Path<i32>,
+    params: Query<PostRevisionDiffParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match get_post_revisions_diff_service(
+        pool.get_ref(),
+        post_id.into_inner(),
+        params.into_inner(),
+    )
+    .await
+    {
+        Ok(diff) => Ok(HttpResponse::Ok().json(&diff)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtos::post_revision_dto::{
+        DiffOperationDTO, PostRevisionDiffDTO,
+    };
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    #[ntex::test]
+    async fn test_get_post_revisions_diff_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_post_revisions_diff_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-post-revisions-diff")
+            .await
+            .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            VALUES ($1, $2, $3, $4, $5::posts_status)
+            RETURNING id
+            "#,
+            "Test Post Revisions Diff",
+            "a\nc\nd",
+            "test-post-revisions-diff",
+            author_id,
+            PostsStatus::Draft as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        let revisions_ids: Vec<i32> = sqlx::query_scalar!(
+            r#"
+            INSERT INTO post_revisions
+                (post_id, author_id, title, content, status)
+            VALUES ($1, $2, 'Test Post Revisions Diff', E'a\nb\nc', 'Draft'),
+                ($1, $2, 'Test Post Revisions Diff', E'a\nc\nd', 'Draft')
+            RETURNING id
+            "#,
+            post_id,
+            author_id,
+        )
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to insert test revisions");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/posts/{}/revisions/diff?from={}&to={}",
+                post_id, revisions_ids[0], revisions_ids[1]
+            ))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let response_body: PostRevisionDiffDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(response_body.from.id, revisions_ids[0]);
+        assert_eq!(response_body.to.id, revisions_ids[1]);
+        assert_eq!(response_body.title.len(), 1);
+        assert_eq!(response_body.title[0].operation, DiffOperationDTO::Equal);
+        let content: Vec<(DiffOperationDTO, &str)> = response_body
+            .content
+            .iter()
+            .map(|line| (line.operation.clone(), line.line.as_str()))
+            .collect();
+        assert_eq!(
+            content,
+            vec![
+                (DiffOperationDTO::Equal, "a"),
+                (DiffOperationDTO::Delete, "b"),
+                (DiffOperationDTO::Equal, "c"),
+                (DiffOperationDTO::Insert, "d"),
+            ]
+        );
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-post-revisions-diff")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_get_post_revisions_diff_controller_revision_not_found() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_post_revisions_diff_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/posts/999999/revisions/diff?from=1&to=2")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+}
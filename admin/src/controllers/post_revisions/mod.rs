@@ -0,0 +1,3 @@
+pub mod get_post_revisions_controller;
+pub mod get_post_revisions_diff_controller;
+pub mod restore_post_revision_controller;
@@ -0,0 +1,224 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::post_revisions_service::restore_post_revision_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/posts/{id}/revisions/{revision_id}/restore",
+    tag = "Posts Revisions",
+    params(
+        ("id" = i32, description = "ID of the post"),
+        ("revision_id" = i32, description = "ID of the revision to restore")
+    ),
+    responses(
+        (status = 200, description = "Revision restored", body = PostDTO),
+        (status = 404, description = "Post or revision not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/posts/{id}/revisions/{revision_id}/restore")]
+pub async fn restore_post_revision_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    path: Path<(i32, i32)>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    let (post_id, revision_id) = path.into_inner();
+
+    match restore_post_revision_service(
+        pool.get_ref(),
+        &current_user,
+        post_id,
+        revision_id,
+    )
+    .await
+    {
+        Ok(post) => Ok(HttpResponse::Ok().json(&post)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtos::post_dto::PostDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    #[ntex::test]
+    async fn test_restore_post_revision_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(restore_post_revision_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-post-restore-revision")
+            .await
+            .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            VALUES ($1, $2, $3, $4, $5::posts_status)
+            RETURNING id
+            "#,
+            "Test Post Restore Revision Current",
+            "Current content",
+            "test-post-restore-revision",
+            author_id,
+            PostsStatus::Draft as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        let revision_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO post_revisions
+                (post_id, author_id, title, content, status)
+            VALUES ($1, $2, $3, $4, $5::posts_status)
+            RETURNING id
+            "#,
+            post_id,
+            author_id,
+            "Test Post Restore Revision Original",
+            "Original content",
+            PostsStatus::Draft as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test revision");
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri(&format!(
+                "/posts/{}/revisions/{}/restore",
+                post_id, revision_id
+            ))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let response_body: PostDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(response_body.title, "Test Post Restore Revision Original");
+        assert_eq!(response_body.content, "Original content");
+
+        let revisions_count = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM post_revisions WHERE post_id = $1",
+            post_id
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to count revisions");
+        assert_eq!(revisions_count, Some(2));
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-post-restore-revision")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_restore_post_revision_controller_revision_not_found() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(restore_post_revision_controller),
+        )
+        .await;
+
+        clean_data_test(
+            &pool,
+            "posts",
+            "slug",
+            "test-post-restore-revision-missing",
+        )
+        .await
+        .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            VALUES ($1, $2, $3, $4, $5::posts_status)
+            RETURNING id
+            "#,
+            "Test Post Restore Revision Missing",
+            "Current content",
+            "test-post-restore-revision-missing",
+            author_id,
+            PostsStatus::Draft as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri(&format!("/posts/{}/revisions/999999/restore", post_id))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+        // Clean up test data
+        clean_data_test(
+            &pool,
+            "posts",
+            "slug",
+            "test-post-restore-revision-missing",
+        )
+        .await
+        .expect("Failed to clean up test data");
+    }
+}
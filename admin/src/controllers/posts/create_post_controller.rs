@@ -3,8 +3,9 @@ use ntex::web::{self, HttpResponse};
 use sqlx::PgPool;
 
 use crate::{
-    dtos::post_dto::CreatePostDTO,
+    dtos::{auth_dtos::CurrentUserDTO, post_dto::CreatePostDTO},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::posts_services::create_post_service,
 };
 
@@ -16,18 +17,273 @@ use crate::{
     responses(
         (status = 201, description = "Post created successfully", body = PostDTO),
         (status = 400, description = "Validation error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal server error", body = Error)
     )
 )]
 #[web::post("/posts")]
 pub async fn create_post_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     post_dto: Json<CreatePostDTO>,
 ) -> Result<HttpResponse, web::Error> {
-    match create_post_service(pool.get_ref(), post_dto.into_inner()).await {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match create_post_service(
+        pool.get_ref(),
+        &current_user,
+        post_dto.into_inner(),
+    )
+    .await
+    {
         Ok(post_with_categories) => {
             Ok(HttpResponse::Created().json(&post_with_categories))
         }
         Err(err) => Err(convert_anyhow_to_ntex(err)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtos::post_dto::PostDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    fn new_post(
+        author_id: i32,
+        slug: &str,
+        categories_ids: Vec<i32>,
+        tags_ids: Vec<i32>,
+    ) -> CreatePostDTO {
+        CreatePostDTO {
+            title: String::from("Test Create Post"),
+            content: String::from("Test Create Post Content"),
+            slug: Some(slug.to_string()),
+            author_id,
+            status: PostsStatus::Draft,
+            date_published: None,
+            featured_media_id: None,
+            categories_ids,
+            tags_ids,
+        }
+    }
+
+    #[ntex::test]
+    async fn test_create_post_controller_with_categories_and_tags() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_post_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-create-post-terms")
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "categories", "slug", "test-create-post-terms")
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "tags", "slug", "test-create-post-terms")
+            .await
+            .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        let category_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO categories (name, slug) VALUES ($1, $2) RETURNING id
+            "#,
+            "Test Create Post Terms",
+            "test-create-post-terms"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test category");
+
+        let tag_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO tags (name, slug) VALUES ($1, $2) RETURNING id
+            "#,
+            "Test Create Post Terms",
+            "test-create-post-terms"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test tag");
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/posts")
+            .set_json(&new_post(
+                author_id,
+                "test-create-post-terms",
+                vec![category_id],
+                vec![tag_id],
+            ))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let body = test::read_body(resp).await;
+        let post: PostDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(post.slug.as_deref(), Some("test-create-post-terms"));
+        assert_eq!(
+            post.categories,
+            Some(serde_json::json!([{
+                "id": category_id,
+                "name": "Test Create Post Terms",
+                "slug": "test-create-post-terms",
+                "description": null
+            }]))
+        );
+        assert_eq!(
+            post.tags,
+            Some(serde_json::json!([{
+                "id": tag_id,
+                "name": "Test Create Post Terms",
+                "slug": "test-create-post-terms"
+            }]))
+        );
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-create-post-terms")
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "categories", "slug", "test-create-post-terms")
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "tags", "slug", "test-create-post-terms")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_create_post_controller_unknown_category_rolls_back() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_post_controller),
+        )
+        .await;
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/posts")
+            .set_json(&new_post(
+                author_id,
+                "test-create-post-rollback",
+                vec![999999],
+                vec![],
+            ))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+        let posts_count: Option<i64> = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM posts WHERE slug = $1",
+            "test-create-post-rollback"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to count posts");
+        assert_eq!(posts_count, Some(0));
+    }
+
+    #[ntex::test]
+    async fn test_create_post_controller_unknown_featured_media() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_post_controller),
+        )
+        .await;
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        let mut post = new_post(
+            author_id,
+            "test-create-post-unknown-media",
+            vec![],
+            vec![],
+        );
+        post.featured_media_id = Some(2_147_483_647);
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/posts")
+            .set_json(&post)
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[ntex::test]
+    async fn test_create_post_controller_service_failure() {
+        // Arrange
+        let invalid_pool = PgPool::connect("postgres://invalid_url").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(invalid_pool.unwrap_err())
+                .service(create_post_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/posts")
+            .set_json(&new_post(1, "test-create-post", vec![], vec![]))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
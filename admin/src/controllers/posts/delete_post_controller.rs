@@ -6,8 +6,9 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::post_dto::DeletePostIdsDTO,
+    dtos::{auth_dtos::CurrentUserDTO, post_dto::DeletePostIdsDTO},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::posts_services::delete_post_service,
 };
 
@@ -19,16 +20,27 @@ use crate::{
     responses(
         (status = 200, description = "Posts deleted", body = i32),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::delete("/posts")]
 pub async fn delete_post_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     delete_post_ids_dto: Json<DeletePostIdsDTO>,
 ) -> Result<HttpResponse, web::Error> {
-    match delete_post_service(pool.get_ref(), delete_post_ids_dto.into_inner())
-        .await
+    current_user
+        .require(Capability::DeletePosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match delete_post_service(
+        pool.get_ref(),
+        &current_user,
+        delete_post_ids_dto.into_inner(),
+    )
+    .await
     {
         Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
@@ -42,15 +54,20 @@ mod tests {
     use serde_json::from_slice;
 
     use super::*;
+    use crate::middlewares::auth_middleware::JwtAuth;
     use crate::models::posts_model::PostsStatus;
-    use crate::tests::helpers::setup::setup_test_db;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
 
     #[ntex::test]
     async fn test_delete_post_success() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(delete_post_controller),
         )
@@ -85,6 +102,9 @@ mod tests {
             .id
         };
 
+        clean_data_test(&pool, "posts", "slug", "test-delete-post")
+            .await
+            .expect("Failed to clean up test data");
         let inserted_post = sqlx::query!(
             r#"
             INSERT INTO posts (title, content, slug, author_id, status) 
@@ -107,6 +127,7 @@ mod tests {
             .set_json(&DeletePostIdsDTO {
                 ids: vec![inserted_post.id],
             })
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -115,8 +136,8 @@ mod tests {
 
         // Check that the response contains the deleted post ID
         let body = test::read_body(resp).await;
-        let deleted_ids: DeletePostIdsDTO =
+        let deleted_ids: Vec<i32> =
             from_slice(&body).expect("Failed to parse response body");
-        assert_eq!(deleted_ids.ids, vec![inserted_post.id]);
+        assert_eq!(deleted_ids, vec![inserted_post.id]);
     }
 }
@@ -6,8 +6,12 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::pagination_dto::PaginationParamsDTO,
+    dtos::{
+        auth_dtos::CurrentUserDTO, pagination_dto::PaginationParamsDTO,
+        post_dto::PostFiltersDTO,
+    },
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::posts_services::get_all_posts_service,
 };
 
@@ -19,19 +23,28 @@ use crate::{
     ("page" = Option<i32>, Query, description = "The page number for pagination"),
     ("limit" = Option<i32>, Query, description = "The number of items per page"),
     ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'id', 'name')"),
-    ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')")
+    ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')"),
+    PostFiltersDTO
   ),
     responses(
         (status = 200, description = "Get all posts", body = PostDTO),
         (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error")
     )
 )]
 #[web::get("/posts")]
 pub async fn get_all_posts_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     params: Query<PaginationParamsDTO>,
+    filters: Query<PostFiltersDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
     let page = params.page.unwrap_or(1);
     let limit = params.limit.unwrap_or(25);
     let sort_column = params.sort_column.as_deref().unwrap_or("id");
@@ -39,6 +52,7 @@ pub async fn get_all_posts_controller(
 
     match get_all_posts_service(
         pool.get_ref(),
+        &filters,
         page,
         limit,
         sort_column,
@@ -54,9 +68,12 @@ pub async fn get_all_posts_controller(
 #[cfg(test)]
 mod tests {
     use crate::controllers::posts::get_all_posts_controller::get_all_posts_controller;
-    use crate::dtos::post_dto::PostDTO;
+    use crate::dtos::{pagination_dto::PaginationDTO, post_dto::PostDTO};
+    use crate::middlewares::auth_middleware::JwtAuth;
     use crate::models::posts_model::PostsStatus;
-    use crate::tests::helpers::setup::{clean_data_test, setup_test_db};
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
     use ntex::http;
     use ntex::web::{self, test};
 
@@ -64,8 +81,10 @@ mod tests {
     async fn test_get_all_posts_controller_success() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(get_all_posts_controller),
         )
@@ -100,6 +119,9 @@ mod tests {
             .id
         };
 
+        clean_data_test(&pool, "posts", "slug", "test-post")
+            .await
+            .expect("Failed to clean up test data");
         let inserted_post = sqlx::query!(
             r#"
             INSERT INTO posts (title, content, slug, author_id, status) 
@@ -119,6 +141,7 @@ mod tests {
         // Act
         let req = test::TestRequest::get()
             .uri("/posts?limit=100&offset=0")
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -126,18 +149,122 @@ mod tests {
         assert_eq!(resp.status(), http::StatusCode::OK);
 
         let body = test::read_body(resp).await;
-        let response_body: Vec<PostDTO> =
+        let response_body: PaginationDTO<PostDTO> =
             serde_json::from_slice(&body).expect("Failed to parse JSON");
 
-        let post = response_body.iter().find(|&post| {
-            post.title == "Test Post" && post.slug == "test-post"
+        let post = response_body.data.iter().find(|&post| {
+            post.title == "Test Post"
+                && post.slug.as_deref() == Some("test-post")
         });
 
         assert!(post.is_some(), "Post not found in the response");
         assert_eq!(post.unwrap().status, PostsStatus::Published);
 
         // Clean up test data
-        clean_data_test(&pool, "posts", "name", "Test Post")
+        clean_data_test(&pool, "posts", "slug", "test-post")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_get_all_posts_controller_filters() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_all_posts_controller),
+        )
+        .await;
+
+        sqlx::query!("DELETE FROM posts WHERE slug LIKE 'test-filter-post-%'")
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "tags", "slug", "test-filter-posts")
+            .await
+            .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        let tag_id: i32 = sqlx::query_scalar!(
+            "INSERT INTO tags (name, slug) VALUES ($1, $2) RETURNING id",
+            "Test Filter Posts",
+            "test-filter-posts"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test tag");
+
+        let mut posts_ids = vec![];
+        for (slug, status, date_published) in [
+            ("test-filter-post-old", PostsStatus::Published, "2020-01-01"),
+            ("test-filter-post-new", PostsStatus::Published, "2024-01-01"),
+            ("test-filter-post-draft", PostsStatus::Draft, "2024-01-01"),
+        ] {
+            let post_id: i32 = sqlx::query_scalar!(
+                r#"
+                INSERT INTO posts
+                    (title, content, slug, author_id, status, date_published)
+                VALUES ($1, $2, $3, $4, $5::posts_status, $6::text::timestamp)
+                RETURNING id
+                "#,
+                "Test Filter Post",
+                "Test Filter Post Content",
+                slug,
+                author_id,
+                status as _,
+                date_published,
+            )
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to insert test post");
+            posts_ids.push(post_id);
+
+            sqlx::query!(
+                "INSERT INTO posts_tags (post_id, tag_id) VALUES ($1, $2)",
+                post_id,
+                tag_id
+            )
+            .execute(&pool)
+            .await
+            .expect("Failed to tag test post");
+        }
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/posts?limit=1&tag_id={}&status=Published\
+                &published_after=2023-01-01T00:00:00",
+                tag_id
+            ))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let response_body: PaginationDTO<PostDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(response_body.total_items, 1);
+        assert_eq!(response_body.data.len(), 1);
+        assert_eq!(response_body.data[0].id, Some(posts_ids[1]));
+
+        // Clean up test data
+        sqlx::query!("DELETE FROM posts WHERE slug LIKE 'test-filter-post-%'")
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "tags", "slug", "test-filter-posts")
             .await
             .expect("Failed to clean up test data");
     }
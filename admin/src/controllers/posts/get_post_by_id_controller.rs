@@ -2,7 +2,9 @@ use ntex::web::{self, Error, HttpResponse};
 use sqlx::PgPool;
 
 use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::posts_services::get_post_by_id_service,
 };
 
@@ -16,14 +18,21 @@ use crate::{
     responses(
         (status = 200, description = "Post retrieved", body = PostDTO),
         (status = 404, description = "Post not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::get("/posts/{id}")]
 pub async fn get_post_by_id_controller(
     pool: web::types::State<PgPool>,
+    current_user: CurrentUserDTO,
     post_id: web::types::Path<i32>,
 ) -> Result<HttpResponse, Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match get_post_by_id_service(pool.get_ref(), post_id.into_inner()).await {
         Ok(post) => Ok(HttpResponse::Ok().json(&post)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
@@ -34,8 +43,11 @@ pub async fn get_post_by_id_controller(
 mod tests {
     use crate::controllers::posts::get_post_by_id_controller::get_post_by_id_controller;
     use crate::dtos::post_dto::PostDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
     use crate::models::posts_model::PostsStatus;
-    use crate::tests::helpers::setup::setup_test_db;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
     use chrono::NaiveDateTime;
     use ntex::http;
     use ntex::web::{self, test};
@@ -44,8 +56,10 @@ mod tests {
     async fn test_post_by_id_controller_success() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(get_post_by_id_controller),
         )
@@ -80,6 +94,9 @@ mod tests {
             .id
         };
 
+        clean_data_test(&pool, "posts", "slug", "test-post")
+            .await
+            .expect("Failed to clean up test data");
         let inserted_post = sqlx::query!(
             r#"
             INSERT INTO posts (title, content, slug, author_id, status) 
@@ -99,6 +116,7 @@ mod tests {
         // Act
         let req = test::TestRequest::get()
             .uri(&format!("/posts/{}", inserted_post.id))
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -111,7 +129,7 @@ mod tests {
 
         let post = &response_body;
         assert_eq!(post.title, "Test Post");
-        assert_eq!(post.slug, "test-post");
+        assert_eq!(post.slug.as_deref(), Some("test-post"));
         assert_eq!(post.status, PostsStatus::Published);
 
         // Clean Data
@@ -2,4 +2,5 @@ pub mod create_post_controller;
 pub mod delete_post_controller;
 pub mod get_all_posts_controller;
 pub mod get_post_by_id_controller;
+pub mod search_posts_controller;
 pub mod update_post_controller;
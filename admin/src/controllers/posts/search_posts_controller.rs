@@ -0,0 +1,180 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, post_dto::PostSearchParamsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::posts_services::search_posts_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/posts/search",
+    tag = "Posts",
+    params(PostSearchParamsDTO),
+    responses(
+        (status = 200, description = "Posts matching the search, best matches first", body = PostSearchDTO),
+        (status = 400, description = "Bad Request", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts/search")]
+pub async fn search_posts_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    params: Query<PostSearchParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match search_posts_service(pool.get_ref(), params.into_inner()).await {
+        Ok(posts) => Ok(HttpResponse::Ok().json(&posts)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtos::{pagination_dto::PaginationDTO, post_dto::PostSearchDTO};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    #[ntex::test]
+    async fn test_search_posts_controller_ranks_title_first() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(search_posts_controller),
+        )
+        .await;
+
+        sqlx::query!("DELETE FROM posts WHERE slug LIKE 'test-search-post-%'")
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        for (title, content, slug) in [
+            (
+                "Gardening notes",
+                "Some words about xylophonist tuning.",
+                "test-search-post-content",
+            ),
+            (
+                "The xylophonist",
+                "A post about music.",
+                "test-search-post-title",
+            ),
+            (
+                "Unrelated",
+                "Nothing to see here.",
+                "test-search-post-unrelated",
+            ),
+        ] {
+            sqlx::query!(
+                r#"
+                INSERT INTO posts (title, content, slug, author_id, status)
+                VALUES ($1, $2, $3, $4, $5::posts_status)
+                "#,
+                title,
+                content,
+                slug,
+                author_id,
+                PostsStatus::Draft as _,
+            )
+            .execute(&pool)
+            .await
+            .expect("Failed to insert test post");
+        }
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/posts/search?q=xylophonist")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let response_body: PaginationDTO<PostSearchDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+
+        assert_eq!(response_body.total_items, 2);
+        let slugs: Vec<Option<String>> = response_body
+            .data
+            .iter()
+            .map(|result| result.post.slug.clone())
+            .collect();
+        assert_eq!(
+            slugs,
+            vec![
+                Some("test-search-post-title".to_string()),
+                Some("test-search-post-content".to_string())
+            ]
+        );
+        assert!(response_body.data[1]
+            .headline
+            .contains("<mark>xylophonist</mark>"));
+
+        // Clean up test data
+        for slug in [
+            "test-search-post-content",
+            "test-search-post-title",
+            "test-search-post-unrelated",
+        ] {
+            clean_data_test(&pool, "posts", "slug", slug)
+                .await
+                .expect("Failed to clean up test data");
+        }
+    }
+
+    #[ntex::test]
+    async fn test_search_posts_controller_empty_query() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(search_posts_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/posts/search?q=")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+}
@@ -2,8 +2,12 @@ use ntex::web::{self, HttpResponse};
 use sqlx::PgPool;
 
 use crate::{
-    dtos::post_dto::{CreatePostDTO, PostDTO},
+    dtos::{
+        auth_dtos::CurrentUserDTO,
+        post_dto::{CreatePostDTO, PostDTO},
+    },
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::posts_services::update_post_service,
 };
 
@@ -19,17 +23,25 @@ use crate::{
         (status = 200, description = "Post updated", body = PostDTO),
         (status = 400, description = "Validation Error", body = Error),
         (status = 404, description = "Post not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::put("/posts/{id}")]
 pub async fn update_post_controller(
     pool: web::types::State<PgPool>,
+    current_user: CurrentUserDTO,
     post_id: web::types::Path<i32>,
     post_dto: web::types::Json<CreatePostDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match update_post_service(
         pool.get_ref(),
+        &current_user,
         post_id.into_inner(),
         post_dto.into_inner(),
     )
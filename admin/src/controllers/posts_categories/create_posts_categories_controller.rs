@@ -2,8 +2,10 @@ use ntex::web::types::{Json, State};
 use ntex::web::{self, HttpResponse};
 use sqlx::PgPool;
 
+use crate::dtos::auth_dtos::CurrentUserDTO;
 use crate::dtos::posts_categories_dto::CreatePostsCategoriesDTO;
 use crate::handlers::error_to_response_handler::convert_anyhow_to_ntex;
+use crate::models::capabilities_model::Capability;
 use crate::services::posts_categories_service::create_post_category_service;
 
 #[utoipa::path(
@@ -14,14 +16,21 @@ use crate::services::posts_categories_service::create_post_category_service;
     responses(
         (status = 201, description = "Posts Categories created successfully", body = PostsCategoriesDTO),
         (status = 400, description = "Validation error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal server error", body = Error)
     )
 )]
 #[web::post("/posts-categories")]
 pub async fn create_posts_categories_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     posts_categories_dto: Json<CreatePostsCategoriesDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match create_post_category_service(
         pool.get_ref(),
         posts_categories_dto.into_inner(),
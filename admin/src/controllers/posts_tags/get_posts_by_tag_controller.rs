@@ -0,0 +1,218 @@
+use ntex::web::{
+    self,
+    types::{Path, Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, pagination_dto::PaginationParamsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::posts_services::get_posts_by_tag_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/tags/{id}/posts",
+    tag = "Posts Tags",
+    params(
+        ("id" = i32, description = "ID of the tag"),
+        ("page" = Option<i32>, Query, description = "The page number for pagination"),
+        ("limit" = Option<i32>, Query, description = "The number of items per page"),
+        ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'id', 'title')"),
+        ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')")
+    ),
+    responses(
+        (status = 200, description = "Get the posts of a tag", body = PostDTO),
+        (status = 400, description = "Bad Request", body = Error),
+        (status = 404, description = "Tag not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/tags/{id}/posts")]
+pub async fn get_posts_by_tag_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    tag_id: Path<i32>,
+    params: Query<PaginationParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(25);
+    let sort_column = params.sort_column.as_deref().unwrap_or("id");
+    let sort_order = params.sort_order.as_deref().unwrap_or("desc");
+
+    match get_posts_by_tag_service(
+        pool.get_ref(),
+        tag_id.into_inner(),
+        page,
+        limit,
+        sort_column,
+        sort_order,
+    )
+    .await
+    {
+        Ok(posts) => Ok(HttpResponse::Ok().json(&posts)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtos::{pagination_dto::PaginationDTO, post_dto::PostDTO};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    #[ntex::test]
+    async fn test_get_posts_by_tag_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_posts_by_tag_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "tags", "slug", "test-posts-by-tag")
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "posts", "slug", "test-post-by-tag")
+            .await
+            .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        let tag_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO tags (name, slug) VALUES ($1, $2) RETURNING id
+            "#,
+            "Test Posts By Tag",
+            "test-posts-by-tag"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test tag");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            VALUES ($1, $2, $3, $4, $5::posts_status)
+            RETURNING id
+            "#,
+            "Test Post By Tag",
+            "Test Post By Tag Content",
+            "test-post-by-tag",
+            author_id,
+            PostsStatus::Draft as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        sqlx::query!(
+            "INSERT INTO posts_tags (post_id, tag_id) VALUES ($1, $2)",
+            post_id,
+            tag_id
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to tag test post");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri(&format!("/tags/{}/posts", tag_id))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let response_body: PaginationDTO<PostDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+
+        assert_eq!(response_body.total_items, 1);
+        let post = &response_body.data[0];
+        assert_eq!(post.id, Some(post_id));
+        assert_eq!(
+            post.tags,
+            Some(serde_json::json!([{
+                "id": tag_id,
+                "name": "Test Posts By Tag",
+                "slug": "test-posts-by-tag"
+            }]))
+        );
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-post-by-tag")
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "tags", "slug", "test-posts-by-tag")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_get_posts_by_tag_controller_not_found() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_posts_by_tag_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/tags/999999/posts")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    #[ntex::test]
+    async fn test_get_posts_by_tag_controller_service_failure() {
+        // Arrange
+        let invalid_pool = PgPool::connect("postgres://invalid_url").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(invalid_pool.unwrap_err())
+                .service(get_posts_by_tag_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get().uri("/tags/1/posts").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
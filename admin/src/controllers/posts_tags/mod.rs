@@ -0,0 +1,2 @@
+pub mod get_posts_by_tag_controller;
+pub mod update_posts_tags_controller;
@@ -0,0 +1,221 @@
+use ntex::web::{
+    self,
+    types::{Json, Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, posts_tags_dto::UpdatePostsTagsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::posts_tags_service::update_posts_tags_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/posts/{id}/tags",
+    tag = "Posts Tags",
+    request_body = UpdatePostsTagsDTO,
+    params(
+        ("id" = i32, description = "ID of the post")
+    ),
+    responses(
+        (status = 200, description = "Tags of the post replaced", body = [PostsTagsDTO]),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 404, description = "Post not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/posts/{id}/tags")]
+pub async fn update_posts_tags_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    post_id: Path<i32>,
+    posts_tags_dto: Json<UpdatePostsTagsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::EditPosts)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match update_posts_tags_service(
+        pool.get_ref(),
+        &current_user,
+        post_id.into_inner(),
+        posts_tags_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(posts_tags) => Ok(HttpResponse::Ok().json(&posts_tags)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtos::posts_tags_dto::PostsTagsDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    #[ntex::test]
+    async fn test_update_posts_tags_controller_replaces_tags() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_posts_tags_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-post-replace-tags")
+            .await
+            .expect("Failed to clean up test data");
+        sqlx::query!(
+            "DELETE FROM tags WHERE slug IN ('test-old-tag', 'test-new-tag')"
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to clean up test data");
+
+        let author_id: i32 = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE username = 'test-administrator'"
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to query test user");
+
+        let post_id: i32 = sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            VALUES ($1, $2, $3, $4, $5::posts_status)
+            RETURNING id
+            "#,
+            "Test Post Replace Tags",
+            "Test Post Replace Tags Content",
+            "test-post-replace-tags",
+            author_id,
+            PostsStatus::Draft as _,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+
+        let tags_ids: Vec<i32> = sqlx::query_scalar!(
+            r#"
+            INSERT INTO tags (name, slug)
+            VALUES ('Test Old Tag', 'test-old-tag'),
+                ('Test New Tag', 'test-new-tag')
+            RETURNING id
+            "#
+        )
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to insert test tags");
+
+        sqlx::query!(
+            "INSERT INTO posts_tags (post_id, tag_id) VALUES ($1, $2)",
+            post_id,
+            tags_ids[0]
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to tag test post");
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri(&format!("/posts/{}/tags", post_id))
+            .set_json(&UpdatePostsTagsDTO {
+                tags_ids: vec![tags_ids[1], tags_ids[1]],
+            })
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let response_body: Vec<PostsTagsDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(response_body.len(), 1);
+        assert_eq!(response_body[0].tag_id, tags_ids[1]);
+
+        let stored_tags_ids: Vec<i32> = sqlx::query_scalar!(
+            "SELECT tag_id FROM posts_tags WHERE post_id = $1",
+            post_id
+        )
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to query post tags");
+        assert_eq!(stored_tags_ids, vec![tags_ids[1]]);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-post-replace-tags")
+            .await
+            .expect("Failed to clean up test data");
+        sqlx::query!(
+            "DELETE FROM tags WHERE slug IN ('test-old-tag', 'test-new-tag')"
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_update_posts_tags_controller_post_not_found() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_posts_tags_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/posts/999999/tags")
+            .set_json(&UpdatePostsTagsDTO { tags_ids: vec![] })
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    #[ntex::test]
+    async fn test_update_posts_tags_controller_service_failure() {
+        // Arrange
+        let invalid_pool = PgPool::connect("postgres://invalid_url").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(invalid_pool.unwrap_err())
+                .service(update_posts_tags_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/posts/1/tags")
+            .set_json(&UpdatePostsTagsDTO { tags_ids: vec![] })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,65 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::public_service::get_public_category_by_slug_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/categories/{slug}",
+    tag = "Public",
+    params(
+        ("slug" = String, description = "Slug of the category")
+    ),
+    responses(
+        (status = 200, description = "Category", body = CategoryDTO),
+        (status = 404, description = "Category not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/categories/{slug}")]
+pub async fn get_public_category_controller(
+    pool: State<PgPool>,
+    slug: Path<String>,
+) -> Result<HttpResponse, web::Error> {
+    match get_public_category_by_slug_service(pool.get_ref(), &slug).await {
+        Ok(category) => Ok(HttpResponse::Ok().json(&category)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::tests::helpers::setup::setup_test_db;
+
+    #[ntex::test]
+    async fn test_get_public_category_controller_not_found() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_category_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/categories/test-public-category-unknown")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+}
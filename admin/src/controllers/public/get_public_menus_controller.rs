@@ -0,0 +1,61 @@
+use ntex::web::{self, types::State, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::menus_service::get_public_menus_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/menus",
+    tag = "Public",
+    responses(
+        (status = 200, description = "Menu of every theme location which has one, published items only", body = HashMap<String, PublicMenuDTO>),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/menus")]
+pub async fn get_public_menus_controller(
+    pool: State<PgPool>,
+) -> Result<HttpResponse, web::Error> {
+    match get_public_menus_service(pool.get_ref()).await {
+        Ok(menus) => Ok(HttpResponse::Ok().json(&menus)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::menu_dto::PublicMenuDTO;
+    use crate::tests::helpers::setup::setup_test_db;
+
+    #[ntex::test]
+    async fn test_get_public_menus_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_menus_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get().uri("/menus").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let _: BTreeMap<String, PublicMenuDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+    }
+}
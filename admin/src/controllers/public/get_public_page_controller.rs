@@ -0,0 +1,95 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::public_service::get_public_page_by_slug_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/pages/{slug}",
+    tag = "Public",
+    params(
+        ("slug" = String, description = "Slug of the page")
+    ),
+    responses(
+        (status = 200, description = "Published page", body = PublicPageDTO),
+        (status = 404, description = "Page not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/pages/{slug}")]
+pub async fn get_public_page_controller(
+    pool: State<PgPool>,
+    slug: Path<String>,
+) -> Result<HttpResponse, web::Error> {
+    match get_public_page_by_slug_service(pool.get_ref(), &slug).await {
+        Ok(page) => Ok(HttpResponse::Ok().json(&page)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::public_dto::PublicPageDTO;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_get_public_page_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_page_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "posts", "slug", "test-public-page")
+            .await
+            .expect("Failed to clean up test data");
+
+        sqlx::query(
+            "INSERT INTO posts \
+            (title, content, slug, author_id, status, post_type, template) \
+            SELECT 'Test Public Page', 'Content', 'test-public-page', id, \
+            'Published', 'Page', 'full-width' \
+            FROM users WHERE username = 'test-editor'",
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test page");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/pages/test-public-page")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let page: PublicPageDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(page.title, "Test Public Page");
+        assert_eq!(page.template.as_deref(), Some("full-width"));
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-public-page")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
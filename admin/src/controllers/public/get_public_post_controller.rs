@@ -0,0 +1,133 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::public_service::get_public_post_by_slug_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/posts/{slug}",
+    tag = "Public",
+    params(
+        ("slug" = String, description = "Slug of the post")
+    ),
+    responses(
+        (status = 200, description = "Published post", body = PublicPostDTO),
+        (status = 404, description = "Post not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts/{slug}")]
+pub async fn get_public_post_controller(
+    pool: State<PgPool>,
+    slug: Path<String>,
+) -> Result<HttpResponse, web::Error> {
+    match get_public_post_by_slug_service(pool.get_ref(), &slug).await {
+        Ok(post) => Ok(HttpResponse::Ok().json(&post)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::public_dto::PublicPostDTO;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    /// Inserts a post of the test author.
+    async fn insert_post(pool: &PgPool, slug: &str, status: PostsStatus) {
+        setup_test_auth(pool, "author").await;
+        clean_data_test(pool, "posts", "slug", slug)
+            .await
+            .expect("Failed to clean up test data");
+
+        sqlx::query(
+            "INSERT INTO posts (title, content, slug, author_id, status) \
+            SELECT 'Test Public Post', 'Content', $1, id, $2 \
+            FROM users WHERE username = 'test-author'",
+        )
+        .bind(slug)
+        .bind(status)
+        .execute(pool)
+        .await
+        .expect("Failed to insert test post");
+    }
+
+    #[ntex::test]
+    async fn test_get_public_post_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_post_controller),
+        )
+        .await;
+
+        insert_post(&pool, "test-public-post", PostsStatus::Published).await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/posts/test-public-post")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let post: PublicPostDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(post.title, "Test Public Post");
+        assert_eq!(
+            post.author.map(|author| author.username).as_deref(),
+            Some("test-author")
+        );
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-public-post")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_get_public_post_controller_private() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_post_controller),
+        )
+        .await;
+
+        insert_post(&pool, "test-public-post-private", PostsStatus::Private)
+            .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/posts/test-public-post-private")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-public-post-private")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,201 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::public_dto::{PublicPaginationParamsDTO, PublicPostFiltersDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::public_service::get_public_posts_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/posts",
+    tag = "Public",
+    params(
+        PublicPaginationParamsDTO,
+        PublicPostFiltersDTO
+    ),
+    responses(
+        (status = 200, description = "Published posts, latest first", body = PublicPostDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 404, description = "Category, tag or author not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts")]
+pub async fn get_public_posts_controller(
+    pool: State<PgPool>,
+    params: Query<PublicPaginationParamsDTO>,
+    filters: Query<PublicPostFiltersDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match get_public_posts_service(
+        pool.get_ref(),
+        filters.into_inner(),
+        params.into_inner(),
+    )
+    .await
+    {
+        Ok(posts) => Ok(HttpResponse::Ok().json(&posts)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::Value;
+
+    use super::*;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_get_public_posts_controller_category() {
+        // Arrange
+        let pool = setup_test_db().await;
+        setup_test_auth(&pool, "author").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_posts_controller),
+        )
+        .await;
+
+        for slug in ["test-public-posts-published", "test-public-posts-draft"] {
+            clean_data_test(&pool, "posts", "slug", slug)
+                .await
+                .expect("Failed to clean up test data");
+        }
+        clean_data_test(&pool, "categories", "slug", "test-public-posts")
+            .await
+            .expect("Failed to clean up test data");
+
+        let category_id: i32 = sqlx::query_scalar(
+            "INSERT INTO categories (name, slug) \
+            VALUES ('Test Public Posts', 'test-public-posts') RETURNING id",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test category");
+
+        sqlx::query(
+            "WITH inserted AS (\
+                INSERT INTO posts \
+                (title, content, slug, author_id, status, date_published) \
+                SELECT v.title, 'Content', v.slug, users.id, \
+                v.status::posts_status, '2024-03-15 10:00:00' \
+                FROM users, (VALUES \
+                    ('Published', 'test-public-posts-published', 'Published'), \
+                    ('Draft', 'test-public-posts-draft', 'Draft')\
+                ) AS v(title, slug, status) \
+                WHERE users.username = 'test-author' \
+                RETURNING id\
+            ) \
+            INSERT INTO posts_categories (post_id, category_id) \
+            SELECT id, $1 FROM inserted",
+        )
+        .bind(category_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test posts");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/posts?category=test-public-posts&year=2024&month=3")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let posts: Value =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(posts["total_items"], 1);
+        let post = &posts["data"][0];
+        assert_eq!(post["slug"], "test-public-posts-published");
+        assert_eq!(post["author"]["username"], "test-author");
+        assert!(post["author"].get("email").is_none());
+
+        // Clean up test data
+        for slug in ["test-public-posts-published", "test-public-posts-draft"] {
+            clean_data_test(&pool, "posts", "slug", slug)
+                .await
+                .expect("Failed to clean up test data");
+        }
+        clean_data_test(&pool, "categories", "slug", "test-public-posts")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_get_public_posts_controller_unknown_category() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_posts_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/posts?category=test-public-posts-unknown")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    #[ntex::test]
+    async fn test_get_public_posts_controller_month_without_year() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_posts_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get().uri("/posts?month=3").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[ntex::test]
+    async fn test_get_public_posts_controller_invalid_pagination() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_posts_controller),
+        )
+        .await;
+
+        for uri in [
+            "/posts?page=0",
+            "/posts?limit=-1",
+            "/posts?limit=2147483647",
+        ] {
+            // Act
+            let req = test::TestRequest::get().uri(uri).to_request();
+            let resp = test::call_service(&app, req).await;
+
+            // Assert
+            assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST, "{}", uri);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::public_dto::PublicRedirectParamsDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::public_service::get_public_redirect_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/redirects",
+    tag = "Public",
+    params(PublicRedirectParamsDTO),
+    responses(
+        (status = 200, description = "Redirect of the path, its hit being counted", body = PublicRedirectDTO),
+        (status = 404, description = "Path not redirected", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/redirects")]
+pub async fn get_public_redirect_controller(
+    pool: State<PgPool>,
+    params: Query<PublicRedirectParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match get_public_redirect_service(pool.get_ref(), &params.path).await {
+        Ok(redirect) => Ok(HttpResponse::Ok().json(&redirect)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::public_dto::PublicRedirectDTO;
+    use crate::dtos::redirect_dto::CreateRedirectDTO;
+    use crate::services::redirects_service::create_redirect_service;
+    use crate::tests::helpers::setup::{clean_data_test, setup_test_db};
+
+    #[ntex::test]
+    async fn test_get_public_redirect_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        clean_data_test(
+            &pool,
+            "redirects",
+            "source_path",
+            "/test-public-redirect",
+        )
+        .await
+        .expect("Failed to clean up test data");
+        create_redirect_service(
+            &pool,
+            CreateRedirectDTO {
+                source_path: String::from("/test-public-redirect"),
+                target: String::from("/test-public-redirect-target"),
+                status_code: Some(302),
+            },
+        )
+        .await
+        .expect("Failed to create the redirect");
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_redirect_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/redirects?path=/test-public-redirect/")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let redirect: PublicRedirectDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(redirect.target, "/test-public-redirect-target");
+        assert_eq!(redirect.status_code, 302);
+
+        // Clean up test data
+        clean_data_test(
+            &pool,
+            "redirects",
+            "source_path",
+            "/test-public-redirect",
+        )
+        .await
+        .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_get_public_redirect_controller_not_found() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_redirect_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/redirects?path=/test-public-redirect-unknown")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+}
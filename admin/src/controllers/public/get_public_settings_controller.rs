@@ -0,0 +1,61 @@
+use ntex::web::{self, types::State, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::settings_service::get_public_settings_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/settings",
+    tag = "Public",
+    responses(
+        (status = 200, description = "Settings the site is rendered with", body = PublicSettingsDTO),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/settings")]
+pub async fn get_public_settings_controller(
+    pool: State<PgPool>,
+) -> Result<HttpResponse, web::Error> {
+    match get_public_settings_service(pool.get_ref()).await {
+        Ok(settings) => Ok(HttpResponse::Ok().json(&settings)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::settings_dto::PublicSettingsDTO;
+    use crate::tests::helpers::setup::setup_test_db;
+
+    #[ntex::test]
+    async fn test_get_public_settings_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_settings_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get().uri("/settings").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let settings: PublicSettingsDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert!(!settings.title.is_empty());
+        assert!(settings.posts_per_page > 0);
+    }
+}
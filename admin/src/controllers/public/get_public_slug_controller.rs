@@ -0,0 +1,110 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::slugs_model::SlugType,
+    services::public_service::get_public_current_slug_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/slugs/{type}/{slug}",
+    tag = "Public",
+    params(
+        ("type" = SlugType, description = "Type of the content"),
+        ("slug" = String, description = "Former slug of the content")
+    ),
+    responses(
+        (status = 200, description = "Current slug of the content", body = PublicSlugDTO),
+        (status = 404, description = "No content had the slug", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/slugs/{type}/{slug}")]
+pub async fn get_public_slug_controller(
+    pool: State<PgPool>,
+    path: Path<(SlugType, String)>,
+) -> Result<HttpResponse, web::Error> {
+    let (slug_type, slug) = path.into_inner();
+
+    match get_public_current_slug_service(pool.get_ref(), slug_type, &slug)
+        .await
+    {
+        Ok(current_slug) => Ok(HttpResponse::Ok().json(&current_slug)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::public_dto::PublicSlugDTO;
+    use crate::dtos::tag_dto::CreateTagDTO;
+    use crate::services::tags_service::{
+        create_tag_service, update_tag_service,
+    };
+    use crate::tests::helpers::setup::{clean_data_test, setup_test_db};
+
+    #[ntex::test]
+    async fn test_get_public_slug_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        clean_data_test(&pool, "tags", "slug", "test-public-slug-renamed")
+            .await
+            .expect("Failed to clean up test data");
+        let tag = create_tag_service(
+            &pool,
+            CreateTagDTO {
+                name: String::from("Test Public Slug"),
+                slug: Some(String::from("test-public-slug-former")),
+                description: None,
+            },
+        )
+        .await
+        .expect("Failed to create the tag");
+        update_tag_service(
+            &pool,
+            tag.id.unwrap(),
+            CreateTagDTO {
+                name: String::from("Test Public Slug"),
+                slug: Some(String::from("test-public-slug-renamed")),
+                description: None,
+            },
+        )
+        .await
+        .expect("Failed to rename the tag");
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_slug_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/slugs/tag/test-public-slug-former")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let current: PublicSlugDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(current.slug, "test-public-slug-renamed");
+
+        // Clean up test data
+        clean_data_test(&pool, "tags", "slug", "test-public-slug-renamed")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
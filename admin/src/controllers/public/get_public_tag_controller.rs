@@ -0,0 +1,88 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::public_service::get_public_tag_by_slug_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/tags/{slug}",
+    tag = "Public",
+    params(
+        ("slug" = String, description = "Slug of the tag")
+    ),
+    responses(
+        (status = 200, description = "Tag", body = TagDTO),
+        (status = 404, description = "Tag not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/tags/{slug}")]
+pub async fn get_public_tag_controller(
+    pool: State<PgPool>,
+    slug: Path<String>,
+) -> Result<HttpResponse, web::Error> {
+    match get_public_tag_by_slug_service(pool.get_ref(), &slug).await {
+        Ok(tag) => Ok(HttpResponse::Ok().json(&tag)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::tag_dto::TagDTO;
+    use crate::tests::helpers::setup::{clean_data_test, setup_test_db};
+
+    #[ntex::test]
+    async fn test_get_public_tag_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_tag_controller),
+        )
+        .await;
+
+        clean_data_test(&pool, "tags", "slug", "test-public-tag")
+            .await
+            .expect("Failed to clean up test data");
+
+        sqlx::query(
+            "INSERT INTO tags (name, slug) \
+            VALUES ('Test Public Tag', 'test-public-tag')",
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test tag");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/tags/test-public-tag")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let tag: TagDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(tag.name, "Test Public Tag");
+
+        // Clean up test data
+        clean_data_test(&pool, "tags", "slug", "test-public-tag")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
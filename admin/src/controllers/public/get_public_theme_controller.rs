@@ -0,0 +1,60 @@
+use ntex::web::{self, types::State, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::themes_service::get_active_theme_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/theme",
+    tag = "Public",
+    responses(
+        (status = 200, description = "Theme rendering the site", body = ActiveThemeDTO),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/theme")]
+pub async fn get_public_theme_controller(
+    pool: State<PgPool>,
+) -> Result<HttpResponse, web::Error> {
+    match get_active_theme_service(pool.get_ref()).await {
+        Ok(theme) => Ok(HttpResponse::Ok().json(&theme)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::theme_dto::ActiveThemeDTO;
+    use crate::tests::helpers::setup::setup_test_db;
+
+    #[ntex::test]
+    async fn test_get_public_theme_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_public_theme_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get().uri("/theme").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let theme: ActiveThemeDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert!(!theme.name.is_empty());
+    }
+}
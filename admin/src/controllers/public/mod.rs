@@ -0,0 +1,10 @@
+pub mod get_public_category_controller;
+pub mod get_public_menus_controller;
+pub mod get_public_page_controller;
+pub mod get_public_post_controller;
+pub mod get_public_posts_controller;
+pub mod get_public_redirect_controller;
+pub mod get_public_settings_controller;
+pub mod get_public_slug_controller;
+pub mod get_public_tag_controller;
+pub mod get_public_theme_controller;
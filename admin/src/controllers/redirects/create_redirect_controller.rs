@@ -0,0 +1,161 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, redirect_dto::CreateRedirectDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::redirects_service::create_redirect_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/redirects",
+    tag = "Redirects",
+    request_body = CreateRedirectDTO,
+    responses(
+        (status = 201, description = "Redirect created", body = RedirectDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 409, description = "Source path already redirected", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/redirects")]
+pub async fn create_redirect_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    redirect_dto: Json<CreateRedirectDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match create_redirect_service(pool.get_ref(), redirect_dto.into_inner())
+        .await
+    {
+        Ok(redirect) => Ok(HttpResponse::Created().json(&redirect)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::dtos::redirect_dto::RedirectDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_create_redirect_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_redirect_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/redirects")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "source_path": "/test-create-redirect/",
+                "target": "/test-create-redirect-target"
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let body = test::read_body(resp).await;
+        let redirect: RedirectDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(redirect.source_path, "/test-create-redirect");
+        assert_eq!(redirect.status_code, 301);
+        assert_eq!(redirect.hits, 0);
+
+        // Clean up test data
+        clean_data_test(
+            &pool,
+            "redirects",
+            "source_path",
+            "/test-create-redirect",
+        )
+        .await
+        .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_create_redirect_controller_validation_failure() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_redirect_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/redirects")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "source_path": "/rs-admin/posts",
+                "target": "/posts",
+                "status_code": 303
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[ntex::test]
+    async fn test_create_redirect_controller_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_redirect_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/redirects")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "source_path": "/test-create-redirect-forbidden",
+                "target": "/posts"
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
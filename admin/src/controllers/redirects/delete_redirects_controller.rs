@@ -0,0 +1,107 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, redirect_dto::DeleteRedirectIdsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::redirects_service::delete_redirects_service,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/redirects",
+    tag = "Redirects",
+    request_body = DeleteRedirectIdsDTO,
+    responses(
+        (status = 200, description = "Redirects deleted", body = Vec<i32>),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/redirects")]
+pub async fn delete_redirects_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    redirect_ids: Json<DeleteRedirectIdsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match delete_redirects_service(pool.get_ref(), redirect_ids.into_inner())
+        .await
+    {
+        Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::dtos::redirect_dto::CreateRedirectDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::services::redirects_service::create_redirect_service;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_delete_redirects_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        clean_data_test(
+            &pool,
+            "redirects",
+            "source_path",
+            "/test-delete-redirects",
+        )
+        .await
+        .expect("Failed to clean up test data");
+        let redirect = create_redirect_service(
+            &pool,
+            CreateRedirectDTO {
+                source_path: String::from("/test-delete-redirects"),
+                target: String::from("/posts"),
+                status_code: None,
+            },
+        )
+        .await
+        .expect("Failed to create the redirect");
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(delete_redirects_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::delete()
+            .uri("/redirects")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "ids": [redirect.id.unwrap()] }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let deleted_ids: Vec<i32> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(deleted_ids, vec![redirect.id.unwrap()]);
+    }
+}
@@ -0,0 +1,135 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, pagination_dto::PaginationParamsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::redirects_service::get_all_redirects_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/redirects",
+    tag = "Redirects",
+    params(
+        ("page" = Option<i32>, Query, description = "The page number for pagination"),
+        ("limit" = Option<i32>, Query, description = "The number of items per page"),
+        ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'source_path', 'hits')"),
+        ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')")
+    ),
+    responses(
+        (status = 200, description = "Redirects, sorted by source path by default", body = RedirectDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/redirects")]
+pub async fn get_all_redirects_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    params: Query<PaginationParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(25);
+    let sort_column = params.sort_column.as_deref().unwrap_or("source_path");
+    let sort_order = params.sort_order.as_deref().unwrap_or("asc");
+
+    match get_all_redirects_service(
+        pool.get_ref(),
+        page,
+        limit,
+        sort_column,
+        sort_order,
+    )
+    .await
+    {
+        Ok(redirects) => Ok(HttpResponse::Ok().json(&redirects)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::pagination_dto::PaginationDTO;
+    use crate::dtos::redirect_dto::{CreateRedirectDTO, RedirectDTO};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::services::redirects_service::create_redirect_service;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_get_all_redirects_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        clean_data_test(
+            &pool,
+            "redirects",
+            "source_path",
+            "/test-get-all-redirects",
+        )
+        .await
+        .expect("Failed to clean up test data");
+        create_redirect_service(
+            &pool,
+            CreateRedirectDTO {
+                source_path: String::from("/test-get-all-redirects"),
+                target: String::from("https://example.com/"),
+                status_code: Some(302),
+            },
+        )
+        .await
+        .expect("Failed to create the redirect");
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_all_redirects_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/redirects?sort_column=date_created&sort_order=desc")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let redirects: PaginationDTO<RedirectDTO> =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(redirects.current_page, 1);
+        assert!(redirects.data.iter().any(|redirect| {
+            redirect.source_path == "/test-get-all-redirects"
+                && redirect.status_code == 302
+        }));
+
+        // Clean up test data
+        clean_data_test(
+            &pool,
+            "redirects",
+            "source_path",
+            "/test-get-all-redirects",
+        )
+        .await
+        .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,4 @@
+pub mod create_redirect_controller;
+pub mod delete_redirects_controller;
+pub mod get_all_redirects_controller;
+pub mod update_redirect_controller;
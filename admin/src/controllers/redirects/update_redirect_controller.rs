@@ -0,0 +1,132 @@
+use ntex::web::{
+    self,
+    types::{Json, Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, redirect_dto::CreateRedirectDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::redirects_service::update_redirect_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/redirects/{id}",
+    tag = "Redirects",
+    params(
+        ("id" = i32, description = "ID of the redirect")
+    ),
+    request_body = CreateRedirectDTO,
+    responses(
+        (status = 200, description = "Redirect updated", body = RedirectDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Redirect not found", body = Error),
+        (status = 409, description = "Source path already redirected", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/redirects/{id}")]
+pub async fn update_redirect_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    redirect_id: Path<i32>,
+    redirect_dto: Json<CreateRedirectDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match update_redirect_service(
+        pool.get_ref(),
+        redirect_id.into_inner(),
+        redirect_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(redirect) => Ok(HttpResponse::Ok().json(&redirect)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::dtos::redirect_dto::RedirectDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::services::redirects_service::create_redirect_service;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_update_redirect_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        clean_data_test(
+            &pool,
+            "redirects",
+            "source_path",
+            "/test-update-redirect",
+        )
+        .await
+        .expect("Failed to clean up test data");
+        let redirect = create_redirect_service(
+            &pool,
+            CreateRedirectDTO {
+                source_path: String::from("/test-update-redirect"),
+                target: String::from("/posts"),
+                status_code: None,
+            },
+        )
+        .await
+        .expect("Failed to create the redirect");
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_redirect_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri(&format!("/redirects/{}", redirect.id.unwrap()))
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "source_path": "/test-update-redirect",
+                "target": "/test-update-redirect-target",
+                "status_code": 308
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let updated: RedirectDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(updated.target, "/test-update-redirect-target");
+        assert_eq!(updated.status_code, 308);
+
+        // Clean up test data
+        clean_data_test(
+            &pool,
+            "redirects",
+            "source_path",
+            "/test-update-redirect",
+        )
+        .await
+        .expect("Failed to clean up test data");
+    }
+}
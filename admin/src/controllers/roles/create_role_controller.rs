@@ -0,0 +1,182 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, role_dto::CreateRoleDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::roles_service::create_role_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/roles",
+    tag = "Roles",
+    request_body = CreateRoleDTO,
+    responses(
+        (status = 201, description = "Create role", body = RoleDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/roles")]
+pub async fn create_role_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    role_dto: Json<CreateRoleDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageRoles)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match create_role_service(pool.get_ref(), role_dto.into_inner()).await {
+        Ok(created_role) => Ok(HttpResponse::Created().json(&created_role)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::role_dto::{CreateRoleDTO, RoleDTO};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_create_role_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_role_controller),
+        )
+        .await;
+
+        let (capability_id,): (i32,) =
+            sqlx::query_as("SELECT id FROM capabilities WHERE name = 'read'")
+                .fetch_one(&pool)
+                .await
+                .expect("Failed to fetch capability");
+
+        let role = CreateRoleDTO {
+            name: String::from("Test Create Role Success"),
+            slug: None,
+            description: None,
+            capabilities_ids: vec![capability_id],
+        };
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/roles")
+            .set_json(&role)
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+
+        let body = test::read_body(resp).await;
+        let created_role: RoleDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(
+            created_role.slug.as_deref(),
+            Some("test-create-role-success")
+        );
+        assert_eq!(
+            created_role.capabilities,
+            Some(serde_json::json!(["read"]))
+        );
+
+        // Clean up test data
+        clean_data_test(&pool, "roles", "name", "Test Create Role Success")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_create_role_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "author").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_role_controller),
+        )
+        .await;
+
+        let role = CreateRoleDTO {
+            name: String::from("Test Create Role Forbidden"),
+            slug: None,
+            description: None,
+            capabilities_ids: vec![],
+        };
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/roles")
+            .set_json(&role)
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    #[ntex::test]
+    async fn test_create_role_unknown_capability_rolls_back() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(create_role_controller),
+        )
+        .await;
+
+        let role = CreateRoleDTO {
+            name: String::from("Test Create Role Rollback"),
+            slug: None,
+            description: None,
+            capabilities_ids: vec![-1],
+        };
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/roles")
+            .set_json(&role)
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
+
+        let (roles,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM roles WHERE name = 'Test Create Role Rollback'",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to count roles");
+        assert_eq!(roles, 0);
+    }
+}
@@ -0,0 +1,47 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    Error, HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, role_dto::DeleteRoleIdsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::roles_service::delete_role_by_id_service,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/roles",
+    tag = "Roles",
+    request_body = DeleteRoleIdsDTO,
+    responses(
+        (status = 200, description = "Roles deleted", body = i32),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/roles")]
+pub async fn delete_role_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    delete_role_ids_dto: Json<DeleteRoleIdsDTO>,
+) -> Result<HttpResponse, Error> {
+    current_user
+        .require(Capability::ManageRoles)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match delete_role_by_id_service(
+        pool.get_ref(),
+        delete_role_ids_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,60 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, pagination_dto::PaginationParamsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::roles_service::get_all_roles_service,
+};
+
+#[utoipa::path(
+  get,
+  path = "/roles",
+  tag = "Roles",
+  params(
+    ("page" = Option<i32>, Query, description = "The page number for pagination"),
+    ("limit" = Option<i32>, Query, description = "The number of items per page"),
+    ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'id', 'name')"),
+    ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')")
+  ),
+  responses(
+    (status = 200, description = "Get all roles", body = [RoleDTO]),
+    (status = 404, description = "Roles not found", body = Error),
+    (status = 401, description = "Unauthorized", body = Error),
+    (status = 403, description = "Forbidden", body = Error),
+    (status = 500, description = "Internal Server Error", body = Error)
+  ),
+)]
+#[web::get("/roles")]
+pub async fn get_all_roles_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    params: Query<PaginationParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageRoles)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(25);
+    let sort_column = params.sort_column.as_deref().unwrap_or("id");
+    let sort_order = params.sort_order.as_deref().unwrap_or("desc");
+
+    match get_all_roles_service(
+        pool.get_ref(),
+        page,
+        limit,
+        sort_column,
+        sort_order,
+    )
+    .await
+    {
+        Ok(roles) => Ok(HttpResponse::Ok().json(&roles)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,44 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::roles_service::get_role_by_id_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/roles/{id}",
+    tag = "Roles",
+    params(
+        ("id" = i32, description = "ID of the role")
+    ),
+    responses(
+        (status = 200, description = "Role retrieved", body = RoleDTO),
+        (status = 404, description = "Role not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/roles/{id}")]
+pub async fn get_role_by_id_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    role_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageRoles)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match get_role_by_id_service(pool.get_ref(), role_id.into_inner()).await {
+        Ok(role) => Ok(HttpResponse::Ok().json(&role)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
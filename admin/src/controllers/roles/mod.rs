@@ -0,0 +1,5 @@
+pub mod create_role_controller;
+pub mod delete_role_controller;
+pub mod get_all_roles_controller;
+pub mod get_role_by_id_controller;
+pub mod update_role_controller;
@@ -0,0 +1,53 @@
+use ntex::web::{
+    self,
+    types::{Json, Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, role_dto::CreateRoleDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::roles_service::update_role_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/roles/{id}",
+    tag = "Roles",
+    request_body = CreateRoleDTO,
+    params(
+        ("id" = i32, description = "ID of the role")
+    ),
+    responses(
+        (status = 200, description = "Role updated", body = RoleDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 404, description = "Role not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/roles/{id}")]
+pub async fn update_role_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    role_id: Path<i32>,
+    role_dto: Json<CreateRoleDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageRoles)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match update_role_service(
+        pool.get_ref(),
+        role_id.into_inner(),
+        role_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(updated_role) => Ok(HttpResponse::Ok().json(&updated_role)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,2 @@
+pub mod get_settings_controller;
+pub mod update_settings_controller;
@@ -0,0 +1,220 @@
+use ntex::web::{
+    self,
+    types::{Json, Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, settings_dto::UpdateSettingsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::{capabilities_model::Capability, settings_model::SettingsGroup},
+    services::settings_service::update_settings_group_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/settings/{group}",
+    tag = "Settings",
+    params(
+        ("group" = SettingsGroup, description = "Group of the settings")
+    ),
+    request_body = UpdateSettingsDTO,
+    responses(
+        (status = 200, description = "Settings updated", body = SettingsDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Unknown group", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/settings/{group}")]
+pub async fn update_settings_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    group: Path<SettingsGroup>,
+    settings: Json<UpdateSettingsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageOptions)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match update_settings_group_service(
+        pool.get_ref(),
+        group.into_inner(),
+        settings.into_inner(),
+    )
+    .await
+    {
+        Ok(settings) => Ok(HttpResponse::Ok().json(&settings)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::dtos::settings_dto::SettingsDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::services::settings_service::get_settings_group_service;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
+
+    #[ntex::test]
+    async fn test_update_settings_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let previous =
+            get_settings_group_service(&pool, SettingsGroup::General)
+                .await
+                .expect("Failed to get the settings");
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_settings_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/settings/general")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "settings": { "site_description": "Test description" }
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let settings: SettingsDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(settings.settings["site_description"], "Test description");
+        assert_eq!(
+            settings.settings["site_title"],
+            previous.settings["site_title"]
+        );
+
+        // Clean up test data
+        let settings = UpdateSettingsDTO {
+            settings: [(
+                "site_description".to_string(),
+                previous.settings["site_description"].clone(),
+            )]
+            .into(),
+        };
+        update_settings_group_service(&pool, SettingsGroup::General, settings)
+            .await
+            .expect("Failed to restore the settings");
+    }
+
+    #[ntex::test]
+    async fn test_update_settings_controller_invalid_value() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_settings_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/settings/reading")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "settings": { "posts_per_page": 0 } }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[ntex::test]
+    async fn test_update_settings_controller_unknown_setting() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_settings_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/settings/reading")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "settings": { "site_title": "Test" } }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[ntex::test]
+    async fn test_update_settings_controller_duplicate_base() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_settings_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/settings/permalinks")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({
+                "settings": { "category_base": "topic", "tag_base": "topic" }
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[ntex::test]
+    async fn test_update_settings_controller_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_settings_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/settings/general")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "settings": { "site_title": "Test" } }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,100 @@
+use anyhow::Result;
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, slug_dto::SlugCheckParamsDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::slugs_service::check_slug_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/slugs/check",
+    tag = "Slugs",
+    params(SlugCheckParamsDTO),
+    responses(
+        (status = 200, description = "Whether the slug is free, and the slug the content would get", body = SlugCheckDTO),
+        (status = 400, description = "Bad Request", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/slugs/check")]
+pub async fn check_slug_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    params: Query<SlugCheckParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(params.slug_type.capability())
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match check_slug_service(pool.get_ref(), params.into_inner()).await {
+        Ok(check) => Ok(HttpResponse::Ok().json(&check)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtos::slug_dto::SlugCheckDTO;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    #[ntex::test]
+    async fn test_check_slug_controller_taken() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(check_slug_controller),
+        )
+        .await;
+
+        sqlx::query(
+            r#"
+            INSERT INTO tags (name, slug)
+            VALUES ('Test Check Slug', 'test-check-slug')
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test data");
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/slugs/check?type=tag&slug=Test%20Check%20Slug")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let check: SlugCheckDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(check.slug, "test-check-slug");
+        assert!(!check.available);
+        assert_eq!(check.suggestion, "test-check-slug-2");
+
+        // Clean Data
+        sqlx::query("DELETE FROM tags WHERE slug = 'test-check-slug'")
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
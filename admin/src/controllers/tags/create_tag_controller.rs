@@ -6,8 +6,9 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::tag_dto::CreateTagDTO,
+    dtos::{auth_dtos::CurrentUserDTO, tag_dto::CreateTagDTO},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::tags_service::create_tag_service,
 };
 
@@ -19,14 +20,21 @@ use crate::{
     responses(
         (status = 201, description = "Create tag", body = TagDTO),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     ),
 )]
 #[web::post("/tags")]
 pub async fn create_tag_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     tag_dto: Json<CreateTagDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageTags)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match create_tag_service(pool.get_ref(), tag_dto.into_inner()).await {
         Ok(created_tag) => Ok(HttpResponse::Created().json(&created_tag)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
@@ -40,14 +48,19 @@ mod tests {
 
     use super::*;
     use crate::dtos::tag_dto::CreateTagDTO;
-    use crate::tests::helpers::setup::{clean_data_test, setup_test_db};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
 
     #[ntex::test]
     async fn test_create_tag_success() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(create_tag_controller),
         )
@@ -55,7 +68,7 @@ mod tests {
 
         let tag = CreateTagDTO {
             name: String::from("Test Create Tag Success"),
-            slug: String::from("test-create-tag-success"),
+            slug: Some(String::from("test-create-tag-success")),
             description: None,
         };
 
@@ -63,6 +76,7 @@ mod tests {
         let req = test::TestRequest::post()
             .uri("/tags")
             .set_json(&tag)
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -79,8 +93,10 @@ mod tests {
     async fn test_create_tag_validation_failure() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(create_tag_controller),
         )
@@ -88,7 +104,7 @@ mod tests {
 
         let tag = CreateTagDTO {
             name: String::from("Test Validation Failure"),
-            slug: String::from("test-validation-failure-*/!"),
+            slug: Some(String::from("test-validation-failure-*/!")),
             description: None,
         };
 
@@ -96,6 +112,7 @@ mod tests {
         let req = test::TestRequest::post()
             .uri("/tags")
             .set_json(&tag)
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -116,7 +133,7 @@ mod tests {
 
         let tag = CreateTagDTO {
             name: String::from("Test Tag Internal Error"),
-            slug: String::from("test-tag-internal-error"),
+            slug: Some(String::from("test-tag-internal-error")),
             description: None,
         };
 
@@ -6,8 +6,9 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::tag_dto::DeleteTagIdsDTO,
+    dtos::{auth_dtos::CurrentUserDTO, tag_dto::DeleteTagIdsDTO},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::tags_service::delete_tag_by_id_service,
 };
 
@@ -19,14 +20,21 @@ use crate::{
     responses(
         (status = 200, description = "Tags deleted", body = i32),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::delete("/tags")]
 pub async fn delete_tag_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     tag_id: Json<DeleteTagIdsDTO>,
 ) -> Result<HttpResponse, Error> {
+    current_user
+        .require(Capability::ManageTags)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match delete_tag_by_id_service(pool.get_ref(), tag_id.into_inner()).await {
         Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
@@ -40,14 +48,17 @@ mod tests {
     use serde_json::json;
 
     use super::*;
-    use crate::tests::helpers::setup::setup_test_db;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
 
     #[ntex::test]
     async fn test_delete_tag_success() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(delete_tag_controller),
         )
@@ -75,6 +86,7 @@ mod tests {
         let req = test::TestRequest::delete()
             .uri("/tags")
             .set_json(&delete_ids)
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
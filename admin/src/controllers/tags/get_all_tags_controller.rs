@@ -6,8 +6,9 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::pagination_dto::PaginationParamsDTO,
+    dtos::{auth_dtos::CurrentUserDTO, pagination_dto::PaginationParamsDTO},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::tags_service::get_all_tags_service,
 };
 
@@ -24,14 +25,21 @@ use crate::{
   responses(
     (status = 200, description = "Get all tags", body = [TagDTO]),
     (status = 404, description = "Tags not found", body = Error),
+    (status = 401, description = "Unauthorized", body = Error),
+    (status = 403, description = "Forbidden", body = Error),
     (status = 500, description = "Internal Server Error", body = Error)
   ),
 )]
 #[web::get("/tags")]
 pub async fn get_all_tags_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     params: Query<PaginationParamsDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::Read)
+        .map_err(convert_anyhow_to_ntex)?;
+
     let page = params.page.unwrap_or(1);
     let limit = params.limit.unwrap_or(25);
     let sort_column = params.sort_column.as_deref().unwrap_or("id");
@@ -54,8 +62,11 @@ pub async fn get_all_tags_controller(
 #[cfg(test)]
 mod tests {
     use crate::controllers::tags::get_all_tags_controller::get_all_tags_controller;
-    use crate::dtos::tag_dto::TagDTO;
-    use crate::tests::helpers::setup::{clean_data_test, setup_test_db};
+    use crate::dtos::{pagination_dto::PaginationDTO, tag_dto::TagDTO};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
     use ntex::http;
     use ntex::web::{self, test};
 
@@ -63,13 +74,18 @@ mod tests {
     async fn test_get_all_tags_controller_success() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(get_all_tags_controller),
         )
         .await;
 
+        clean_data_test(&pool, "tags", "slug", "test-tag")
+            .await
+            .expect("Failed to clean up test data");
         sqlx::query!(
             r#"
             INSERT INTO tags (name, slug, description) 
@@ -84,19 +100,22 @@ mod tests {
         .expect("Failed to insert test data");
 
         // Act
-        let req = test::TestRequest::get().uri("/tags").to_request();
+        let req = test::TestRequest::get()
+            .uri("/tags")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
         let resp = test::call_service(&app, req).await;
 
         // Assert
         assert_eq!(resp.status(), http::StatusCode::OK);
 
         let body = test::read_body(resp).await;
-        let response_body: Vec<TagDTO> =
+        let response_body: PaginationDTO<TagDTO> =
             serde_json::from_slice(&body).expect("Failed to parse JSON");
 
-        let tag = response_body
-            .iter()
-            .find(|&tag| tag.name == "Test Tag" && tag.slug == "test-tag");
+        let tag = response_body.data.iter().find(|&tag| {
+            tag.name == "Test Tag" && tag.slug.as_deref() == Some("test-tag")
+        });
 
         assert!(tag.is_some(), "Tag not found in the response");
         assert_eq!(
@@ -114,8 +133,10 @@ mod tests {
     async fn test_get_all_tags_controller_no_tags() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(get_all_tags_controller),
         )
@@ -136,14 +157,42 @@ mod tests {
         assert_eq!(row_count.0, 0, "The database is not empty before the test");
 
         // Act
-        let req = test::TestRequest::get().uri("/tags").to_request();
+        let req = test::TestRequest::get()
+            .uri("/tags")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
         let resp = test::call_service(&app, req).await;
 
         // Assert
         assert_eq!(resp.status(), http::StatusCode::OK);
         let body = test::read_body(resp).await;
-        let response_body: Vec<TagDTO> =
+        let response_body: PaginationDTO<TagDTO> =
             serde_json::from_slice(&body).expect("Failed to parse JSON");
-        assert!(response_body.is_empty(), "Expected an empty array");
+        assert!(response_body.data.is_empty(), "Expected an empty array");
+        assert_eq!(response_body.total_items, 0);
+    }
+
+    #[ntex::test]
+    async fn test_get_all_tags_controller_invalid_sort_column() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(get_all_tags_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/tags?sort_column=name%3BDROP%20TABLE%20tags&sort_order=asc")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
     }
 }
@@ -6,7 +6,9 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::tags_service::get_tag_by_id_service,
 };
 
@@ -20,14 +22,21 @@ use crate::{
     responses(
         (status = 200, description = "Tag retrieved", body = TagDTO),
         (status = 404, description = "Tag not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::get("/tags/{id}")]
 pub async fn get_tag_by_id_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     tag_id: Path<i32>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::Read)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match get_tag_by_id_service(pool.get_ref(), tag_id.into_inner()).await {
         Ok(tag) => Ok(HttpResponse::Ok().json(&tag)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
@@ -38,7 +47,8 @@ pub async fn get_tag_by_id_controller(
 mod tests {
     use crate::controllers::tags::get_tag_by_id_controller::get_tag_by_id_controller;
     use crate::dtos::tag_dto::TagDTO;
-    use crate::tests::helpers::setup::setup_test_db;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
     use ntex::http;
     use ntex::web::{self, test};
 
@@ -46,8 +56,10 @@ mod tests {
     async fn test_get_all_tags_controller_success() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(get_tag_by_id_controller),
         )
@@ -70,6 +82,7 @@ mod tests {
         // Act
         let req = test::TestRequest::get()
             .uri(&format!("/tags/{}", inserted_tag.id))
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -82,7 +95,7 @@ mod tests {
 
         let tag = &response_body;
         assert_eq!(tag.name, "Test Tag By ID");
-        assert_eq!(tag.slug, "test-tag-by-id");
+        assert_eq!(tag.slug.as_deref(), Some("test-tag-by-id"));
         assert_eq!(
             tag.description.as_deref(),
             Some("Description for test tag by id")
@@ -7,8 +7,12 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::tag_dto::{CreateTagDTO, TagDTO},
+    dtos::{
+        auth_dtos::CurrentUserDTO,
+        tag_dto::{CreateTagDTO, TagDTO},
+    },
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::tags_service::update_tag_service,
 };
 
@@ -24,15 +28,22 @@ use crate::{
         (status = 200, description = "Tag updated", body = TagDTO),
         (status = 400, description = "Validation Error", body = Error),
         (status = 404, description = "Tag not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::put("/tags/{id}")]
 pub async fn update_tag_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     tag_id: Path<i32>,
     tag_dto: Json<CreateTagDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ManageTags)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match update_tag_service(
         pool.get_ref(),
         tag_id.into_inner(),
@@ -52,7 +63,10 @@ mod tests {
     use super::*;
     use crate::controllers::tags::create_tag_controller::create_tag_controller;
     use crate::dtos::tag_dto::CreateTagDTO;
-    use crate::tests::helpers::setup::{clean_data_test, setup_test_db};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        clean_data_test, setup_test_auth, setup_test_db,
+    };
     use ntex::http;
     use ntex::web::{self, test};
 
@@ -60,8 +74,10 @@ mod tests {
     async fn test_update_tag_success() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(create_tag_controller)
                 .service(update_tag_controller),
@@ -70,7 +86,7 @@ mod tests {
 
         let new_tag = CreateTagDTO {
             name: String::from("Test Update Tag Success"),
-            slug: String::from("test-update-tag-success"),
+            slug: Some(String::from("test-update-tag-success")),
             description: None,
         };
 
@@ -78,6 +94,7 @@ mod tests {
         let req = test::TestRequest::post()
             .uri("/tags")
             .set_json(&new_tag)
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -97,13 +114,14 @@ mod tests {
         // Act
         let updated_tag = CreateTagDTO {
             name: String::from("Test Updated Tag New Name"),
-            slug: String::from("test-updated-tag-new-name"),
+            slug: Some(String::from("test-updated-tag-new-name")),
             description: Some(String::from("Updated description")),
         };
 
         let req = test::TestRequest::put()
             .uri(&format!("/tags/{}", tag_id))
             .set_json(&updated_tag)
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -112,7 +130,10 @@ mod tests {
         let updated_tag_response: TagDTO =
             serde_json::from_slice(&body).expect("Failed to parse JSON");
         assert_eq!(updated_tag_response.name, "Test Updated Tag New Name");
-        assert_eq!(updated_tag_response.slug, "test-updated-tag-new-name");
+        assert_eq!(
+            updated_tag_response.slug.as_deref(),
+            Some("test-updated-tag-new-name")
+        );
         assert_eq!(
             updated_tag_response.description.unwrap(),
             "Updated description"
@@ -128,8 +149,10 @@ mod tests {
     async fn test_update_tag_not_found() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(update_tag_controller),
         )
@@ -137,7 +160,7 @@ mod tests {
 
         let updated_tag = CreateTagDTO {
             name: String::from("Non-existent Tag"),
-            slug: String::from("non-existent-tag"),
+            slug: Some(String::from("non-existent-tag")),
             description: Some(String::from("Description of non-existent tag")),
         };
 
@@ -145,6 +168,7 @@ mod tests {
         let req = test::TestRequest::put()
             .uri("/tags/999")
             .set_json(&updated_tag)
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -156,8 +180,10 @@ mod tests {
     async fn test_update_tag_validation_failure() {
         // Arrange
         let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
         let app = test::init_service(
             web::App::new()
+                .wrap(JwtAuth)
                 .state(pool.clone())
                 .service(update_tag_controller),
         )
@@ -165,7 +191,7 @@ mod tests {
 
         let invalid_tag = CreateTagDTO {
             name: String::from(""),
-            slug: String::from("invalid-tag-*"),
+            slug: Some(String::from("invalid-tag-*")),
             description: None,
         };
 
@@ -173,6 +199,7 @@ mod tests {
         let req = test::TestRequest::put()
             .uri("/tags/1")
             .set_json(&invalid_tag)
+            .header(http::header::AUTHORIZATION, auth.clone())
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -193,7 +220,7 @@ mod tests {
 
         let valid_tag = CreateTagDTO {
             name: String::from("Valid Tag"),
-            slug: String::from("valid-tag"),
+            slug: Some(String::from("valid-tag")),
             description: Some(String::from("Description of valid tag")),
         };
 
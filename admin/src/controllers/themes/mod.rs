@@ -0,0 +1 @@
+pub mod update_active_theme_controller;
@@ -0,0 +1,142 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{auth_dtos::CurrentUserDTO, theme_dto::ActiveThemeDTO},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
+    services::themes_service::update_active_theme_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/themes/active",
+    tag = "Themes",
+    request_body = ActiveThemeDTO,
+    responses(
+        (status = 200, description = "Theme activated", body = ActiveThemeDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/themes/active")]
+pub async fn update_active_theme_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    theme: Json<ActiveThemeDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::SwitchThemes)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match update_active_theme_service(pool.get_ref(), theme.into_inner()).await
+    {
+        Ok(theme) => Ok(HttpResponse::Ok().json(&theme)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+    use serde_json::json;
+
+    use super::*;
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
+
+    #[ntex::test]
+    async fn test_update_active_theme_controller_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_active_theme_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/themes/active")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "name": "Test-Theme" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body = test::read_body(resp).await;
+        let theme: ActiveThemeDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(theme.name, "Test-Theme");
+
+        // Clean up test data
+        let theme = ActiveThemeDTO {
+            name: "Default".to_string(),
+        };
+        update_active_theme_service(&pool, theme)
+            .await
+            .expect("Failed to restore the active theme");
+    }
+
+    #[ntex::test]
+    async fn test_update_active_theme_controller_invalid_name() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "administrator").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_active_theme_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/themes/active")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "name": "../Default" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[ntex::test]
+    async fn test_update_active_theme_controller_forbidden() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "editor").await;
+        let app = test::init_service(
+            web::App::new()
+                .wrap(JwtAuth)
+                .state(pool.clone())
+                .service(update_active_theme_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::put()
+            .uri("/themes/active")
+            .header(http::header::AUTHORIZATION, auth.clone())
+            .set_json(&json!({ "name": "Default" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
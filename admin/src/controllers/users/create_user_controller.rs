@@ -6,8 +6,9 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::user_dtos::CreateUserDTO,
+    dtos::{auth_dtos::CurrentUserDTO, user_dtos::CreateUserDTO},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::users_service::create_user_service,
 };
 
@@ -19,14 +20,21 @@ use crate::{
     responses(
         (status = 201, description = "Create user", body = UserDTO),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     ),
 )]
 #[web::post("/users")]
 pub async fn create_user_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     user_dto: Json<CreateUserDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::CreateUsers)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match create_user_service(pool.get_ref(), user_dto.into_inner()).await {
         Ok(created_user) => Ok(HttpResponse::Created().json(&created_user)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
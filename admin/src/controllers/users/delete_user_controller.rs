@@ -6,8 +6,9 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::user_dtos::DeleteUserIdsDTO,
+    dtos::{auth_dtos::CurrentUserDTO, user_dtos::DeleteUserIdsDTO},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::users_service::delete_user_by_id_service,
 };
 
@@ -19,14 +20,21 @@ use crate::{
     responses(
         (status = 200, description = "Users deleted", body = i32),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::delete("/users")]
 pub async fn delete_user_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     user_id: Json<DeleteUserIdsDTO>,
 ) -> Result<HttpResponse, Error> {
+    current_user
+        .require(Capability::DeleteUsers)
+        .map_err(convert_anyhow_to_ntex)?;
+
     match delete_user_by_id_service(pool.get_ref(), user_id.into_inner()).await
     {
         Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
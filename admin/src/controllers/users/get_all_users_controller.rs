@@ -6,8 +6,12 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::pagination_dto::PaginationParamsDTO,
+    dtos::{
+        auth_dtos::CurrentUserDTO, pagination_dto::PaginationParamsDTO,
+        user_dtos::UserFiltersDTO,
+    },
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::users_service::get_all_users_service,
 };
 
@@ -19,19 +23,28 @@ use crate::{
     ("page" = Option<i32>, Query, description = "The page number for pagination"),
     ("limit" = Option<i32>, Query, description = "The number of items per page"),
     ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'id', 'username')"),
-    ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')")
+    ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')"),
+    UserFiltersDTO
   ),
   responses(
     (status = 200, description = "Get all users", body = [UserDTO]),
     (status = 404, description = "Users not found", body = Error),
+    (status = 401, description = "Unauthorized", body = Error),
+    (status = 403, description = "Forbidden", body = Error),
     (status = 500, description = "Internal Server Error", body = Error)
   ),
 )]
 #[web::get("/users")]
 pub async fn get_all_users_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     params: Query<PaginationParamsDTO>,
+    filters: Query<UserFiltersDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::ListUsers)
+        .map_err(convert_anyhow_to_ntex)?;
+
     let page = params.page.unwrap_or(1);
     let limit = params.limit.unwrap_or(25);
     let sort_column = params.sort_column.as_deref().unwrap_or("id");
@@ -39,6 +52,7 @@ pub async fn get_all_users_controller(
 
     match get_all_users_service(
         pool.get_ref(),
+        &filters,
         page,
         limit,
         sort_column,
@@ -6,7 +6,9 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
+    dtos::auth_dtos::CurrentUserDTO,
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::users_service::get_user_by_id_service,
 };
 
@@ -20,15 +22,27 @@ use crate::{
     responses(
         (status = 200, description = "User retrieved", body = TagDTO),
         (status = 404, description = "User not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::get("/users/{id}")]
 pub async fn get_user_by_id_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     user_id: Path<i32>,
 ) -> Result<HttpResponse, web::Error> {
-    match get_user_by_id_service(pool.get_ref(), user_id.into_inner()).await {
+    let user_id = user_id.into_inner();
+
+    // Users can always read their own profile
+    if user_id != current_user.id {
+        current_user
+            .require(Capability::ListUsers)
+            .map_err(convert_anyhow_to_ntex)?;
+    }
+
+    match get_user_by_id_service(pool.get_ref(), user_id).await {
         Ok(user) => Ok(HttpResponse::Ok().json(&user)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
@@ -7,8 +7,12 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::user_dtos::{CreateUserDTO, UserDTO},
+    dtos::{
+        auth_dtos::CurrentUserDTO,
+        user_dtos::{CreateUserDTO, UserDTO},
+    },
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::capabilities_model::Capability,
     services::users_service::update_user_service,
 };
 
@@ -24,21 +28,29 @@ use crate::{
         (status = 200, description = "User updated", body = TagDTO),
         (status = 400, description = "Validation Error", body = Error),
         (status = 404, description = "User not found", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::put("/users/{id}")]
 pub async fn update_user_controller(
     pool: State<PgPool>,
+    current_user: CurrentUserDTO,
     user_id: Path<i32>,
     user_dto: Json<CreateUserDTO>,
 ) -> Result<HttpResponse, web::Error> {
-    match update_user_service(
-        pool.get_ref(),
-        user_id.into_inner(),
-        user_dto.into_inner(),
-    )
-    .await
+    let user_id = user_id.into_inner();
+
+    // Users can always edit their own profile
+    if user_id != current_user.id {
+        current_user
+            .require(Capability::EditUsers)
+            .map_err(convert_anyhow_to_ntex)?;
+    }
+
+    match update_user_service(pool.get_ref(), user_id, user_dto.into_inner())
+        .await
     {
         Ok(updated_user) => {
             Ok(HttpResponse::Ok().json(&UserDTO::from(updated_user)))
@@ -0,0 +1,40 @@
+use ntex::web::types::{Json, State};
+use ntex::web::{self, HttpResponse};
+use sqlx::PgPool;
+
+use crate::dtos::auth_dtos::CurrentUserDTO;
+use crate::dtos::users_roles_dto::DeleteUsersRolesIdsDTO;
+use crate::handlers::error_to_response_handler::convert_anyhow_to_ntex;
+use crate::models::capabilities_model::Capability;
+use crate::services::users_roles_service::delete_user_role_service;
+
+#[utoipa::path(
+    delete,
+    path = "/users-roles",
+    tag = "Users Roles",
+    request_body = DeleteUsersRolesIdsDTO,
+    responses(
+        (status = 200, description = "Roles removed from the users", body = i32),
+        (status = 400, description = "Validation error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal server error", body = Error)
+    )
+)]
+#[web::delete("/users-roles")]
+pub async fn delete_users_roles_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUserDTO,
+    delete_dto: Json<DeleteUsersRolesIdsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    current_user
+        .require(Capability::PromoteUsers)
+        .map_err(convert_anyhow_to_ntex)?;
+
+    match delete_user_role_service(pool.get_ref(), delete_dto.into_inner())
+        .await
+    {
+        Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
+        Err(err) => Err(convert_anyhow_to_ntex(err)),
+    }
+}
@@ -0,0 +1,2 @@
+pub mod create_users_roles_controller;
+pub mod delete_users_roles_controller;
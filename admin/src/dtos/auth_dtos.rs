@@ -1,18 +1,61 @@
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
 
+use crate::{
+    handlers::error_to_response_handler::ForbiddenError,
+    models::capabilities_model::Capability,
+};
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TokenDTO {
     pub token: String,
     pub token_type: String,
+    /// Lifetime of the access token, in seconds.
     pub expires_in: usize,
+    pub refresh_token: String,
+    /// Lifetime of the refresh token, in seconds.
+    pub refresh_expires_in: usize,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ClaimsDTO {
     pub sub: String,
     pub exp: usize,
+    /// Unique identifier of the token, used for revocation.
+    pub jti: String,
+}
+
+impl ClaimsDTO {
+    /// ID of the user the token was issued to.
+    pub fn user_id(&self) -> Option<i32> {
+        self.sub.parse().ok()
+    }
+}
+
+/// Authenticated user of the current request, with the capabilities granted
+/// by its roles.
+#[derive(Debug, Clone)]
+pub struct CurrentUserDTO {
+    pub id: i32,
+    pub capabilities: Vec<String>,
+}
+
+impl CurrentUserDTO {
+    /// Returns `true` if one of the user roles grants the capability.
+    pub fn can(&self, capability: Capability) -> bool {
+        self.capabilities.iter().any(|c| c == capability.as_str())
+    }
+
+    /// Fails with a `ForbiddenError` if the user lacks the capability.
+    pub fn require(&self, capability: Capability) -> Result<()> {
+        if self.can(capability) {
+            Ok(())
+        } else {
+            Err(ForbiddenError::missing_capability(capability).into())
+        }
+    }
 }
 
 #[derive(Debug, Validate, Serialize, Deserialize, ToSchema)]
@@ -31,3 +74,14 @@ pub struct LoginRequestDTO {
     ))]
     pub password: String,
 }
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RefreshTokenRequestDTO {
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LogoutRequestDTO {
+    /// Refresh token to revoke along with the current access token.
+    pub refresh_token: Option<String>,
+}
@@ -0,0 +1,28 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+use crate::models::capabilities_model::CapabilityModel;
+
+/// Full capability data
+#[derive(FromRow, Serialize, Deserialize, ToSchema)]
+pub struct CapabilityDTO {
+    pub id: Option<i32>,
+    pub name: String,
+    pub description: Option<String>,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Converts `CapabilityModel` to `CapabilityDTO`
+impl From<CapabilityModel> for CapabilityDTO {
+    fn from(capability: CapabilityModel) -> Self {
+        CapabilityDTO {
+            id: capability.id,
+            name: capability.name,
+            description: capability.description,
+            date_created: capability.date_created,
+        }
+    }
+}
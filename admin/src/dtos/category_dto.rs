@@ -1,7 +1,7 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use validator::{Validate, ValidationErrors};
 
 use crate::{
@@ -101,3 +101,26 @@ impl TryFrom<CategoryDTO> for CategoryModel {
         Ok(category)
     }
 }
+
+/// Category of the tree of categories, with its sub-categories
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CategoryTreeDTO {
+    pub id: i32,
+    pub parent_id: Option<i32>,
+    pub name: String,
+    pub slug: String,
+    pub description: Option<String>,
+
+    /// Posts in the category, not counting its sub-categories
+    pub post_count: i64,
+
+    pub children: Vec<CategoryTreeDTO>,
+}
+
+/// Filters of the categories list
+#[derive(Deserialize, Debug, Default, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CategoryFiltersDTO {
+    /// Only the children of this category
+    pub parent_id: Option<i32>,
+}
@@ -0,0 +1,103 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::models::comments_model::{CommentModel, CommentsStatus};
+
+/// Batch deletion of comments
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DeleteCommentIdsDTO {
+    pub ids: Vec<i32>,
+}
+
+/// Comment left by a reader, held for moderation
+#[derive(Validate, Serialize, Deserialize, ToSchema)]
+pub struct CreateCommentDTO {
+    /// ID of the comment replied to, none for a top-level comment
+    pub parent_id: Option<i32>,
+
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub author_name: String,
+
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Email must be between 1 and 200 characters"
+    ))]
+    #[validate(email(message = "Email must be a valid email address"))]
+    pub author_email: String,
+
+    #[validate(length(
+        min = 1,
+        max = 5000,
+        message = "Content must be between 1 and 5000 characters"
+    ))]
+    pub content: String,
+}
+
+/// Reply of the current user from the administration, approved at once
+#[derive(Validate, Serialize, Deserialize, ToSchema)]
+pub struct ReplyCommentDTO {
+    pub post_id: i32,
+
+    /// ID of the comment replied to, none for a top-level comment
+    pub parent_id: Option<i32>,
+
+    #[validate(length(
+        min = 1,
+        max = 5000,
+        message = "Content must be between 1 and 5000 characters"
+    ))]
+    pub content: String,
+}
+
+/// Batch moderation of comments
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct UpdateCommentsStatusDTO {
+    pub ids: Vec<i32>,
+    pub status: CommentsStatus,
+}
+
+/// Filters of the comments list
+#[derive(Deserialize, Debug, Default, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CommentFiltersDTO {
+    pub status: Option<CommentsStatus>,
+    pub post_id: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CommentDTO {
+    pub id: i32,
+    pub post_id: i32,
+    pub parent_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub author_name: String,
+    pub author_email: String,
+    pub content: String,
+    pub status: CommentsStatus,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: NaiveDateTime,
+}
+
+/// Converts `CommentModel` to `CommentDTO`
+impl From<CommentModel> for CommentDTO {
+    fn from(comment: CommentModel) -> Self {
+        CommentDTO {
+            id: comment.id,
+            post_id: comment.post_id,
+            parent_id: comment.parent_id,
+            user_id: comment.user_id,
+            author_name: comment.author_name,
+            author_email: comment.author_email,
+            content: comment.content,
+            status: comment.status,
+            date_created: comment.date_created,
+        }
+    }
+}
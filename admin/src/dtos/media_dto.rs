@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{models::media_model::MediaModel, storage::StorageBackend};
+
+/// Batch deletion of media
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DeleteMediaIdsDTO {
+    pub ids: Vec<i32>,
+}
+
+/// Regeneration of the resized copies of images
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RegenerateMediaDTO {
+    /// IDs of the images to regenerate, all the images when omitted
+    pub ids: Option<Vec<i32>>,
+}
+
+/// File read from an upload request
+pub struct UploadMediaDTO {
+    /// Name of the file on the computer of the user
+    pub filename: String,
+    pub data: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct MediaDTO {
+    pub id: i32,
+    pub author_id: i32,
+
+    /// Name of the file on the computer of the user
+    pub filename: String,
+
+    /// MIME type sniffed from the content of the file
+    #[schema(example = "image/png")]
+    pub mime_type: String,
+
+    /// Size of the file, in bytes
+    pub size: i64,
+
+    /// Public URL of the file
+    pub url: String,
+
+    /// Dimensions of an image, none for other files
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+
+    /// Resized copies of an image, by size name such as `thumbnail`
+    pub sizes: BTreeMap<String, MediaSizeDTO>,
+
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: NaiveDateTime,
+}
+
+impl MediaDTO {
+    /// Converts `MediaModel` to `MediaDTO`, with the URL of the file in the
+    /// storage.
+    pub fn from_model(media: MediaModel, storage: &dyn StorageBackend) -> Self {
+        MediaDTO {
+            id: media.id,
+            author_id: media.author_id,
+            filename: media.filename,
+            mime_type: media.mime_type,
+            size: media.size,
+            url: storage.url(&media.path),
+            width: media.width,
+            height: media.height,
+            sizes: media
+                .sizes
+                .0
+                .into_iter()
+                .map(|(name, size)| {
+                    let size = MediaSizeDTO {
+                        url: storage.url(&size.path),
+                        width: size.width,
+                        height: size.height,
+                    };
+                    (name, size)
+                })
+                .collect(),
+            date_created: media.date_created,
+        }
+    }
+}
+
+/// Resized copy of an image
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct MediaSizeDTO {
+    /// Public URL of the resized image
+    pub url: String,
+    pub width: u32,
+    pub height: u32,
+}
@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationErrors};
+
+use crate::{
+    handlers::generate_slug_handler::generate_slug,
+    models::menus_model::{MenuItemsType, MenuModel},
+    validators::slug_validator::validate_slug,
+};
+
+/// Batch deletion of menus
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DeleteMenuIdsDTO {
+    pub ids: Vec<i32>,
+}
+
+/// Creating or renaming a menu
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateMenuDTO {
+    #[schema(example = "Main menu")]
+    pub name: String,
+
+    /// Generated from the name if missing
+    #[schema(example = "main-menu")]
+    pub slug: Option<String>,
+}
+
+/// Converts `CreateMenuDTO` to `MenuModel`
+impl TryFrom<CreateMenuDTO> for MenuModel {
+    type Error = ValidationErrors;
+
+    fn try_from(dto: CreateMenuDTO) -> Result<Self, Self::Error> {
+        let mut errors = ValidationErrors::new();
+        let slug = dto.slug.unwrap_or_else(|| generate_slug(&dto.name));
+        if let Err(validation_error) = validate_slug(&slug, 1, 100) {
+            errors.add("slug", validation_error);
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let menu = MenuModel {
+            id: None,
+            name: dto.name,
+            slug: Some(slug),
+            date_created: None,
+        };
+
+        menu.validate()?;
+        Ok(menu)
+    }
+}
+
+/// Menu without its items
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct MenuDTO {
+    pub id: Option<i32>,
+    pub name: String,
+    pub slug: Option<String>,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Converts `MenuModel` to `MenuDTO`
+impl From<MenuModel> for MenuDTO {
+    fn from(menu: MenuModel) -> Self {
+        MenuDTO {
+            id: menu.id,
+            name: menu.name,
+            slug: menu.slug,
+            date_created: menu.date_created,
+        }
+    }
+}
+
+/// Menu with the tree of its items
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct MenuTreeDTO {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+    pub items: Vec<MenuItemDTO>,
+}
+
+/// Item of a menu, with its sub-items
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct MenuItemDTO {
+    pub id: i32,
+    pub item_type: MenuItemsType,
+
+    /// ID of the post, page, category or tag
+    pub object_id: Option<i32>,
+
+    /// URL of a custom item
+    pub url: Option<String>,
+
+    /// Label of the item, the title of its content if `None`
+    pub title: Option<String>,
+
+    /// Title or name of the content, `None` if it was deleted
+    pub object_title: Option<String>,
+
+    pub children: Vec<MenuItemDTO>,
+}
+
+/// New item of a menu, with its sub-items
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct MenuItemInputDTO {
+    pub item_type: MenuItemsType,
+
+    /// ID of the post, page, category or tag, `None` for a custom item
+    #[schema(example = 1)]
+    pub object_id: Option<i32>,
+
+    /// URL of a custom item, an HTTP URL or a path starting with `/`
+    pub url: Option<String>,
+
+    /// Label of the item, the title of its content if missing
+    pub title: Option<String>,
+
+    #[serde(default)]
+    pub children: Vec<MenuItemInputDTO>,
+}
+
+/// Replacing the items of a menu
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct UpdateMenuItemsDTO {
+    /// Top-level items, in order
+    pub items: Vec<MenuItemInputDTO>,
+}
+
+/// Menus shown at the theme locations
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct MenuLocationsDTO {
+    /// ID of the menu of every location which has one
+    #[schema(example = json!({ "primary": 1, "footer": 2 }))]
+    pub locations: BTreeMap<String, i32>,
+}
+
+/// Published menu, as rendered by the themes
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PublicMenuDTO {
+    pub name: String,
+    pub slug: String,
+    pub items: Vec<PublicMenuItemDTO>,
+}
+
+/// Published item of a menu
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PublicMenuItemDTO {
+    pub title: String,
+    pub item_type: MenuItemsType,
+
+    /// Slug of the post, page, category or tag
+    pub slug: Option<String>,
+
+    /// URL of a custom item
+    pub url: Option<String>,
+
+    pub children: Vec<PublicMenuItemDTO>,
+}
@@ -1,7 +1,22 @@
 pub mod auth_dtos;
+pub mod capability_dto;
 pub mod category_dto;
+pub mod comment_dto;
+pub mod media_dto;
+pub mod menu_dto;
+pub mod page_dto;
 pub mod pagination_dto;
+pub mod post_autosave_dto;
 pub mod post_dto;
+pub mod post_revision_dto;
 pub mod posts_categories_dto;
+pub mod posts_tags_dto;
+pub mod public_dto;
+pub mod redirect_dto;
+pub mod role_dto;
+pub mod settings_dto;
+pub mod slug_dto;
 pub mod tag_dto;
+pub mod theme_dto;
 pub mod user_dtos;
+pub mod users_roles_dto;
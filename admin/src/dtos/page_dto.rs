@@ -0,0 +1,140 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationErrors};
+
+use crate::{
+    handlers::generate_slug_handler::generate_slug,
+    models::{pages_model::PageModel, posts_model::PostsStatus},
+    validators::slug_validator::validate_slug,
+};
+
+/// Batch deletion of pages
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DeletePageIdsDTO {
+    pub ids: Vec<i32>,
+}
+
+/// Creating a page
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreatePageDTO {
+    pub title: String,
+    pub content: String,
+    pub slug: Option<String>,
+    pub author_id: i32,
+    pub status: PostsStatus,
+    pub date_published: Option<NaiveDateTime>,
+
+    /// ID of the media shown as the featured image of the page
+    #[serde(default)]
+    pub featured_media_id: Option<i32>,
+
+    /// ID of the page this page is a child of
+    #[serde(default)]
+    pub parent_id: Option<i32>,
+
+    /// Position of the page among its siblings, lowest first
+    #[serde(default)]
+    pub menu_order: i32,
+
+    /// Name of the theme template rendering the page
+    #[serde(default)]
+    pub template: Option<String>,
+}
+
+/// Converts `CreatePageDTO` to `PageModel`
+impl TryFrom<CreatePageDTO> for PageModel {
+    type Error = ValidationErrors;
+
+    fn try_from(dto: CreatePageDTO) -> Result<Self, Self::Error> {
+        let mut errors = ValidationErrors::new();
+        let slug = dto.slug.unwrap_or_else(|| generate_slug(&dto.title));
+        let min_length = 1;
+        let max_length = 200;
+        if let Err(validation_error) =
+            validate_slug(&slug, min_length, max_length)
+        {
+            errors.add("slug", validation_error);
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let page = PageModel {
+            id: None,
+            title: dto.title,
+            content: dto.content,
+            slug: Some(slug),
+            author_id: dto.author_id,
+            status: dto.status,
+            date_published: dto.date_published,
+            featured_media_id: dto.featured_media_id,
+            parent_id: dto.parent_id,
+            menu_order: dto.menu_order,
+            template: dto.template,
+            date_created: None,
+        };
+
+        page.validate()?;
+        Ok(page)
+    }
+}
+
+/// Full page data
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PageDTO {
+    pub id: Option<i32>,
+    pub title: String,
+    pub content: String,
+    pub slug: Option<String>,
+    pub author_id: i32,
+    pub status: PostsStatus,
+
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_published: Option<NaiveDateTime>,
+
+    /// ID of the media shown as the featured image of the page
+    pub featured_media_id: Option<i32>,
+
+    /// ID of the page this page is a child of
+    pub parent_id: Option<i32>,
+
+    /// Position of the page among its siblings, lowest first
+    pub menu_order: i32,
+
+    /// Name of the theme template rendering the page
+    pub template: Option<String>,
+
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Converts `PageModel` to `PageDTO`
+impl From<PageModel> for PageDTO {
+    fn from(page: PageModel) -> Self {
+        PageDTO {
+            id: page.id,
+            title: page.title,
+            content: page.content,
+            slug: page.slug,
+            author_id: page.author_id,
+            status: page.status,
+            date_published: page.date_published,
+            featured_media_id: page.featured_media_id,
+            parent_id: page.parent_id,
+            menu_order: page.menu_order,
+            template: page.template,
+            date_created: page.date_created,
+        }
+    }
+}
+
+/// Filters of the pages list
+#[derive(Deserialize, Debug, Default, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PageFiltersDTO {
+    pub status: Option<PostsStatus>,
+
+    /// Only the children of this page
+    pub parent_id: Option<i32>,
+}
@@ -0,0 +1,58 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::models::post_autosaves_model::PostAutosaveModel;
+
+/// Autosaving the changes of a post
+#[derive(Validate, Serialize, Deserialize, ToSchema)]
+pub struct SavePostAutosaveDTO {
+    /// ID of the edited post, none for a new post
+    pub post_id: Option<i32>,
+
+    #[validate(length(
+        max = 200,
+        message = "Title cannot exceed 200 characters"
+    ))]
+    pub title: String,
+
+    #[validate(length(
+        max = 5000,
+        message = "Content cannot exceed 5000 characters"
+    ))]
+    pub content: String,
+}
+
+/// Post the autosave belongs to
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PostAutosaveParamsDTO {
+    /// ID of the edited post, none for a new post
+    pub post_id: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PostAutosaveDTO {
+    pub id: i32,
+    pub post_id: Option<i32>,
+    pub author_id: i32,
+    pub title: String,
+    pub content: String,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_modified: NaiveDateTime,
+}
+
+/// Converts `PostAutosaveModel` to `PostAutosaveDTO`
+impl From<PostAutosaveModel> for PostAutosaveDTO {
+    fn from(autosave: PostAutosaveModel) -> Self {
+        PostAutosaveDTO {
+            id: autosave.id,
+            post_id: autosave.post_id,
+            author_id: autosave.author_id,
+            title: autosave.title,
+            content: autosave.content,
+            date_modified: autosave.date_modified,
+        }
+    }
+}
@@ -1,12 +1,12 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use validator::{Validate, ValidationErrors};
 
 use crate::{
     handlers::generate_slug_handler::generate_slug,
-    models::posts_model::{PostModel, PostsStatus},
+    models::posts_model::{PostModel, PostSearchModel, PostsStatus},
     validators::slug_validator::validate_slug,
 };
 
@@ -30,7 +30,15 @@ pub struct CreatePostDTO {
     pub author_id: i32,
     pub status: PostsStatus,
     pub date_published: Option<NaiveDateTime>,
+
+    /// ID of the media shown as the featured image of the post
+    #[serde(default)]
+    pub featured_media_id: Option<i32>,
+
     pub categories_ids: Vec<i32>,
+
+    #[serde(default)]
+    pub tags_ids: Vec<i32>,
 }
 
 /// Converts `CreatePostDTO` to `PostModel`
@@ -59,8 +67,11 @@ impl TryFrom<CreatePostDTO> for PostModel {
             author_id: dto.author_id,
             status: dto.status,
             date_published: dto.date_published,
+            featured_media_id: dto.featured_media_id,
             date_created: None,
             categories: None,
+            tags: None,
+            comment_count: None,
         };
 
         post.validate()?;
@@ -81,9 +92,17 @@ pub struct PostDTO {
     #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
     pub date_published: Option<NaiveDateTime>,
 
+    /// ID of the media shown as the featured image of the post
+    pub featured_media_id: Option<i32>,
+
     #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
     pub date_created: Option<NaiveDateTime>,
     pub categories: Option<serde_json::Value>,
+    pub tags: Option<serde_json::Value>,
+
+    /// Number of approved comments
+    #[serde(default)]
+    pub comment_count: i64,
 }
 
 /// Converts `PostModel` to `PostDTO`
@@ -97,8 +116,11 @@ impl From<PostModel> for PostDTO {
             author_id: post.author_id,
             status: post.status,
             date_published: post.date_published,
+            featured_media_id: post.featured_media_id,
             date_created: post.date_created,
             categories: post.categories,
+            tags: post.tags,
+            comment_count: post.comment_count.unwrap_or_default(),
         }
     }
 }
@@ -116,11 +138,86 @@ impl TryFrom<PostDTO> for PostModel {
             author_id: dto.author_id,
             status: dto.status,
             date_published: dto.date_published,
+            featured_media_id: dto.featured_media_id,
             date_created: dto.date_created,
             categories: dto.categories,
+            tags: dto.tags,
+            comment_count: Some(dto.comment_count),
         };
 
         tag.validate()?;
         Ok(tag)
     }
 }
+
+/// Full-text search of posts
+#[derive(Validate, Deserialize, Debug, ToSchema, IntoParams)]
+pub struct PostSearchParamsDTO {
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Search query must be between 1 and 200 characters"
+    ))]
+    #[schema(example = "rust")]
+    pub q: String,
+
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "Limit must be between 1 and 2,147,483,647"
+    ))]
+    #[schema(example = 20)]
+    pub limit: Option<i64>,
+
+    #[validate(range(
+        min = 0,
+        max = 2_147_483_647,
+        message = "Offset must be between 0 and 2,147,483,647"
+    ))]
+    #[schema(example = 1)]
+    pub page: Option<i64>,
+}
+
+/// Post matching a full-text search
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PostSearchDTO {
+    #[serde(flatten)]
+    pub post: PostDTO,
+
+    /// Relevance of the post, higher is better
+    pub rank: f32,
+
+    /// Content excerpt with the matching words wrapped in `<mark>` tags
+    pub headline: String,
+}
+
+/// Converts `PostSearchModel` to `PostSearchDTO`
+impl From<PostSearchModel> for PostSearchDTO {
+    fn from(result: PostSearchModel) -> Self {
+        PostSearchDTO {
+            post: PostDTO::from(result.post),
+            rank: result.rank,
+            headline: result.headline,
+        }
+    }
+}
+
+/// Filters of the posts list
+#[derive(Deserialize, Debug, Default, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PostFiltersDTO {
+    pub status: Option<PostsStatus>,
+    pub author_id: Option<i32>,
+    pub category_id: Option<i32>,
+    pub tag_id: Option<i32>,
+
+    /// Only the posts published at or before this date
+    #[schema(value_type = Option<String>, format = "date-time", example = "2022-01-01T00:00:00")]
+    #[param(value_type = Option<String>, format = "date-time")]
+    pub published_before: Option<NaiveDateTime>,
+
+    /// Only the posts published at or after this date
+    #[schema(value_type = Option<String>, format = "date-time", example = "2022-01-01T00:00:00")]
+    #[param(value_type = Option<String>, format = "date-time")]
+    pub published_after: Option<NaiveDateTime>,
+}
@@ -0,0 +1,85 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    handlers::diff_handler::DiffLine,
+    models::{
+        post_revisions_model::PostRevisionModel, posts_model::PostsStatus,
+    },
+};
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PostRevisionDTO {
+    pub id: i32,
+    pub post_id: i32,
+    pub author_id: i32,
+    pub title: String,
+    pub content: String,
+    pub status: PostsStatus,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: NaiveDateTime,
+}
+
+/// Converts `PostRevisionModel` to `PostRevisionDTO`
+impl From<PostRevisionModel> for PostRevisionDTO {
+    fn from(revision: PostRevisionModel) -> Self {
+        PostRevisionDTO {
+            id: revision.id,
+            post_id: revision.post_id,
+            author_id: revision.author_id,
+            title: revision.title,
+            content: revision.content,
+            status: revision.status,
+            date_created: revision.date_created,
+        }
+    }
+}
+
+/// Revisions to compare
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PostRevisionDiffParamsDTO {
+    /// ID of the original revision
+    pub from: i32,
+    /// ID of the modified revision
+    pub to: i32,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub enum DiffOperationDTO {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DiffLineDTO {
+    pub operation: DiffOperationDTO,
+    pub line: String,
+}
+
+/// Converts a `DiffLine` to `DiffLineDTO`
+impl From<DiffLine<'_>> for DiffLineDTO {
+    fn from(line: DiffLine<'_>) -> Self {
+        let (operation, line) = match line {
+            DiffLine::Equal(line) => (DiffOperationDTO::Equal, line),
+            DiffLine::Insert(line) => (DiffOperationDTO::Insert, line),
+            DiffLine::Delete(line) => (DiffOperationDTO::Delete, line),
+        };
+
+        DiffLineDTO {
+            operation,
+            line: line.to_string(),
+        }
+    }
+}
+
+/// Line-level differences between two revisions of a post
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PostRevisionDiffDTO {
+    pub from: PostRevisionDTO,
+    pub to: PostRevisionDTO,
+    pub title: Vec<DiffLineDTO>,
+    pub content: Vec<DiffLineDTO>,
+}
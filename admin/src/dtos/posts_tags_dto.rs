@@ -0,0 +1,33 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+use crate::models::posts_tags_model::PostsTagsModel;
+
+/// Replacing the tags of a post
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct UpdatePostsTagsDTO {
+    pub tags_ids: Vec<i32>,
+}
+
+#[derive(FromRow, Serialize, Deserialize, ToSchema)]
+pub struct PostsTagsDTO {
+    pub id: Option<i32>,
+    pub post_id: i32,
+    pub tag_id: i32,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Converts `PostsTagsModel` to `PostsTagsDTO`
+impl From<PostsTagsModel> for PostsTagsDTO {
+    fn from(posts_tags: PostsTagsModel) -> Self {
+        PostsTagsDTO {
+            id: posts_tags.id,
+            post_id: posts_tags.post_id,
+            tag_id: posts_tags.tag_id,
+            date_created: posts_tags.date_created,
+        }
+    }
+}
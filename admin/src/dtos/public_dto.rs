@@ -0,0 +1,200 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::models::{
+    pages_model::PageModel, posts_model::PostModel, slugs_model::SlugType,
+    users_models::AuthorModel,
+};
+
+/// Most posts an anonymous request can list at once
+pub const MAX_PUBLIC_LIMIT: i64 = 100;
+
+/// Pagination of the published posts
+#[derive(Validate, Deserialize, Debug, Default, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PublicPaginationParamsDTO {
+    /// The page number, from 1
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "Page must be between 1 and 2,147,483,647"
+    ))]
+    #[schema(example = 1)]
+    pub page: Option<i64>,
+
+    /// The number of posts per page, the `posts_per_page` setting by default
+    #[validate(range(
+        min = 1,
+        max = MAX_PUBLIC_LIMIT,
+        message = "Limit must be between 1 and 100"
+    ))]
+    #[schema(example = 10)]
+    pub limit: Option<i64>,
+}
+
+/// Archive filters of the published posts
+#[derive(Validate, Deserialize, Debug, Default, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PublicPostFiltersDTO {
+    /// Slug of the category of the posts
+    pub category: Option<String>,
+
+    /// Slug of the tag of the posts
+    pub tag: Option<String>,
+
+    /// User name of the author of the posts
+    pub author: Option<String>,
+
+    /// Year the posts were published
+    #[validate(range(
+        min = 1,
+        max = 9999,
+        message = "Year must be between 1 and 9999"
+    ))]
+    pub year: Option<i32>,
+
+    /// Month the posts were published, along with `year`
+    #[validate(range(
+        min = 1,
+        max = 12,
+        message = "Month must be between 1 and 12"
+    ))]
+    pub month: Option<u32>,
+}
+
+/// Public profile of an author
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PublicAuthorDTO {
+    pub id: i32,
+    pub username: String,
+    pub firstname: String,
+    pub lastname: String,
+    pub url: Option<String>,
+}
+
+/// Converts `AuthorModel` to `PublicAuthorDTO`
+impl From<AuthorModel> for PublicAuthorDTO {
+    fn from(author: AuthorModel) -> Self {
+        PublicAuthorDTO {
+            id: author.id,
+            username: author.username,
+            firstname: author.firstname,
+            lastname: author.lastname,
+            url: author.url,
+        }
+    }
+}
+
+/// Published post
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PublicPostDTO {
+    pub id: Option<i32>,
+    pub title: String,
+    pub content: String,
+    pub slug: Option<String>,
+    pub author: Option<PublicAuthorDTO>,
+
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_published: Option<NaiveDateTime>,
+
+    /// ID of the media shown as the featured image of the post
+    pub featured_media_id: Option<i32>,
+
+    pub categories: Option<serde_json::Value>,
+    pub tags: Option<serde_json::Value>,
+
+    /// Number of approved comments
+    pub comment_count: i64,
+}
+
+/// Converts a `PostModel` and its author to `PublicPostDTO`
+impl From<(PostModel, Option<AuthorModel>)> for PublicPostDTO {
+    fn from((post, author): (PostModel, Option<AuthorModel>)) -> Self {
+        PublicPostDTO {
+            id: post.id,
+            title: post.title,
+            content: post.content,
+            slug: post.slug,
+            author: author.map(PublicAuthorDTO::from),
+            date_published: post.date_published,
+            featured_media_id: post.featured_media_id,
+            categories: post.categories,
+            tags: post.tags,
+            comment_count: post.comment_count.unwrap_or_default(),
+        }
+    }
+}
+
+/// Published page
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PublicPageDTO {
+    pub id: Option<i32>,
+    pub title: String,
+    pub content: String,
+    pub slug: Option<String>,
+    pub author: Option<PublicAuthorDTO>,
+
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_published: Option<NaiveDateTime>,
+
+    /// ID of the media shown as the featured image of the page
+    pub featured_media_id: Option<i32>,
+
+    /// ID of the page this page is a child of
+    pub parent_id: Option<i32>,
+
+    /// Position of the page among its siblings, lowest first
+    pub menu_order: i32,
+
+    /// Name of the theme template rendering the page
+    pub template: Option<String>,
+}
+
+/// Converts a `PageModel` and its author to `PublicPageDTO`
+impl From<(PageModel, Option<AuthorModel>)> for PublicPageDTO {
+    fn from((page, author): (PageModel, Option<AuthorModel>)) -> Self {
+        PublicPageDTO {
+            id: page.id,
+            title: page.title,
+            content: page.content,
+            slug: page.slug,
+            author: author.map(PublicAuthorDTO::from),
+            date_published: page.date_published,
+            featured_media_id: page.featured_media_id,
+            parent_id: page.parent_id,
+            menu_order: page.menu_order,
+            template: page.template,
+        }
+    }
+}
+
+/// Path of the public site which matched no content
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PublicRedirectParamsDTO {
+    /// Path requested, without query
+    #[schema(example = "/old-post")]
+    pub path: String,
+}
+
+/// Redirect of a path of the public site
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PublicRedirectDTO {
+    /// Path of the site or absolute URL
+    pub target: String,
+
+    /// 301, 302, 307 or 308
+    pub status_code: i32,
+}
+
+/// Current slug of a content, found from one of its former slugs
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PublicSlugDTO {
+    /// Type of the content
+    #[serde(rename = "type")]
+    pub slug_type: SlugType,
+
+    pub slug: String,
+}
@@ -0,0 +1,103 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{ValidationError, ValidationErrors};
+
+use crate::{
+    models::redirects_model::RedirectModel,
+    validators::redirect_validator::{
+        normalize_redirect_path, validate_redirect_source,
+        validate_redirect_status, validate_redirect_target,
+    },
+};
+
+/// Batch deletion of redirects
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DeleteRedirectIdsDTO {
+    pub ids: Vec<i32>,
+}
+
+/// Creating or updating a redirect
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateRedirectDTO {
+    /// Path of the site, without query; a trailing slash is ignored
+    #[schema(example = "/old-post")]
+    pub source_path: String,
+
+    /// Path of the site or absolute URL
+    #[schema(example = "/new-post")]
+    pub target: String,
+
+    /// 301, 302, 307 or 308, 301 if missing
+    #[schema(example = 301)]
+    pub status_code: Option<i32>,
+}
+
+/// Converts `CreateRedirectDTO` to `RedirectModel`
+impl TryFrom<CreateRedirectDTO> for RedirectModel {
+    type Error = ValidationErrors;
+
+    fn try_from(dto: CreateRedirectDTO) -> Result<Self, Self::Error> {
+        let mut errors = ValidationErrors::new();
+        let source_path = normalize_redirect_path(dto.source_path.trim());
+        let target = dto.target.trim();
+        let status_code = dto.status_code.unwrap_or(301);
+
+        if let Err(validation_error) = validate_redirect_source(source_path) {
+            errors.add("source_path", validation_error);
+        }
+        if let Err(validation_error) = validate_redirect_target(target) {
+            errors.add("target", validation_error);
+        } else if normalize_redirect_path(target) == source_path {
+            let mut validation_error = ValidationError::new("redirect_loop");
+            validation_error.message =
+                Some("Target cannot be the source path".into());
+            errors.add("target", validation_error);
+        }
+        if let Err(validation_error) = validate_redirect_status(status_code) {
+            errors.add("status_code", validation_error);
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(RedirectModel {
+            id: None,
+            source_path: source_path.to_string(),
+            target: target.to_string(),
+            status_code,
+            hits: 0,
+            last_hit: None,
+            date_created: None,
+        })
+    }
+}
+
+/// Redirect, with the number of requests it answered
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RedirectDTO {
+    pub id: Option<i32>,
+    pub source_path: String,
+    pub target: String,
+    pub status_code: i32,
+    pub hits: i32,
+    #[schema(value_type = Option<String>, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub last_hit: Option<NaiveDateTime>,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Converts `RedirectModel` to `RedirectDTO`
+impl From<RedirectModel> for RedirectDTO {
+    fn from(redirect: RedirectModel) -> Self {
+        RedirectDTO {
+            id: redirect.id,
+            source_path: redirect.source_path,
+            target: redirect.target,
+            status_code: redirect.status_code,
+            hits: redirect.hits,
+            last_hit: redirect.last_hit,
+            date_created: redirect.date_created,
+        }
+    }
+}
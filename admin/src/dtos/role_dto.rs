@@ -0,0 +1,83 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationErrors};
+
+use crate::{
+    handlers::generate_slug_handler::generate_slug,
+    models::roles_model::RoleModel, validators::slug_validator::validate_slug,
+};
+
+/// Batch deletion of roles
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DeleteRoleIdsDTO {
+    pub ids: Vec<i32>,
+}
+
+/// Creating a role
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateRoleDTO {
+    pub name: String,
+    pub slug: Option<String>,
+    pub description: Option<String>,
+    pub capabilities_ids: Vec<i32>,
+}
+
+/// Converts `CreateRoleDTO` to `RoleModel`
+impl TryFrom<CreateRoleDTO> for RoleModel {
+    type Error = ValidationErrors;
+
+    fn try_from(dto: CreateRoleDTO) -> Result<Self, Self::Error> {
+        let mut errors = ValidationErrors::new();
+        let slug = dto.slug.unwrap_or_else(|| generate_slug(&dto.name));
+        let min_length = 1;
+        let max_length = 200;
+        if let Err(validation_error) =
+            validate_slug(&slug, min_length, max_length)
+        {
+            errors.add("slug", validation_error);
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let role = RoleModel {
+            id: None,
+            name: dto.name.trim().to_string(),
+            slug: Some(slug),
+            description: dto.description.map(|desc| desc.trim().to_string()),
+            date_created: None,
+            capabilities: None,
+        };
+
+        role.validate()?;
+        Ok(role)
+    }
+}
+
+/// Full role data
+#[derive(FromRow, Serialize, Deserialize, ToSchema)]
+pub struct RoleDTO {
+    pub id: Option<i32>,
+    pub name: String,
+    pub slug: Option<String>,
+    pub description: Option<String>,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: Option<NaiveDateTime>,
+    pub capabilities: Option<serde_json::Value>,
+}
+
+/// Converts `RoleModel` to `RoleDTO`
+impl From<RoleModel> for RoleDTO {
+    fn from(role: RoleModel) -> Self {
+        RoleDTO {
+            id: role.id,
+            name: role.name,
+            slug: role.slug,
+            description: role.description,
+            date_created: role.date_created,
+            capabilities: role.capabilities,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use crate::models::settings_model::SettingsGroup;
+
+/// Settings of a group, by name
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SettingsDTO {
+    pub group: SettingsGroup,
+
+    /// Value of every setting of the group, its default value if it was
+    /// never changed
+    #[schema(value_type = Object, example = json!({
+        "site_title": "RustPress",
+        "site_description": "A Wordpress clone in Rust!"
+    }))]
+    pub settings: BTreeMap<String, Value>,
+}
+
+/// New values of settings of a group, by name
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct UpdateSettingsDTO {
+    /// Settings to change, the other settings of the group being kept
+    #[schema(value_type = Object, example = json!({
+        "site_title": "My blog"
+    }))]
+    pub settings: BTreeMap<String, Value>,
+}
+
+/// Settings the public site is rendered with
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PublicSettingsDTO {
+    #[schema(example = "RustPress")]
+    pub title: String,
+    #[schema(example = "A Wordpress clone in Rust!")]
+    pub description: String,
+    #[schema(example = "http://127.0.0.1:3000")]
+    pub url: String,
+
+    /// Language of the dates
+    #[schema(example = "en")]
+    pub language: String,
+
+    /// `strftime` format of the dates, the format of the language if empty
+    #[schema(example = "")]
+    pub date_format: String,
+
+    pub posts_per_page: i64,
+    pub feed_items: i64,
+
+    /// What the feeds show of a post, `full` content or `excerpt`
+    #[schema(example = "full")]
+    pub feed_content: String,
+
+    pub comments_enabled: bool,
+    pub permalinks: PermalinksDTO,
+}
+
+/// First segment of the URLs of the archives
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PermalinksDTO {
+    #[schema(example = "category")]
+    pub category_base: String,
+    #[schema(example = "tag")]
+    pub tag_base: String,
+    #[schema(example = "author")]
+    pub author_base: String,
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::models::slugs_model::SlugType;
+
+/// Slug to check before saving a content
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SlugCheckParamsDTO {
+    /// Type of the content
+    #[serde(rename = "type")]
+    pub slug_type: SlugType,
+
+    /// Slug, or title to make a slug from
+    #[schema(example = "hello-world")]
+    pub slug: String,
+
+    /// ID of the content being edited, whose own slug is not a duplicate
+    pub exclude_id: Option<i32>,
+}
+
+/// Result of a slug check
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SlugCheckDTO {
+    /// The slug checked, as it would be saved
+    pub slug: String,
+
+    /// Whether no other content of the type has the slug
+    pub available: bool,
+
+    /// The slug the content gets when saved without an explicit slug
+    pub suggestion: String,
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::validators::slug_validator::validate_slug;
+
+/// Theme rendering the public site
+#[derive(Serialize, Deserialize, Validate, ToSchema)]
+pub struct ActiveThemeDTO {
+    /// Name of the theme directory
+    #[validate(custom(function = "validate_theme_name"))]
+    #[schema(example = "Default")]
+    pub name: String,
+}
+
+fn validate_theme_name(name: &str) -> Result<(), ValidationError> {
+    let min_length = 1;
+    let max_length = 100;
+
+    validate_slug(name, min_length, max_length)
+}
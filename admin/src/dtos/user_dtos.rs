@@ -1,7 +1,7 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use validator::{Validate, ValidationErrors};
 
 use crate::models::users_models::UserModel;
@@ -99,3 +99,11 @@ impl TryFrom<UserDTO> for UserModel {
         Ok(user)
     }
 }
+
+/// Filters of the users list
+#[derive(Deserialize, Debug, Default, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UserFiltersDTO {
+    /// Only the active, or inactive, users
+    pub active: Option<bool>,
+}
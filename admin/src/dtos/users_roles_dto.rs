@@ -0,0 +1,58 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationErrors};
+
+use crate::models::users_roles_model::UsersRolesModel;
+
+/// Batch deletion of users roles
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DeleteUsersRolesIdsDTO {
+    pub ids: Vec<i32>,
+}
+
+/// Assigning a role to a user
+#[derive(FromRow, Serialize, Deserialize, ToSchema)]
+pub struct CreateUsersRolesDTO {
+    pub user_id: i32,
+    pub role_id: i32,
+}
+
+/// Converts `CreateUsersRolesDTO` to `UsersRolesModel`
+impl TryFrom<CreateUsersRolesDTO> for UsersRolesModel {
+    type Error = ValidationErrors;
+
+    fn try_from(dto: CreateUsersRolesDTO) -> Result<Self, Self::Error> {
+        let users_roles = UsersRolesModel {
+            id: None,
+            user_id: dto.user_id,
+            role_id: dto.role_id,
+            date_created: None,
+        };
+
+        users_roles.validate()?;
+        Ok(users_roles)
+    }
+}
+
+#[derive(FromRow, Serialize, Deserialize, ToSchema)]
+pub struct UsersRolesDTO {
+    pub id: Option<i32>,
+    pub user_id: i32,
+    pub role_id: i32,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Converts `UsersRolesModel` to `UsersRolesDTO`
+impl From<UsersRolesModel> for UsersRolesDTO {
+    fn from(users_roles: UsersRolesModel) -> Self {
+        UsersRolesDTO {
+            id: users_roles.id,
+            user_id: users_roles.user_id,
+            role_id: users_roles.role_id,
+            date_created: users_roles.date_created,
+        }
+    }
+}
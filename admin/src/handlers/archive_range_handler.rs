@@ -0,0 +1,79 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+/// Computes the bounds, both included, of the dates of a yearly or monthly
+/// archive.
+///
+/// # Arguments
+///
+/// * `year` - The year of the archive.
+/// * `month` - The month of the archive, the whole year when `None`.
+///
+/// # Returns
+///
+/// Returns the first and the last instant of the period, or `None` when the
+/// year or the month is out of range.
+pub fn archive_range(
+    year: i32,
+    month: Option<u32>,
+) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let (start, end) = match month {
+        Some(12) => (
+            NaiveDate::from_ymd_opt(year, 12, 1)?,
+            NaiveDate::from_ymd_opt(year + 1, 1, 1)?,
+        ),
+        Some(month) => (
+            NaiveDate::from_ymd_opt(year, month, 1)?,
+            NaiveDate::from_ymd_opt(year, month + 1, 1)?,
+        ),
+        None => (
+            NaiveDate::from_ymd_opt(year, 1, 1)?,
+            NaiveDate::from_ymd_opt(year + 1, 1, 1)?,
+        ),
+    };
+
+    Some((
+        start.and_hms_opt(0, 0, 0)?,
+        end.and_hms_opt(0, 0, 0)? - Duration::microseconds(1),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f").unwrap()
+    }
+
+    #[test]
+    fn test_archive_range_year() {
+        let expected = (
+            date("2024-01-01 00:00:00.0"),
+            date("2024-12-31 23:59:59.999999"),
+        );
+        assert_eq!(archive_range(2024, None), Some(expected));
+    }
+
+    #[test]
+    fn test_archive_range_month() {
+        let expected = (
+            date("2024-02-01 00:00:00.0"),
+            date("2024-02-29 23:59:59.999999"),
+        );
+        assert_eq!(archive_range(2024, Some(2)), Some(expected));
+    }
+
+    #[test]
+    fn test_archive_range_december() {
+        let expected = (
+            date("2024-12-01 00:00:00.0"),
+            date("2024-12-31 23:59:59.999999"),
+        );
+        assert_eq!(archive_range(2024, Some(12)), Some(expected));
+    }
+
+    #[test]
+    fn test_archive_range_invalid_month() {
+        assert_eq!(archive_range(2024, Some(13)), None);
+    }
+}
@@ -0,0 +1,107 @@
+/// A line of a line-level diff between two texts.
+#[derive(Debug, PartialEq)]
+pub enum DiffLine<'a> {
+    /// The line is in both texts.
+    Equal(&'a str),
+    /// The line is only in the new text.
+    Insert(&'a str),
+    /// The line is only in the old text.
+    Delete(&'a str),
+}
+
+/// Computes the line-level diff turning `old` into `new`.
+///
+/// The diff is based on the longest common subsequence of lines, deletions
+/// are listed before the insertions replacing them.
+///
+/// # Arguments
+///
+/// * `old` - The original text.
+/// * `new` - The modified text.
+///
+/// # Returns
+///
+/// Returns the lines of both texts, in order, tagged as kept, inserted or
+/// deleted.
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j] is the length of the longest common subsequence of
+    // old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            diff.push(DiffLine::Equal(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(DiffLine::Delete(old[i]));
+            i += 1;
+        } else {
+            diff.push(DiffLine::Insert(new[j]));
+            j += 1;
+        }
+    }
+    diff.extend(old[i..].iter().map(|line| DiffLine::Delete(line)));
+    diff.extend(new[j..].iter().map(|line| DiffLine::Insert(line)));
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines_identical() {
+        let diff = diff_lines("a\nb", "a\nb");
+        assert_eq!(diff, vec![DiffLine::Equal("a"), DiffLine::Equal("b")]);
+    }
+
+    #[test]
+    fn test_diff_lines_insert_and_delete() {
+        let diff = diff_lines("a\nb\nc", "a\nc\nd");
+        assert_eq!(
+            diff,
+            vec![
+                DiffLine::Equal("a"),
+                DiffLine::Delete("b"),
+                DiffLine::Equal("c"),
+                DiffLine::Insert("d"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_replaced_line() {
+        let diff = diff_lines("Hello\nWorld", "Hello\nRust");
+        assert_eq!(
+            diff,
+            vec![
+                DiffLine::Equal("Hello"),
+                DiffLine::Delete("World"),
+                DiffLine::Insert("Rust"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_empty_texts() {
+        assert_eq!(diff_lines("", ""), vec![]);
+        assert_eq!(diff_lines("", "a"), vec![DiffLine::Insert("a")]);
+        assert_eq!(diff_lines("a", ""), vec![DiffLine::Delete("a")]);
+    }
+}
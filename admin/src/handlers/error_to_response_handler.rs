@@ -8,6 +8,61 @@ use sqlx::Error as SqlxError; // Importer le type d'erreur SQLx
 use validator::ValidationErrors;
 
 use crate::middlewares::error_middleware::Error;
+use crate::models::capabilities_model::Capability;
+
+/// Error raised when the current user is not allowed to perform an action.
+#[derive(Debug)]
+pub struct ForbiddenError {
+    pub message: String,
+}
+
+impl ForbiddenError {
+    pub fn missing_capability(capability: Capability) -> Self {
+        ForbiddenError {
+            message: format!(
+                "Missing required capability '{}'",
+                capability.as_str()
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for ForbiddenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ForbiddenError {}
+
+/// Error raised when the credentials or the token of a request are invalid.
+#[derive(Debug)]
+pub struct UnauthorizedError {
+    pub message: String,
+}
+
+impl std::fmt::Display for UnauthorizedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for UnauthorizedError {}
+
+/// Error raised when a value conflicts with the current state of the
+/// database, such as a slug already taken.
+#[derive(Debug)]
+pub struct ConflictError {
+    pub message: String,
+}
+
+impl std::fmt::Display for ConflictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ConflictError {}
 
 /// Maps `anyhow::Error` to `ntex::web::Error`, handling specific error types and logging them.
 pub fn convert_anyhow_to_ntex(e: AnyhowError) -> web::Error {
@@ -15,44 +70,67 @@ pub fn convert_anyhow_to_ntex(e: AnyhowError) -> web::Error {
     let mut backtrace = None;
 
     // Determine the type of error and assign an appropriate HTTP status code
-    let status_code =
-        if let Some(validation_errors) = e.downcast_ref::<ValidationErrors>() {
-            // Handle validation errors
-            error_message = format!(
-                "Validation error: {}",
-                format_validation_errors(validation_errors)
-            );
-            warn!("Validation error occurred: {:?}", validation_errors);
-            StatusCode::BAD_REQUEST
-        } else if let Some(json_error) = e.downcast_ref::<SerdeJsonError>() {
-            // Handle JSON serialization/deserialization errors
-            error_message = format!("JSON deserialize error: {}", json_error);
-            warn!("JSON deserialization error: {}", json_error);
-            StatusCode::BAD_REQUEST
-        } else if let Some(sqlx_error) = e.downcast_ref::<SqlxError>() {
-            // Handle SQLx database errors
-            error_message = format_sqlx_error(sqlx_error);
-            match sqlx_error {
-                SqlxError::RowNotFound => {
-                    info!("Row not found in the database");
-                    StatusCode::NOT_FOUND
-                }
-                SqlxError::Database(_) => {
-                    error!("Database error: {:?}", sqlx_error);
-                    StatusCode::INTERNAL_SERVER_ERROR
-                }
-                _ => {
-                    warn!("SQLx error: {:?}", sqlx_error);
-                    StatusCode::BAD_REQUEST
-                }
+    let status_code = if let Some(unauthorized_error) =
+        e.downcast_ref::<UnauthorizedError>()
+    {
+        // Handle authentication errors
+        error_message = unauthorized_error.message.clone();
+        warn!("Authentication failed: {}", unauthorized_error);
+        StatusCode::UNAUTHORIZED
+    } else if let Some(forbidden_error) = e.downcast_ref::<ForbiddenError>() {
+        // Handle permission errors
+        error_message = forbidden_error.message.clone();
+        warn!("Access denied: {}", forbidden_error);
+        StatusCode::FORBIDDEN
+    } else if let Some(conflict_error) = e.downcast_ref::<ConflictError>() {
+        // Handle conflicts with existing data
+        error_message = conflict_error.message.clone();
+        warn!("Conflict: {}", conflict_error);
+        StatusCode::CONFLICT
+    } else if let Some(validation_errors) = e.downcast_ref::<ValidationErrors>()
+    {
+        // Handle validation errors
+        error_message = format!(
+            "Validation error: {}",
+            format_validation_errors(validation_errors)
+        );
+        warn!("Validation error occurred: {:?}", validation_errors);
+        StatusCode::BAD_REQUEST
+    } else if let Some(json_error) = e.downcast_ref::<SerdeJsonError>() {
+        // Handle JSON serialization/deserialization errors
+        error_message = format!("JSON deserialize error: {}", json_error);
+        warn!("JSON deserialization error: {}", json_error);
+        StatusCode::BAD_REQUEST
+    } else if let Some(sqlx_error) = e.downcast_ref::<SqlxError>() {
+        // Handle SQLx database errors
+        error_message = format_sqlx_error(sqlx_error);
+        match sqlx_error {
+            SqlxError::RowNotFound => {
+                info!("Row not found in the database");
+                StatusCode::NOT_FOUND
+            }
+            SqlxError::Database(db_error)
+                if db_error.is_unique_violation() =>
+            {
+                warn!("Unique constraint violated: {:?}", sqlx_error);
+                StatusCode::CONFLICT
             }
-        } else {
-            // Generic error, include a backtrace if available
-            error_message = format!("{:?}", e);
-            backtrace = Some(format!("{:?}", e.backtrace()));
-            error!("Internal server error: {:?}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        };
+            SqlxError::Database(_) => {
+                error!("Database error: {:?}", sqlx_error);
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+            _ => {
+                warn!("SQLx error: {:?}", sqlx_error);
+                StatusCode::BAD_REQUEST
+            }
+        }
+    } else {
+        // Generic error, include a backtrace if available
+        error_message = format!("{:?}", e);
+        backtrace = Some(format!("{:?}", e.backtrace()));
+        error!("Internal server error: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
 
     // Create an HTTP response with the determined status code and error details
     let response = HttpResponse::build(status_code).json(&Error {
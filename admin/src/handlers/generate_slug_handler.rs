@@ -1,5 +1,11 @@
+use std::collections::HashSet;
+
 use deunicode::deunicode;
 
+/// Characters kept at the end of a slug for the number its duplicates are
+/// suffixed with.
+const SUFFIX_MAX_LENGTH: usize = 11;
+
 pub fn generate_slug(slug: &str) -> String {
     let normalized_slug = deunicode(slug);
 
@@ -12,6 +18,35 @@ pub fn generate_slug(slug: &str) -> String {
         .join("-")
 }
 
+/// Start shared by a slug and all the slugs `deduplicate_slug` can return for
+/// it.
+pub fn slug_stem(slug: &str, max_length: usize) -> &str {
+    &slug[..slug.len().min(max_length.saturating_sub(SUFFIX_MAX_LENGTH))]
+}
+
+/// Returns the slug if it is not taken, otherwise the slug suffixed with the
+/// first of `-2`, `-3`... that is not, shortened to fit in `max_length`.
+pub fn deduplicate_slug(
+    slug: &str,
+    taken: &HashSet<String>,
+    max_length: usize,
+) -> String {
+    if !taken.contains(slug) {
+        return slug.to_string();
+    }
+
+    let mut number = 2;
+    loop {
+        let suffix = format!("-{}", number);
+        let stem = &slug[..slug.len().min(max_length - suffix.len())];
+        let candidate = format!("{}{}", stem, suffix);
+        if !taken.contains(&candidate) {
+            return candidate;
+        }
+        number += 1;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,4 +106,34 @@ mod tests {
         let expected = "hello-world-2023";
         assert_eq!(generate_slug(input), expected);
     }
+
+    #[test]
+    fn test_deduplicate_slug_free() {
+        let taken = HashSet::from(["hello".to_string()]);
+        assert_eq!(deduplicate_slug("hello-world", &taken, 200), "hello-world");
+    }
+
+    #[test]
+    fn test_deduplicate_slug_taken() {
+        let taken = HashSet::from([
+            "hello-world".to_string(),
+            "hello-world-2".to_string(),
+            "hello-world-4".to_string(),
+        ]);
+        assert_eq!(
+            deduplicate_slug("hello-world", &taken, 200),
+            "hello-world-3"
+        );
+    }
+
+    #[test]
+    fn test_deduplicate_slug_max_length() {
+        let slug = "a".repeat(20);
+        let taken = HashSet::from([slug.clone()]);
+
+        let deduplicated = deduplicate_slug(&slug, &taken, 20);
+
+        assert_eq!(deduplicated, format!("{}-2", "a".repeat(18)));
+        assert!(deduplicated.starts_with(slug_stem(&slug, 20)));
+    }
 }
@@ -0,0 +1,201 @@
+use std::io::Cursor;
+
+use anyhow::{anyhow, Result};
+use image::{imageops::FilterType, GenericImageView};
+
+/// Size the uploaded images are resized to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSize {
+    pub name: String,
+    pub width: u32,
+    pub height: u32,
+
+    /// Crops the image to the exact size instead of fitting it inside
+    pub crop: bool,
+}
+
+/// Copy of an image resized to one of the image sizes.
+pub struct ImageDerivative {
+    pub name: String,
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+/// Dimensions of an image and its resized copies.
+pub struct ResizedImage {
+    pub width: u32,
+    pub height: u32,
+    pub derivatives: Vec<ImageDerivative>,
+}
+
+/// Parses a list of image sizes, such as
+/// `thumbnail=150x150:crop,medium=300x300`.
+///
+/// # Returns
+///
+/// Returns the sizes, or an error naming the invalid definition.
+pub fn parse_image_sizes(definitions: &str) -> Result<Vec<ImageSize>> {
+    definitions
+        .split(',')
+        .map(str::trim)
+        .filter(|definition| !definition.is_empty())
+        .map(|definition| {
+            let invalid = || anyhow!("Invalid image size '{}'", definition);
+
+            let (name, dimensions) =
+                definition.split_once('=').ok_or_else(invalid)?;
+            let (dimensions, crop) = match dimensions.split_once(':') {
+                Some((dimensions, "crop")) => (dimensions, true),
+                Some(_) => return Err(invalid()),
+                None => (dimensions, false),
+            };
+            let (width, height) =
+                dimensions.split_once('x').ok_or_else(invalid)?;
+            let width: u32 = width.parse().map_err(|_| invalid())?;
+            let height: u32 = height.parse().map_err(|_| invalid())?;
+            if name.trim().is_empty() || width == 0 || height == 0 {
+                return Err(invalid());
+            }
+
+            Ok(ImageSize {
+                name: name.trim().to_string(),
+                width,
+                height,
+                crop,
+            })
+        })
+        .collect()
+}
+
+/// Resizes an image to each size, keeping its format.
+///
+/// Images are never upscaled: the sizes the image already fits in are
+/// skipped.
+///
+/// # Arguments
+///
+/// * `data` - The content of the image file.
+/// * `sizes` - The sizes to resize the image to.
+///
+/// # Returns
+///
+/// Returns the dimensions of the image and its resized copies.
+pub fn resize_image(data: &[u8], sizes: &[ImageSize]) -> Result<ResizedImage> {
+    let format = image::guess_format(data)?;
+    let original = image::load_from_memory_with_format(data, format)?;
+    let (width, height) = original.dimensions();
+
+    let mut derivatives = Vec::new();
+    for size in sizes {
+        if width <= size.width && height <= size.height {
+            continue;
+        }
+
+        let resized = if size.crop {
+            original.resize_to_fill(
+                size.width.min(width),
+                size.height.min(height),
+                FilterType::Lanczos3,
+            )
+        } else {
+            original.resize(size.width, size.height, FilterType::Lanczos3)
+        };
+
+        let mut data = Vec::new();
+        resized.write_to(&mut Cursor::new(&mut data), format)?;
+        derivatives.push(ImageDerivative {
+            name: size.name.clone(),
+            width: resized.width(),
+            height: resized.height(),
+            data,
+        });
+    }
+
+    Ok(ResizedImage {
+        width,
+        height,
+        derivatives,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{DynamicImage, ImageFormat};
+
+    use super::*;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut data = Vec::new();
+        DynamicImage::new_rgb8(width, height)
+            .write_to(&mut Cursor::new(&mut data), ImageFormat::Png)
+            .expect("Failed to encode test image");
+        data
+    }
+
+    fn size(name: &str, width: u32, height: u32, crop: bool) -> ImageSize {
+        ImageSize {
+            name: name.to_string(),
+            width,
+            height,
+            crop,
+        }
+    }
+
+    #[test]
+    fn test_parse_image_sizes() {
+        let sizes = parse_image_sizes("thumbnail=150x150:crop, large=1024x768")
+            .expect("Failed to parse image sizes");
+        assert_eq!(
+            sizes,
+            vec![
+                size("thumbnail", 150, 150, true),
+                size("large", 1024, 768, false)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_image_sizes_invalid() {
+        assert!(parse_image_sizes("thumbnail=150").is_err());
+        assert!(parse_image_sizes("thumbnail=0x150").is_err());
+        assert!(parse_image_sizes("thumbnail=150x150:stretch").is_err());
+        assert!(parse_image_sizes("=150x150").is_err());
+    }
+
+    #[test]
+    fn test_resize_image_fit_and_crop() {
+        let sizes = vec![
+            size("thumbnail", 50, 50, true),
+            size("medium", 100, 100, false),
+        ];
+
+        let resized =
+            resize_image(&png(400, 200), &sizes).expect("Failed to resize");
+
+        assert_eq!((resized.width, resized.height), (400, 200));
+        let dimensions: Vec<(&str, u32, u32)> = resized
+            .derivatives
+            .iter()
+            .map(|d| (d.name.as_str(), d.width, d.height))
+            .collect();
+        assert_eq!(
+            dimensions,
+            vec![("thumbnail", 50, 50), ("medium", 100, 50)]
+        );
+        assert_eq!(
+            image::guess_format(&resized.derivatives[0].data).ok(),
+            Some(ImageFormat::Png)
+        );
+    }
+
+    #[test]
+    fn test_resize_image_never_upscales() {
+        let sizes = vec![size("large", 1024, 1024, false)];
+
+        let resized =
+            resize_image(&png(300, 200), &sizes).expect("Failed to resize");
+
+        assert!(resized.derivatives.is_empty());
+    }
+}
@@ -1,3 +1,7 @@
+pub mod archive_range_handler;
+pub mod diff_handler;
 pub mod error_to_response_handler;
 pub mod generate_slug_handler;
+pub mod image_handler;
+pub mod multipart_handler;
 pub mod openapi_handler;
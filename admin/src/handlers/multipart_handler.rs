@@ -0,0 +1,139 @@
+use anyhow::Result;
+use futures::StreamExt;
+use ntex::http::header::CONTENT_DISPOSITION;
+use ntex_multipart::Multipart;
+use validator::{ValidationError, ValidationErrors};
+
+use crate::dtos::media_dto::UploadMediaDTO;
+
+/// Reads the file sent in a field of a `multipart/form-data` request, the
+/// other fields being ignored.
+///
+/// # Arguments
+///
+/// * `multipart` - The body of the request.
+/// * `field_name` - The name of the field holding the file.
+/// * `max_size` - The maximum size of the file, in bytes.
+///
+/// # Returns
+///
+/// Returns the file, or a `ValidationErrors` on `field_name` if the field is
+/// missing, the file is too large or the body is malformed.
+pub async fn read_file_field(
+    mut multipart: Multipart,
+    field_name: &'static str,
+    max_size: usize,
+) -> Result<UploadMediaDTO> {
+    while let Some(field) = multipart.next().await {
+        let mut field = field.map_err(|e| file_error(field_name, e))?;
+
+        let disposition = field
+            .headers()
+            .get(CONTENT_DISPOSITION)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        let filename =
+            disposition_param(disposition, "filename").filter(|_| {
+                disposition_param(disposition, "name").as_deref()
+                    == Some(field_name)
+            });
+
+        let Some(filename) = filename else {
+            while let Some(chunk) = field.next().await {
+                chunk.map_err(|e| file_error(field_name, e))?;
+            }
+            continue;
+        };
+
+        let mut data = Vec::new();
+        while let Some(chunk) = field.next().await {
+            let chunk = chunk.map_err(|e| file_error(field_name, e))?;
+            if data.len() + chunk.len() > max_size {
+                return Err(file_error(
+                    field_name,
+                    format!(
+                        "File exceeds the maximum size of {} bytes",
+                        max_size
+                    ),
+                ));
+            }
+            data.extend_from_slice(&chunk);
+        }
+
+        return Ok(UploadMediaDTO { filename, data });
+    }
+
+    Err(file_error(field_name, "A file is required"))
+}
+
+/// Returns a parameter of a `Content-Disposition` header, such as the
+/// `filename` of `form-data; name="file"; filename="photo.png"`.
+fn disposition_param(disposition: &str, param: &str) -> Option<String> {
+    disposition.split(';').skip(1).find_map(|part| {
+        let (key, value) = part.trim().split_once('=')?;
+        if !key.trim().eq_ignore_ascii_case(param) {
+            return None;
+        }
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .unwrap_or(value);
+        Some(value.replace("\\\"", "\""))
+    })
+}
+
+/// Builds the validation error of a file field.
+fn file_error(
+    field_name: &'static str,
+    message: impl ToString,
+) -> anyhow::Error {
+    let mut error = ValidationError::new("invalid_file");
+    error.message = Some(message.to_string().into());
+    let mut errors = ValidationErrors::new();
+    errors.add(field_name, error);
+    errors.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disposition_param_quoted() {
+        let disposition = r#"form-data; name="file"; filename="photo.png""#;
+        assert_eq!(
+            disposition_param(disposition, "name").as_deref(),
+            Some("file")
+        );
+        assert_eq!(
+            disposition_param(disposition, "filename").as_deref(),
+            Some("photo.png")
+        );
+    }
+
+    #[test]
+    fn test_disposition_param_unquoted() {
+        let disposition = "form-data; name=file; filename=photo.png";
+        assert_eq!(
+            disposition_param(disposition, "filename").as_deref(),
+            Some("photo.png")
+        );
+    }
+
+    #[test]
+    fn test_disposition_param_escaped_quotes() {
+        let disposition =
+            r#"form-data; name="file"; filename="my \"best\" photo.png""#;
+        assert_eq!(
+            disposition_param(disposition, "filename").as_deref(),
+            Some(r#"my "best" photo.png"#)
+        );
+    }
+
+    #[test]
+    fn test_disposition_param_missing() {
+        let disposition = r#"form-data; name="title""#;
+        assert_eq!(disposition_param(disposition, "filename"), None);
+    }
+}
@@ -8,34 +8,97 @@ use utoipa::{
 
 use crate::{
     dtos::{
-        auth_dtos::{ClaimsDTO, LoginRequestDTO, TokenDTO},
-        category_dto::{CategoryDTO, CreateCategoryDTO, DeleteCategoryIdsDTO},
+        auth_dtos::{
+            ClaimsDTO, LoginRequestDTO, LogoutRequestDTO,
+            RefreshTokenRequestDTO, TokenDTO,
+        },
+        capability_dto::CapabilityDTO,
+        category_dto::{
+            CategoryDTO, CategoryFiltersDTO, CategoryTreeDTO,
+            CreateCategoryDTO, DeleteCategoryIdsDTO,
+        },
+        comment_dto::{
+            CommentDTO, CommentFiltersDTO, CreateCommentDTO,
+            DeleteCommentIdsDTO, ReplyCommentDTO, UpdateCommentsStatusDTO,
+        },
+        media_dto::{
+            DeleteMediaIdsDTO, MediaDTO, MediaSizeDTO, RegenerateMediaDTO,
+        },
+        menu_dto::{
+            CreateMenuDTO, DeleteMenuIdsDTO, MenuDTO, MenuItemDTO,
+            MenuItemInputDTO, MenuLocationsDTO, MenuTreeDTO, PublicMenuDTO,
+            PublicMenuItemDTO, UpdateMenuItemsDTO,
+        },
+        page_dto::{CreatePageDTO, DeletePageIdsDTO, PageDTO, PageFiltersDTO},
         pagination_dto::PaginationParamsDTO,
-        post_dto::{CreatePostDTO, DeletePostIdsDTO, PostDTO},
+        post_autosave_dto::{PostAutosaveDTO, SavePostAutosaveDTO},
+        post_dto::{
+            CreatePostDTO, DeletePostIdsDTO, PostDTO, PostFiltersDTO,
+            PostSearchDTO, PostSearchParamsDTO,
+        },
+        post_revision_dto::{
+            DiffLineDTO, DiffOperationDTO, PostRevisionDTO, PostRevisionDiffDTO,
+        },
         posts_categories_dto::{CreatePostsCategoriesDTO, PostsCategoriesDTO},
+        posts_tags_dto::{PostsTagsDTO, UpdatePostsTagsDTO},
+        public_dto::{
+            PublicAuthorDTO, PublicPageDTO, PublicPaginationParamsDTO,
+            PublicPostDTO, PublicPostFiltersDTO, PublicRedirectDTO,
+            PublicRedirectParamsDTO, PublicSlugDTO,
+        },
+        redirect_dto::{CreateRedirectDTO, DeleteRedirectIdsDTO, RedirectDTO},
+        role_dto::{CreateRoleDTO, DeleteRoleIdsDTO, RoleDTO},
+        settings_dto::{
+            PermalinksDTO, PublicSettingsDTO, SettingsDTO, UpdateSettingsDTO,
+        },
+        slug_dto::{SlugCheckDTO, SlugCheckParamsDTO},
         tag_dto::{CreateTagDTO, DeleteTagIdsDTO, TagDTO},
-        user_dtos::{CreateUserDTO, DeleteUserIdsDTO, UserDTO},
+        theme_dto::ActiveThemeDTO,
+        user_dtos::{CreateUserDTO, DeleteUserIdsDTO, UserDTO, UserFiltersDTO},
+        users_roles_dto::{
+            CreateUsersRolesDTO, DeleteUsersRolesIdsDTO, UsersRolesDTO,
+        },
     },
     middlewares::error_middleware::Error,
-    models::posts_model::PostsStatus,
+    models::{
+        comments_model::CommentsStatus, menus_model::MenuItemsType,
+        posts_model::PostsStatus, settings_model::SettingsGroup,
+        slugs_model::SlugType,
+    },
 };
 
 /// Main structure to generate OpenAPI documentation
 #[derive(OpenApi)]
 #[openapi(
     components(
-        schemas(Error, DeleteCategoryIdsDTO, CategoryDTO, CreateCategoryDTO,
-        TagDTO, PostDTO, CreateTagDTO, DeleteTagIdsDTO, CreatePostDTO, DeletePostIdsDTO,
+        schemas(Error, DeleteCategoryIdsDTO, CategoryDTO, CategoryTreeDTO, CreateCategoryDTO,
+        TagDTO, PostDTO, PostSearchDTO, PostSearchParamsDTO, PostFiltersDTO, CategoryFiltersDTO, UserFiltersDTO, CreateTagDTO, DeleteTagIdsDTO, CreatePostDTO, DeletePostIdsDTO,
         DeleteUserIdsDTO, CreateUserDTO, UserDTO, PaginationParamsDTO, LoginRequestDTO,
-        PostsCategoriesDTO, CreatePostsCategoriesDTO,
-        TokenDTO, ClaimsDTO, PostsStatus
+        PostsCategoriesDTO, CreatePostsCategoriesDTO, PostsTagsDTO, UpdatePostsTagsDTO,
+        PostRevisionDTO, PostRevisionDiffDTO, DiffLineDTO, DiffOperationDTO,
+        PostAutosaveDTO, SavePostAutosaveDTO, MediaDTO, MediaSizeDTO, DeleteMediaIdsDTO, RegenerateMediaDTO,
+        CommentDTO, CommentFiltersDTO, CreateCommentDTO, ReplyCommentDTO, UpdateCommentsStatusDTO, DeleteCommentIdsDTO, CommentsStatus,
+        PageDTO, CreatePageDTO, DeletePageIdsDTO, PageFiltersDTO,
+        PublicPostDTO, PublicPageDTO, PublicAuthorDTO, PublicPostFiltersDTO, PublicPaginationParamsDTO,
+        PublicRedirectDTO, PublicRedirectParamsDTO, PublicSlugDTO,
+        RedirectDTO, CreateRedirectDTO, DeleteRedirectIdsDTO,
+        ActiveThemeDTO,
+        MenuDTO, CreateMenuDTO, DeleteMenuIdsDTO, MenuTreeDTO, MenuItemDTO, MenuItemInputDTO, UpdateMenuItemsDTO,
+        MenuLocationsDTO, PublicMenuDTO, PublicMenuItemDTO, MenuItemsType,
+        SettingsDTO, UpdateSettingsDTO, PublicSettingsDTO, PermalinksDTO, SettingsGroup,
+        SlugCheckDTO, SlugCheckParamsDTO, SlugType,
+        TokenDTO, ClaimsDTO, RefreshTokenRequestDTO, LogoutRequestDTO, PostsStatus,
+        RoleDTO, CreateRoleDTO, DeleteRoleIdsDTO, CapabilityDTO,
+        UsersRolesDTO, CreateUsersRolesDTO, DeleteUsersRolesIdsDTO
         )
     ),
     modifiers(&SecurityAddon),
     paths(
         crate::controllers::categories::create_category_controller::create_category_controller,
         crate::controllers::categories::get_all_categories_controller::get_all_categories_controller,
+        crate::controllers::categories::get_categories_tree_controller::get_categories_tree_controller,
         crate::controllers::categories::get_category_by_id_controller::get_category_by_id_controller,
+        crate::controllers::categories::get_category_path_controller::get_category_path_controller,
         crate::controllers::categories::delete_category_controller::delete_category_controller,
         crate::controllers::categories::update_category_controller::update_category_controller,
         crate::controllers::tags::create_tag_controller::create_tag_controller,
@@ -49,13 +112,73 @@ use crate::{
         crate::controllers::posts::delete_post_controller::delete_post_controller,
         crate::controllers::posts::update_post_controller::update_post_controller,
         crate::controllers::posts::get_post_by_id_controller::get_post_by_id_controller,
+        crate::controllers::posts::search_posts_controller::search_posts_controller,
+        crate::controllers::post_revisions::get_post_revisions_controller::get_post_revisions_controller,
+        crate::controllers::post_revisions::get_post_revisions_diff_controller::get_post_revisions_diff_controller,
+        crate::controllers::post_revisions::restore_post_revision_controller::restore_post_revision_controller,
+        crate::controllers::post_autosaves::save_post_autosave_controller::save_post_autosave_controller,
+        crate::controllers::post_autosaves::get_post_autosave_controller::get_post_autosave_controller,
+        crate::controllers::post_autosaves::delete_post_autosave_controller::delete_post_autosave_controller,
+        crate::controllers::pages::create_page_controller::create_page_controller,
+        crate::controllers::pages::get_all_pages_controller::get_all_pages_controller,
+        crate::controllers::pages::get_page_by_id_controller::get_page_by_id_controller,
+        crate::controllers::pages::update_page_controller::update_page_controller,
+        crate::controllers::pages::delete_pages_controller::delete_pages_controller,
+        crate::controllers::comments::create_comment_controller::create_comment_controller,
+        crate::controllers::comments::get_all_comments_controller::get_all_comments_controller,
+        crate::controllers::comments::reply_comment_controller::reply_comment_controller,
+        crate::controllers::comments::update_comments_status_controller::update_comments_status_controller,
+        crate::controllers::comments::delete_comments_controller::delete_comments_controller,
+        crate::controllers::media::upload_media_controller::upload_media_controller,
+        crate::controllers::media::get_all_media_controller::get_all_media_controller,
+        crate::controllers::media::get_media_by_id_controller::get_media_by_id_controller,
+        crate::controllers::media::regenerate_media_controller::regenerate_media_controller,
+        crate::controllers::media::delete_media_controller::delete_media_controller,
+        crate::controllers::public::get_public_posts_controller::get_public_posts_controller,
+        crate::controllers::public::get_public_post_controller::get_public_post_controller,
+        crate::controllers::public::get_public_page_controller::get_public_page_controller,
+        crate::controllers::public::get_public_category_controller::get_public_category_controller,
+        crate::controllers::public::get_public_tag_controller::get_public_tag_controller,
+        crate::controllers::public::get_public_theme_controller::get_public_theme_controller,
+        crate::controllers::public::get_public_settings_controller::get_public_settings_controller,
+        crate::controllers::public::get_public_menus_controller::get_public_menus_controller,
+        crate::controllers::public::get_public_redirect_controller::get_public_redirect_controller,
+        crate::controllers::public::get_public_slug_controller::get_public_slug_controller,
+        crate::controllers::themes::update_active_theme_controller::update_active_theme_controller,
+        crate::controllers::settings::get_settings_controller::get_settings_controller,
+        crate::controllers::settings::update_settings_controller::update_settings_controller,
+        crate::controllers::slugs::check_slug_controller::check_slug_controller,
+        crate::controllers::menus::create_menu_controller::create_menu_controller,
+        crate::controllers::menus::get_all_menus_controller::get_all_menus_controller,
+        crate::controllers::menus::get_menu_by_id_controller::get_menu_by_id_controller,
+        crate::controllers::menus::update_menu_controller::update_menu_controller,
+        crate::controllers::menus::update_menu_items_controller::update_menu_items_controller,
+        crate::controllers::menus::delete_menus_controller::delete_menus_controller,
+        crate::controllers::menus::get_menu_locations_controller::get_menu_locations_controller,
+        crate::controllers::menus::update_menu_locations_controller::update_menu_locations_controller,
+        crate::controllers::redirects::create_redirect_controller::create_redirect_controller,
+        crate::controllers::redirects::get_all_redirects_controller::get_all_redirects_controller,
+        crate::controllers::redirects::update_redirect_controller::update_redirect_controller,
+        crate::controllers::redirects::delete_redirects_controller::delete_redirects_controller,
         crate::controllers::users::get_user_by_id_controller::get_user_by_id_controller,
         crate::controllers::users::get_all_users_controller::get_all_users_controller,
         crate::controllers::users::create_user_controller::create_user_controller,
         crate::controllers::users::update_user_controller::update_user_controller,
         crate::controllers::users::delete_user_controller::delete_user_controller,
         crate::controllers::auth::login_controller::login_controller,
+        crate::controllers::auth::refresh_controller::refresh_controller,
+        crate::controllers::auth::logout_controller::logout_controller,
         crate::controllers::posts_categories::create_posts_categories_controller::create_posts_categories_controller,
+        crate::controllers::posts_tags::update_posts_tags_controller::update_posts_tags_controller,
+        crate::controllers::posts_tags::get_posts_by_tag_controller::get_posts_by_tag_controller,
+        crate::controllers::roles::create_role_controller::create_role_controller,
+        crate::controllers::roles::get_all_roles_controller::get_all_roles_controller,
+        crate::controllers::roles::get_role_by_id_controller::get_role_by_id_controller,
+        crate::controllers::roles::update_role_controller::update_role_controller,
+        crate::controllers::roles::delete_role_controller::delete_role_controller,
+        crate::controllers::capabilities::get_all_capabilities_controller::get_all_capabilities_controller,
+        crate::controllers::users_roles::create_users_roles_controller::create_users_roles_controller,
+        crate::controllers::users_roles::delete_users_roles_controller::delete_users_roles_controller,
     ),
     servers(
         (url = "/api/v1", description = "API v1")
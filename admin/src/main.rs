@@ -1,8 +1,13 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use dotenv::dotenv;
 use env_logger::Env;
 use middlewares::error_middleware::Error;
 use ntex::web::{App, HttpServer};
 use ntex_cors::Cors;
+use ntex_files::Files;
+use storage::{local_storage::LocalStorage, StorageBackend};
 
 mod config;
 mod controllers;
@@ -14,8 +19,10 @@ mod models;
 mod repositories;
 mod routes;
 mod services;
+mod storage;
 mod tests;
 mod validators;
+mod workers;
 
 #[ntex::main]
 async fn main() -> std::io::Result<()> {
@@ -28,6 +35,18 @@ async fn main() -> std::io::Result<()> {
     let cors_allowed_url = config::config::get_cors_allowed_url();
     let api_url = config::config::get_api_url();
     let api_port = config::config::get_api_port();
+    let media_dir = config::config::get_media_dir();
+    std::fs::create_dir_all(&media_dir)?;
+    let storage: Arc<dyn StorageBackend> = Arc::new(LocalStorage::new(
+        &media_dir,
+        &config::config::get_media_url(),
+    ));
+
+    workers::scheduled_posts_worker::spawn(
+        pool.clone(),
+        Arc::new(workers::SystemClock),
+        Duration::from_secs(config::config::get_scheduled_posts_interval()),
+    );
 
     HttpServer::new(move || {
         App::new()
@@ -48,7 +67,9 @@ async fn main() -> std::io::Result<()> {
                     .finish(),
             )
             .state(pool.clone())
+            .state(storage.clone())
             .configure(handlers::openapi_handler::ntex_config)
+            .service(Files::new("/uploads", &media_dir))
             .configure(routes::init)
     })
     .workers(1)
@@ -1,33 +1,298 @@
+use jsonwebtoken::errors::ErrorKind;
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
-use ntex::http;
-use ntex::web::guard::Guard;
+use ntex::http::{header, Payload, StatusCode};
+use ntex::service::{Middleware, Service, ServiceCtx};
+use ntex::web::error::InternalError;
+use ntex::web::{self, FromRequest, HttpRequest, HttpResponse};
+use sqlx::PgPool;
 
 use crate::config::config::get_secret_key;
-use crate::dtos::auth_dtos::ClaimsDTO;
-
-pub struct JwtGuard;
-
-impl Guard for JwtGuard {
-    fn check(&self, req: &http::RequestHead) -> bool {
-        if let Some(auth_header) =
-            req.headers().get(http::header::AUTHORIZATION)
-        {
-            if let Ok(auth_str) = auth_header.to_str() {
-                if auth_str.starts_with("Bearer ") {
-                    let token = &auth_str[7..];
-
-                    let decoding_key =
-                        DecodingKey::from_secret(get_secret_key().as_ref());
-                    let validation = Validation::new(Algorithm::HS256);
-
-                    if decode::<ClaimsDTO>(token, &decoding_key, &validation)
-                        .is_ok()
-                    {
-                        return true;
-                    }
-                }
+use crate::dtos::auth_dtos::{ClaimsDTO, CurrentUserDTO};
+use crate::middlewares::error_middleware::Error;
+use crate::repositories::capabilities_repository::select_capabilities_by_user_id;
+use crate::repositories::revoked_tokens_repository::is_token_revoked;
+use crate::repositories::users_repository::select_user_by_id;
+
+/// Middleware validating the bearer token of every request in its scope.
+///
+/// Requests without a valid token, or with a token revoked at logout, are
+/// rejected with a `401`, tokens of disabled accounts with a `403`, and the
+/// request fails with a `500` when the database cannot be checked. On success
+/// the decoded `ClaimsDTO` and the `CurrentUserDTO` holding the user
+/// capabilities are stored in the request extensions.
+pub struct JwtAuth;
+
+impl<S> Middleware<S> for JwtAuth {
+    type Service = JwtAuthMiddleware<S>;
+
+    fn create(&self, service: S) -> Self::Service {
+        JwtAuthMiddleware { service }
+    }
+}
+
+pub struct JwtAuthMiddleware<S> {
+    service: S,
+}
+
+impl<S, Err> Service<web::WebRequest<Err>> for JwtAuthMiddleware<S>
+where
+    S: Service<
+        web::WebRequest<Err>,
+        Response = web::WebResponse,
+        Error = web::Error,
+    >,
+    Err: web::ErrorRenderer,
+{
+    type Response = web::WebResponse;
+    type Error = web::Error;
+
+    ntex::forward_ready!(service);
+
+    async fn call(
+        &self,
+        req: web::WebRequest<Err>,
+        ctx: ServiceCtx<'_, Self>,
+    ) -> Result<Self::Response, Self::Error> {
+        let claims = match decode_bearer_token(req.headers()) {
+            Ok(claims) => claims,
+            Err(message) => {
+                return Ok(req.into_response(error_response(
+                    StatusCode::UNAUTHORIZED,
+                    message,
+                )))
+            }
+        };
+
+        let user_id = match claims.user_id() {
+            Some(user_id) => user_id,
+            None => {
+                return Ok(req.into_response(error_response(
+                    StatusCode::UNAUTHORIZED,
+                    "Invalid token subject",
+                )))
+            }
+        };
+
+        let mut current_user = CurrentUserDTO {
+            id: user_id,
+            capabilities: Vec::new(),
+        };
+
+        // The checks below need the database, fail closed without it
+        let Some(pool) = req.app_state::<PgPool>() else {
+            return Ok(req.into_response(error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Database connection unavailable",
+            )));
+        };
+
+        // Reject tokens revoked at logout
+        match is_token_revoked(pool, &claims.jti).await {
+            Ok(false) => {}
+            Ok(true) => {
+                return Ok(req.into_response(error_response(
+                    StatusCode::UNAUTHORIZED,
+                    "Token has been revoked",
+                )))
+            }
+            Err(_) => {
+                return Ok(req.into_response(error_response(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to check token revocation",
+                )))
             }
         }
-        false
+
+        // Make sure the account behind the token still exists and is enabled
+        match select_user_by_id(pool, user_id).await {
+            Ok(user) if !user.active => {
+                return Ok(req.into_response(error_response(
+                    StatusCode::FORBIDDEN,
+                    "User account is disabled",
+                )))
+            }
+            Ok(_) => {}
+            Err(e) if is_row_not_found(&e) => {
+                return Ok(req.into_response(error_response(
+                    StatusCode::UNAUTHORIZED,
+                    "User not found",
+                )))
+            }
+            Err(_) => {
+                return Ok(req.into_response(error_response(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to load the user",
+                )))
+            }
+        }
+
+        match select_capabilities_by_user_id(pool, user_id).await {
+            Ok(capabilities) => current_user.capabilities = capabilities,
+            Err(_) => {
+                return Ok(req.into_response(error_response(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to load user capabilities",
+                )))
+            }
+        }
+
+        req.extensions_mut().insert(claims);
+        req.extensions_mut().insert(current_user);
+        ctx.call(&self.service, req).await
+    }
+}
+
+/// Extracts the claims injected by `JwtAuth` in a controller.
+impl<Err: web::ErrorRenderer> FromRequest<Err> for ClaimsDTO {
+    type Error = web::Error;
+
+    async fn from_request(
+        req: &HttpRequest,
+        _: &mut Payload,
+    ) -> Result<Self, Self::Error> {
+        match req.extensions().get::<ClaimsDTO>() {
+            Some(claims) => Ok(claims.clone()),
+            None => {
+                let message = "Authentication required".to_string();
+                let response =
+                    error_response(StatusCode::UNAUTHORIZED, &message);
+                Err(InternalError::from_response(message, response).into())
+            }
+        }
+    }
+}
+
+/// Extracts the authenticated user injected by `JwtAuth` in a controller.
+impl<Err: web::ErrorRenderer> FromRequest<Err> for CurrentUserDTO {
+    type Error = web::Error;
+
+    async fn from_request(
+        req: &HttpRequest,
+        _: &mut Payload,
+    ) -> Result<Self, Self::Error> {
+        match req.extensions().get::<CurrentUserDTO>() {
+            Some(current_user) => Ok(current_user.clone()),
+            None => {
+                let message = "Authentication required".to_string();
+                let response =
+                    error_response(StatusCode::UNAUTHORIZED, &message);
+                Err(InternalError::from_response(message, response).into())
+            }
+        }
+    }
+}
+
+/// Reads the `Authorization` header and decodes its bearer token.
+fn decode_bearer_token(
+    headers: &header::HeaderMap,
+) -> Result<ClaimsDTO, &'static str> {
+    let auth_str = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .ok_or("Missing Authorization header")?;
+
+    let token = auth_str
+        .strip_prefix("Bearer ")
+        .ok_or("Authorization header must use the Bearer scheme")?;
+
+    let decoding_key = DecodingKey::from_secret(get_secret_key().as_ref());
+    let validation = Validation::new(Algorithm::HS256);
+
+    decode::<ClaimsDTO>(token, &decoding_key, &validation)
+        .map(|data| data.claims)
+        .map_err(|e| match e.kind() {
+            ErrorKind::ExpiredSignature => "Token has expired",
+            _ => "Invalid token",
+        })
+}
+
+/// Whether an error is a missing row rather than a database failure.
+fn is_row_not_found(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<sqlx::Error>(),
+        Some(sqlx::Error::RowNotFound)
+    )
+}
+
+/// Builds a JSON error response in the same shape as `convert_anyhow_to_ntex`.
+fn error_response(status: StatusCode, message: &str) -> HttpResponse {
+    HttpResponse::build(status).json(&Error {
+        message: message.to_string(),
+        backtrace: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test, HttpResponse};
+
+    use super::*;
+    use crate::tests::helpers::setup::{setup_test_auth, setup_test_db};
+
+    #[web::get("/protected")]
+    async fn protected_controller(claims: ClaimsDTO) -> HttpResponse {
+        HttpResponse::Ok().body(claims.sub)
+    }
+
+    #[ntex::test]
+    async fn test_jwt_auth_missing_header() {
+        // Arrange
+        let app = test::init_service(web::App::new().service(
+            web::scope("/").wrap(JwtAuth).service(protected_controller),
+        ))
+        .await;
+
+        // Act
+        let req = test::TestRequest::get().uri("/protected").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+
+        let body = test::read_body(resp).await;
+        let error: serde_json::Value =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(error["message"], "Missing Authorization header");
+    }
+
+    #[ntex::test]
+    async fn test_jwt_auth_wrong_scheme() {
+        // Arrange
+        let app = test::init_service(web::App::new().service(
+            web::scope("/").wrap(JwtAuth).service(protected_controller),
+        ))
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/protected")
+            .header(http::header::AUTHORIZATION, "Basic dXNlcjpwYXNz")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[ntex::test]
+    async fn test_jwt_auth_without_pool() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let auth = setup_test_auth(&pool, "author").await;
+        let app = test::init_service(web::App::new().service(
+            web::scope("/").wrap(JwtAuth).service(protected_controller),
+        ))
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/protected")
+            .header(http::header::AUTHORIZATION, auth)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
     }
 }
@@ -0,0 +1,65 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, FromRow, Clone)]
+pub struct CapabilityModel {
+    pub id: Option<i32>,
+    pub name: String,
+    pub description: Option<String>,
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Capabilities known by the API, stored by name in the `capabilities` table.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Capability {
+    Read,
+    EditPosts,
+    EditOthersPosts,
+    PublishPosts,
+    DeletePosts,
+    DeleteOthersPosts,
+    ManageCategories,
+    ManageTags,
+    ListUsers,
+    CreateUsers,
+    EditUsers,
+    DeleteUsers,
+    PromoteUsers,
+    ManageRoles,
+    UploadFiles,
+    ManageOptions,
+    ModerateComments,
+    EditPages,
+    SwitchThemes,
+    EditThemeOptions,
+}
+
+impl Capability {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Capability::Read => "read",
+            Capability::EditPosts => "edit_posts",
+            Capability::EditOthersPosts => "edit_others_posts",
+            Capability::PublishPosts => "publish_posts",
+            Capability::DeletePosts => "delete_posts",
+            Capability::DeleteOthersPosts => "delete_others_posts",
+            Capability::ManageCategories => "manage_categories",
+            Capability::ManageTags => "manage_tags",
+            Capability::ListUsers => "list_users",
+            Capability::CreateUsers => "create_users",
+            Capability::EditUsers => "edit_users",
+            Capability::DeleteUsers => "delete_users",
+            Capability::PromoteUsers => "promote_users",
+            Capability::ManageRoles => "manage_roles",
+            Capability::UploadFiles => "upload_files",
+            Capability::ManageOptions => "manage_options",
+            Capability::ModerateComments => "moderate_comments",
+            Capability::EditPages => "edit_pages",
+            Capability::SwitchThemes => "switch_themes",
+            Capability::EditThemeOptions => "edit_theme_options",
+        }
+    }
+}
@@ -41,6 +41,20 @@ pub struct CategoryModel {
     pub date_created: Option<NaiveDateTime>,
 }
 
+/// Category of the tree of categories, with the number of its posts.
+#[derive(Serialize, Deserialize, FromRow, Clone)]
+pub struct CategoryNodeModel {
+    pub id: i32,
+    pub parent_id: Option<i32>,
+    pub name: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub date_created: NaiveDateTime,
+
+    /// Posts in the category, not counting its sub-categories
+    pub post_count: i64,
+}
+
 fn validate_slug_category(slug: &str) -> Result<(), ValidationError> {
     let min_length = 1;
     let max_length = 200;
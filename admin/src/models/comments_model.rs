@@ -0,0 +1,42 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use utoipa::ToSchema;
+
+/// Comment left by a reader on a post, threaded through `parent_id`.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct CommentModel {
+    pub id: i32,
+    pub post_id: i32,
+    pub parent_id: Option<i32>,
+
+    /// Account of the author of a reply from the administration
+    pub user_id: Option<i32>,
+
+    pub author_name: String,
+    pub author_email: String,
+    pub content: String,
+    pub status: CommentsStatus,
+    pub date_created: NaiveDateTime,
+}
+
+/// Moderation status of a comment, only approved comments being public.
+#[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq, ToSchema)]
+#[sqlx(type_name = "comments_status")]
+pub enum CommentsStatus {
+    Pending,
+    Approved,
+    Spam,
+    Trash,
+}
+
+/// Comment to insert, the ID and creation date being set by the database.
+pub struct NewCommentModel {
+    pub post_id: i32,
+    pub parent_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub author_name: String,
+    pub author_email: String,
+    pub content: String,
+    pub status: CommentsStatus,
+}
@@ -0,0 +1,41 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{types::Json, FromRow};
+
+/// Uploaded file of the media library, `path` being its storage key.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct MediaModel {
+    pub id: i32,
+    pub author_id: i32,
+    pub filename: String,
+    pub path: String,
+    pub mime_type: String,
+    pub size: i64,
+
+    /// Dimensions of an image, `None` for other files
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+
+    /// Resized copies of an image, by size name
+    pub sizes: Json<BTreeMap<String, MediaSizeModel>>,
+
+    pub date_created: NaiveDateTime,
+}
+
+/// Resized copy of an image, stored next to the original.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MediaSizeModel {
+    pub path: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Dimensions and resized copies of an uploaded image.
+#[derive(Debug, Default)]
+pub struct MediaImageModel {
+    pub width: i32,
+    pub height: i32,
+    pub sizes: BTreeMap<String, MediaSizeModel>,
+}
@@ -0,0 +1,99 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::models::posts_model::PostsStatus;
+
+/// Navigation menu, shown by the themes at their menu locations.
+#[derive(Validate, Serialize, Deserialize, FromRow)]
+pub struct MenuModel {
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    // https://www.postgresql.org/docs/8.1/datatype.html#DATATYPE-NUMERIC
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "ID must be between 1 and 2,147,483,647"
+    ))]
+    pub id: Option<i32>,
+
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+
+    pub slug: Option<String>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Content a menu item links to.
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    Type,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    ToSchema,
+)]
+#[sqlx(type_name = "menu_items_type")]
+pub enum MenuItemsType {
+    Post,
+    Page,
+    Category,
+    Tag,
+    Custom,
+}
+
+/// Item of a menu, with the title and slug of the content it links to.
+#[derive(Debug, Serialize, Deserialize, FromRow, Clone)]
+pub struct MenuItemModel {
+    pub id: i32,
+    pub menu_id: i32,
+    pub parent_id: Option<i32>,
+    pub item_type: MenuItemsType,
+
+    /// ID of the post, page, category or tag
+    pub object_id: Option<i32>,
+
+    /// URL of a custom item
+    pub url: Option<String>,
+
+    /// Label of the item, the title of its content if `None`
+    pub title: Option<String>,
+
+    pub menu_order: i32,
+
+    /// Title or name of the content, `None` if it was deleted
+    pub object_title: Option<String>,
+    pub object_slug: Option<String>,
+
+    /// Status of the post or page
+    pub object_status: Option<PostsStatus>,
+}
+
+/// Menu item to insert, its parent being inserted first.
+pub struct NewMenuItemModel {
+    pub menu_id: i32,
+    pub parent_id: Option<i32>,
+    pub item_type: MenuItemsType,
+    pub object_id: Option<i32>,
+    pub url: Option<String>,
+    pub title: Option<String>,
+    pub menu_order: i32,
+}
+
+/// Theme location showing a menu.
+#[derive(Debug, Serialize, Deserialize, FromRow, Clone)]
+pub struct MenuLocationModel {
+    pub location: String,
+    pub menu_id: i32,
+}
@@ -1,5 +1,19 @@
+pub mod capabilities_model;
 pub mod categories_model;
+pub mod comments_model;
+pub mod media_model;
+pub mod menus_model;
+pub mod pages_model;
+pub mod post_autosaves_model;
+pub mod post_revisions_model;
 pub mod posts_categories_model;
 pub mod posts_model;
+pub mod posts_tags_model;
+pub mod redirects_model;
+pub mod refresh_tokens_model;
+pub mod roles_model;
+pub mod settings_model;
+pub mod slugs_model;
 pub mod tags_model;
 pub mod users_models;
+pub mod users_roles_model;
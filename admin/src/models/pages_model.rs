@@ -0,0 +1,84 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::{Validate, ValidationError};
+
+use crate::{
+    models::posts_model::PostsStatus, validators::slug_validator::validate_slug,
+};
+
+/// Represents a page, stored in the `posts` table with the `Page` type.
+#[derive(Validate, Serialize, Deserialize, FromRow)]
+pub struct PageModel {
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    // https://www.postgresql.org/docs/8.1/datatype.html#DATATYPE-NUMERIC
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "ID must be between 1 and 2,147,483,647"
+    ))]
+    pub id: Option<i32>,
+
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Title must be between 1 and 500 characters"
+    ))]
+    pub title: String,
+
+    #[validate(length(
+        max = 5000,
+        message = "Content cannot exceed 5000 characters"
+    ))]
+    pub content: String,
+
+    #[validate(custom(function = "validate_slug_page"))]
+    pub slug: Option<String>,
+
+    // https://www.postgresql.org/docs/8.1/datatype.html#DATATYPE-NUMERIC
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "Author ID must be between 1 and 2,147,483,647"
+    ))]
+    pub author_id: i32,
+
+    pub status: PostsStatus,
+
+    pub date_published: Option<NaiveDateTime>,
+
+    // https://www.postgresql.org/docs/8.1/datatype.html#DATATYPE-NUMERIC
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "Featured media ID must be between 1 and 2,147,483,647"
+    ))]
+    pub featured_media_id: Option<i32>,
+
+    // https://www.postgresql.org/docs/8.1/datatype.html#DATATYPE-NUMERIC
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "Parent ID must be between 1 and 2,147,483,647"
+    ))]
+    pub parent_id: Option<i32>,
+
+    pub menu_order: i32,
+
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Template must be between 1 and 100 characters"
+    ))]
+    pub template: Option<String>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+fn validate_slug_page(slug: &str) -> Result<(), ValidationError> {
+    let min_length = 1;
+    let max_length = 200;
+
+    validate_slug(slug, min_length, max_length)
+}
@@ -0,0 +1,14 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Unsaved changes of a user on a post, `post_id` is `None` for a new post.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct PostAutosaveModel {
+    pub id: i32,
+    pub post_id: Option<i32>,
+    pub author_id: i32,
+    pub title: String,
+    pub content: String,
+    pub date_modified: NaiveDateTime,
+}
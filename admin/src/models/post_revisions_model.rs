@@ -0,0 +1,17 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use super::posts_model::PostsStatus;
+
+/// Snapshot of a post, saved each time the post is written.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct PostRevisionModel {
+    pub id: i32,
+    pub post_id: i32,
+    pub author_id: i32,
+    pub title: String,
+    pub content: String,
+    pub status: PostsStatus,
+    pub date_created: NaiveDateTime,
+}
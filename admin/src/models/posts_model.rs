@@ -47,11 +47,38 @@ pub struct PostModel {
 
     pub date_published: Option<NaiveDateTime>,
 
+    // https://www.postgresql.org/docs/8.1/datatype.html#DATATYPE-NUMERIC
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "Featured media ID must be between 1 and 2,147,483,647"
+    ))]
+    pub featured_media_id: Option<i32>,
+
     #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
     pub date_created: Option<NaiveDateTime>,
 
     #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    #[sqlx(default)]
     pub categories: Option<serde_json::Value>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    #[sqlx(default)]
+    pub tags: Option<serde_json::Value>,
+
+    /// Number of approved comments
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    #[sqlx(default)]
+    pub comment_count: Option<i64>,
+}
+
+/// A post matching a full-text search, with its rank and highlighted snippet.
+#[derive(FromRow)]
+pub struct PostSearchModel {
+    #[sqlx(flatten)]
+    pub post: PostModel,
+    pub rank: f32,
+    pub headline: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq, ToSchema)]
@@ -64,6 +91,14 @@ pub enum PostsStatus {
     Published,
 }
 
+/// Discriminates the posts from the pages, both living in the `posts` table.
+#[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq, ToSchema)]
+#[sqlx(type_name = "posts_type")]
+pub enum PostsType {
+    Post,
+    Page,
+}
+
 fn validate_post_status(status: &PostsStatus) -> Result<(), ValidationError> {
     match status {
         PostsStatus::Draft
@@ -0,0 +1,28 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Redirect managed by hand, from a path of the site to a path or a URL.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct RedirectModel {
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    /// Path of the site, e.g. `/old-post`
+    pub source_path: String,
+
+    /// Path of the site or absolute URL
+    pub target: String,
+
+    /// One of `REDIRECT_STATUS_CODES`
+    pub status_code: i32,
+
+    pub hits: i32,
+    pub last_hit: Option<NaiveDateTime>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Status codes a redirect can answer with.
+pub const REDIRECT_STATUS_CODES: &[i32] = &[301, 302, 307, 308];
@@ -0,0 +1,38 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+/// Refresh token issued at login, only its SHA-256 hash is stored.
+#[derive(Validate, Serialize, Deserialize, FromRow)]
+pub struct RefreshTokenModel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    // https://www.postgresql.org/docs/8.1/datatype.html#DATATYPE-NUMERIC
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "ID must be between 1 and 2,147,483,647"
+    ))]
+    pub id: Option<i32>,
+
+    // https://www.postgresql.org/docs/8.1/datatype.html#DATATYPE-NUMERIC
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "User ID must be between 1 and 2,147,483,647"
+    ))]
+    pub user_id: i32,
+
+    #[validate(length(
+        equal = 64,
+        message = "Token hash must be 64 characters"
+    ))]
+    pub token_hash: String,
+
+    pub expires_at: NaiveDateTime,
+
+    pub revoked_at: Option<NaiveDateTime>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<NaiveDateTime>,
+}
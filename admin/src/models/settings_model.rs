@@ -0,0 +1,191 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+/// A setting of the site, stored by name in the `settings` table.
+#[derive(Serialize, Deserialize, FromRow, Clone)]
+pub struct SettingModel {
+    pub name: String,
+    pub value: serde_json::Value,
+    pub date_updated: NaiveDateTime,
+}
+
+/// Group of settings, edited together from the administration.
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingsGroup {
+    General,
+    Reading,
+    Permalinks,
+    Discussion,
+    Appearance,
+}
+
+impl SettingsGroup {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SettingsGroup::General => "general",
+            SettingsGroup::Reading => "reading",
+            SettingsGroup::Permalinks => "permalinks",
+            SettingsGroup::Discussion => "discussion",
+            SettingsGroup::Appearance => "appearance",
+        }
+    }
+}
+
+/// Type of the value of a setting, with its constraints.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingType {
+    Text { min_length: usize, max_length: usize },
+    Integer { min: i64, max: i64 },
+    Boolean,
+    Url,
+    Slug,
+    /// A slug which is not one of `RESERVED_PERMALINK_BASES`
+    PermalinkBase,
+    Choice(&'static [&'static str]),
+}
+
+/// Value of a setting until it is changed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DefaultValue {
+    Text(&'static str),
+    Integer(i64),
+    Boolean(bool),
+}
+
+impl DefaultValue {
+    pub fn to_json(self) -> serde_json::Value {
+        match self {
+            DefaultValue::Text(value) => serde_json::Value::from(value),
+            DefaultValue::Integer(value) => serde_json::Value::from(value),
+            DefaultValue::Boolean(value) => serde_json::Value::from(value),
+        }
+    }
+}
+
+/// A setting known by the API.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SettingDefinition {
+    pub name: &'static str,
+    pub group: SettingsGroup,
+    pub setting_type: SettingType,
+    pub default: DefaultValue,
+}
+
+/// Languages the dates of the public site can be written in.
+pub const LANGUAGES: &[&str] = &["en", "fr", "de", "es"];
+
+/// What the feeds show of a post: its full content or an excerpt.
+pub const FEED_CONTENTS: &[&str] = &["full", "excerpt"];
+
+/// First segments of the URLs of the public site which are not content, and
+/// cannot be used as a permalink base.
+pub const RESERVED_PERMALINK_BASES: &[&str] =
+    &["api", "archives", "pkg", "rs-admin", "themes"];
+
+/// Every setting known by the API, the settings of the `settings` table
+/// which are not listed here being ignored.
+pub const SETTINGS: &[SettingDefinition] = &[
+    SettingDefinition {
+        name: "site_title",
+        group: SettingsGroup::General,
+        setting_type: SettingType::Text {
+            min_length: 1,
+            max_length: 200,
+        },
+        default: DefaultValue::Text("RustPress"),
+    },
+    SettingDefinition {
+        name: "site_description",
+        group: SettingsGroup::General,
+        setting_type: SettingType::Text {
+            min_length: 0,
+            max_length: 500,
+        },
+        default: DefaultValue::Text("A Wordpress clone in Rust!"),
+    },
+    SettingDefinition {
+        name: "site_url",
+        group: SettingsGroup::General,
+        setting_type: SettingType::Url,
+        default: DefaultValue::Text("http://127.0.0.1:3000"),
+    },
+    SettingDefinition {
+        name: "language",
+        group: SettingsGroup::General,
+        setting_type: SettingType::Choice(LANGUAGES),
+        default: DefaultValue::Text("en"),
+    },
+    SettingDefinition {
+        name: "date_format",
+        group: SettingsGroup::General,
+        setting_type: SettingType::Text {
+            min_length: 0,
+            max_length: 100,
+        },
+        default: DefaultValue::Text(""),
+    },
+    SettingDefinition {
+        name: "posts_per_page",
+        group: SettingsGroup::Reading,
+        setting_type: SettingType::Integer { min: 1, max: 100 },
+        default: DefaultValue::Integer(10),
+    },
+    SettingDefinition {
+        name: "feed_items",
+        group: SettingsGroup::Reading,
+        setting_type: SettingType::Integer { min: 1, max: 100 },
+        default: DefaultValue::Integer(10),
+    },
+    SettingDefinition {
+        name: "feed_content",
+        group: SettingsGroup::Reading,
+        setting_type: SettingType::Choice(FEED_CONTENTS),
+        default: DefaultValue::Text("full"),
+    },
+    SettingDefinition {
+        name: "category_base",
+        group: SettingsGroup::Permalinks,
+        setting_type: SettingType::PermalinkBase,
+        default: DefaultValue::Text("category"),
+    },
+    SettingDefinition {
+        name: "tag_base",
+        group: SettingsGroup::Permalinks,
+        setting_type: SettingType::PermalinkBase,
+        default: DefaultValue::Text("tag"),
+    },
+    SettingDefinition {
+        name: "author_base",
+        group: SettingsGroup::Permalinks,
+        setting_type: SettingType::PermalinkBase,
+        default: DefaultValue::Text("author"),
+    },
+    SettingDefinition {
+        name: "comments_enabled",
+        group: SettingsGroup::Discussion,
+        setting_type: SettingType::Boolean,
+        default: DefaultValue::Boolean(true),
+    },
+    SettingDefinition {
+        name: "comment_moderation",
+        group: SettingsGroup::Discussion,
+        setting_type: SettingType::Boolean,
+        default: DefaultValue::Boolean(true),
+    },
+    SettingDefinition {
+        name: "active_theme",
+        group: SettingsGroup::Appearance,
+        setting_type: SettingType::Slug,
+        default: DefaultValue::Text("Default"),
+    },
+];
+
+/// Returns the definition of a setting by its name.
+pub fn find_setting(name: &str) -> Option<&'static SettingDefinition> {
+    SETTINGS.iter().find(|setting| setting.name == name)
+}
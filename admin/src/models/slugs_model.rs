@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::models::capabilities_model::Capability;
+
+/// Types of content whose slugs are unique among the contents of the type,
+/// posts and pages sharing their slugs.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SlugType {
+    Post,
+    Page,
+    Category,
+    Tag,
+}
+
+impl SlugType {
+    /// Name of the contents sharing the slugs of the type.
+    pub fn namespace(&self) -> &'static str {
+        match self {
+            SlugType::Post | SlugType::Page => "post or page",
+            SlugType::Category => "category",
+            SlugType::Tag => "tag",
+        }
+    }
+
+    /// Capability required to edit the contents of the type.
+    pub fn capability(&self) -> Capability {
+        match self {
+            SlugType::Post => Capability::EditPosts,
+            SlugType::Page => Capability::EditPages,
+            SlugType::Category => Capability::ManageCategories,
+            SlugType::Tag => Capability::ManageTags,
+        }
+    }
+}
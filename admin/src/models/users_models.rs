@@ -62,3 +62,13 @@ pub struct UserModel {
     #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
     pub date_created: Option<NaiveDateTime>,
 }
+
+/// Public profile of an author, without the credentials and the email.
+#[derive(Serialize, Deserialize, FromRow, Clone)]
+pub struct AuthorModel {
+    pub id: i32,
+    pub username: String,
+    pub firstname: String,
+    pub lastname: String,
+    pub url: Option<String>,
+}
@@ -0,0 +1,60 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::models::capabilities_model::CapabilityModel;
+
+use super::QueryBuilder;
+
+/// Columns the capabilities can be sorted by.
+const SORTABLE_COLUMNS: &[&str] = &["id", "name"];
+
+/// Retrieves all capabilities from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<Vec<CapabilityModel>>` - A vector containing all `CapabilityModel` records.
+pub async fn select_capabilities(
+    pool: &PgPool,
+) -> Result<Vec<CapabilityModel>> {
+    let result = QueryBuilder::<CapabilityModel>::new(pool)
+        .table("capabilities")
+        .sortable_columns(SORTABLE_COLUMNS)
+        .sort_column("id")
+        .sort_order("ASC")
+        .fields(&["id", "name", "description", "date_created"])
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the names of the capabilities granted to a user by its roles.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+///
+/// # Returns
+///
+/// * `Result<Vec<String>>` - The distinct capability names of the user.
+pub async fn select_capabilities_by_user_id(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<Vec<String>> {
+    let result = sqlx::query_scalar::<_, String>(
+        "SELECT DISTINCT c.name FROM users_roles ur \
+        JOIN roles_capabilities rc ON rc.role_id = ur.role_id \
+        JOIN capabilities c ON c.id = rc.capability_id \
+        WHERE ur.user_id = $1",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
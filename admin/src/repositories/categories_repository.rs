@@ -1,25 +1,32 @@
 use anyhow::Result;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 
-use crate::models::categories_model::CategoryModel;
+use crate::{
+    dtos::category_dto::CategoryFiltersDTO,
+    models::categories_model::{CategoryModel, CategoryNodeModel},
+};
 
-use super::{Bind, QueryBuilder};
+use super::{lock_ancestor_ids, Bind, Condition, QueryBuilder};
+
+/// Columns the categories can be sorted by.
+const SORTABLE_COLUMNS: &[&str] =
+    &["id", "parent_id", "name", "slug", "date_created"];
 
 /// Inserts a new category into the database.
 ///
 /// # Arguments
 ///
-/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `conn` - The connection, usually a transaction, to run the query on.
 /// * `category_model` - The `CategoryModel` instance containing the category data to insert.
 ///
 /// # Returns
 ///
 /// * `Result<CategoryModel>` - The newly inserted `CategoryModel` record.
 pub async fn insert_category(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     category_model: CategoryModel,
 ) -> Result<CategoryModel> {
-    let result = QueryBuilder::<CategoryModel>::new(&pool)
+    let result = QueryBuilder::<CategoryModel>::detached()
         .table("categories")
         .fields(&["parent_id", "name", "slug", "description"])
         .values(vec![
@@ -28,7 +35,7 @@ pub async fn insert_category(
             category_model.slug.map_or(Bind::Null, Bind::Text),
             category_model.description.map_or(Bind::Null, Bind::Text),
         ])
-        .insert()
+        .insert_in(conn)
         .await?;
 
     Ok(result)
@@ -38,7 +45,7 @@ pub async fn insert_category(
 ///
 /// # Arguments
 ///
-/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `conn` - The connection, usually a transaction, to run the query on.
 /// * `id` - The ID of the category to update.
 /// * `model` - The `CategoryModel` instance containing the updated category data.
 ///
@@ -46,11 +53,11 @@ pub async fn insert_category(
 ///
 /// * `Result<CategoryModel>` - The updated `CategoryModel` record.
 pub async fn update_category(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     id: i32,
     category_model: CategoryModel,
 ) -> Result<CategoryModel> {
-    let result = QueryBuilder::<CategoryModel>::new(&pool)
+    let result = QueryBuilder::<CategoryModel>::detached()
         .table("categories")
         .fields(&["parent_id", "name", "slug", "description"])
         .values(vec![
@@ -59,23 +66,25 @@ pub async fn update_category(
             category_model.slug.map_or(Bind::Null, Bind::Text),
             category_model.description.map_or(Bind::Null, Bind::Text),
         ])
-        .update("id", Bind::Int(id))
+        .update_in(conn, "id", Bind::Int(id))
         .await?;
 
     Ok(result)
 }
 
-/// Retrieves all categories from the database.
+/// Retrieves the categories matching the filters from the database.
 ///
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `filters` - The filters the categories must match.
 ///
 /// # Returns
 ///
 /// * `Result<Vec<CategoryModel>>` - A vector containing the retrieved `CategoryModel` records.
 pub async fn select_categories(
     pool: &PgPool,
+    filters: &CategoryFiltersDTO,
     limit: i64,
     offset: i64,
     sort_column: &str,
@@ -85,6 +94,7 @@ pub async fn select_categories(
         .table("categories")
         .limit(limit)
         .offset(offset)
+        .sortable_columns(SORTABLE_COLUMNS)
         .sort_column(sort_column)
         .sort_order(sort_order)
         .fields(&[
@@ -95,6 +105,7 @@ pub async fn select_categories(
             "description",
             "date_created",
         ])
+        .filter(filters_condition(filters))
         .select(None, None)
         .await?;
 
@@ -131,33 +142,198 @@ pub async fn select_category_by_id(
     Ok(result)
 }
 
-/// Deletes categories by their IDs from the database.
+/// Retrieves a category by its slug from the database.
 ///
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `slug` - The slug of the category to retrieve.
+///
+/// # Returns
+///
+/// * `Result<CategoryModel>` - The `CategoryModel` record for the specified slug.
+pub async fn select_category_by_slug(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<CategoryModel> {
+    let result = QueryBuilder::<CategoryModel>::new(pool)
+        .table("categories")
+        .fields(&[
+            "id",
+            "parent_id",
+            "name",
+            "slug",
+            "description",
+            "date_created",
+        ])
+        .select_one("slug", Bind::Text(slug.to_string()))
+        .await?;
+
+    Ok(result)
+}
+
+/// Deletes categories by their IDs from the database.
+///
+/// The sub-categories of a deleted category are moved to its parent, or to
+/// the closest of its ancestors which is not deleted.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the queries on.
 /// * `ids` - A vector containing the IDs of the categories to delete.
 ///
 /// # Returns
 ///
 /// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted categories.
 pub async fn delete_category_by_id(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     ids: Vec<i32>,
 ) -> Result<Vec<i32>> {
-    let result = QueryBuilder::<CategoryModel>::new(pool)
-        .table("categories")
-        .delete("id", ids)
-        .await?;
+    sqlx::query(
+        "WITH RECURSIVE reparented(id, parent_id) AS (\
+            SELECT id, parent_id FROM categories \
+            WHERE parent_id = ANY($1::int[]) AND NOT id = ANY($1::int[]) \
+            UNION \
+            SELECT reparented.id, categories.parent_id FROM reparented \
+            JOIN categories ON categories.id = reparented.parent_id \
+            WHERE reparented.parent_id = ANY($1::int[])\
+        ) \
+        UPDATE categories SET parent_id = reparented.parent_id \
+        FROM reparented \
+        WHERE categories.id = reparented.id \
+        AND (reparented.parent_id IS NULL \
+        OR NOT reparented.parent_id = ANY($1::int[]))",
+    )
+    .bind(&ids)
+    .execute(&mut *conn)
+    .await?;
+
+    let result = sqlx::query_as::<_, (i32,)>(
+        "DELETE FROM categories WHERE id = ANY($1::int[]) RETURNING id",
+    )
+    .bind(&ids)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(result.into_iter().map(|(id,)| id).collect())
+}
+
+/// Retrieves every category, each after its parent and sorted by name among
+/// its siblings, with the number of its posts.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<Vec<CategoryNodeModel>>` - The categories, in the order of the tree.
+pub async fn select_categories_tree(
+    pool: &PgPool,
+) -> Result<Vec<CategoryNodeModel>> {
+    let result = sqlx::query_as::<_, CategoryNodeModel>(
+        "WITH RECURSIVE tree(id, path) AS (\
+            SELECT id, ARRAY[name::text, id::text] FROM categories \
+            WHERE parent_id IS NULL \
+            UNION ALL \
+            SELECT categories.id, \
+            tree.path || categories.name::text || categories.id::text \
+            FROM categories JOIN tree ON categories.parent_id = tree.id\
+        ) \
+        SELECT c.id, c.parent_id, c.name, c.slug, c.description, \
+        c.date_created, \
+        (SELECT COUNT(*) FROM posts_categories pc \
+        WHERE pc.category_id = c.id) AS post_count \
+        FROM tree JOIN categories c ON c.id = tree.id \
+        ORDER BY tree.path",
+    )
+    .fetch_all(pool)
+    .await?;
 
     Ok(result)
 }
 
-pub async fn count_categories(pool: &PgPool) -> Result<i64> {
+/// Retrieves a category and all its ancestors, from the top-level category
+/// down to the category itself.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the category.
+///
+/// # Returns
+///
+/// * `Result<Vec<CategoryModel>>` - The ancestors of the category followed
+///   by the category, empty if the category does not exist.
+pub async fn select_category_ancestors(
+    pool: &PgPool,
+    id: i32,
+) -> Result<Vec<CategoryModel>> {
+    // The path stops the recursion on a category already seen
+    let result = sqlx::query_as::<_, CategoryModel>(
+        "WITH RECURSIVE ancestors(id, parent_id, path) AS (\
+            SELECT id, parent_id, ARRAY[id] FROM categories WHERE id = $1 \
+            UNION ALL \
+            SELECT categories.id, categories.parent_id, \
+            ancestors.path || categories.id \
+            FROM categories \
+            JOIN ancestors ON categories.id = ancestors.parent_id \
+            WHERE NOT categories.id = ANY(ancestors.path)\
+        ) \
+        SELECT c.id, c.parent_id, c.name, c.slug, c.description, \
+        c.date_created \
+        FROM ancestors JOIN categories c ON c.id = ancestors.id \
+        ORDER BY CARDINALITY(ancestors.path) DESC",
+    )
+    .bind(id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the IDs of a category and of all its ancestors, and locks them
+/// along with the category about to become its child until the end of the
+/// transaction.
+///
+/// # Arguments
+///
+/// * `conn` - The transaction to run the queries on and hold the locks.
+/// * `id` - The ID of the child category, if it exists.
+/// * `parent_id` - The ID of the category.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the category and its ancestors, empty
+///   if the category does not exist.
+pub async fn lock_category_ancestor_ids(
+    conn: &mut PgConnection,
+    id: Option<i32>,
+    parent_id: i32,
+) -> Result<Vec<i32>> {
+    lock_ancestor_ids(conn, "categories", "TRUE", id, parent_id).await
+}
+
+pub async fn count_categories(
+    pool: &PgPool,
+    filters: &CategoryFiltersDTO,
+) -> Result<i64> {
     let result = QueryBuilder::<CategoryModel>::new(pool)
         .table("categories")
+        .filter(filters_condition(filters))
         .count()
         .await?;
 
     Ok(result)
 }
+
+/// Builds the condition matching the categories list filters.
+fn filters_condition(filters: &CategoryFiltersDTO) -> Condition {
+    let mut conditions = vec![];
+
+    if let Some(parent_id) = filters.parent_id {
+        conditions.push(Condition::Eq("parent_id", Bind::Int(parent_id)));
+    }
+
+    Condition::And(conditions)
+}
@@ -0,0 +1,212 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::{
+    dtos::comment_dto::CommentFiltersDTO,
+    models::comments_model::{CommentModel, CommentsStatus, NewCommentModel},
+};
+
+use super::{Bind, Condition, QueryBuilder};
+
+/// Columns the comments can be sorted by.
+const SORTABLE_COLUMNS: &[&str] =
+    &["id", "post_id", "author_name", "status", "date_created"];
+
+/// Fields selected when reading comments.
+const COMMENT_FIELDS: &[&str] = &[
+    "id",
+    "post_id",
+    "parent_id",
+    "user_id",
+    "author_name",
+    "author_email",
+    "content",
+    "status",
+    "date_created",
+];
+
+/// Inserts a new comment into the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `comment` - The comment to insert.
+///
+/// # Returns
+///
+/// * `Result<CommentModel>` - The newly inserted `CommentModel` record.
+pub async fn insert_comment(
+    pool: &PgPool,
+    comment: NewCommentModel,
+) -> Result<CommentModel> {
+    let result = QueryBuilder::<CommentModel>::new(pool)
+        .table("comments")
+        .fields(&[
+            "post_id",
+            "parent_id",
+            "user_id",
+            "author_name",
+            "author_email",
+            "content",
+            "status",
+        ])
+        .values(vec![
+            Bind::Int(comment.post_id),
+            comment.parent_id.map_or(Bind::Null, Bind::Int),
+            comment.user_id.map_or(Bind::Null, Bind::Int),
+            Bind::Text(comment.author_name),
+            Bind::Text(comment.author_email),
+            Bind::Text(comment.content),
+            Bind::CommentStatus(comment.status),
+        ])
+        .insert()
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a paginated list of comments from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `filters` - The filters the comments must match.
+/// * `limit` - The maximum number of comments to retrieve.
+/// * `offset` - The number of comments to skip before starting to retrieve the records.
+///
+/// # Returns
+///
+/// * `Result<Vec<CommentModel>>` - A vector containing the retrieved `CommentModel` records.
+pub async fn select_comments(
+    pool: &PgPool,
+    filters: &CommentFiltersDTO,
+    limit: i64,
+    offset: i64,
+    sort_column: &str,
+    sort_order: &str,
+) -> Result<Vec<CommentModel>> {
+    let result = QueryBuilder::<CommentModel>::new(pool)
+        .table("comments")
+        .limit(limit)
+        .offset(offset)
+        .sortable_columns(SORTABLE_COLUMNS)
+        .sort_column(sort_column)
+        .sort_order(sort_order)
+        .fields(COMMENT_FIELDS)
+        .filter(filters_condition(filters))
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a comment by its ID from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the comment to retrieve.
+///
+/// # Returns
+///
+/// * `Result<CommentModel>` - The `CommentModel` record for the specified ID.
+pub async fn select_comment_by_id(
+    pool: &PgPool,
+    id: i32,
+) -> Result<CommentModel> {
+    let result = QueryBuilder::<CommentModel>::new(pool)
+        .table("comments")
+        .fields(COMMENT_FIELDS)
+        .select_one("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Sets the moderation status of comments.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - The IDs of the comments to moderate.
+/// * `status` - The new status of the comments.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the updated comments.
+pub async fn update_comments_status(
+    pool: &PgPool,
+    ids: &[i32],
+    status: CommentsStatus,
+) -> Result<Vec<i32>> {
+    let result = sqlx::query_as::<_, (i32,)>(
+        "UPDATE comments SET status = $1 WHERE id = ANY($2::int[]) \
+        RETURNING id",
+    )
+    .bind(status)
+    .bind(ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result.into_iter().map(|(id,)| id).collect())
+}
+
+/// Deletes comments by their IDs from the database, with their replies.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - A vector containing the IDs of the comments to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted comments.
+pub async fn delete_comments_by_ids(
+    pool: &PgPool,
+    ids: Vec<i32>,
+) -> Result<Vec<i32>> {
+    let result = QueryBuilder::<CommentModel>::new(pool)
+        .table("comments")
+        .delete("id", ids)
+        .await?;
+
+    Ok(result)
+}
+
+/// Counts the comments matching the filters.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `filters` - The filters the comments must match.
+///
+/// # Returns
+///
+/// * `Result<i64>` - The number of matching comments.
+pub async fn count_comments(
+    pool: &PgPool,
+    filters: &CommentFiltersDTO,
+) -> Result<i64> {
+    let result = QueryBuilder::<CommentModel>::new(pool)
+        .table("comments")
+        .filter(filters_condition(filters))
+        .count()
+        .await?;
+
+    Ok(result)
+}
+
+/// Builds the condition matching the filters of the comments list.
+fn filters_condition(filters: &CommentFiltersDTO) -> Condition {
+    let mut conditions = vec![];
+
+    if let Some(ref status) = filters.status {
+        conditions
+            .push(Condition::Eq("status", Bind::CommentStatus(status.clone())));
+    }
+    if let Some(post_id) = filters.post_id {
+        conditions.push(Condition::Eq("post_id", Bind::Int(post_id)));
+    }
+
+    Condition::And(conditions)
+}
@@ -0,0 +1,274 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::models::media_model::{MediaImageModel, MediaModel};
+
+use super::{Bind, Condition, QueryBuilder};
+
+/// Columns the media can be sorted by.
+const SORTABLE_COLUMNS: &[&str] =
+    &["id", "filename", "mime_type", "size", "date_created"];
+
+/// Fields selected when reading media.
+const MEDIA_FIELDS: &[&str] = &[
+    "id",
+    "author_id",
+    "filename",
+    "path",
+    "mime_type",
+    "size",
+    "width",
+    "height",
+    "sizes",
+    "date_created",
+];
+
+/// Inserts a new media into the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `author_id` - The ID of the user uploading the file.
+/// * `filename` - The original name of the file.
+/// * `path` - The key of the file in the storage.
+/// * `mime_type` - The MIME type of the file.
+/// * `size` - The size of the file, in bytes.
+/// * `image` - The dimensions and resized copies of an image, `None` for
+///   other files.
+///
+/// # Returns
+///
+/// * `Result<MediaModel>` - The newly inserted `MediaModel` record.
+pub async fn insert_media(
+    pool: &PgPool,
+    author_id: i32,
+    filename: &str,
+    path: &str,
+    mime_type: &str,
+    size: i64,
+    image: Option<&MediaImageModel>,
+) -> Result<MediaModel> {
+    let result = QueryBuilder::<MediaModel>::new(pool)
+        .table("media")
+        .fields(&[
+            "author_id",
+            "filename",
+            "path",
+            "mime_type",
+            "size",
+            "width",
+            "height",
+            "sizes",
+        ])
+        .values(vec![
+            Bind::Int(author_id),
+            Bind::Text(filename.to_string()),
+            Bind::Text(path.to_string()),
+            Bind::Text(mime_type.to_string()),
+            Bind::BigInt(size),
+            image.map_or(Bind::Null, |image| Bind::Int(image.width)),
+            image.map_or(Bind::Null, |image| Bind::Int(image.height)),
+            Bind::Json(match image {
+                Some(image) => serde_json::to_value(&image.sizes)?,
+                None => serde_json::json!({}),
+            }),
+        ])
+        .insert()
+        .await?;
+
+    Ok(result)
+}
+
+/// Replaces the dimensions and resized copies of an image.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the media.
+/// * `image` - The new dimensions and resized copies.
+///
+/// # Returns
+///
+/// * `Result<MediaModel>` - The updated `MediaModel` record.
+pub async fn update_media_image(
+    pool: &PgPool,
+    id: i32,
+    image: &MediaImageModel,
+) -> Result<MediaModel> {
+    let result = QueryBuilder::<MediaModel>::new(pool)
+        .table("media")
+        .fields(&["width", "height", "sizes"])
+        .values(vec![
+            Bind::Int(image.width),
+            Bind::Int(image.height),
+            Bind::Json(serde_json::to_value(&image.sizes)?),
+        ])
+        .update("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a paginated list of media from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `limit` - The maximum number of media to retrieve.
+/// * `offset` - The number of media to skip.
+/// * `sort_column` - The column to sort by.
+/// * `sort_order` - The sort order, either `asc` or `desc`.
+///
+/// # Returns
+///
+/// * `Result<Vec<MediaModel>>` - A vector containing the `MediaModel` records.
+pub async fn select_media(
+    pool: &PgPool,
+    limit: i64,
+    offset: i64,
+    sort_column: &str,
+    sort_order: &str,
+) -> Result<Vec<MediaModel>> {
+    let result = QueryBuilder::<MediaModel>::new(pool)
+        .table("media")
+        .limit(limit)
+        .offset(offset)
+        .sortable_columns(SORTABLE_COLUMNS)
+        .sort_column(sort_column)
+        .sort_order(sort_order)
+        .fields(MEDIA_FIELDS)
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a media by its ID from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the media to retrieve.
+///
+/// # Returns
+///
+/// * `Result<MediaModel>` - The `MediaModel` record for the specified ID.
+pub async fn select_media_by_id(pool: &PgPool, id: i32) -> Result<MediaModel> {
+    let result = QueryBuilder::<MediaModel>::new(pool)
+        .table("media")
+        .fields(MEDIA_FIELDS)
+        .select_one("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the images of the media library.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `mime_types` - The MIME types of the images.
+/// * `ids` - The IDs of the images to retrieve, all the images when `None`.
+///
+/// # Returns
+///
+/// * `Result<Vec<MediaModel>>` - The matching `MediaModel` records.
+pub async fn select_images(
+    pool: &PgPool,
+    mime_types: &[&str],
+    ids: Option<&[i32]>,
+) -> Result<Vec<MediaModel>> {
+    let mut conditions = vec![Condition::In(
+        "mime_type",
+        mime_types
+            .iter()
+            .map(|mime_type| Bind::Text(mime_type.to_string()))
+            .collect(),
+    )];
+    if let Some(ids) = ids {
+        conditions.push(Condition::In(
+            "id",
+            ids.iter().map(|&id| Bind::Int(id)).collect(),
+        ));
+    }
+
+    let result = QueryBuilder::<MediaModel>::new(pool)
+        .table("media")
+        .fields(MEDIA_FIELDS)
+        .filter(Condition::And(conditions))
+        .sortable_columns(SORTABLE_COLUMNS)
+        .sort_column("id")
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the authors of media, used to check deletion rights.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - The IDs of the media.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The author IDs of the existing media.
+pub async fn select_media_author_ids(
+    pool: &PgPool,
+    ids: &[i32],
+) -> Result<Vec<i32>> {
+    let ids = ids.iter().map(|&id| Bind::Int(id)).collect();
+    let result = QueryBuilder::<(i32,)>::new(pool)
+        .table("media")
+        .fields(&["author_id"])
+        .filter(Condition::In("id", ids))
+        .select(None, None)
+        .await?;
+
+    Ok(result.into_iter().map(|(author_id,)| author_id).collect())
+}
+
+/// Deletes media by their IDs from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - The IDs of the media to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<MediaModel>>` - The deleted media, whose files are left to
+///   remove from the storage.
+pub async fn delete_media_by_ids(
+    pool: &PgPool,
+    ids: &[i32],
+) -> Result<Vec<MediaModel>> {
+    let result = sqlx::query_as::<_, MediaModel>(
+        "DELETE FROM media WHERE id = ANY($1::int[]) RETURNING *",
+    )
+    .bind(ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Counts the total number of media in the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<i64>` - The total number of media.
+pub async fn count_media(pool: &PgPool) -> Result<i64> {
+    let result = QueryBuilder::<MediaModel>::new(pool)
+        .table("media")
+        .count()
+        .await?;
+
+    Ok(result)
+}
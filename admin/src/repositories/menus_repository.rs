@@ -0,0 +1,313 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+use crate::models::menus_model::{
+    MenuItemModel, MenuItemsType, MenuLocationModel, MenuModel,
+    NewMenuItemModel,
+};
+
+use super::{Bind, QueryBuilder};
+
+/// Columns the menus can be sorted by.
+const SORTABLE_COLUMNS: &[&str] = &["id", "name", "slug", "date_created"];
+
+/// Inserts a new menu into the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `menu_model` - The `MenuModel` instance containing the menu data.
+///
+/// # Returns
+///
+/// * `Result<MenuModel>` - The newly inserted `MenuModel` record.
+pub async fn insert_menu(
+    pool: &PgPool,
+    menu_model: MenuModel,
+) -> Result<MenuModel> {
+    let result = QueryBuilder::<MenuModel>::new(pool)
+        .table("menus")
+        .fields(&["name", "slug"])
+        .values(vec![
+            Bind::Text(menu_model.name),
+            menu_model.slug.map_or(Bind::Null, Bind::Text),
+        ])
+        .insert()
+        .await?;
+
+    Ok(result)
+}
+
+/// Updates the name and slug of a menu by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the menu to update.
+/// * `menu_model` - The `MenuModel` instance containing the updated data.
+///
+/// # Returns
+///
+/// * `Result<MenuModel>` - The updated `MenuModel` record.
+pub async fn update_menu(
+    pool: &PgPool,
+    id: i32,
+    menu_model: MenuModel,
+) -> Result<MenuModel> {
+    let result = QueryBuilder::<MenuModel>::new(pool)
+        .table("menus")
+        .fields(&["name", "slug"])
+        .values(vec![
+            Bind::Text(menu_model.name),
+            menu_model.slug.map_or(Bind::Null, Bind::Text),
+        ])
+        .update("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves every menu, sorted by name.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<Vec<MenuModel>>` - A vector containing all `MenuModel` records.
+pub async fn select_menus(pool: &PgPool) -> Result<Vec<MenuModel>> {
+    let result = QueryBuilder::<MenuModel>::new(pool)
+        .table("menus")
+        .sortable_columns(SORTABLE_COLUMNS)
+        .sort_column("name")
+        .sort_order("ASC")
+        .fields(&["id", "name", "slug", "date_created"])
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a menu by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the menu to retrieve.
+///
+/// # Returns
+///
+/// * `Result<MenuModel>` - The `MenuModel` record for the specified ID.
+pub async fn select_menu_by_id(pool: &PgPool, id: i32) -> Result<MenuModel> {
+    let result = QueryBuilder::<MenuModel>::new(pool)
+        .table("menus")
+        .fields(&["id", "name", "slug", "date_created"])
+        .select_one("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Deletes menus by their IDs, along with their items and locations.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - A vector containing the IDs of the menus to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted menus.
+pub async fn delete_menus_by_ids(
+    pool: &PgPool,
+    ids: Vec<i32>,
+) -> Result<Vec<i32>> {
+    let result = QueryBuilder::<MenuModel>::new(pool)
+        .table("menus")
+        .delete("id", ids)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the items of some menus, with the title, slug and status of the
+/// content they link to, ordered by menu and position.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `menu_ids` - The IDs of the menus.
+///
+/// # Returns
+///
+/// * `Result<Vec<MenuItemModel>>` - The items of the menus.
+pub async fn select_menu_items(
+    pool: &PgPool,
+    menu_ids: &[i32],
+) -> Result<Vec<MenuItemModel>> {
+    let result = sqlx::query_as::<_, MenuItemModel>(
+        "SELECT mi.id, mi.menu_id, mi.parent_id, mi.item_type, mi.object_id, \
+        mi.url, mi.title, mi.menu_order, \
+        COALESCE(p.title, c.name, t.name) AS object_title, \
+        COALESCE(p.slug, c.slug, t.slug) AS object_slug, \
+        p.status AS object_status \
+        FROM menu_items mi \
+        LEFT JOIN posts p ON mi.item_type IN ('Post', 'Page') \
+        AND p.id = mi.object_id AND p.post_type::text = mi.item_type::text \
+        LEFT JOIN categories c ON mi.item_type = 'Category' \
+        AND c.id = mi.object_id \
+        LEFT JOIN tags t ON mi.item_type = 'Tag' AND t.id = mi.object_id \
+        WHERE mi.menu_id = ANY($1::int[]) \
+        ORDER BY mi.menu_id, mi.menu_order, mi.id",
+    )
+    .bind(menu_ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Deletes every item of a menu.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `menu_id` - The ID of the menu.
+///
+/// # Returns
+///
+/// * `Result<()>` - Nothing, or an error.
+pub async fn delete_menu_items(
+    conn: &mut PgConnection,
+    menu_id: i32,
+) -> Result<()> {
+    sqlx::query("DELETE FROM menu_items WHERE menu_id = $1")
+        .bind(menu_id)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Inserts an item of a menu.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `menu_item` - The item to insert, its parent being already inserted.
+///
+/// # Returns
+///
+/// * `Result<i32>` - The ID of the inserted item.
+pub async fn insert_menu_item(
+    conn: &mut PgConnection,
+    menu_item: NewMenuItemModel,
+) -> Result<i32> {
+    let (id,): (i32,) = sqlx::query_as(
+        "INSERT INTO menu_items \
+        (menu_id, parent_id, item_type, object_id, url, title, menu_order) \
+        VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING id",
+    )
+    .bind(menu_item.menu_id)
+    .bind(menu_item.parent_id)
+    .bind(menu_item.item_type)
+    .bind(menu_item.object_id)
+    .bind(menu_item.url)
+    .bind(menu_item.title)
+    .bind(menu_item.menu_order)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(id)
+}
+
+/// Retrieves the IDs of some posts, pages, categories or tags which do not
+/// exist.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `item_type` - The type of the content, not `Custom`.
+/// * `ids` - The IDs to check.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs which are unknown.
+pub async fn select_unknown_objects(
+    pool: &PgPool,
+    item_type: MenuItemsType,
+    ids: &[i32],
+) -> Result<Vec<i32>> {
+    let exists = match item_type {
+        MenuItemsType::Post => {
+            "SELECT 1 FROM posts WHERE id = ids.id AND post_type = 'Post'"
+        }
+        MenuItemsType::Page => {
+            "SELECT 1 FROM posts WHERE id = ids.id AND post_type = 'Page'"
+        }
+        MenuItemsType::Category => "SELECT 1 FROM categories WHERE id = ids.id",
+        MenuItemsType::Tag => "SELECT 1 FROM tags WHERE id = ids.id",
+        MenuItemsType::Custom => return Ok(Vec::new()),
+    };
+    let query = format!(
+        "SELECT ids.id FROM UNNEST($1::int[]) AS ids(id) WHERE NOT EXISTS ({})",
+        exists
+    );
+    let rows: Vec<(i32,)> =
+        sqlx::query_as(&query).bind(ids).fetch_all(pool).await?;
+
+    Ok(rows.into_iter().map(|(id,)| id).collect())
+}
+
+/// Retrieves the menu shown at every theme location.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<Vec<MenuLocationModel>>` - The locations which have a menu.
+pub async fn select_menu_locations(
+    pool: &PgPool,
+) -> Result<Vec<MenuLocationModel>> {
+    let result = QueryBuilder::<MenuLocationModel>::new(pool)
+        .table("menu_locations")
+        .fields(&["location", "menu_id"])
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Replaces the menus shown at the theme locations.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `locations` - The menu of every location which has one.
+///
+/// # Returns
+///
+/// * `Result<()>` - Nothing, or an error.
+pub async fn replace_menu_locations(
+    conn: &mut PgConnection,
+    locations: Vec<MenuLocationModel>,
+) -> Result<()> {
+    sqlx::query("DELETE FROM menu_locations")
+        .execute(&mut *conn)
+        .await?;
+
+    for location in locations {
+        sqlx::query(
+            "INSERT INTO menu_locations (location, menu_id) VALUES ($1, $2)",
+        )
+        .bind(location.location)
+        .bind(location.menu_id)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
@@ -1,22 +1,52 @@
+use anyhow::{anyhow, Result};
+use chrono::NaiveDateTime;
 use sqlx::{
     postgres::PgRow,
     query::{Query, QueryAs},
-    query_as, Database, Encode, Error, FromRow, PgPool, Type,
+    query_as, Database, Encode, FromRow, PgConnection, PgPool, Type,
 };
+use validator::{ValidationError, ValidationErrors};
 
+use crate::models::{
+    comments_model::CommentsStatus,
+    posts_model::{PostsStatus, PostsType},
+};
+
+pub mod capabilities_repository;
 pub mod categories_repository;
+pub mod comments_repository;
+pub mod media_repository;
+pub mod menus_repository;
+pub mod pages_repository;
+pub mod post_autosaves_repository;
+pub mod post_revisions_repository;
 pub mod posts_categories_repository;
 pub mod posts_repository;
+pub mod posts_tags_repository;
+pub mod redirects_repository;
+pub mod refresh_tokens_repository;
+pub mod revoked_tokens_repository;
+pub mod roles_repository;
+pub mod settings_repository;
+pub mod slugs_repository;
 pub mod tags_repository;
 pub mod users_repository;
+pub mod users_roles_repository;
 
 /// Enum to represent different types of bindable values for SQL queries
 #[derive(Clone, Debug)]
 enum Bind {
     Int(i32),
+    BigInt(i64),
     Text(String),
     Bool(bool),
+    DateTime(NaiveDateTime),
+    PostStatus(PostsStatus),
+    PostType(PostsType),
+    CommentStatus(CommentsStatus),
+    Json(serde_json::Value),
     Null,
+    NullDateTime,
 }
 
 impl Bind {
@@ -26,15 +56,29 @@ impl Bind {
         DB: Database,
         Q: BindableQuery<'q, DB>,
         i32: Encode<'q, DB> + Type<DB>,
+        i64: Encode<'q, DB> + Type<DB>,
         String: Encode<'q, DB> + Type<DB>,
         bool: Encode<'q, DB> + Type<DB>,
+        NaiveDateTime: Encode<'q, DB> + Type<DB>,
+        PostsStatus: Encode<'q, DB> + Type<DB>,
+        PostsType: Encode<'q, DB> + Type<DB>,
+        CommentsStatus: Encode<'q, DB> + Type<DB>,
+        serde_json::Value: Encode<'q, DB> + Type<DB>,
         Option<i32>: Encode<'q, DB> + Type<DB>,
+        Option<NaiveDateTime>: Encode<'q, DB> + Type<DB>,
     {
         match self {
             Bind::Int(val) => query.bind_value(val),
+            Bind::BigInt(val) => query.bind_value(val),
             Bind::Text(val) => query.bind_value(val),
             Bind::Bool(val) => query.bind_value(val),
+            Bind::DateTime(val) => query.bind_value(val),
+            Bind::PostStatus(val) => query.bind_value(val),
+            Bind::PostType(val) => query.bind_value(val),
+            Bind::CommentStatus(val) => query.bind_value(val),
+            Bind::Json(val) => query.bind_value(val),
             Bind::Null => query.bind_value(None::<i32>),
+            Bind::NullDateTime => query.bind_value(None::<NaiveDateTime>),
         }
     }
 }
@@ -74,14 +118,243 @@ where
     }
 }
 
+/// Typed `WHERE` condition, rendered with positional parameters.
+///
+/// Column names are expected to come from the repositories, never from user
+/// input; values are always bound.
+#[derive(Clone, Debug)]
+enum Condition {
+    /// `column = value`
+    Eq(&'static str, Bind),
+    /// `column IN (values)`, always false when `values` is empty
+    In(&'static str, Vec<Bind>),
+    /// `column IN (SELECT select FROM table WHERE field = value)`, used to
+    /// filter through a join table
+    InSubquery {
+        column: &'static str,
+        table: &'static str,
+        select: &'static str,
+        field: &'static str,
+        value: Bind,
+    },
+    /// `column LIKE pattern`
+    Like(&'static str, String),
+    /// `column >= min AND column <= max`, each bound being optional
+    Range(&'static str, Option<Bind>, Option<Bind>),
+    /// `column IS NULL`
+    IsNull(&'static str),
+    /// `column IS NOT NULL`
+    IsNotNull(&'static str),
+    /// All conditions must match
+    And(Vec<Condition>),
+    /// At least one condition must match
+    Or(Vec<Condition>),
+}
+
+impl Condition {
+    /// Renders the condition, pushing its values to `binds`.
+    ///
+    /// # Arguments
+    /// * `binds` - The values already bound by the query, placeholders are numbered after them.
+    ///
+    /// # Returns
+    /// Returns the SQL expression of the condition.
+    fn to_sql(&self, binds: &mut Vec<Bind>) -> String {
+        let mut placeholder = |bind: &Bind| {
+            binds.push(bind.clone());
+            format!("${}", binds.len())
+        };
+
+        match self {
+            Condition::Eq(column, value) => {
+                format!("{} = {}", column, placeholder(value))
+            }
+            Condition::In(_, values) if values.is_empty() => {
+                "FALSE".to_string()
+            }
+            Condition::In(column, values) => {
+                let placeholders = values
+                    .iter()
+                    .map(placeholder)
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!("{} IN ({})", column, placeholders)
+            }
+            Condition::InSubquery {
+                column,
+                table,
+                select,
+                field,
+                value,
+            } => format!(
+                "{} IN (SELECT {} FROM {} WHERE {} = {})",
+                column,
+                select,
+                table,
+                field,
+                placeholder(value)
+            ),
+            Condition::Like(column, pattern) => format!(
+                "{} LIKE {}",
+                column,
+                placeholder(&Bind::Text(pattern.clone()))
+            ),
+            Condition::Range(column, min, max) => {
+                let mut bounds = vec![];
+                if let Some(min) = min {
+                    bounds.push(format!("{} >= {}", column, placeholder(min)));
+                }
+                if let Some(max) = max {
+                    bounds.push(format!("{} <= {}", column, placeholder(max)));
+                }
+                if bounds.is_empty() {
+                    "TRUE".to_string()
+                } else {
+                    bounds.join(" AND ")
+                }
+            }
+            Condition::IsNull(column) => format!("{} IS NULL", column),
+            Condition::IsNotNull(column) => format!("{} IS NOT NULL", column),
+            Condition::And(conditions) => {
+                Self::join(conditions, " AND ", "TRUE", binds)
+            }
+            Condition::Or(conditions) => {
+                Self::join(conditions, " OR ", "FALSE", binds)
+            }
+        }
+    }
+
+    /// Renders a group of conditions joined by an operator.
+    fn join(
+        conditions: &[Condition],
+        operator: &str,
+        empty: &str,
+        binds: &mut Vec<Bind>,
+    ) -> String {
+        if conditions.is_empty() {
+            return empty.to_string();
+        }
+
+        let parts = conditions
+            .iter()
+            .map(|condition| format!("({})", condition.to_sql(binds)))
+            .collect::<Vec<String>>();
+        parts.join(operator)
+    }
+}
+
+/// Rejects the IDs that do not exist in a table.
+///
+/// # Arguments
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `table` - The table the IDs refer to.
+/// * `field` - The request field holding the IDs, used in the error.
+/// * `ids` - The IDs to check.
+///
+/// # Returns
+/// Returns a `ValidationErrors` on `field` if any ID is unknown.
+async fn reject_unknown_ids(
+    conn: &mut PgConnection,
+    table: &'static str,
+    field: &'static str,
+    ids: &[i32],
+) -> Result<()> {
+    let query = format!(
+        "SELECT COUNT(*) FROM UNNEST($1::int[]) AS ids(id) \
+        WHERE NOT EXISTS (SELECT 1 FROM {} WHERE {}.id = ids.id)",
+        table, table
+    );
+    let (unknown_ids,): (i64,) =
+        query_as(&query).bind(ids).fetch_one(conn).await?;
+
+    if unknown_ids > 0 {
+        let mut error = ValidationError::new("unknown_ids");
+        error.message = Some(format!("Unknown ID in {}", field).into());
+        let mut errors = ValidationErrors::new();
+        errors.add(field, error);
+        return Err(errors.into());
+    }
+
+    Ok(())
+}
+
+/// Retrieves the IDs of a row of a hierarchy and of all its ancestors, and
+/// locks them along with the row about to be moved under it.
+///
+/// The locks are taken in the order of the IDs, and the ancestors are read
+/// again once locked, so concurrent moves wait for each other instead of
+/// both passing the check and creating a cycle.
+///
+/// # Arguments
+/// * `conn` - The transaction to run the queries on and hold the locks.
+/// * `table` - The table of the hierarchy.
+/// * `scope` - The condition the row `parent_id` must match.
+/// * `id` - The ID of the row about to be moved, if it exists.
+/// * `parent_id` - The ID of the row to read the ancestors of.
+///
+/// # Returns
+/// The IDs of `parent_id` and its ancestors, empty if it does not exist.
+async fn lock_ancestor_ids(
+    conn: &mut PgConnection,
+    table: &'static str,
+    scope: &'static str,
+    id: Option<i32>,
+    parent_id: i32,
+) -> Result<Vec<i32>> {
+    // The path stops the recursion on a row already seen
+    let select_query = format!(
+        "WITH RECURSIVE ancestors(id, parent_id, path) AS (\
+            SELECT id, parent_id, ARRAY[id] FROM {table} \
+            WHERE id = $1 AND {scope} \
+            UNION ALL \
+            SELECT {table}.id, {table}.parent_id, \
+            ancestors.path || {table}.id \
+            FROM {table} \
+            JOIN ancestors ON {table}.id = ancestors.parent_id \
+            WHERE NOT {table}.id = ANY(ancestors.path)\
+        ) \
+        SELECT id FROM ancestors ORDER BY CARDINALITY(path)"
+    );
+    let lock_query = format!(
+        "SELECT id FROM {table} WHERE id = ANY($1::int[]) \
+        ORDER BY id FOR UPDATE"
+    );
+
+    let mut ancestor_ids: Vec<(i32,)> = query_as(&select_query)
+        .bind(parent_id)
+        .fetch_all(&mut *conn)
+        .await?;
+    loop {
+        let mut locked_ids: Vec<i32> =
+            ancestor_ids.iter().map(|(id,)| *id).collect();
+        locked_ids.extend(id);
+        let _: Vec<(i32,)> = query_as(&lock_query)
+            .bind(&locked_ids)
+            .fetch_all(&mut *conn)
+            .await?;
+
+        // The hierarchy may have changed before the rows were locked
+        let locked_ancestor_ids: Vec<(i32,)> = query_as(&select_query)
+            .bind(parent_id)
+            .fetch_all(&mut *conn)
+            .await?;
+        if locked_ancestor_ids == ancestor_ids {
+            return Ok(ancestor_ids.into_iter().map(|(id,)| id).collect());
+        }
+        ancestor_ids = locked_ancestor_ids;
+    }
+}
+
 /// Struct to build and execute dynamic SQL queries
 struct QueryBuilder<'a, T> {
-    pool: &'a PgPool,
+    pool: Option<&'a PgPool>,
     table: String,
     fields: Vec<String>,
     values: Vec<Bind>,
+    condition: Option<Condition>,
     limit: Option<i64>,
     offset: Option<i64>,
+    sortable_columns: &'a [&'a str],
     sort_column: Option<String>,
     sort_order: Option<String>,
     query_type: QueryType,
@@ -109,13 +382,29 @@ where
     /// # Returns
     /// Returns a new instance of `QueryBuilder`.
     fn new(pool: &'a PgPool) -> Self {
+        Self::with_pool(Some(pool))
+    }
+
+    /// Initializes a new `QueryBuilder` without connection pool, for queries
+    /// executed on an existing connection (e.g. `insert_in`, `update_in`).
+    ///
+    /// # Returns
+    /// Returns a new instance of `QueryBuilder`.
+    fn detached() -> Self {
+        Self::with_pool(None)
+    }
+
+    /// Initializes a new `QueryBuilder` with an optional connection pool.
+    fn with_pool(pool: Option<&'a PgPool>) -> Self {
         QueryBuilder {
             pool,
             table: String::new(),
             fields: vec![],
             values: vec![],
+            condition: None,
             limit: None,
             offset: None,
+            sortable_columns: &[],
             sort_column: None,
             sort_order: None,
             query_type: QueryType::Select,
@@ -123,6 +412,12 @@ where
         }
     }
 
+    /// Returns the connection pool the query runs on.
+    fn pool(&self) -> Result<&'a PgPool> {
+        self.pool
+            .ok_or_else(|| anyhow!("QueryBuilder has no connection pool"))
+    }
+
     /// Sets the table for the query.
     ///
     /// # Arguments
@@ -159,6 +454,18 @@ where
         self
     }
 
+    /// Restricts a SELECT or COUNT query with a `WHERE` condition.
+    ///
+    /// # Arguments
+    /// * `condition` - The condition rows must match.
+    ///
+    /// # Returns
+    /// Returns the `QueryBuilder` with the condition set.
+    fn filter(mut self, condition: Condition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
     /// Sets a limit on the number of rows returned by the query.
     ///
     /// # Arguments
@@ -183,99 +490,191 @@ where
         self
     }
 
+    /// Sets the columns the query can be sorted by.
+    ///
+    /// # Arguments
+    /// * `columns` - The whitelist of sortable columns of the entity.
+    ///
+    /// # Returns
+    /// Returns the `QueryBuilder` with the sortable columns set.
+    fn sortable_columns(mut self, columns: &'a [&'a str]) -> Self {
+        self.sortable_columns = columns;
+        self
+    }
+
+    /// Sets the column to sort by, checked against the sortable columns.
     fn sort_column(mut self, column: &str) -> Self {
         self.sort_column = Some(column.to_string());
         self
     }
 
-    // Méthode pour définir l'ordre du tri
+    /// Sets the sort order, either `asc` or `desc`.
     fn sort_order(mut self, order: &str) -> Self {
         self.sort_order = Some(order.to_string());
         self
     }
 
-    /// Builds and executes a SELECT query, with the option to return either one or multiple rows.
+    /// Builds the `ORDER BY` clause from the whitelisted sort column and order.
+    ///
+    /// # Returns
+    /// Returns the clause, or a `ValidationErrors` if the column is not
+    /// sortable or the order is neither `asc` nor `desc`.
+    fn order_by_clause(&self) -> Result<Option<String>> {
+        let Some(ref column) = self.sort_column else {
+            return Ok(None);
+        };
+
+        let mut errors = ValidationErrors::new();
+
+        if !self.sortable_columns.contains(&column.as_str()) {
+            let mut error = ValidationError::new("sort_column");
+            error.message = Some(
+                format!(
+                    "Cannot sort by '{}', expected one of: {}",
+                    column,
+                    self.sortable_columns.join(", ")
+                )
+                .into(),
+            );
+            errors.add("sort_column", error);
+        }
+
+        let order = match self.sort_order.as_deref() {
+            None => Some("ASC"),
+            Some(order) if order.eq_ignore_ascii_case("asc") => Some("ASC"),
+            Some(order) if order.eq_ignore_ascii_case("desc") => Some("DESC"),
+            Some(_) => None,
+        };
+        if order.is_none() {
+            let mut error = ValidationError::new("sort_order");
+            error.message =
+                Some("Sort order must be either 'asc' or 'desc'".into());
+            errors.add("sort_order", error);
+        }
+
+        if !errors.is_empty() {
+            return Err(errors.into());
+        }
+
+        Ok(Some(format!(
+            " ORDER BY {} {}",
+            column,
+            order.unwrap_or("ASC")
+        )))
+    }
+
+    /// Builds the SQL of a SELECT query and the values to bind to it.
     ///
     /// # Arguments
     /// * `id_field` - An optional field representing the ID (e.g., `category_id`).
     /// * `id_value` - An optional value of the ID to search for.
-    /// * `single_result` - A boolean indicating whether to expect a single result or multiple results.
     ///
     /// # Returns
-    /// Returns a `Result` containing either a single item (`QueryResult::Single(T)`) or multiple items (`QueryResult::Multiple(Vec<T>)`).
-    async fn select(
-        mut self,
+    /// Returns the query and its values.
+    fn build_select(
+        &self,
         id_field: Option<&str>,
         id_value: Option<&Bind>,
-    ) -> Result<Vec<T>, Error> {
-        self.query_type = QueryType::Select;
+    ) -> Result<(String, Vec<Bind>)> {
+        let mut binds = vec![];
         let mut query =
             format!("SELECT {} FROM {}", self.fields.join(", "), self.table);
 
-        // Add WHERE clause if an ID filter is provided
-        if let Some(id_field) = id_field {
-            query.push_str(&format!(" WHERE {} = $1", id_field));
+        let mut conditions = vec![];
+        if let (Some(id_field), Some(id_value)) = (id_field, id_value) {
+            binds.push(id_value.clone());
+            conditions.push(format!("{} = ${}", id_field, binds.len()));
+        }
+        if let Some(ref condition) = self.condition {
+            conditions.push(format!("({})", condition.to_sql(&mut binds)));
+        }
+        if !conditions.is_empty() {
+            query.push_str(&format!(" WHERE {}", conditions.join(" AND ")));
         }
 
         // Add ORDER if defined
-        if let Some(ref column) = self.sort_column {
-            let order =
-                self.sort_order.clone().unwrap_or_else(|| "ASC".to_string());
-            query.push_str(&format!(" ORDER BY {} {}", column, order));
+        if let Some(order_by) = self.order_by_clause()? {
+            query.push_str(&order_by);
         }
 
         // Add LIMIT if defined
         if let Some(limit) = self.limit {
-            query.push_str(&format!(" LIMIT {}", limit));
+            binds.push(Bind::BigInt(limit));
+            query.push_str(&format!(" LIMIT ${}", binds.len()));
         }
 
         // Add OFFSET if defined
         if let Some(offset) = self.offset {
-            query.push_str(&format!(" OFFSET {}", offset));
+            binds.push(Bind::BigInt(offset));
+            query.push_str(&format!(" OFFSET ${}", binds.len()));
         }
 
-        let mut sql_query = query_as::<_, T>(&query);
+        Ok((query, binds))
+    }
 
-        if let Some(bind_value) = id_value {
-            sql_query = bind_value.clone().bind_to_query(sql_query);
+    /// Builds and executes a SELECT query returning multiple rows.
+    ///
+    /// # Arguments
+    /// * `id_field` - An optional field representing the ID (e.g., `category_id`).
+    /// * `id_value` - An optional value of the ID to search for.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the matching rows.
+    async fn select(
+        mut self,
+        id_field: Option<&str>,
+        id_value: Option<&Bind>,
+    ) -> Result<Vec<T>> {
+        self.query_type = QueryType::Select;
+        let (query, binds) = self.build_select(id_field, id_value)?;
+
+        let mut sql_query = query_as::<_, T>(&query);
+        for bind in binds {
+            sql_query = bind.bind_to_query(sql_query);
         }
 
         // Execute the query and fetch all results
-        let rows = sql_query.fetch_all(self.pool).await?;
+        let rows = sql_query.fetch_all(self.pool()?).await?;
         Ok(rows)
     }
 
     /// Builds and executes a SELECT query, returning a single row.
-    async fn select_one(
-        mut self,
-        field: &str,
-        value: Bind,
-    ) -> Result<T, Error> {
+    async fn select_one(mut self, field: &str, value: Bind) -> Result<T> {
         self.query_type = QueryType::Select;
-        let query = format!(
-            "SELECT {} FROM {} WHERE {} = $1 LIMIT 1",
-            self.fields.join(", "),
-            self.table,
-            field
-        );
+        self.limit = Some(1);
+        let (query, binds) = self.build_select(Some(field), Some(&value))?;
 
         let mut sql_query = query_as::<_, T>(&query);
+        for bind in binds {
+            sql_query = bind.bind_to_query(sql_query);
+        }
 
-        sql_query = value.bind_to_query(sql_query);
+        let row = sql_query.fetch_one(self.pool()?).await?;
+        Ok(row)
+    }
 
-        let row = sql_query.fetch_one(self.pool).await?;
+    /// Builds and executes an INSERT query.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the inserted row, or an error.
+    async fn insert(self) -> Result<T> {
+        let mut tx = self.pool()?.begin().await?;
+        let result = self.insert_in(&mut tx).await?;
+        tx.commit().await?;
 
-        let test = row;
-        Ok(test)
+        Ok(result)
     }
 
-    /// Builds and executes an INSERT query.
+    /// Builds and executes an INSERT query on an existing connection, so it
+    /// can take part in a wider transaction.
+    ///
+    /// # Arguments
+    /// * `conn` - The connection, usually a transaction, to run the query on.
     ///
     /// # Returns
     /// Returns a `Result` containing the inserted row, or an error.
-    async fn insert(mut self) -> Result<T, Error> {
+    async fn insert_in(mut self, conn: &mut PgConnection) -> Result<T> {
         self.query_type = QueryType::Insert;
-        let mut tx = self.pool.begin().await?;
 
         let fields_str = self.fields.join(", ");
         let placeholders_str = (1..=self.values.len())
@@ -294,24 +693,44 @@ where
             sql_query = value.bind_to_query(sql_query);
         }
 
-        let result = sql_query.fetch_one(&mut *tx).await?;
+        let result = sql_query.fetch_one(conn).await?;
 
+        Ok(result)
+    }
+
+    /// Builds and executes an UPDATE query based on a condition.
+    ///
+    /// # Arguments
+    /// * `field` - The field to apply the condition to (e.g., `id`).
+    /// * `value` - The value to bind to the condition.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the updated row, or an error.
+    async fn update(self, field: &str, value: Bind) -> Result<T> {
+        let mut tx = self.pool()?.begin().await?;
+        let result = self.update_in(&mut tx, field, value).await?;
         tx.commit().await?;
 
         Ok(result)
     }
 
-    /// Builds and executes an UPDATE query based on a condition.
+    /// Builds and executes an UPDATE query on an existing connection, so it
+    /// can take part in a wider transaction.
     ///
     /// # Arguments
+    /// * `conn` - The connection, usually a transaction, to run the query on.
     /// * `field` - The field to apply the condition to (e.g., `id`).
     /// * `value` - The value to bind to the condition.
     ///
     /// # Returns
-    /// Returns a `Result` containing the number of rows affected, or an error.
-    async fn update(mut self, field: &str, value: Bind) -> Result<T, Error> {
+    /// Returns a `Result` containing the updated row, or an error.
+    async fn update_in(
+        mut self,
+        conn: &mut PgConnection,
+        field: &str,
+        value: Bind,
+    ) -> Result<T> {
         self.query_type = QueryType::Update;
-        let mut tx = self.pool.begin().await?;
 
         let update_fields_str = self
             .fields
@@ -321,34 +740,23 @@ where
             .collect::<Vec<String>>()
             .join(", ");
 
-        let query = match value {
-            Bind::Int(val) => format!(
-                "UPDATE {} SET {} WHERE {} = {} RETURNING *;",
-                self.table, update_fields_str, field, val
-            ),
-            Bind::Text(ref val) => format!(
-                "UPDATE {} SET {} WHERE {} = '{}' RETURNING *;",
-                self.table, update_fields_str, field, val
-            ),
-            Bind::Bool(val) => format!(
-                "UPDATE {} SET {} WHERE {} = {} RETURNING *;",
-                self.table, update_fields_str, field, val
-            ),
-            Bind::Null => {
-                return Err(Error::RowNotFound);
-            }
-        };
+        let query = format!(
+            "UPDATE {} SET {} WHERE {} = ${} RETURNING *",
+            self.table,
+            update_fields_str,
+            field,
+            self.values.len() + 1
+        );
+
         let mut sql_query = query_as::<_, T>(&query);
 
-        for value in &self.values {
-            sql_query = value.clone().bind_to_query(sql_query);
+        for value in self.values {
+            sql_query = value.bind_to_query(sql_query);
         }
 
         sql_query = value.bind_to_query(sql_query);
 
-        let result = sql_query.fetch_one(&mut *tx).await?;
-
-        tx.commit().await?;
+        let result = sql_query.fetch_one(conn).await?;
 
         Ok(result)
     }
@@ -361,13 +769,9 @@ where
     ///
     /// # Returns
     /// Returns a `Result` containing the list of deleted IDs.
-    async fn delete(
-        mut self,
-        column: &str,
-        ids: Vec<i32>,
-    ) -> Result<Vec<i32>, Error> {
+    async fn delete(mut self, column: &str, ids: Vec<i32>) -> Result<Vec<i32>> {
         self.query_type = QueryType::Delete;
-        let mut tx = self.pool.begin().await?;
+        let mut tx = self.pool()?.begin().await?;
 
         let query = format!(
             "DELETE FROM {} WHERE {} = ANY($1::int[]) RETURNING {}",
@@ -384,15 +788,179 @@ where
         Ok(deleted_ids)
     }
 
-    /// Builds and executes a COUNT query to count the number of rows.
+    /// Builds and executes a COUNT query to count the number of rows
+    /// matching the condition, if any.
     ///
     /// # Returns
     /// Returns a `Result` containing the count of rows.
-    async fn count(self) -> Result<i64, Error> {
-        let query = format!("SELECT COUNT(*) FROM {}", self.table);
+    async fn count(self) -> Result<i64> {
+        let mut binds = vec![];
+        let mut query = format!("SELECT COUNT(*) FROM {}", self.table);
+        if let Some(ref condition) = self.condition {
+            query.push_str(&format!(" WHERE {}", condition.to_sql(&mut binds)));
+        }
+
+        let mut sql_query = sqlx::query_as::<_, (i64,)>(&query);
+        for bind in binds {
+            sql_query = bind.bind_to_query(sql_query);
+        }
 
-        let row: (i64,) = sqlx::query_as(&query).fetch_one(self.pool).await?;
+        let row = sql_query.fetch_one(self.pool()?).await?;
 
         Ok(row.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use validator::ValidationErrors;
+
+    use super::*;
+
+    const SORTABLE_COLUMNS: &[&str] = &["id", "name"];
+
+    fn lazy_pool() -> PgPool {
+        PgPool::connect_lazy("postgres://localhost/unused")
+            .expect("Failed to create lazy pool")
+    }
+
+    #[ntex::test]
+    async fn test_build_select_binds_every_value() {
+        // Arrange
+        let pool = lazy_pool();
+        let builder = QueryBuilder::<(i32,)>::new(&pool)
+            .table("posts")
+            .fields(&["id"])
+            .filter(Condition::And(vec![
+                Condition::Eq("status", Bind::Text("Draft".to_string())),
+                Condition::Or(vec![
+                    Condition::In(
+                        "author_id",
+                        vec![Bind::Int(1), Bind::Int(2)],
+                    ),
+                    Condition::IsNull("date_published"),
+                ]),
+                Condition::Range("id", Some(Bind::Int(10)), None),
+                Condition::Like("title", "%rust%".to_string()),
+            ]))
+            .limit(25)
+            .offset(50);
+
+        // Act
+        let (query, binds) = builder
+            .build_select(Some("parent_id"), Some(&Bind::Int(3)))
+            .expect("Failed to build query");
+
+        // Assert
+        assert_eq!(
+            query,
+            "SELECT id FROM posts WHERE parent_id = $1 AND \
+            ((status = $2) AND ((author_id IN ($3, $4)) OR \
+            (date_published IS NULL)) AND (id >= $5) AND (title LIKE $6)) \
+            LIMIT $7 OFFSET $8"
+        );
+        assert_eq!(binds.len(), 8);
+    }
+
+    #[test]
+    fn test_condition_in_empty_matches_nothing() {
+        // Arrange
+        let mut binds = vec![];
+
+        // Act
+        let sql = Condition::In("id", vec![]).to_sql(&mut binds);
+
+        // Assert
+        assert_eq!(sql, "FALSE");
+        assert!(binds.is_empty());
+    }
+
+    #[test]
+    fn test_condition_in_subquery_binds_value() {
+        // Arrange
+        let mut binds = vec![Bind::Int(1)];
+
+        // Act
+        let sql = Condition::InSubquery {
+            column: "id",
+            table: "posts_tags",
+            select: "post_id",
+            field: "tag_id",
+            value: Bind::Int(7),
+        }
+        .to_sql(&mut binds);
+
+        // Assert
+        assert_eq!(
+            sql,
+            "id IN (SELECT post_id FROM posts_tags WHERE tag_id = $2)"
+        );
+        assert_eq!(binds.len(), 2);
+    }
+
+    #[ntex::test]
+    async fn test_build_select_whitelisted_sort() {
+        // Arrange
+        let pool = lazy_pool();
+        let builder = QueryBuilder::<(i32,)>::new(&pool)
+            .table("tags")
+            .fields(&["id"])
+            .sortable_columns(SORTABLE_COLUMNS)
+            .sort_column("name")
+            .sort_order("desc");
+
+        // Act
+        let (query, _) = builder
+            .build_select(None, None)
+            .expect("Failed to build query");
+
+        // Assert
+        assert_eq!(query, "SELECT id FROM tags ORDER BY name DESC");
+    }
+
+    #[ntex::test]
+    async fn test_build_select_rejects_unknown_sort_column() {
+        // Arrange
+        let pool = lazy_pool();
+        let builder = QueryBuilder::<(i32,)>::new(&pool)
+            .table("tags")
+            .fields(&["id"])
+            .sortable_columns(SORTABLE_COLUMNS)
+            .sort_column("id; DROP TABLE tags")
+            .sort_order("asc");
+
+        // Act
+        let error = builder
+            .build_select(None, None)
+            .expect_err("Unknown sort column must be rejected");
+
+        // Assert
+        let errors = error
+            .downcast_ref::<ValidationErrors>()
+            .expect("Expected a validation error");
+        assert!(errors.field_errors().contains_key("sort_column"));
+    }
+
+    #[ntex::test]
+    async fn test_build_select_rejects_invalid_sort_order() {
+        // Arrange
+        let pool = lazy_pool();
+        let builder = QueryBuilder::<(i32,)>::new(&pool)
+            .table("tags")
+            .fields(&["id"])
+            .sortable_columns(SORTABLE_COLUMNS)
+            .sort_column("id")
+            .sort_order("asc, (SELECT 1)");
+
+        // Act
+        let error = builder
+            .build_select(None, None)
+            .expect_err("Invalid sort order must be rejected");
+
+        // Assert
+        let errors = error
+            .downcast_ref::<ValidationErrors>()
+            .expect("Expected a validation error");
+        assert!(errors.field_errors().contains_key("sort_order"));
+    }
+}
@@ -0,0 +1,326 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+use crate::{
+    dtos::page_dto::PageFiltersDTO,
+    models::{
+        pages_model::PageModel,
+        posts_model::{PostsStatus, PostsType},
+    },
+};
+
+use super::{
+    lock_ancestor_ids, reject_unknown_ids, Bind, Condition, QueryBuilder,
+};
+
+/// Columns the pages can be sorted by.
+const SORTABLE_COLUMNS: &[&str] = &[
+    "id",
+    "title",
+    "slug",
+    "author_id",
+    "status",
+    "parent_id",
+    "menu_order",
+    "date_published",
+    "date_created",
+];
+
+/// Fields selected when reading pages.
+const PAGE_FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "content",
+    "slug",
+    "author_id",
+    "status",
+    "date_published",
+    "featured_media_id",
+    "parent_id",
+    "menu_order",
+    "template",
+    "date_created",
+];
+
+/// Fields written when inserting or updating a page.
+const PAGE_WRITE_FIELDS: &[&str] = &[
+    "title",
+    "content",
+    "slug",
+    "author_id",
+    "status",
+    "date_published",
+    "featured_media_id",
+    "parent_id",
+    "menu_order",
+    "template",
+];
+
+/// Inserts a new page into the database.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `page_model` - The `PageModel` instance containing the page data to insert.
+///
+/// # Returns
+///
+/// * `Result<PageModel>` - The newly inserted `PageModel` record.
+pub async fn insert_page(
+    conn: &mut PgConnection,
+    page_model: PageModel,
+) -> Result<PageModel> {
+    reject_unknown_media(conn, &page_model).await?;
+
+    let mut fields = PAGE_WRITE_FIELDS.to_vec();
+    fields.push("post_type");
+    let mut values = page_values(page_model);
+    values.push(Bind::PostType(PostsType::Page));
+
+    let result = QueryBuilder::<PageModel>::detached()
+        .table("posts")
+        .fields(&fields)
+        .values(values)
+        .insert_in(conn)
+        .await?;
+
+    Ok(result)
+}
+
+/// Updates an existing page in the database by its ID.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `id` - The ID of the page to update.
+/// * `page_model` - The `PageModel` instance containing the updated page data.
+///
+/// # Returns
+///
+/// * `Result<PageModel>` - The updated `PageModel` record.
+pub async fn update_page(
+    conn: &mut PgConnection,
+    id: i32,
+    page_model: PageModel,
+) -> Result<PageModel> {
+    reject_unknown_media(conn, &page_model).await?;
+
+    let result = QueryBuilder::<PageModel>::detached()
+        .table("posts")
+        .fields(PAGE_WRITE_FIELDS)
+        .values(page_values(page_model))
+        .update_in(conn, "id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a paginated list of pages from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `filters` - The filters the pages must match.
+/// * `limit` - The maximum number of pages to retrieve.
+/// * `offset` - The number of pages to skip before starting to retrieve the records.
+///
+/// # Returns
+///
+/// * `Result<Vec<PageModel>>` - A vector containing the retrieved `PageModel` records.
+pub async fn select_pages(
+    pool: &PgPool,
+    filters: &PageFiltersDTO,
+    limit: i64,
+    offset: i64,
+    sort_column: &str,
+    sort_order: &str,
+) -> Result<Vec<PageModel>> {
+    let result = QueryBuilder::<PageModel>::new(pool)
+        .table("posts")
+        .limit(limit)
+        .offset(offset)
+        .sortable_columns(SORTABLE_COLUMNS)
+        .sort_column(sort_column)
+        .sort_order(sort_order)
+        .fields(PAGE_FIELDS)
+        .filter(filters_condition(filters))
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a page by its ID from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the page to retrieve.
+///
+/// # Returns
+///
+/// * `Result<PageModel>` - The `PageModel` record for the specified ID.
+pub async fn select_page_by_id(pool: &PgPool, id: i32) -> Result<PageModel> {
+    let result = QueryBuilder::<PageModel>::new(pool)
+        .table("posts")
+        .fields(PAGE_FIELDS)
+        .filter(page_type_condition())
+        .select_one("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a published page by its slug from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `slug` - The slug of the page to retrieve.
+///
+/// # Returns
+///
+/// * `Result<PageModel>` - The published `PageModel` record for the specified slug.
+pub async fn select_published_page_by_slug(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<PageModel> {
+    let result = QueryBuilder::<PageModel>::new(pool)
+        .table("posts")
+        .fields(PAGE_FIELDS)
+        .filter(Condition::And(vec![
+            page_type_condition(),
+            Condition::Eq("status", Bind::PostStatus(PostsStatus::Published)),
+        ]))
+        .select_one("slug", Bind::Text(slug.to_string()))
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the IDs of a page and of all its ancestors, and locks them
+/// along with the page about to become its child until the end of the
+/// transaction.
+///
+/// # Arguments
+///
+/// * `conn` - The transaction to run the queries on and hold the locks.
+/// * `id` - The ID of the child page, if it exists.
+/// * `parent_id` - The ID of the page.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the page and its ancestors, empty if the page does not exist.
+pub async fn lock_page_ancestor_ids(
+    conn: &mut PgConnection,
+    id: Option<i32>,
+    parent_id: i32,
+) -> Result<Vec<i32>> {
+    lock_ancestor_ids(conn, "posts", "post_type = 'Page'", id, parent_id).await
+}
+
+/// Deletes pages by their IDs from the database, leaving the posts untouched.
+///
+/// The children of a deleted page become top-level pages.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - A vector containing the IDs of the pages to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted pages.
+pub async fn delete_pages_by_ids(
+    pool: &PgPool,
+    ids: Vec<i32>,
+) -> Result<Vec<i32>> {
+    let result = sqlx::query_as::<_, (i32,)>(
+        "DELETE FROM posts WHERE id = ANY($1::int[]) AND post_type = 'Page' \
+        RETURNING id",
+    )
+    .bind(ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result.into_iter().map(|(id,)| id).collect())
+}
+
+/// Counts the pages matching the filters.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `filters` - The filters the pages must match.
+///
+/// # Returns
+///
+/// * `Result<i64>` - The number of matching pages.
+pub async fn count_pages(
+    pool: &PgPool,
+    filters: &PageFiltersDTO,
+) -> Result<i64> {
+    let result = QueryBuilder::<PageModel>::new(pool)
+        .table("posts")
+        .filter(filters_condition(filters))
+        .count()
+        .await?;
+
+    Ok(result)
+}
+
+/// Builds the condition matching the pages, and not the posts.
+fn page_type_condition() -> Condition {
+    Condition::Eq("post_type", Bind::PostType(PostsType::Page))
+}
+
+/// Builds the condition matching the filters of the pages list.
+fn filters_condition(filters: &PageFiltersDTO) -> Condition {
+    let mut conditions = vec![page_type_condition()];
+
+    if let Some(ref status) = filters.status {
+        conditions
+            .push(Condition::Eq("status", Bind::PostStatus(status.clone())));
+    }
+    if let Some(parent_id) = filters.parent_id {
+        conditions.push(Condition::Eq("parent_id", Bind::Int(parent_id)));
+    }
+
+    Condition::And(conditions)
+}
+
+/// Rejects a featured media that does not exist.
+async fn reject_unknown_media(
+    conn: &mut PgConnection,
+    page_model: &PageModel,
+) -> Result<()> {
+    if let Some(featured_media_id) = page_model.featured_media_id {
+        reject_unknown_ids(
+            conn,
+            "media",
+            "featured_media_id",
+            &[featured_media_id],
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Builds the values bound to `PAGE_WRITE_FIELDS`.
+fn page_values(page_model: PageModel) -> Vec<Bind> {
+    vec![
+        Bind::Text(page_model.title),
+        Bind::Text(page_model.content),
+        page_model.slug.map_or(Bind::Null, Bind::Text),
+        Bind::Int(page_model.author_id),
+        Bind::PostStatus(page_model.status),
+        page_model
+            .date_published
+            .map_or(Bind::NullDateTime, Bind::DateTime),
+        page_model.featured_media_id.map_or(Bind::Null, Bind::Int),
+        page_model.parent_id.map_or(Bind::Null, Bind::Int),
+        Bind::Int(page_model.menu_order),
+        page_model.template.map_or(Bind::Null, Bind::Text),
+    ]
+}
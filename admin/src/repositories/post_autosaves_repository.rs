@@ -0,0 +1,104 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+use crate::models::post_autosaves_model::PostAutosaveModel;
+
+/// Inserts or replaces the autosave of a user on a post.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `author_id` - The ID of the user editing the post.
+/// * `post_id` - The ID of the post, `None` for a new post.
+/// * `title` - The unsaved title.
+/// * `content` - The unsaved content.
+///
+/// # Returns
+///
+/// * `Result<PostAutosaveModel>` - The stored autosave.
+pub async fn upsert_post_autosave(
+    pool: &PgPool,
+    author_id: i32,
+    post_id: Option<i32>,
+    title: &str,
+    content: &str,
+) -> Result<PostAutosaveModel> {
+    let result = sqlx::query_as::<_, PostAutosaveModel>(
+        "INSERT INTO post_autosaves (author_id, post_id, title, content) \
+        VALUES ($1, $2, $3, $4) \
+        ON CONFLICT (author_id, (COALESCE(post_id, 0))) DO UPDATE \
+        SET title = EXCLUDED.title, content = EXCLUDED.content, \
+        date_modified = CURRENT_TIMESTAMP \
+        RETURNING *",
+    )
+    .bind(author_id)
+    .bind(post_id)
+    .bind(title)
+    .bind(content)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the autosave of a user on a post, if it is newer than the last
+/// saved revision of the post.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `author_id` - The ID of the user editing the post.
+/// * `post_id` - The ID of the post, `None` for a new post.
+///
+/// # Returns
+///
+/// * `Result<PostAutosaveModel>` - The autosave, `RowNotFound` if there is
+///   none or if the post was saved since.
+pub async fn select_post_autosave(
+    pool: &PgPool,
+    author_id: i32,
+    post_id: Option<i32>,
+) -> Result<PostAutosaveModel> {
+    let result = sqlx::query_as::<_, PostAutosaveModel>(
+        "SELECT a.* FROM post_autosaves a \
+        WHERE a.author_id = $1 AND a.post_id IS NOT DISTINCT FROM $2 \
+        AND a.date_modified > COALESCE(( \
+            SELECT MAX(r.date_created) FROM post_revisions r \
+            WHERE r.post_id = a.post_id \
+        ), '-infinity')",
+    )
+    .bind(author_id)
+    .bind(post_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Deletes the autosave of a user on a post.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `author_id` - The ID of the user editing the post.
+/// * `post_id` - The ID of the post, `None` for a new post.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The number of deleted autosaves.
+pub async fn delete_post_autosave(
+    conn: &mut PgConnection,
+    author_id: i32,
+    post_id: Option<i32>,
+) -> Result<u64> {
+    let result = sqlx::query(
+        "DELETE FROM post_autosaves \
+        WHERE author_id = $1 AND post_id IS NOT DISTINCT FROM $2",
+    )
+    .bind(author_id)
+    .bind(post_id)
+    .execute(conn)
+    .await?;
+
+    Ok(result.rows_affected())
+}
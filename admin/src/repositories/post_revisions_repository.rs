@@ -0,0 +1,135 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+use crate::models::post_revisions_model::PostRevisionModel;
+
+use super::{Bind, Condition, QueryBuilder};
+
+/// Fields selected when reading revisions.
+const POST_REVISION_FIELDS: &[&str] = &[
+    "id",
+    "post_id",
+    "author_id",
+    "title",
+    "content",
+    "status",
+    "date_created",
+];
+
+/// Saves the current state of a post as a new revision.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `post_id` - The ID of the post.
+/// * `author_id` - The ID of the user who wrote the post.
+///
+/// # Returns
+///
+/// * `Result<PostRevisionModel>` - The inserted revision.
+pub async fn insert_post_revision(
+    conn: &mut PgConnection,
+    post_id: i32,
+    author_id: i32,
+) -> Result<PostRevisionModel> {
+    let result = sqlx::query_as::<_, PostRevisionModel>(
+        "INSERT INTO post_revisions \
+        (post_id, author_id, title, content, status) \
+        SELECT id, $2, title, content, status FROM posts WHERE id = $1 \
+        RETURNING *",
+    )
+    .bind(post_id)
+    .bind(author_id)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the revisions of a post, latest first.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `post_id` - The ID of the post.
+///
+/// # Returns
+///
+/// * `Result<Vec<PostRevisionModel>>` - The revisions of the post.
+pub async fn select_post_revisions(
+    pool: &PgPool,
+    post_id: i32,
+) -> Result<Vec<PostRevisionModel>> {
+    let result = QueryBuilder::<PostRevisionModel>::new(pool)
+        .table("post_revisions")
+        .fields(POST_REVISION_FIELDS)
+        .sortable_columns(&["id"])
+        .sort_column("id")
+        .sort_order("desc")
+        .filter(Condition::Eq("post_id", Bind::Int(post_id)))
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a revision of a post.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `post_id` - The ID of the post.
+/// * `id` - The ID of the revision.
+///
+/// # Returns
+///
+/// * `Result<PostRevisionModel>` - The revision, `RowNotFound` if it does
+///   not belong to the post.
+pub async fn select_post_revision_by_id(
+    pool: &PgPool,
+    post_id: i32,
+    id: i32,
+) -> Result<PostRevisionModel> {
+    let result = QueryBuilder::<PostRevisionModel>::new(pool)
+        .table("post_revisions")
+        .fields(POST_REVISION_FIELDS)
+        .filter(Condition::Eq("post_id", Bind::Int(post_id)))
+        .select_one("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Restores the title and the content of a post from one of its revisions.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `post_id` - The ID of the post.
+/// * `id` - The ID of the revision to restore.
+///
+/// # Returns
+///
+/// * `Result<()>` - `RowNotFound` if the revision does not belong to the
+///   post.
+pub async fn restore_post_revision(
+    conn: &mut PgConnection,
+    post_id: i32,
+    id: i32,
+) -> Result<()> {
+    let result = sqlx::query(
+        "UPDATE posts SET title = r.title, content = r.content \
+        FROM post_revisions r \
+        WHERE posts.id = $1 AND r.id = $2 AND r.post_id = posts.id",
+    )
+    .bind(post_id)
+    .bind(id)
+    .execute(conn)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound.into());
+    }
+
+    Ok(())
+}
@@ -1,9 +1,9 @@
 use anyhow::Result;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 
 use crate::models::posts_categories_model::PostsCategoriesModel;
 
-use super::{Bind, QueryBuilder};
+use super::{reject_unknown_ids, Bind, QueryBuilder};
 
 pub async fn insert_post_category(
     pool: &PgPool,
@@ -34,6 +34,47 @@ pub async fn update_post_category(
     Ok(result)
 }
 
+/// Replaces the categories of a post.
+///
+/// The current categories are removed and the given ones inserted, duplicated
+/// IDs are ignored. Unknown categories are rejected with a validation error on
+/// `categories_ids`.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the queries on.
+/// * `post_id` - The ID of the post.
+/// * `categories_ids` - The IDs of the categories the post must have.
+///
+/// # Returns
+///
+/// * `Result<Vec<PostsCategoriesModel>>` - The categories relationships of the post.
+pub async fn replace_post_categories(
+    conn: &mut PgConnection,
+    post_id: i32,
+    categories_ids: &[i32],
+) -> Result<Vec<PostsCategoriesModel>> {
+    reject_unknown_ids(conn, "categories", "categories_ids", categories_ids)
+        .await?;
+
+    sqlx::query("DELETE FROM posts_categories WHERE post_id = $1")
+        .bind(post_id)
+        .execute(&mut *conn)
+        .await?;
+
+    let result = sqlx::query_as::<_, PostsCategoriesModel>(
+        "INSERT INTO posts_categories (post_id, category_id) \
+        SELECT $1, id FROM (SELECT DISTINCT UNNEST($2::int[]) AS id) ids \
+        RETURNING *",
+    )
+    .bind(post_id)
+    .bind(categories_ids)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(result)
+}
+
 pub async fn select_posts_categories(
     pool: &PgPool,
 ) -> Result<Vec<PostsCategoriesModel>> {
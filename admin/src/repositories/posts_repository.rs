@@ -1,25 +1,89 @@
 use anyhow::Result;
-use sqlx::PgPool;
+use chrono::NaiveDateTime;
+use sqlx::{PgConnection, PgPool};
 
-use crate::models::posts_model::PostModel;
+use crate::{
+    dtos::post_dto::PostFiltersDTO,
+    models::posts_model::{PostModel, PostSearchModel, PostsStatus, PostsType},
+};
 
-use super::{Bind, QueryBuilder};
+use super::{reject_unknown_ids, Bind, Condition, QueryBuilder};
+
+/// Columns the posts can be sorted by.
+const SORTABLE_COLUMNS: &[&str] = &[
+    "id",
+    "title",
+    "slug",
+    "author_id",
+    "status",
+    "date_published",
+    "date_created",
+];
+
+/// Categories of a post, aggregated as a JSON array.
+const POST_CATEGORIES_FIELD: &str =
+    "(SELECT COALESCE(json_agg(json_build_object(\
+    'id', c.id, 'name', c.name, 'slug', c.slug, \
+    'description', c.description) \
+    ORDER BY c.name), '[]') \
+    FROM posts_categories pc \
+    JOIN categories c ON c.id = pc.category_id \
+    WHERE pc.post_id = posts.id) AS categories";
+
+/// Tags of a post, aggregated as a JSON array.
+const POST_TAGS_FIELD: &str = "(SELECT COALESCE(json_agg(json_build_object(\
+    'id', t.id, 'name', t.name, 'slug', t.slug) \
+    ORDER BY t.name), '[]') \
+    FROM posts_tags pt \
+    JOIN tags t ON t.id = pt.tag_id \
+    WHERE pt.post_id = posts.id) AS tags";
+
+/// Number of approved comments of a post.
+const POST_COMMENT_COUNT_FIELD: &str = "(SELECT COUNT(*) FROM comments \
+    WHERE comments.post_id = posts.id AND comments.status = 'Approved') \
+    AS comment_count";
+
+/// Fields selected when reading posts.
+const POST_FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "content",
+    "slug",
+    "author_id",
+    "status",
+    "date_published",
+    "featured_media_id",
+    "date_created",
+    POST_CATEGORIES_FIELD,
+    POST_TAGS_FIELD,
+    POST_COMMENT_COUNT_FIELD,
+];
 
 /// Inserts a new post into the database.
 ///
 /// # Arguments
 ///
-/// * `pool` - A reference to the PostgreSQL connection pool.
-/// * `model` - The `PostModel` instance containing the post data to insert.
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `post_model` - The `PostModel` instance containing the post data to insert.
 ///
 /// # Returns
 ///
 /// * `Result<PostModel>` - The newly inserted `PostModel` record.
 pub async fn insert_post(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     post_model: PostModel,
 ) -> Result<PostModel> {
-    let result = QueryBuilder::<PostModel>::new(&pool)
+    if let Some(featured_media_id) = post_model.featured_media_id {
+        reject_unknown_ids(
+            conn,
+            "media",
+            "featured_media_id",
+            &[featured_media_id],
+        )
+        .await?;
+    }
+
+    let result = QueryBuilder::<PostModel>::detached()
         .table("posts")
         .fields(&[
             "title",
@@ -28,14 +92,20 @@ pub async fn insert_post(
             "author_id",
             "status",
             "date_published",
+            "featured_media_id",
         ])
         .values(vec![
             Bind::Text(post_model.title),
             Bind::Text(post_model.content),
             post_model.slug.map_or(Bind::Null, Bind::Text),
             Bind::Int(post_model.author_id),
+            Bind::PostStatus(post_model.status),
+            post_model
+                .date_published
+                .map_or(Bind::NullDateTime, Bind::DateTime),
+            post_model.featured_media_id.map_or(Bind::Null, Bind::Int),
         ])
-        .insert()
+        .insert_in(conn)
         .await?;
 
     Ok(result)
@@ -45,19 +115,29 @@ pub async fn insert_post(
 ///
 /// # Arguments
 ///
-/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `conn` - The connection, usually a transaction, to run the query on.
 /// * `id` - The ID of the post to update.
-/// * `model` - The `PostModel` instance containing the updated post data.
+/// * `post_model` - The `PostModel` instance containing the updated post data.
 ///
 /// # Returns
 ///
 /// * `Result<PostModel>` - The updated `PostModel` record.
 pub async fn update_post(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     id: i32,
     post_model: PostModel,
 ) -> Result<PostModel> {
-    let result = QueryBuilder::<PostModel>::new(&pool)
+    if let Some(featured_media_id) = post_model.featured_media_id {
+        reject_unknown_ids(
+            conn,
+            "media",
+            "featured_media_id",
+            &[featured_media_id],
+        )
+        .await?;
+    }
+
+    let result = QueryBuilder::<PostModel>::detached()
         .table("posts")
         .fields(&[
             "title",
@@ -66,14 +146,20 @@ pub async fn update_post(
             "author_id",
             "status",
             "date_published",
+            "featured_media_id",
         ])
         .values(vec![
             Bind::Text(post_model.title),
             Bind::Text(post_model.content),
             post_model.slug.map_or(Bind::Null, Bind::Text),
             Bind::Int(post_model.author_id),
+            Bind::PostStatus(post_model.status),
+            post_model
+                .date_published
+                .map_or(Bind::NullDateTime, Bind::DateTime),
+            post_model.featured_media_id.map_or(Bind::Null, Bind::Int),
         ])
-        .update("id", Bind::Int(id))
+        .update_in(conn, "id", Bind::Int(id))
         .await?;
 
     Ok(result)
@@ -84,6 +170,7 @@ pub async fn update_post(
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `filters` - The filters the posts must match.
 /// * `limit` - The maximum number of posts to retrieve.
 /// * `offset` - The number of posts to skip before starting to retrieve the records.
 ///
@@ -92,6 +179,7 @@ pub async fn update_post(
 /// * `Result<Vec<PostModel>>` - A vector containing the retrieved `PostModel` records.
 pub async fn select_posts(
     pool: &PgPool,
+    filters: &PostFiltersDTO,
     limit: i64,
     offset: i64,
     sort_column: &str,
@@ -101,25 +189,85 @@ pub async fn select_posts(
         .table("posts")
         .limit(limit)
         .offset(offset)
+        .sortable_columns(SORTABLE_COLUMNS)
         .sort_column(sort_column)
         .sort_order(sort_order)
-        .fields(&[
-            "id",
-            "title",
-            "content",
-            "slug",
-            "author_id",
-            "status",
-            "date_published",
-            "date_created",
-            "categories",
-        ])
+        .fields(POST_FIELDS)
+        .filter(filters_condition(filters))
         .select(None, None)
         .await?;
 
     Ok(result)
 }
 
+/// Searches the posts matching a full-text query, best matches first.
+///
+/// The query uses the web search syntax (quoted phrases, `or`, `-` to exclude
+/// a word) and is matched against the title and the content, the title
+/// weighing more in the rank.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `query` - The search query.
+/// * `limit` - The maximum number of posts to retrieve.
+/// * `offset` - The number of posts to skip before starting to retrieve the records.
+///
+/// # Returns
+///
+/// * `Result<Vec<PostSearchModel>>` - The matching posts with their rank and highlighted snippet.
+pub async fn search_posts(
+    pool: &PgPool,
+    query: &str,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<PostSearchModel>> {
+    let sql = format!(
+        "SELECT {}, \
+        ts_rank(posts.search_vector, search_query) AS rank, \
+        ts_headline('english', posts.content, search_query, \
+        'StartSel=<mark>, StopSel=</mark>, MaxFragments=2') AS headline \
+        FROM posts, websearch_to_tsquery('english', $1) search_query \
+        WHERE posts.post_type = 'Post' \
+        AND posts.search_vector @@ search_query \
+        ORDER BY rank DESC, posts.date_created DESC \
+        LIMIT $2 OFFSET $3",
+        POST_FIELDS.join(", ")
+    );
+
+    let result = sqlx::query_as::<_, PostSearchModel>(&sql)
+        .bind(query)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(result)
+}
+
+/// Counts the posts matching a full-text query.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `query` - The search query.
+///
+/// # Returns
+///
+/// * `Result<i64>` - The number of matching posts.
+pub async fn count_search_posts(pool: &PgPool, query: &str) -> Result<i64> {
+    let (result,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM posts \
+        WHERE post_type = 'Post' \
+        AND search_vector @@ websearch_to_tsquery('english', $1)",
+    )
+    .bind(query)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
+
 /// Retrieves a post by its ID from the database.
 ///
 /// # Arguments
@@ -133,22 +281,42 @@ pub async fn select_posts(
 pub async fn select_post_by_id(pool: &PgPool, id: i32) -> Result<PostModel> {
     let result = QueryBuilder::<PostModel>::new(pool)
         .table("posts")
-        .fields(&[
-            "id",
-            "title",
-            "content",
-            "slug",
-            "author_id",
-            "status",
-            "date_published",
-        ])
+        .fields(POST_FIELDS)
+        .filter(Condition::Eq("post_type", Bind::PostType(PostsType::Post)))
         .select_one("id", Bind::Int(id))
         .await?;
 
     Ok(result)
 }
 
-/// Deletes posts by their IDs from the database.
+/// Retrieves a published post by its slug from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `slug` - The slug of the post to retrieve.
+///
+/// # Returns
+///
+/// * `Result<PostModel>` - The published `PostModel` record for the specified slug.
+pub async fn select_published_post_by_slug(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<PostModel> {
+    let result = QueryBuilder::<PostModel>::new(pool)
+        .table("posts")
+        .fields(POST_FIELDS)
+        .filter(Condition::And(vec![
+            Condition::Eq("post_type", Bind::PostType(PostsType::Post)),
+            Condition::Eq("status", Bind::PostStatus(PostsStatus::Published)),
+        ]))
+        .select_one("slug", Bind::Text(slug.to_string()))
+        .await?;
+
+    Ok(result)
+}
+
+/// Deletes posts by their IDs from the database, leaving the pages untouched.
 ///
 /// # Arguments
 ///
@@ -162,28 +330,139 @@ pub async fn delete_post_by_id(
     pool: &PgPool,
     ids: Vec<i32>,
 ) -> Result<Vec<i32>> {
-    let result = QueryBuilder::<PostModel>::new(pool)
+    let result = sqlx::query_as::<_, (i32,)>(
+        "DELETE FROM posts WHERE id = ANY($1::int[]) AND post_type = 'Post' \
+        RETURNING id",
+    )
+    .bind(ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result.into_iter().map(|(id,)| id).collect())
+}
+
+/// Retrieves the authors of the given posts or pages.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - The IDs of the posts or pages.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The author IDs of the existing posts.
+pub async fn select_posts_author_ids(
+    pool: &PgPool,
+    ids: &[i32],
+) -> Result<Vec<i32>> {
+    let ids = ids.iter().map(|&id| Bind::Int(id)).collect();
+    let result = QueryBuilder::<(i32,)>::new(pool)
         .table("posts")
-        .delete("id", ids)
+        .fields(&["author_id"])
+        .filter(Condition::In("id", ids))
+        .select(None, None)
         .await?;
 
-    Ok(result)
+    Ok(result.into_iter().map(|(author_id,)| author_id).collect())
 }
 
-/// Counts the total number of posts in the database.
+/// Counts the number of posts matching the filters.
 ///
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `filters` - The filters the posts must match.
 ///
 /// # Returns
 ///
-/// * `Result<i64>` - The total number of posts.
-pub async fn count_posts(pool: &PgPool) -> Result<i64> {
+/// * `Result<i64>` - The number of matching posts.
+pub async fn count_posts(
+    pool: &PgPool,
+    filters: &PostFiltersDTO,
+) -> Result<i64> {
     let result = QueryBuilder::<PostModel>::new(pool)
         .table("posts")
+        .filter(filters_condition(filters))
         .count()
         .await?;
 
     Ok(result)
 }
+
+/// Builds the condition matching the posts list filters.
+fn filters_condition(filters: &PostFiltersDTO) -> Condition {
+    let mut conditions =
+        vec![Condition::Eq("post_type", Bind::PostType(PostsType::Post))];
+
+    if let Some(ref status) = filters.status {
+        conditions
+            .push(Condition::Eq("status", Bind::PostStatus(status.clone())));
+    }
+    if let Some(author_id) = filters.author_id {
+        conditions.push(Condition::Eq("author_id", Bind::Int(author_id)));
+    }
+    if let Some(category_id) = filters.category_id {
+        conditions.push(Condition::InSubquery {
+            column: "id",
+            table: "posts_categories",
+            select: "post_id",
+            field: "category_id",
+            value: Bind::Int(category_id),
+        });
+    }
+    if let Some(tag_id) = filters.tag_id {
+        conditions.push(Condition::InSubquery {
+            column: "id",
+            table: "posts_tags",
+            select: "post_id",
+            field: "tag_id",
+            value: Bind::Int(tag_id),
+        });
+    }
+    if filters.published_after.is_some() || filters.published_before.is_some() {
+        conditions.push(Condition::Range(
+            "date_published",
+            filters.published_after.map(Bind::DateTime),
+            filters.published_before.map(Bind::DateTime),
+        ));
+    }
+
+    Condition::And(conditions)
+}
+
+/// Publishes the scheduled posts whose publication date is due.
+///
+/// Rows locked by a concurrent run are skipped, so several schedulers can
+/// share the database without publishing a post twice.
+///
+/// # Arguments
+///
+/// * `conn` - A mutable reference to the connection running the transaction.
+/// * `now` - The date the publication dates are compared to.
+///
+/// # Returns
+///
+/// * `Result<Vec<PostModel>>` - The posts that were published.
+pub async fn publish_due_posts(
+    conn: &mut PgConnection,
+    now: NaiveDateTime,
+) -> Result<Vec<PostModel>> {
+    let result = sqlx::query_as::<_, PostModel>(
+        "WITH due AS (\
+            SELECT id FROM posts \
+            WHERE status = 'Scheduled' AND date_published <= $1 \
+            ORDER BY date_published \
+            FOR UPDATE SKIP LOCKED\
+        ) \
+        UPDATE posts SET status = 'Published' \
+        FROM due WHERE posts.id = due.id \
+        RETURNING posts.id, posts.title, posts.content, posts.slug, \
+        posts.author_id, posts.status, posts.date_published, \
+        posts.featured_media_id, posts.date_created",
+    )
+    .bind(now)
+    .fetch_all(conn)
+    .await?;
+
+    Ok(result)
+}
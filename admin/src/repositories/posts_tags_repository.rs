@@ -0,0 +1,46 @@
+use anyhow::Result;
+use sqlx::PgConnection;
+
+use crate::models::posts_tags_model::PostsTagsModel;
+
+use super::reject_unknown_ids;
+
+/// Replaces the tags of a post.
+///
+/// The current tags are removed and the given ones inserted, duplicated IDs
+/// are ignored. Unknown tags are rejected with a validation error on
+/// `tags_ids`.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the queries on.
+/// * `post_id` - The ID of the post.
+/// * `tags_ids` - The IDs of the tags the post must have.
+///
+/// # Returns
+///
+/// * `Result<Vec<PostsTagsModel>>` - The tags relationships of the post.
+pub async fn replace_post_tags(
+    conn: &mut PgConnection,
+    post_id: i32,
+    tags_ids: &[i32],
+) -> Result<Vec<PostsTagsModel>> {
+    reject_unknown_ids(conn, "tags", "tags_ids", tags_ids).await?;
+
+    sqlx::query("DELETE FROM posts_tags WHERE post_id = $1")
+        .bind(post_id)
+        .execute(&mut *conn)
+        .await?;
+
+    let result = sqlx::query_as::<_, PostsTagsModel>(
+        "INSERT INTO posts_tags (post_id, tag_id) \
+        SELECT $1, id FROM (SELECT DISTINCT UNNEST($2::int[]) AS id) ids \
+        RETURNING *",
+    )
+    .bind(post_id)
+    .bind(tags_ids)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(result)
+}
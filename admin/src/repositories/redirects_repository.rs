@@ -0,0 +1,180 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::models::redirects_model::RedirectModel;
+
+use super::{Bind, QueryBuilder};
+
+/// Columns the redirects can be sorted by.
+const SORTABLE_COLUMNS: &[&str] =
+    &["id", "source_path", "hits", "last_hit", "date_created"];
+
+/// Columns of the redirects.
+const FIELDS: &[&str] = &[
+    "id",
+    "source_path",
+    "target",
+    "status_code",
+    "hits",
+    "last_hit",
+    "date_created",
+];
+
+/// Inserts a new redirect into the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `redirect_model` - The `RedirectModel` instance containing the redirect.
+///
+/// # Returns
+///
+/// * `Result<RedirectModel>` - The newly inserted `RedirectModel` record.
+pub async fn insert_redirect(
+    pool: &PgPool,
+    redirect_model: RedirectModel,
+) -> Result<RedirectModel> {
+    let result = QueryBuilder::<RedirectModel>::new(pool)
+        .table("redirects")
+        .fields(&["source_path", "target", "status_code"])
+        .values(vec![
+            Bind::Text(redirect_model.source_path),
+            Bind::Text(redirect_model.target),
+            Bind::Int(redirect_model.status_code),
+        ])
+        .insert()
+        .await?;
+
+    Ok(result)
+}
+
+/// Updates the source path, target and status code of a redirect by its ID,
+/// keeping its hit counter.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the redirect to update.
+/// * `redirect_model` - The `RedirectModel` instance containing the data.
+///
+/// # Returns
+///
+/// * `Result<RedirectModel>` - The updated `RedirectModel` record.
+pub async fn update_redirect(
+    pool: &PgPool,
+    id: i32,
+    redirect_model: RedirectModel,
+) -> Result<RedirectModel> {
+    let result = QueryBuilder::<RedirectModel>::new(pool)
+        .table("redirects")
+        .fields(&["source_path", "target", "status_code"])
+        .values(vec![
+            Bind::Text(redirect_model.source_path),
+            Bind::Text(redirect_model.target),
+            Bind::Int(redirect_model.status_code),
+        ])
+        .update("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a paginated list of redirects.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `limit` - The maximum number of redirects to retrieve.
+/// * `offset` - The number of redirects to skip.
+/// * `sort_column` - The column to sort the redirects by.
+/// * `sort_order` - The order of sorting, `asc` or `desc`.
+///
+/// # Returns
+///
+/// * `Result<Vec<RedirectModel>>` - A vector containing the redirects.
+pub async fn select_redirects(
+    pool: &PgPool,
+    limit: i64,
+    offset: i64,
+    sort_column: &str,
+    sort_order: &str,
+) -> Result<Vec<RedirectModel>> {
+    let result = QueryBuilder::<RedirectModel>::new(pool)
+        .table("redirects")
+        .limit(limit)
+        .offset(offset)
+        .sortable_columns(SORTABLE_COLUMNS)
+        .sort_column(sort_column)
+        .sort_order(sort_order)
+        .fields(FIELDS)
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Counts the redirects.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<i64>` - The number of redirects.
+pub async fn count_redirects(pool: &PgPool) -> Result<i64> {
+    let result = QueryBuilder::<RedirectModel>::new(pool)
+        .table("redirects")
+        .count()
+        .await?;
+
+    Ok(result)
+}
+
+/// Deletes redirects by their IDs.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - A vector containing the IDs of the redirects to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted redirects.
+pub async fn delete_redirects_by_ids(
+    pool: &PgPool,
+    ids: Vec<i32>,
+) -> Result<Vec<i32>> {
+    let result = QueryBuilder::<RedirectModel>::new(pool)
+        .table("redirects")
+        .delete("id", ids)
+        .await?;
+
+    Ok(result)
+}
+
+/// Counts a request to the source path of a redirect.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `source_path` - The path requested.
+///
+/// # Returns
+///
+/// * `Result<RedirectModel>` - The redirect of the path, `RowNotFound` if
+///   there is none.
+pub async fn hit_redirect(
+    pool: &PgPool,
+    source_path: &str,
+) -> Result<RedirectModel> {
+    let result = sqlx::query_as::<_, RedirectModel>(
+        "UPDATE redirects SET hits = hits + 1, last_hit = NOW() \
+         WHERE source_path = $1 RETURNING *",
+    )
+    .bind(source_path)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
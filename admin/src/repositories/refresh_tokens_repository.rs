@@ -0,0 +1,134 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use sqlx::PgPool;
+
+use crate::models::refresh_tokens_model::RefreshTokenModel;
+
+use super::{Bind, QueryBuilder};
+
+/// Inserts a new refresh token into the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `refresh_token_model` - The `RefreshTokenModel` instance to insert.
+///
+/// # Returns
+///
+/// * `Result<RefreshTokenModel>` - The newly inserted `RefreshTokenModel` record.
+pub async fn insert_refresh_token(
+    pool: &PgPool,
+    refresh_token_model: RefreshTokenModel,
+) -> Result<RefreshTokenModel> {
+    let result = QueryBuilder::<RefreshTokenModel>::new(pool)
+        .table("refresh_tokens")
+        .fields(&["user_id", "token_hash", "expires_at"])
+        .values(vec![
+            Bind::Int(refresh_token_model.user_id),
+            Bind::Text(refresh_token_model.token_hash),
+            Bind::DateTime(refresh_token_model.expires_at),
+        ])
+        .insert()
+        .await?;
+
+    Ok(result)
+}
+
+/// Revokes an active refresh token and stores its replacement atomically.
+///
+/// Presenting a refresh token that was already rotated is treated as token
+/// theft: every refresh token of its owner is revoked.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `token_hash` - The hash of the refresh token presented by the client.
+/// * `new_token_hash` - The hash of the refresh token replacing it.
+/// * `new_expires_at` - The expiration date of the new refresh token.
+///
+/// # Returns
+///
+/// * `Result<Option<i32>>` - The ID of the token owner, `None` if the token
+///   is unknown, expired or revoked.
+pub async fn rotate_refresh_token(
+    pool: &PgPool,
+    token_hash: &str,
+    new_token_hash: &str,
+    new_expires_at: NaiveDateTime,
+) -> Result<Option<i32>> {
+    let mut tx = pool.begin().await?;
+
+    let user_id = sqlx::query_scalar::<_, i32>(
+        "UPDATE refresh_tokens SET revoked_at = NOW() \
+        WHERE token_hash = $1 AND revoked_at IS NULL AND expires_at > NOW() \
+        RETURNING user_id",
+    )
+    .bind(token_hash)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(user_id) = user_id else {
+        let reused_by = sqlx::query_scalar::<_, i32>(
+            "SELECT user_id FROM refresh_tokens \
+            WHERE token_hash = $1 AND revoked_at IS NOT NULL",
+        )
+        .bind(token_hash)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if let Some(user_id) = reused_by {
+            sqlx::query(
+                "UPDATE refresh_tokens SET revoked_at = NOW() \
+                WHERE user_id = $1 AND revoked_at IS NULL",
+            )
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        return Ok(None);
+    };
+
+    sqlx::query(
+        "INSERT INTO refresh_tokens (user_id, token_hash, expires_at) \
+        VALUES ($1, $2, $3)",
+    )
+    .bind(user_id)
+    .bind(new_token_hash)
+    .bind(new_expires_at)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(Some(user_id))
+}
+
+/// Revokes a refresh token of a user.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the token owner.
+/// * `token_hash` - The hash of the refresh token to revoke.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The number of revoked tokens.
+pub async fn revoke_refresh_token(
+    pool: &PgPool,
+    user_id: i32,
+    token_hash: &str,
+) -> Result<u64> {
+    let result = sqlx::query(
+        "UPDATE refresh_tokens SET revoked_at = NOW() \
+        WHERE user_id = $1 AND token_hash = $2 AND revoked_at IS NULL",
+    )
+    .bind(user_id)
+    .bind(token_hash)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
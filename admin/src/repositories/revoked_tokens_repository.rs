@@ -0,0 +1,65 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use sqlx::PgPool;
+
+/// Revokes an access token until its expiration.
+///
+/// Entries of already expired tokens are purged at the same time.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `jti` - The unique identifier of the access token.
+/// * `user_id` - The ID of the token owner.
+/// * `expires_at` - The expiration date of the access token.
+///
+/// # Returns
+///
+/// * `Result<()>` - Success or failure of the operation.
+pub async fn insert_revoked_token(
+    pool: &PgPool,
+    jti: &str,
+    user_id: i32,
+    expires_at: NaiveDateTime,
+) -> Result<()> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM revoked_tokens WHERE expires_at < NOW()")
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query(
+        "INSERT INTO revoked_tokens (jti, user_id, expires_at) \
+        VALUES ($1, $2, $3) ON CONFLICT (jti) DO NOTHING",
+    )
+    .bind(jti)
+    .bind(user_id)
+    .bind(expires_at)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Checks whether an access token has been revoked.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `jti` - The unique identifier of the access token.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `true` if the token is revoked.
+pub async fn is_token_revoked(pool: &PgPool, jti: &str) -> Result<bool> {
+    let result = sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS (SELECT 1 FROM revoked_tokens WHERE jti = $1)",
+    )
+    .bind(jti)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,211 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+use crate::models::roles_model::RoleModel;
+
+use super::{Bind, QueryBuilder};
+
+/// Columns the roles can be sorted by.
+const SORTABLE_COLUMNS: &[&str] = &["id", "name", "slug", "date_created"];
+
+/// Capabilities names of a role, aggregated as a JSON array.
+const ROLE_CAPABILITIES_FIELD: &str =
+    "(SELECT COALESCE(json_agg(c.name ORDER BY c.name), '[]') \
+    FROM roles_capabilities rc \
+    JOIN capabilities c ON c.id = rc.capability_id \
+    WHERE rc.role_id = roles.id) AS capabilities";
+
+/// Inserts a new role into the database.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `role_model` - The `RoleModel` instance containing the role data to insert.
+///
+/// # Returns
+///
+/// * `Result<RoleModel>` - The newly inserted `RoleModel` record.
+pub async fn insert_role(
+    conn: &mut PgConnection,
+    role_model: RoleModel,
+) -> Result<RoleModel> {
+    let result = QueryBuilder::<RoleModel>::detached()
+        .table("roles")
+        .fields(&["name", "slug", "description"])
+        .values(vec![
+            Bind::Text(role_model.name),
+            role_model.slug.map_or(Bind::Null, Bind::Text),
+            role_model.description.map_or(Bind::Null, Bind::Text),
+        ])
+        .insert_in(conn)
+        .await?;
+
+    Ok(result)
+}
+
+/// Updates an existing role in the database by its ID.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `id` - The ID of the role to update.
+/// * `role_model` - The `RoleModel` instance containing the updated role data.
+///
+/// # Returns
+///
+/// * `Result<RoleModel>` - The updated `RoleModel` record.
+pub async fn update_role(
+    conn: &mut PgConnection,
+    id: i32,
+    role_model: RoleModel,
+) -> Result<RoleModel> {
+    let result = QueryBuilder::<RoleModel>::detached()
+        .table("roles")
+        .fields(&["name", "slug", "description"])
+        .values(vec![
+            Bind::Text(role_model.name),
+            role_model.slug.map_or(Bind::Null, Bind::Text),
+            role_model.description.map_or(Bind::Null, Bind::Text),
+        ])
+        .update_in(conn, "id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Replaces the capabilities granted by a role.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the queries on.
+/// * `role_id` - The ID of the role.
+/// * `capabilities_ids` - The IDs of the capabilities the role grants.
+///
+/// # Returns
+///
+/// * `Result<()>` - Success or failure of the operation.
+pub async fn replace_role_capabilities(
+    conn: &mut PgConnection,
+    role_id: i32,
+    capabilities_ids: Vec<i32>,
+) -> Result<()> {
+    sqlx::query("DELETE FROM roles_capabilities WHERE role_id = $1")
+        .bind(role_id)
+        .execute(&mut *conn)
+        .await?;
+
+    sqlx::query(
+        "INSERT INTO roles_capabilities (role_id, capability_id) \
+        SELECT $1, UNNEST($2::int[]) ON CONFLICT DO NOTHING",
+    )
+    .bind(role_id)
+    .bind(&capabilities_ids)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Retrieves all roles from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<Vec<RoleModel>>` - A vector containing the retrieved `RoleModel` records.
+pub async fn select_roles(
+    pool: &PgPool,
+    limit: i64,
+    offset: i64,
+    sort_column: &str,
+    sort_order: &str,
+) -> Result<Vec<RoleModel>> {
+    let result = QueryBuilder::<RoleModel>::new(pool)
+        .table("roles")
+        .limit(limit)
+        .offset(offset)
+        .sortable_columns(SORTABLE_COLUMNS)
+        .sort_column(sort_column)
+        .sort_order(sort_order)
+        .fields(&[
+            "id",
+            "name",
+            "slug",
+            "description",
+            "date_created",
+            ROLE_CAPABILITIES_FIELD,
+        ])
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a role by its ID from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the role to retrieve.
+///
+/// # Returns
+///
+/// * `Result<RoleModel>` - The `RoleModel` record for the specified ID.
+pub async fn select_role_by_id(pool: &PgPool, id: i32) -> Result<RoleModel> {
+    let result = QueryBuilder::<RoleModel>::new(pool)
+        .table("roles")
+        .fields(&[
+            "id",
+            "name",
+            "slug",
+            "description",
+            "date_created",
+            ROLE_CAPABILITIES_FIELD,
+        ])
+        .select_one("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Deletes roles by their IDs from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - A vector containing the IDs of the roles to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted roles.
+pub async fn delete_role_by_id(
+    pool: &PgPool,
+    ids: Vec<i32>,
+) -> Result<Vec<i32>> {
+    let result = QueryBuilder::<RoleModel>::new(pool)
+        .table("roles")
+        .delete("id", ids)
+        .await?;
+
+    Ok(result)
+}
+
+/// Counts the total number of roles in the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<i64>` - The total number of roles.
+pub async fn count_roles(pool: &PgPool) -> Result<i64> {
+    let result = QueryBuilder::<RoleModel>::new(pool)
+        .table("roles")
+        .count()
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,55 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+use crate::models::settings_model::SettingModel;
+
+use super::QueryBuilder;
+
+/// Retrieves every setting stored.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<Vec<SettingModel>>` - The settings which were set.
+pub async fn select_settings(pool: &PgPool) -> Result<Vec<SettingModel>> {
+    let result = QueryBuilder::<SettingModel>::new(pool)
+        .table("settings")
+        .fields(&["name", "value", "date_updated"])
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Inserts or replaces the value of a setting.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `name` - The name of the setting.
+/// * `value` - The new value of the setting.
+///
+/// # Returns
+///
+/// * `Result<SettingModel>` - The stored setting.
+pub async fn upsert_setting(
+    conn: &mut PgConnection,
+    name: &str,
+    value: serde_json::Value,
+) -> Result<SettingModel> {
+    let result = sqlx::query_as::<_, SettingModel>(
+        "INSERT INTO settings (name, value) VALUES ($1, $2) \
+        ON CONFLICT (name) DO UPDATE \
+        SET value = EXCLUDED.value, date_updated = CURRENT_TIMESTAMP \
+        RETURNING name, value, date_updated",
+    )
+    .bind(name)
+    .bind(value)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(result)
+}
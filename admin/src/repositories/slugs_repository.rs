@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+use crate::models::slugs_model::SlugType;
+
+/// Retrieves the slugs of a type of content starting with a prefix.
+///
+/// Posts and pages share their slugs, as both are served at `/{slug}`.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `slug_type` - The type of content whose slugs are retrieved.
+/// * `prefix` - The start of the slugs to retrieve.
+/// * `exclude_id` - The ID of a content whose slug is left out, if any.
+///
+/// # Returns
+///
+/// * `Result<HashSet<String>>` - The slugs starting with the prefix.
+pub async fn select_slugs_starting_with(
+    conn: &mut PgConnection,
+    slug_type: SlugType,
+    prefix: &str,
+    exclude_id: Option<i32>,
+) -> Result<HashSet<String>> {
+    let table = match slug_type {
+        SlugType::Post | SlugType::Page => "posts",
+        SlugType::Category => "categories",
+        SlugType::Tag => "tags",
+    };
+
+    let result = sqlx::query_as::<_, (String,)>(&format!(
+        "SELECT slug FROM {} \
+        WHERE LEFT(slug, LENGTH($1)) = $1 \
+        AND id IS DISTINCT FROM $2",
+        table
+    ))
+    .bind(prefix)
+    .bind(exclude_id)
+    .fetch_all(conn)
+    .await?;
+
+    Ok(result.into_iter().map(|(slug,)| slug).collect())
+}
+
+/// Column of `slug_history` referencing the contents of a type.
+fn history_column(slug_type: SlugType) -> &'static str {
+    match slug_type {
+        SlugType::Post | SlugType::Page => "post_id",
+        SlugType::Category => "category_id",
+        SlugType::Tag => "tag_id",
+    }
+}
+
+/// Records a former slug of a content.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `slug_type` - The type of the content.
+/// * `id` - The ID of the content.
+/// * `slug` - The slug the content had.
+///
+/// # Returns
+///
+/// * `Result<()>` - Nothing on success.
+pub async fn insert_slug_history(
+    conn: &mut PgConnection,
+    slug_type: SlugType,
+    id: i32,
+    slug: &str,
+) -> Result<()> {
+    sqlx::query(&format!(
+        "INSERT INTO slug_history ({}, slug) VALUES ($1, $2)",
+        history_column(slug_type)
+    ))
+    .bind(id)
+    .bind(slug)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Forgets a former slug of a content, when the content takes it back.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the query on.
+/// * `slug_type` - The type of the content.
+/// * `id` - The ID of the content.
+/// * `slug` - The former slug to forget.
+///
+/// # Returns
+///
+/// * `Result<()>` - Nothing on success.
+pub async fn delete_slug_history(
+    conn: &mut PgConnection,
+    slug_type: SlugType,
+    id: i32,
+    slug: &str,
+) -> Result<()> {
+    sqlx::query(&format!(
+        "DELETE FROM slug_history WHERE {} = $1 AND slug = $2",
+        history_column(slug_type)
+    ))
+    .bind(id)
+    .bind(slug)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Retrieves the current slug of the content which last had a former slug,
+/// among the published posts and pages, the categories or the tags.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `slug_type` - The type of the content.
+/// * `slug` - The former slug of the content.
+///
+/// # Returns
+///
+/// * `Result<String>` - The current slug, `RowNotFound` if no content had the slug.
+pub async fn select_current_slug(
+    pool: &PgPool,
+    slug_type: SlugType,
+    slug: &str,
+) -> Result<String> {
+    let (table, condition) = match slug_type {
+        SlugType::Post => {
+            ("posts", "c.post_type = 'Post' AND c.status = 'Published'")
+        }
+        SlugType::Page => {
+            ("posts", "c.post_type = 'Page' AND c.status = 'Published'")
+        }
+        SlugType::Category => ("categories", "TRUE"),
+        SlugType::Tag => ("tags", "TRUE"),
+    };
+
+    let result = sqlx::query_as::<_, (String,)>(&format!(
+        "SELECT c.slug FROM slug_history h \
+        JOIN {} c ON c.id = h.{} \
+        WHERE h.slug = $1 AND {} \
+        ORDER BY h.date_created DESC, h.id DESC \
+        LIMIT 1",
+        table,
+        history_column(slug_type),
+        condition
+    ))
+    .bind(slug)
+    .fetch_optional(pool)
+    .await?
+    .ok_or(sqlx::Error::RowNotFound)?;
+
+    Ok(result.0)
+}
@@ -1,25 +1,28 @@
 use anyhow::Result;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 
 use crate::models::tags_model::TagModel;
 
 use super::{Bind, QueryBuilder};
 
+/// Columns the tags can be sorted by.
+const SORTABLE_COLUMNS: &[&str] = &["id", "name", "slug", "date_created"];
+
 /// Inserts a new tag into the database.
 ///
 /// # Arguments
 ///
-/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `conn` - The connection, usually a transaction, to run the query on.
 /// * `tag_model` - The `TagModel` instance containing the tag data to insert.
 ///
 /// # Returns
 ///
 /// * `Result<TagModel>` - The newly inserted `TagModel` record.
 pub async fn insert_tag(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     tag_model: TagModel,
 ) -> Result<TagModel> {
-    let result = QueryBuilder::<TagModel>::new(&pool)
+    let result = QueryBuilder::<TagModel>::detached()
         .table("tags")
         .fields(&["name", "slug", "description"])
         .values(vec![
@@ -27,7 +30,7 @@ pub async fn insert_tag(
             tag_model.slug.map_or(Bind::Null, Bind::Text),
             tag_model.description.map_or(Bind::Null, Bind::Text),
         ])
-        .insert()
+        .insert_in(conn)
         .await?;
 
     Ok(result)
@@ -37,7 +40,7 @@ pub async fn insert_tag(
 ///
 /// # Arguments
 ///
-/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `conn` - The connection, usually a transaction, to run the query on.
 /// * `id` - The ID of the tag to update.
 /// * `tag_model` - The `TagModel` instance containing the updated tag data.
 ///
@@ -45,11 +48,11 @@ pub async fn insert_tag(
 ///
 /// * `Result<TagModel>` - The updated `TagModel` record.
 pub async fn update_tag(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     id: i32,
     tag_model: TagModel,
 ) -> Result<TagModel> {
-    let result = QueryBuilder::<TagModel>::new(&pool)
+    let result = QueryBuilder::<TagModel>::detached()
         .table("tags")
         .fields(&["name", "slug", "description"])
         .values(vec![
@@ -57,7 +60,7 @@ pub async fn update_tag(
             tag_model.slug.map_or(Bind::Null, Bind::Text),
             tag_model.description.map_or(Bind::Null, Bind::Text),
         ])
-        .update("id", Bind::Int(id))
+        .update_in(conn, "id", Bind::Int(id))
         .await?;
 
     Ok(result)
@@ -83,6 +86,7 @@ pub async fn select_tags(
         .table("tags")
         .limit(limit)
         .offset(offset)
+        .sortable_columns(SORTABLE_COLUMNS)
         .sort_column(sort_column)
         .sort_order(sort_order)
         .fields(&["id", "name", "slug", "description", "date_created"])
@@ -112,6 +116,26 @@ pub async fn select_tag_by_id(pool: &PgPool, id: i32) -> Result<TagModel> {
     Ok(result)
 }
 
+/// Retrieves a tag by its slug from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `slug` - The slug of the tag to retrieve.
+///
+/// # Returns
+///
+/// * `Result<TagModel>` - The `TagModel` record for the specified slug.
+pub async fn select_tag_by_slug(pool: &PgPool, slug: &str) -> Result<TagModel> {
+    let result = QueryBuilder::<TagModel>::new(pool)
+        .table("tags")
+        .fields(&["id", "name", "slug", "description", "date_created"])
+        .select_one("slug", Bind::Text(slug.to_string()))
+        .await?;
+
+    Ok(result)
+}
+
 /// Deletes tags by their IDs from the database.
 ///
 /// # Arguments
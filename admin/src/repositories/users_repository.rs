@@ -1,9 +1,27 @@
 use anyhow::Result;
 use sqlx::PgPool;
 
-use crate::models::users_models::UserModel;
+use crate::{
+    dtos::user_dtos::UserFiltersDTO,
+    models::users_models::{AuthorModel, UserModel},
+};
 
-use super::{Bind, QueryBuilder};
+use super::{Bind, Condition, QueryBuilder};
+
+/// Fields of the public profile of an author.
+const AUTHOR_FIELDS: &[&str] =
+    &["id", "username", "firstname", "lastname", "url"];
+
+/// Columns the users can be sorted by.
+const SORTABLE_COLUMNS: &[&str] = &[
+    "id",
+    "username",
+    "email",
+    "firstname",
+    "lastname",
+    "active",
+    "date_created",
+];
 
 /// Inserts a user into the database.
 ///
@@ -87,6 +105,7 @@ pub async fn update_user(
 ///
 /// # Arguments
 /// * `pool` - Reference to the PgPool pool.
+/// * `filters` - The filters the users must match.
 /// * `limit` - The number of users to return.
 /// * `offset` - The pagination offset.
 /// * `sort_column` - The column to sort by.
@@ -96,6 +115,7 @@ pub async fn update_user(
 /// A `Result` containing a vector of `UserModel` or an error.
 pub async fn select_users(
     pool: &PgPool,
+    filters: &UserFiltersDTO,
     limit: i64,
     offset: i64,
     sort_column: &str,
@@ -105,6 +125,7 @@ pub async fn select_users(
         .table("users")
         .limit(limit)
         .offset(offset)
+        .sortable_columns(SORTABLE_COLUMNS)
         .sort_column(sort_column)
         .sort_order(sort_order)
         .fields(&[
@@ -118,6 +139,7 @@ pub async fn select_users(
             "active",
             "date_created",
         ])
+        .filter(filters_condition(filters))
         .select(None, None)
         .await?;
 
@@ -183,6 +205,50 @@ pub async fn select_user_by_email(
     Ok(result)
 }
 
+/// Selects the public profile of an author by user name.
+///
+/// # Arguments
+/// * `pool` - Reference to the PgPool pool.
+/// * `username` - The user name of the author.
+///
+/// # Returns
+/// A `Result` containing an `AuthorModel` or an error.
+pub async fn select_author_by_username(
+    pool: &PgPool,
+    username: &str,
+) -> Result<AuthorModel> {
+    let result = QueryBuilder::<AuthorModel>::new(pool)
+        .table("users")
+        .fields(AUTHOR_FIELDS)
+        .select_one("username", Bind::Text(username.to_string()))
+        .await?;
+
+    Ok(result)
+}
+
+/// Selects the public profiles of authors by their IDs.
+///
+/// # Arguments
+/// * `pool` - Reference to the PgPool pool.
+/// * `ids` - The IDs of the authors.
+///
+/// # Returns
+/// A `Result` containing a vector of `AuthorModel` or an error.
+pub async fn select_authors_by_ids(
+    pool: &PgPool,
+    ids: &[i32],
+) -> Result<Vec<AuthorModel>> {
+    let ids = ids.iter().map(|&id| Bind::Int(id)).collect();
+    let result = QueryBuilder::<AuthorModel>::new(pool)
+        .table("users")
+        .fields(AUTHOR_FIELDS)
+        .filter(Condition::In("id", ids))
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
 /// Deletes one or more users by their IDs.
 ///
 /// # Arguments
@@ -203,18 +269,34 @@ pub async fn delete_user_by_id(
     Ok(result)
 }
 
-/// Counts the total number of users.
+/// Counts the number of users matching the filters.
 ///
 /// # Arguments
 /// * `pool` - Reference to the PgPool pool.
+/// * `filters` - The filters the users must match.
 ///
 /// # Returns
-/// A `Result` containing the total user count or an error.
-pub async fn count_users(pool: &PgPool) -> Result<i64> {
+/// A `Result` containing the user count or an error.
+pub async fn count_users(
+    pool: &PgPool,
+    filters: &UserFiltersDTO,
+) -> Result<i64> {
     let result = QueryBuilder::<UserModel>::new(pool)
         .table("users")
+        .filter(filters_condition(filters))
         .count()
         .await?;
 
     Ok(result)
 }
+
+/// Builds the condition matching the users list filters.
+fn filters_condition(filters: &UserFiltersDTO) -> Condition {
+    let mut conditions = vec![];
+
+    if let Some(active) = filters.active {
+        conditions.push(Condition::Eq("active", Bind::Bool(active)));
+    }
+
+    Condition::And(conditions)
+}
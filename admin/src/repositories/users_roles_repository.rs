@@ -0,0 +1,52 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::models::users_roles_model::UsersRolesModel;
+
+use super::{Bind, QueryBuilder};
+
+/// Assigns a role to a user.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `model` - The `UsersRolesModel` instance to insert.
+///
+/// # Returns
+///
+/// * `Result<UsersRolesModel>` - The newly inserted `UsersRolesModel` record.
+pub async fn insert_user_role(
+    pool: &PgPool,
+    model: UsersRolesModel,
+) -> Result<UsersRolesModel> {
+    let result = QueryBuilder::<UsersRolesModel>::new(pool)
+        .table("users_roles")
+        .fields(&["user_id", "role_id"])
+        .values(vec![Bind::Int(model.user_id), Bind::Int(model.role_id)])
+        .insert()
+        .await?;
+
+    Ok(result)
+}
+
+/// Removes role assignments by their IDs.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - A vector containing the IDs of the assignments to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted assignments.
+pub async fn delete_user_role_by_id(
+    pool: &PgPool,
+    ids: Vec<i32>,
+) -> Result<Vec<i32>> {
+    let result = QueryBuilder::<UsersRolesModel>::new(pool)
+        .table("users_roles")
+        .delete("id", ids)
+        .await?;
+
+    Ok(result)
+}
@@ -2,22 +2,105 @@ use ntex::web;
 
 use crate::{
     controllers::{
-        auth::login_controller::login_controller,
+        auth::{
+            login_controller::login_controller,
+            logout_controller::logout_controller,
+            refresh_controller::refresh_controller,
+        },
+        capabilities::get_all_capabilities_controller::get_all_capabilities_controller,
         categories::{
             create_category_controller::create_category_controller,
             delete_category_controller::delete_category_controller,
             get_all_categories_controller::get_all_categories_controller,
+            get_categories_tree_controller::get_categories_tree_controller,
             get_category_by_id_controller::get_category_by_id_controller,
+            get_category_path_controller::get_category_path_controller,
             update_category_controller::update_category_controller,
         },
+        comments::{
+            create_comment_controller::create_comment_controller,
+            delete_comments_controller::delete_comments_controller,
+            get_all_comments_controller::get_all_comments_controller,
+            reply_comment_controller::reply_comment_controller,
+            update_comments_status_controller::update_comments_status_controller,
+        },
+        media::{
+            delete_media_controller::delete_media_controller,
+            get_all_media_controller::get_all_media_controller,
+            get_media_by_id_controller::get_media_by_id_controller,
+            regenerate_media_controller::regenerate_media_controller,
+            upload_media_controller::upload_media_controller,
+        },
+        menus::{
+            create_menu_controller::create_menu_controller,
+            delete_menus_controller::delete_menus_controller,
+            get_all_menus_controller::get_all_menus_controller,
+            get_menu_by_id_controller::get_menu_by_id_controller,
+            get_menu_locations_controller::get_menu_locations_controller,
+            update_menu_controller::update_menu_controller,
+            update_menu_items_controller::update_menu_items_controller,
+            update_menu_locations_controller::update_menu_locations_controller,
+        },
+        pages::{
+            create_page_controller::create_page_controller,
+            delete_pages_controller::delete_pages_controller,
+            get_all_pages_controller::get_all_pages_controller,
+            get_page_by_id_controller::get_page_by_id_controller,
+            update_page_controller::update_page_controller,
+        },
+        post_autosaves::{
+            delete_post_autosave_controller::delete_post_autosave_controller,
+            get_post_autosave_controller::get_post_autosave_controller,
+            save_post_autosave_controller::save_post_autosave_controller,
+        },
+        post_revisions::{
+            get_post_revisions_controller::get_post_revisions_controller,
+            get_post_revisions_diff_controller::get_post_revisions_diff_controller,
+            restore_post_revision_controller::restore_post_revision_controller,
+        },
         posts::{
             create_post_controller::create_post_controller,
             delete_post_controller::delete_post_controller,
             get_all_posts_controller::get_all_posts_controller,
             get_post_by_id_controller::get_post_by_id_controller,
+            search_posts_controller::search_posts_controller,
             update_post_controller::update_post_controller,
         },
         posts_categories::create_posts_categories_controller::create_posts_categories_controller,
+        posts_tags::{
+            get_posts_by_tag_controller::get_posts_by_tag_controller,
+            update_posts_tags_controller::update_posts_tags_controller,
+        },
+        public::{
+            get_public_category_controller::get_public_category_controller,
+            get_public_menus_controller::get_public_menus_controller,
+            get_public_page_controller::get_public_page_controller,
+            get_public_post_controller::get_public_post_controller,
+            get_public_posts_controller::get_public_posts_controller,
+            get_public_redirect_controller::get_public_redirect_controller,
+            get_public_settings_controller::get_public_settings_controller,
+            get_public_slug_controller::get_public_slug_controller,
+            get_public_tag_controller::get_public_tag_controller,
+            get_public_theme_controller::get_public_theme_controller,
+        },
+        redirects::{
+            create_redirect_controller::create_redirect_controller,
+            delete_redirects_controller::delete_redirects_controller,
+            get_all_redirects_controller::get_all_redirects_controller,
+            update_redirect_controller::update_redirect_controller,
+        },
+        roles::{
+            create_role_controller::create_role_controller,
+            delete_role_controller::delete_role_controller,
+            get_all_roles_controller::get_all_roles_controller,
+            get_role_by_id_controller::get_role_by_id_controller,
+            update_role_controller::update_role_controller,
+        },
+        settings::{
+            get_settings_controller::get_settings_controller,
+            update_settings_controller::update_settings_controller,
+        },
+        slugs::check_slug_controller::check_slug_controller,
         tags::{
             create_tag_controller::create_tag_controller,
             delete_tag_controller::delete_tag_controller,
@@ -25,14 +108,20 @@ use crate::{
             get_tag_by_id_controller::get_tag_by_id_controller,
             update_tag_controller::update_tag_controller,
         },
+        themes::update_active_theme_controller::update_active_theme_controller,
         users::{
             create_user_controller::create_user_controller,
             delete_user_controller::delete_user_controller,
+            get_all_users_controller::get_all_users_controller,
             get_user_by_id_controller::get_user_by_id_controller,
             update_user_controller::update_user_controller,
         },
+        users_roles::{
+            create_users_roles_controller::create_users_roles_controller,
+            delete_users_roles_controller::delete_users_roles_controller,
+        },
     },
-    middlewares::auth_middleware::JwtGuard,
+    middlewares::auth_middleware::JwtAuth,
 };
 
 pub fn init(cfg: &mut web::ServiceConfig) {
@@ -40,10 +129,28 @@ pub fn init(cfg: &mut web::ServiceConfig) {
         web::scope("/api/v1")
             // Public routes
             .service(login_controller)
+            .service(refresh_controller)
+            .service(create_comment_controller)
+            // Public content, published only
+            .service(
+                web::scope("/public")
+                    .service(get_public_posts_controller)
+                    .service(get_public_post_controller)
+                    .service(get_public_page_controller)
+                    .service(get_public_category_controller)
+                    .service(get_public_tag_controller)
+                    .service(get_public_theme_controller)
+                    .service(get_public_settings_controller)
+                    .service(get_public_menus_controller)
+                    .service(get_public_redirect_controller)
+                    .service(get_public_slug_controller),
+            )
             // JWT routes
             .service(
                 web::scope("/")
-                    //.guard(JwtGuard)
+                    .wrap(JwtAuth)
+                    // Auth Controllers
+                    .service(logout_controller)
                     // Tag Controllers
                     .service(create_tag_controller)
                     .service(get_all_tags_controller)
@@ -53,22 +160,85 @@ pub fn init(cfg: &mut web::ServiceConfig) {
                     // Category Controllers
                     .service(create_category_controller)
                     .service(get_all_categories_controller)
+                    .service(get_categories_tree_controller)
                     .service(get_category_by_id_controller)
+                    .service(get_category_path_controller)
                     .service(update_category_controller)
                     .service(delete_category_controller)
                     // Post Controllers
                     .service(create_post_controller)
                     .service(get_all_posts_controller)
+                    .service(search_posts_controller)
                     .service(get_post_by_id_controller)
                     .service(update_post_controller)
                     .service(delete_post_controller)
+                    // Post Revision Controllers
+                    .service(get_post_revisions_diff_controller)
+                    .service(get_post_revisions_controller)
+                    .service(restore_post_revision_controller)
+                    // Post Autosave Controllers
+                    .service(save_post_autosave_controller)
+                    .service(get_post_autosave_controller)
+                    .service(delete_post_autosave_controller)
+                    // Page Controllers
+                    .service(create_page_controller)
+                    .service(get_all_pages_controller)
+                    .service(get_page_by_id_controller)
+                    .service(update_page_controller)
+                    .service(delete_pages_controller)
+                    // Comment Controllers
+                    .service(get_all_comments_controller)
+                    .service(reply_comment_controller)
+                    .service(update_comments_status_controller)
+                    .service(delete_comments_controller)
+                    // Media Controllers
+                    .service(upload_media_controller)
+                    .service(get_all_media_controller)
+                    .service(regenerate_media_controller)
+                    .service(get_media_by_id_controller)
+                    .service(delete_media_controller)
+                    // Theme Controllers
+                    .service(update_active_theme_controller)
+                    // Setting Controllers
+                    .service(get_settings_controller)
+                    .service(update_settings_controller)
+                    // Slug Controllers
+                    .service(check_slug_controller)
+                    // Menu Controllers
+                    .service(get_menu_locations_controller)
+                    .service(update_menu_locations_controller)
+                    .service(create_menu_controller)
+                    .service(get_all_menus_controller)
+                    .service(get_menu_by_id_controller)
+                    .service(update_menu_controller)
+                    .service(update_menu_items_controller)
+                    .service(delete_menus_controller)
+                    // Redirect Controllers
+                    .service(create_redirect_controller)
+                    .service(get_all_redirects_controller)
+                    .service(update_redirect_controller)
+                    .service(delete_redirects_controller)
                     // User Controllers
                     .service(create_user_controller)
+                    .service(get_all_users_controller)
                     .service(get_user_by_id_controller)
                     .service(update_user_controller)
                     .service(delete_user_controller)
                     // Post-Category Relationship Controller
-                    .service(create_posts_categories_controller),
+                    .service(create_posts_categories_controller)
+                    // Post-Tag Relationship Controllers
+                    .service(update_posts_tags_controller)
+                    .service(get_posts_by_tag_controller)
+                    // Role and Capability Controllers
+                    .service(create_role_controller)
+                    .service(get_all_roles_controller)
+                    .service(get_role_by_id_controller)
+                    .service(update_role_controller)
+                    .service(delete_role_controller)
+                    .service(get_all_capabilities_controller)
+                    // User-Role Relationship Controllers
+                    .service(create_users_roles_controller)
+                    .service(delete_users_roles_controller),
             ),
     );
 }
@@ -1,14 +1,30 @@
 use anyhow::{anyhow, Result};
 use argon2::{self, Argon2, PasswordHash, PasswordVerifier};
+use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::{encode, EncodingKey, Header};
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha256};
 use sqlx::PgPool;
 
 use crate::{
     config::config::get_secret_key,
     dtos::auth_dtos::{ClaimsDTO, TokenDTO},
-    repositories::users_repository::select_user_by_email,
+    models::refresh_tokens_model::RefreshTokenModel,
+    repositories::{
+        refresh_tokens_repository::{
+            insert_refresh_token, revoke_refresh_token, rotate_refresh_token,
+        },
+        revoked_tokens_repository::insert_revoked_token,
+        users_repository::{select_user_by_email, select_user_by_id},
+    },
 };
 
+/// Lifetime of an access token.
+const ACCESS_TOKEN_TTL: Duration = Duration::minutes(15);
+
+/// Lifetime of a refresh token.
+const REFRESH_TOKEN_TTL: Duration = Duration::days(30);
+
 pub async fn login_service(
     pool: &PgPool,
     email: &str,
@@ -33,34 +49,133 @@ pub async fn login_service(
 
     match argon2.verify_password(password.as_bytes(), &parsed_hash) {
         Ok(_) => {
-            let claims = ClaimsDTO {
-                sub: user
-                    .id
-                    .expect("L'utilisateur doit avoir un ID")
-                    .to_string(),
-                exp: chrono::Utc::now()
-                    .checked_add_signed(chrono::Duration::hours(24))
-                    .expect("Erreur lors de la création du token")
-                    .timestamp() as usize,
-            };
+            let user_id = user.id.expect("L'utilisateur doit avoir un ID");
 
-            let token = encode(
-                &Header::default(),
-                &claims,
-                &EncodingKey::from_secret(get_secret_key().as_ref()),
-            )
-            .map_err(|e| {
-                anyhow!("Erreur lors de la génération du jeton: {}", e)
-            })?;
-
-            let token_dto = TokenDTO {
-                token,
-                token_type: "Bearer".to_string(),
-                expires_in: 3600,
+            let refresh_token = generate_random_token();
+            let refresh_token_model = RefreshTokenModel {
+                id: None,
+                user_id,
+                token_hash: hash_token(&refresh_token),
+                expires_at: (Utc::now() + REFRESH_TOKEN_TTL).naive_utc(),
+                revoked_at: None,
+                date_created: None,
             };
+            insert_refresh_token(pool, refresh_token_model).await?;
 
+            let token_dto = build_token_dto(user_id, refresh_token)?;
             Ok(Some(token_dto))
         }
         Err(_) => Ok(None),
     }
 }
+
+/// Service to exchange a refresh token for a new token pair.
+///
+/// The presented refresh token is revoked and replaced (rotation).
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `refresh_token` - The refresh token presented by the client.
+///
+/// # Returns
+///
+/// * `Result<Option<TokenDTO>>` - The new tokens, `None` if the refresh token
+///   is invalid or its owner is disabled.
+pub async fn refresh_service(
+    pool: &PgPool,
+    refresh_token: &str,
+) -> Result<Option<TokenDTO>> {
+    let new_refresh_token = generate_random_token();
+    let new_expires_at = (Utc::now() + REFRESH_TOKEN_TTL).naive_utc();
+
+    let user_id = match rotate_refresh_token(
+        pool,
+        &hash_token(refresh_token),
+        &hash_token(&new_refresh_token),
+        new_expires_at,
+    )
+    .await?
+    {
+        Some(user_id) => user_id,
+        None => return Ok(None),
+    };
+
+    let user = select_user_by_id(pool, user_id).await?;
+    if !user.active {
+        revoke_refresh_token(pool, user_id, &hash_token(&new_refresh_token))
+            .await?;
+        return Ok(None);
+    }
+
+    let token_dto = build_token_dto(user_id, new_refresh_token)?;
+    Ok(Some(token_dto))
+}
+
+/// Service to revoke the current access token and, optionally, a refresh token.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `claims` - The claims of the access token used for the request.
+/// * `refresh_token` - The refresh token to revoke, if any.
+///
+/// # Returns
+///
+/// * `Result<()>` - Success or failure of the operation.
+pub async fn logout_service(
+    pool: &PgPool,
+    claims: &ClaimsDTO,
+    refresh_token: Option<&str>,
+) -> Result<()> {
+    let user_id = claims
+        .user_id()
+        .ok_or_else(|| anyhow!("Invalid token subject"))?;
+
+    let expires_at = DateTime::from_timestamp(claims.exp as i64, 0)
+        .ok_or_else(|| anyhow!("Invalid token expiration"))?
+        .naive_utc();
+    insert_revoked_token(pool, &claims.jti, user_id, expires_at).await?;
+
+    if let Some(refresh_token) = refresh_token {
+        revoke_refresh_token(pool, user_id, &hash_token(refresh_token)).await?;
+    }
+
+    Ok(())
+}
+
+/// Signs a new access token and pairs it with the refresh token.
+fn build_token_dto(user_id: i32, refresh_token: String) -> Result<TokenDTO> {
+    let claims = ClaimsDTO {
+        sub: user_id.to_string(),
+        exp: (Utc::now() + ACCESS_TOKEN_TTL).timestamp() as usize,
+        jti: generate_random_token(),
+    };
+
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(get_secret_key().as_ref()),
+    )
+    .map_err(|e| anyhow!("Erreur lors de la génération du jeton: {}", e))?;
+
+    Ok(TokenDTO {
+        token,
+        token_type: "Bearer".to_string(),
+        expires_in: ACCESS_TOKEN_TTL.num_seconds() as usize,
+        refresh_token,
+        refresh_expires_in: REFRESH_TOKEN_TTL.num_seconds() as usize,
+    })
+}
+
+/// Generates a random 256 bits token, hex encoded.
+fn generate_random_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// Hashes a token with SHA-256 before it is stored or looked up.
+fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::dtos::capability_dto::CapabilityDTO;
+use crate::repositories::capabilities_repository::select_capabilities;
+
+/// Service to retrieve all capabilities from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<Vec<CapabilityDTO>>` - A vector containing the `CapabilityDTO` records.
+pub async fn get_all_capabilities_service(
+    pool: &PgPool,
+) -> Result<Vec<CapabilityDTO>> {
+    let capabilities_model = select_capabilities(pool).await?;
+    let result = capabilities_model
+        .into_iter()
+        .map(CapabilityDTO::from)
+        .collect();
+    Ok(result)
+}
@@ -1,22 +1,30 @@
+use std::collections::HashMap;
+
 use anyhow::Result;
 use sqlx::PgPool;
 use validator::Validate;
 
 use crate::dtos::category_dto::{
-    CategoryDTO, CreateCategoryDTO, DeleteCategoryIdsDTO,
+    CategoryDTO, CategoryFiltersDTO, CategoryTreeDTO, CreateCategoryDTO,
+    DeleteCategoryIdsDTO,
 };
 use crate::dtos::pagination_dto::PaginationDTO;
 use crate::handlers::generate_slug_handler::generate_slug;
-use crate::models::categories_model::CategoryModel;
+use crate::models::categories_model::{CategoryModel, CategoryNodeModel};
+use crate::models::slugs_model::SlugType;
 use crate::repositories::categories_repository::{
     count_categories, delete_category_by_id, insert_category,
-    select_categories, select_category_by_id, update_category,
+    lock_category_ancestor_ids, select_categories, select_categories_tree,
+    select_category_ancestors, select_category_by_id, update_category,
 };
 
-use super::calculate_pagination;
+use super::slugs_service::{record_slug_change, unique_slug};
+use super::{calculate_pagination, validate_parent};
 
 /// Service to create a new category.
 ///
+/// A slug made from the name is suffixed when another category has it.
+///
 /// # Arguments
 ///
 /// * `pool` - A reference to the Postgres connection pool.
@@ -29,6 +37,7 @@ pub async fn create_category_service(
     pool: &PgPool,
     create_category_dto: CreateCategoryDTO,
 ) -> Result<CategoryDTO> {
+    let explicit_slug = create_category_dto.slug.is_some();
     let mut category_model: CategoryModel = create_category_dto.try_into()?;
 
     if category_model.slug.is_none() {
@@ -36,13 +45,30 @@ pub async fn create_category_service(
     }
     category_model.validate()?;
 
-    let create_category_model = insert_category(pool, category_model).await?;
+    let mut tx = pool.begin().await?;
+    if let Some(parent_id) = category_model.parent_id {
+        let ancestor_ids =
+            lock_category_ancestor_ids(&mut tx, None, parent_id).await?;
+        validate_parent("category", None, &ancestor_ids)?;
+    }
+    let slug = category_model.slug.take().unwrap_or_default();
+    category_model.slug = Some(
+        unique_slug(&mut tx, SlugType::Category, &slug, None, explicit_slug)
+            .await?,
+    );
+    let create_category_model =
+        insert_category(&mut tx, category_model).await?;
+    tx.commit().await?;
+
     let result = CategoryDTO::from(create_category_model);
     Ok(result)
 }
 
 /// Service to update an existing category by its ID.
 ///
+/// A slug made from the name is suffixed when another category has it, and
+/// a former slug is recorded to redirect the old permalink.
+///
 /// # Arguments
 ///
 /// * `pool` - A reference to the Postgres connection pool.
@@ -57,6 +83,7 @@ pub async fn update_category_service(
     id: i32,
     category_dto: CreateCategoryDTO,
 ) -> Result<CategoryDTO> {
+    let explicit_slug = category_dto.slug.is_some();
     let mut category_model: CategoryModel = category_dto.try_into()?;
     category_model.id = Some(id);
 
@@ -65,8 +92,25 @@ pub async fn update_category_service(
     }
     category_model.validate()?;
 
+    let former_category = select_category_by_id(pool, id).await?;
+    let mut tx = pool.begin().await?;
+    if let Some(parent_id) = category_model.parent_id {
+        let ancestor_ids =
+            lock_category_ancestor_ids(&mut tx, Some(id), parent_id).await?;
+        validate_parent("category", Some(id), &ancestor_ids)?;
+    }
+    let slug = category_model.slug.take().unwrap_or_default();
+    let slug =
+        unique_slug(&mut tx, SlugType::Category, &slug, Some(id), explicit_slug)
+            .await?;
+    let former_slug = former_category.slug.unwrap_or_default();
+    record_slug_change(&mut tx, SlugType::Category, id, &former_slug, &slug)
+        .await?;
+    category_model.slug = Some(slug);
     let update_category_model =
-        update_category(pool, id, category_model).await?;
+        update_category(&mut tx, id, category_model).await?;
+    tx.commit().await?;
+
     let result = CategoryDTO::from(update_category_model);
     Ok(result)
 }
@@ -76,6 +120,7 @@ pub async fn update_category_service(
 /// # Arguments
 ///
 /// * `pool` - A reference to the Postgres connection pool.
+/// * `filters` - The filters the categories must match.
 /// * `page` - The current page number for pagination.
 /// * `limit` - The number of items per page.
 /// * `sort_column` - The column name to sort the results by.
@@ -86,16 +131,18 @@ pub async fn update_category_service(
 /// Returns a `PaginationDTO` containing paginated category data.
 pub async fn get_all_categories_service(
     pool: &PgPool,
+    filters: &CategoryFiltersDTO,
     page: i64,
     limit: i64,
     sort_column: &str,
     sort_order: &str,
 ) -> Result<PaginationDTO<CategoryDTO>> {
-    let total_items = count_categories(pool).await?;
+    let total_items = count_categories(pool, filters).await?;
     let pagination = calculate_pagination(total_items, page, limit);
 
     let category_model: Vec<CategoryModel> = select_categories(
         pool,
+        filters,
         limit,
         pagination.offset,
         sort_column,
@@ -135,6 +182,9 @@ pub async fn get_category_by_id_service(
 
 /// Service to delete categories by a list of IDs.
 ///
+/// The sub-categories of a deleted category are moved to its parent, or to
+/// the closest of its ancestors which is not deleted.
+///
 /// # Arguments
 ///
 /// * `pool` - A reference to the Postgres connection pool.
@@ -147,7 +197,83 @@ pub async fn delete_category_service(
     pool: &PgPool,
     delete_category_ids_dto: DeleteCategoryIdsDTO,
 ) -> Result<Vec<i32>> {
+    let mut tx = pool.begin().await?;
     let deleted_ids =
-        delete_category_by_id(pool, delete_category_ids_dto.ids).await?;
+        delete_category_by_id(&mut tx, delete_category_ids_dto.ids).await?;
+    tx.commit().await?;
+
     Ok(deleted_ids)
 }
+
+/// Service to retrieve the tree of categories.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the Postgres connection pool.
+///
+/// # Returns
+///
+/// Returns the top-level categories, sorted by name, each with its
+/// sub-categories and the number of its posts.
+pub async fn get_categories_tree_service(
+    pool: &PgPool,
+) -> Result<Vec<CategoryTreeDTO>> {
+    let mut categories_by_parent: HashMap<Option<i32>, Vec<CategoryNodeModel>> =
+        HashMap::new();
+    for category in select_categories_tree(pool).await? {
+        categories_by_parent
+            .entry(category.parent_id)
+            .or_default()
+            .push(category);
+    }
+
+    Ok(build_categories_tree(&mut categories_by_parent, None))
+}
+
+/// Service to retrieve the breadcrumb path of a category.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the Postgres connection pool.
+/// * `id` - The ID of the category.
+///
+/// # Returns
+///
+/// Returns the ancestors of the category, from its top-level category,
+/// followed by the category itself.
+pub async fn get_category_path_service(
+    pool: &PgPool,
+    id: i32,
+) -> Result<Vec<CategoryDTO>> {
+    let categories = select_category_ancestors(pool, id).await?;
+    if categories.is_empty() {
+        return Err(sqlx::Error::RowNotFound.into());
+    }
+
+    Ok(categories.into_iter().map(CategoryDTO::from).collect())
+}
+
+/// Builds the tree of the categories under a parent, in the order they were
+/// selected in.
+fn build_categories_tree(
+    categories_by_parent: &mut HashMap<Option<i32>, Vec<CategoryNodeModel>>,
+    parent_id: Option<i32>,
+) -> Vec<CategoryTreeDTO> {
+    categories_by_parent
+        .remove(&parent_id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|category| CategoryTreeDTO {
+            children: build_categories_tree(
+                categories_by_parent,
+                Some(category.id),
+            ),
+            id: category.id,
+            parent_id: category.parent_id,
+            name: category.name,
+            slug: category.slug,
+            description: category.description,
+            post_count: category.post_count,
+        })
+        .collect()
+}
@@ -0,0 +1,264 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::{
+    dtos::{
+        auth_dtos::CurrentUserDTO,
+        comment_dto::{
+            CommentDTO, CommentFiltersDTO, CreateCommentDTO,
+            DeleteCommentIdsDTO, ReplyCommentDTO, UpdateCommentsStatusDTO,
+        },
+        pagination_dto::PaginationDTO,
+    },
+    handlers::error_to_response_handler::ForbiddenError,
+    models::{
+        comments_model::{CommentsStatus, NewCommentModel},
+        posts_model::PostsStatus,
+    },
+    repositories::{
+        comments_repository::{
+            count_comments, delete_comments_by_ids, insert_comment,
+            select_comment_by_id, select_comments, update_comments_status,
+        },
+        posts_repository::select_post_by_id,
+        users_repository::select_user_by_id,
+    },
+};
+
+use super::calculate_pagination;
+use super::settings_service::get_boolean_setting;
+
+/// Service to add the comment of a reader to a published post.
+///
+/// The comment is held for moderation, unless the `comment_moderation`
+/// setting is off.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `post_id` - The ID of the commented post.
+/// * `create_comment_dto` - Data Transfer Object containing the comment.
+///
+/// # Returns
+///
+/// Returns the new comment, `RowNotFound` if the post is not published, a
+/// `ForbiddenError` if the `comments_enabled` setting is off, or a
+/// `ValidationErrors` if the replied comment is not an approved comment of
+/// the post.
+pub async fn create_comment_service(
+    pool: &PgPool,
+    post_id: i32,
+    create_comment_dto: CreateCommentDTO,
+) -> Result<CommentDTO> {
+    create_comment_dto.validate()?;
+
+    if !get_boolean_setting(pool, "comments_enabled").await? {
+        return Err(ForbiddenError {
+            message: "Comments are closed".to_string(),
+        }
+        .into());
+    }
+
+    let post = select_post_by_id(pool, post_id).await?;
+    if post.status != PostsStatus::Published {
+        return Err(sqlx::Error::RowNotFound.into());
+    }
+    if let Some(parent_id) = create_comment_dto.parent_id {
+        validate_parent(pool, post_id, parent_id, true).await?;
+    }
+    let status = if get_boolean_setting(pool, "comment_moderation").await? {
+        CommentsStatus::Pending
+    } else {
+        CommentsStatus::Approved
+    };
+
+    let comment = insert_comment(
+        pool,
+        NewCommentModel {
+            post_id,
+            parent_id: create_comment_dto.parent_id,
+            user_id: None,
+            author_name: create_comment_dto.author_name,
+            author_email: create_comment_dto.author_email,
+            content: create_comment_dto.content,
+            status,
+        },
+    )
+    .await?;
+
+    Ok(comment.into())
+}
+
+/// Service to reply to the comments of a post from the administration.
+///
+/// The reply is approved at once, the name and email of its author being
+/// taken from the account of the current user.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user replying.
+/// * `reply_comment_dto` - Data Transfer Object containing the reply.
+///
+/// # Returns
+///
+/// Returns the new comment, `RowNotFound` if the post does not exist, or a
+/// `ValidationErrors` if the replied comment does not belong to the post.
+pub async fn reply_comment_service(
+    pool: &PgPool,
+    current_user: &CurrentUserDTO,
+    reply_comment_dto: ReplyCommentDTO,
+) -> Result<CommentDTO> {
+    reply_comment_dto.validate()?;
+
+    // Replies may be left on any post, published or not
+    select_post_by_id(pool, reply_comment_dto.post_id).await?;
+    if let Some(parent_id) = reply_comment_dto.parent_id {
+        validate_parent(pool, reply_comment_dto.post_id, parent_id, false)
+            .await?;
+    }
+    let user = select_user_by_id(pool, current_user.id).await?;
+
+    let comment = insert_comment(
+        pool,
+        NewCommentModel {
+            post_id: reply_comment_dto.post_id,
+            parent_id: reply_comment_dto.parent_id,
+            user_id: Some(current_user.id),
+            author_name: format!("{} {}", user.firstname, user.lastname),
+            author_email: user.email,
+            content: reply_comment_dto.content,
+            status: CommentsStatus::Approved,
+        },
+    )
+    .await?;
+
+    Ok(comment.into())
+}
+
+/// Service to retrieve the comments with pagination, sorting and filters.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `filters` - The filters the comments must match.
+/// * `page` - The current page number for pagination.
+/// * `limit` - The number of comments per page.
+/// * `sort_column` - The column name to sort by.
+/// * `sort_order` - The order of sorting, either `asc` or `desc`.
+///
+/// # Returns
+///
+/// Returns a `PaginationDTO<CommentDTO>` containing the page of comments.
+pub async fn get_all_comments_service(
+    pool: &PgPool,
+    filters: &CommentFiltersDTO,
+    page: i64,
+    limit: i64,
+    sort_column: &str,
+    sort_order: &str,
+) -> Result<PaginationDTO<CommentDTO>> {
+    let total_items = count_comments(pool, filters).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let comments_model = select_comments(
+        pool,
+        filters,
+        limit,
+        pagination.offset,
+        sort_column,
+        sort_order,
+    )
+    .await?;
+
+    let comments_dto: Vec<CommentDTO> =
+        comments_model.into_iter().map(CommentDTO::from).collect();
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: comments_dto,
+    })
+}
+
+/// Service to approve, hold or discard comments.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `update_status_dto` - The IDs of the comments and their new status.
+///
+/// # Returns
+///
+/// Returns the IDs of the updated comments.
+pub async fn update_comments_status_service(
+    pool: &PgPool,
+    update_status_dto: UpdateCommentsStatusDTO,
+) -> Result<Vec<i32>> {
+    let updated_ids = update_comments_status(
+        pool,
+        &update_status_dto.ids,
+        update_status_dto.status,
+    )
+    .await?;
+
+    Ok(updated_ids)
+}
+
+/// Service to delete comments, with their replies.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `delete_comment_ids_dto` - Data Transfer Object containing the IDs of
+///   the comments to delete.
+///
+/// # Returns
+///
+/// Returns the IDs of the deleted comments.
+pub async fn delete_comments_service(
+    pool: &PgPool,
+    delete_comment_ids_dto: DeleteCommentIdsDTO,
+) -> Result<Vec<i32>> {
+    let deleted_ids =
+        delete_comments_by_ids(pool, delete_comment_ids_dto.ids).await?;
+
+    Ok(deleted_ids)
+}
+
+/// Checks that a replied comment belongs to the post, and is public when
+/// `approved_only` is set.
+///
+/// # Returns
+///
+/// Returns a `ValidationErrors` on `parent_id` otherwise.
+async fn validate_parent(
+    pool: &PgPool,
+    post_id: i32,
+    parent_id: i32,
+    approved_only: bool,
+) -> Result<()> {
+    let parent = match select_comment_by_id(pool, parent_id).await {
+        Ok(parent) => Some(parent),
+        Err(e) => match e.downcast_ref::<sqlx::Error>() {
+            Some(sqlx::Error::RowNotFound) => None,
+            _ => return Err(e),
+        },
+    };
+
+    let is_valid = parent.is_some_and(|parent| {
+        parent.post_id == post_id
+            && (!approved_only || parent.status == CommentsStatus::Approved)
+    });
+    if !is_valid {
+        let mut error = ValidationError::new("unknown_parent");
+        error.message = Some("Unknown comment in parent_id".into());
+        let mut errors = ValidationErrors::new();
+        errors.add("parent_id", error);
+        return Err(errors.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,359 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use chrono::Utc;
+use log::warn;
+use sqlx::PgPool;
+use uuid::Uuid;
+use validator::{ValidationError, ValidationErrors};
+
+use crate::{
+    dtos::{
+        auth_dtos::CurrentUserDTO,
+        media_dto::{
+            DeleteMediaIdsDTO, MediaDTO, RegenerateMediaDTO, UploadMediaDTO,
+        },
+        pagination_dto::PaginationDTO,
+    },
+    handlers::image_handler::{resize_image, ImageSize},
+    models::{
+        capabilities_model::Capability,
+        media_model::{MediaImageModel, MediaSizeModel},
+    },
+    repositories::media_repository::{
+        count_media, delete_media_by_ids, insert_media, select_images,
+        select_media, select_media_author_ids, select_media_by_id,
+        update_media_image,
+    },
+    storage::StorageBackend,
+    validators::media_validator::{validate_media_type, IMAGE_MIME_TYPES},
+};
+
+use super::calculate_pagination;
+
+/// Maximum length of the original name of a file.
+const FILENAME_MAX_LENGTH: usize = 255;
+
+/// Service to add an uploaded file to the media library.
+///
+/// The type of the file is sniffed from its content, the file is saved in
+/// the storage under a random name, grouped by year and month. Images are
+/// resized to each image size, the copies being saved next to the original.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `storage` - The storage the file is saved in.
+/// * `sizes` - The sizes the images are resized to.
+/// * `current_user` - The authenticated user uploading the file.
+/// * `upload` - The uploaded file.
+///
+/// # Returns
+///
+/// Returns the new media, or a `ValidationErrors` if the type of the file is
+/// not allowed or the image cannot be decoded.
+pub async fn upload_media_service(
+    pool: &PgPool,
+    storage: &dyn StorageBackend,
+    sizes: &[ImageSize],
+    current_user: &CurrentUserDTO,
+    upload: UploadMediaDTO,
+) -> Result<MediaDTO> {
+    let (mime_type, extension) =
+        validate_media_type(&upload.data).map_err(|error| {
+            let mut errors = ValidationErrors::new();
+            errors.add("file", error);
+            errors
+        })?;
+
+    let filename = sanitize_filename(&upload.filename);
+    let path = format!(
+        "{}/{}.{}",
+        Utc::now().format("%Y/%m"),
+        Uuid::new_v4(),
+        extension
+    );
+
+    let size = upload.data.len() as i64;
+    storage.save(&path, &upload.data).await?;
+
+    let image = if IMAGE_MIME_TYPES.contains(&mime_type) {
+        match resize_and_save(storage, &path, upload.data, sizes).await {
+            Ok(image) => Some(image),
+            Err(e) => {
+                delete_files(storage, [path.as_str()]).await;
+                return Err(e);
+            }
+        }
+    } else {
+        None
+    };
+
+    let media = match insert_media(
+        pool,
+        current_user.id,
+        &filename,
+        &path,
+        mime_type,
+        size,
+        image.as_ref(),
+    )
+    .await
+    {
+        Ok(media) => media,
+        Err(e) => {
+            let sizes_paths = image
+                .iter()
+                .flat_map(|image| image.sizes.values())
+                .map(|size| size.path.as_str());
+            delete_files(storage, sizes_paths.chain([path.as_str()])).await;
+            return Err(e);
+        }
+    };
+
+    Ok(MediaDTO::from_model(media, storage))
+}
+
+/// Service to retrieve the media library with pagination and sorting.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `storage` - The storage the files are saved in.
+/// * `page` - The current page number for pagination.
+/// * `limit` - The number of media per page.
+/// * `sort_column` - The column name to sort by.
+/// * `sort_order` - The order of sorting, either `asc` or `desc`.
+///
+/// # Returns
+///
+/// Returns a `PaginationDTO<MediaDTO>` containing the page of media.
+pub async fn get_all_media_service(
+    pool: &PgPool,
+    storage: &dyn StorageBackend,
+    page: i64,
+    limit: i64,
+    sort_column: &str,
+    sort_order: &str,
+) -> Result<PaginationDTO<MediaDTO>> {
+    let total_items = count_media(pool).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let media_model =
+        select_media(pool, limit, pagination.offset, sort_column, sort_order)
+            .await?;
+
+    let media_dto: Vec<MediaDTO> = media_model
+        .into_iter()
+        .map(|media| MediaDTO::from_model(media, storage))
+        .collect();
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: media_dto,
+    })
+}
+
+/// Service to retrieve a media by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `storage` - The storage the file is saved in.
+/// * `id` - The ID of the media.
+///
+/// # Returns
+///
+/// Returns the media, or `RowNotFound` if it does not exist.
+pub async fn get_media_by_id_service(
+    pool: &PgPool,
+    storage: &dyn StorageBackend,
+    id: i32,
+) -> Result<MediaDTO> {
+    let media = select_media_by_id(pool, id).await?;
+    Ok(MediaDTO::from_model(media, storage))
+}
+
+/// Service to delete media and their files.
+///
+/// Deleting the media of other users requires `DeleteOthersPosts`. The
+/// posts featuring a deleted media lose their featured image.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `storage` - The storage the files are saved in.
+/// * `current_user` - The authenticated user deleting the media.
+/// * `delete_media_ids_dto` - The IDs of the media to delete.
+///
+/// # Returns
+///
+/// Returns the IDs of the deleted media.
+pub async fn delete_media_service(
+    pool: &PgPool,
+    storage: &dyn StorageBackend,
+    current_user: &CurrentUserDTO,
+    delete_media_ids_dto: DeleteMediaIdsDTO,
+) -> Result<Vec<i32>> {
+    let authors_ids =
+        select_media_author_ids(pool, &delete_media_ids_dto.ids).await?;
+    if authors_ids
+        .iter()
+        .any(|&author_id| author_id != current_user.id)
+    {
+        current_user.require(Capability::DeleteOthersPosts)?;
+    }
+
+    let deleted_media =
+        delete_media_by_ids(pool, &delete_media_ids_dto.ids).await?;
+
+    // The rows are gone, a file left behind is only logged
+    let paths = deleted_media.iter().flat_map(|media| {
+        media
+            .sizes
+            .values()
+            .map(|size| size.path.as_str())
+            .chain([media.path.as_str()])
+    });
+    delete_files(storage, paths).await;
+
+    Ok(deleted_media.into_iter().map(|media| media.id).collect())
+}
+
+/// Service to resize the images again, after the image sizes changed.
+///
+/// The copies of the sizes no longer defined, or whose dimensions changed,
+/// are deleted.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `storage` - The storage the files are saved in.
+/// * `sizes` - The sizes the images are resized to.
+/// * `regenerate_media_dto` - The IDs of the images, all the images when
+///   omitted.
+///
+/// # Returns
+///
+/// Returns the regenerated images, the other media being skipped.
+pub async fn regenerate_media_sizes_service(
+    pool: &PgPool,
+    storage: &dyn StorageBackend,
+    sizes: &[ImageSize],
+    regenerate_media_dto: RegenerateMediaDTO,
+) -> Result<Vec<MediaDTO>> {
+    let images = select_images(
+        pool,
+        IMAGE_MIME_TYPES,
+        regenerate_media_dto.ids.as_deref(),
+    )
+    .await?;
+
+    let mut regenerated = Vec::with_capacity(images.len());
+    for media in images {
+        let data = storage.read(&media.path).await?;
+        let image = resize_and_save(storage, &media.path, data, sizes).await?;
+        let updated = update_media_image(pool, media.id, &image).await?;
+
+        let stale_paths =
+            media.sizes.values().map(|size| size.path.as_str()).filter(
+                |&path| !image.sizes.values().any(|size| size.path == path),
+            );
+        delete_files(storage, stale_paths).await;
+
+        regenerated.push(MediaDTO::from_model(updated, storage));
+    }
+
+    Ok(regenerated)
+}
+
+/// Resizes an image to each size and saves the copies next to it, named
+/// after their dimensions such as `2024/10/0b5c0f2e-150x150.png`, the sizes
+/// of the same dimensions sharing their copy.
+///
+/// # Returns
+///
+/// Returns the dimensions of the image and its copies, or a
+/// `ValidationErrors` if the image cannot be decoded.
+async fn resize_and_save(
+    storage: &dyn StorageBackend,
+    path: &str,
+    data: Vec<u8>,
+    sizes: &[ImageSize],
+) -> Result<MediaImageModel> {
+    let sizes = sizes.to_vec();
+    let resized =
+        tokio::task::spawn_blocking(move || resize_image(&data, &sizes))
+            .await?
+            .map_err(|e| {
+                let mut error = ValidationError::new("invalid_image");
+                error.message = Some(format!("Invalid image: {}", e).into());
+                let mut errors = ValidationErrors::new();
+                errors.add("file", error);
+                errors
+            })?;
+
+    let (stem, extension) = path.rsplit_once('.').unwrap_or((path, ""));
+    let mut image = MediaImageModel {
+        width: resized.width as i32,
+        height: resized.height as i32,
+        sizes: Default::default(),
+    };
+    for derivative in resized.derivatives {
+        let size = MediaSizeModel {
+            path: format!(
+                "{}-{}x{}.{}",
+                stem, derivative.width, derivative.height, extension
+            ),
+            width: derivative.width,
+            height: derivative.height,
+        };
+        // Sizes of the same dimensions resize the image alike, and share
+        // the copy saved for the first of them
+        let saved = image.sizes.values().any(|s| s.path == size.path);
+        if saved {
+            image.sizes.insert(derivative.name, size);
+            continue;
+        }
+        if let Err(e) = storage.save(&size.path, &derivative.data).await {
+            delete_files(
+                storage,
+                image.sizes.values().map(|s| s.path.as_str()),
+            )
+            .await;
+            return Err(e);
+        }
+        image.sizes.insert(derivative.name, size);
+    }
+
+    Ok(image)
+}
+
+/// Deletes files from the storage, a file left behind being only logged.
+///
+/// A file shared by several sizes is deleted once.
+async fn delete_files<'a>(
+    storage: &dyn StorageBackend,
+    paths: impl IntoIterator<Item = &'a str>,
+) {
+    let paths: BTreeSet<&str> = paths.into_iter().collect();
+    for path in paths {
+        if let Err(e) = storage.delete(path).await {
+            warn!("Failed to delete file {}: {}", path, e);
+        }
+    }
+}
+
+/// Keeps the base name of an uploaded file, browsers may send a full path.
+fn sanitize_filename(filename: &str) -> String {
+    let name = filename
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .trim();
+    let name = if name.is_empty() { "file" } else { name };
+
+    name.chars().take(FILENAME_MAX_LENGTH).collect()
+}
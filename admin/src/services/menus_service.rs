@@ -0,0 +1,528 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::Result;
+use sqlx::PgPool;
+use validator::{ValidateUrl, ValidationError, ValidationErrors};
+
+use crate::{
+    dtos::menu_dto::{
+        CreateMenuDTO, DeleteMenuIdsDTO, MenuDTO, MenuItemDTO,
+        MenuItemInputDTO, MenuLocationsDTO, MenuTreeDTO, PublicMenuDTO,
+        PublicMenuItemDTO, UpdateMenuItemsDTO,
+    },
+    models::{
+        menus_model::{
+            MenuItemModel, MenuItemsType, MenuLocationModel, MenuModel,
+            NewMenuItemModel,
+        },
+        posts_model::PostsStatus,
+    },
+    repositories::menus_repository::{
+        delete_menu_items, delete_menus_by_ids, insert_menu, insert_menu_item,
+        replace_menu_locations, select_menu_by_id, select_menu_items,
+        select_menu_locations, select_menus, select_unknown_objects,
+        update_menu,
+    },
+    validators::slug_validator::validate_slug,
+};
+
+/// Service to create a menu, without items.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `create_menu_dto` - The name and slug of the menu.
+///
+/// # Returns
+///
+/// * `Result<MenuDTO>` - The created menu.
+pub async fn create_menu_service(
+    pool: &PgPool,
+    create_menu_dto: CreateMenuDTO,
+) -> Result<MenuDTO> {
+    let menu_model: MenuModel = create_menu_dto.try_into()?;
+
+    let menu_model = insert_menu(pool, menu_model).await?;
+    Ok(MenuDTO::from(menu_model))
+}
+
+/// Service to rename a menu by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the menu to update.
+/// * `update_menu_dto` - The new name and slug of the menu.
+///
+/// # Returns
+///
+/// * `Result<MenuDTO>` - The updated menu.
+pub async fn update_menu_service(
+    pool: &PgPool,
+    id: i32,
+    update_menu_dto: CreateMenuDTO,
+) -> Result<MenuDTO> {
+    let menu_model: MenuModel = update_menu_dto.try_into()?;
+
+    let menu_model = update_menu(pool, id, menu_model).await?;
+    Ok(MenuDTO::from(menu_model))
+}
+
+/// Service to retrieve every menu, without their items.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<Vec<MenuDTO>>` - The menus, sorted by name.
+pub async fn get_all_menus_service(pool: &PgPool) -> Result<Vec<MenuDTO>> {
+    let menus = select_menus(pool).await?;
+
+    Ok(menus.into_iter().map(MenuDTO::from).collect())
+}
+
+/// Service to retrieve a menu by its ID, with the tree of its items.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the menu to retrieve.
+///
+/// # Returns
+///
+/// * `Result<MenuTreeDTO>` - The menu and its items.
+pub async fn get_menu_by_id_service(
+    pool: &PgPool,
+    id: i32,
+) -> Result<MenuTreeDTO> {
+    let menu = select_menu_by_id(pool, id).await?;
+    let items = select_menu_items(pool, &[id]).await?;
+
+    Ok(MenuTreeDTO {
+        id,
+        name: menu.name,
+        slug: menu.slug.unwrap_or_default(),
+        items: build_items_tree(&items_by_parent(items), None),
+    })
+}
+
+/// Service to replace the items of a menu.
+///
+/// Every item is checked before any of them is stored, the errors telling
+/// the position of the item in the tree, e.g. `items[0].children[1]`.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the menu.
+/// * `update_menu_items_dto` - The new tree of items.
+///
+/// # Returns
+///
+/// * `Result<MenuTreeDTO>` - The menu and its new items, or a
+///   `ValidationErrors` under `items` if an item is invalid.
+pub async fn update_menu_items_service(
+    pool: &PgPool,
+    id: i32,
+    update_menu_items_dto: UpdateMenuItemsDTO,
+) -> Result<MenuTreeDTO> {
+    select_menu_by_id(pool, id).await?;
+
+    let mut flat_items = Vec::new();
+    flatten_items(update_menu_items_dto.items, None, "items", &mut flat_items);
+    validate_items(pool, &flat_items).await?;
+
+    let mut tx = pool.begin().await?;
+    delete_menu_items(&mut tx, id).await?;
+    let mut inserted_ids: Vec<i32> = Vec::with_capacity(flat_items.len());
+    for flat_item in flat_items {
+        let item_id = insert_menu_item(
+            &mut tx,
+            NewMenuItemModel {
+                menu_id: id,
+                parent_id: flat_item.parent.map(|parent| inserted_ids[parent]),
+                item_type: flat_item.item_type,
+                object_id: flat_item.object_id,
+                url: flat_item.url,
+                title: flat_item.title,
+                menu_order: flat_item.menu_order,
+            },
+        )
+        .await?;
+        inserted_ids.push(item_id);
+    }
+    tx.commit().await?;
+
+    get_menu_by_id_service(pool, id).await
+}
+
+/// Service to delete menus by their IDs, with their items and locations.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `delete_menu_ids_dto` - The IDs of the menus to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the deleted menus.
+pub async fn delete_menus_service(
+    pool: &PgPool,
+    delete_menu_ids_dto: DeleteMenuIdsDTO,
+) -> Result<Vec<i32>> {
+    let deleted_ids =
+        delete_menus_by_ids(pool, delete_menu_ids_dto.ids).await?;
+    Ok(deleted_ids)
+}
+
+/// Service to retrieve the menus shown at the theme locations.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<MenuLocationsDTO>` - The menu of every location which has one.
+pub async fn get_menu_locations_service(
+    pool: &PgPool,
+) -> Result<MenuLocationsDTO> {
+    let locations = select_menu_locations(pool).await?;
+
+    Ok(MenuLocationsDTO {
+        locations: locations
+            .into_iter()
+            .map(|location| (location.location, location.menu_id))
+            .collect(),
+    })
+}
+
+/// Service to assign the menus to the theme locations, the locations which
+/// are missing being left without a menu.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `menu_locations_dto` - The menu of every location which has one.
+///
+/// # Returns
+///
+/// * `Result<MenuLocationsDTO>` - The new locations, or a `ValidationErrors`
+///   under `locations` if a location or a menu is invalid.
+pub async fn update_menu_locations_service(
+    pool: &PgPool,
+    menu_locations_dto: MenuLocationsDTO,
+) -> Result<MenuLocationsDTO> {
+    let menu_ids: Vec<i32> = select_menus(pool)
+        .await?
+        .into_iter()
+        .filter_map(|menu| menu.id)
+        .collect();
+
+    let mut errors = ValidationErrors::new();
+    for (location, menu_id) in &menu_locations_dto.locations {
+        if let Err(mut error) = validate_slug(location, 1, 100) {
+            error.message = Some(
+                format!("'{}' is not a valid location name", location).into(),
+            );
+            errors.add("locations", error);
+        }
+        if !menu_ids.contains(menu_id) {
+            errors.add(
+                "locations",
+                menu_error(
+                    "unknown_menu",
+                    format!("Unknown menu {} at '{}'", menu_id, location),
+                ),
+            );
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors.into());
+    }
+
+    let mut tx = pool.begin().await?;
+    replace_menu_locations(
+        &mut tx,
+        menu_locations_dto
+            .locations
+            .into_iter()
+            .map(|(location, menu_id)| MenuLocationModel { location, menu_id })
+            .collect(),
+    )
+    .await?;
+    tx.commit().await?;
+
+    get_menu_locations_service(pool).await
+}
+
+/// Service to retrieve the menus of the theme locations, as shown on the
+/// public site.
+///
+/// The items linking to deleted content or to posts and pages which are not
+/// published are left out, with their sub-items.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<BTreeMap<String, PublicMenuDTO>>` - The menu of every location
+///   which has one.
+pub async fn get_public_menus_service(
+    pool: &PgPool,
+) -> Result<BTreeMap<String, PublicMenuDTO>> {
+    let locations = select_menu_locations(pool).await?;
+    if locations.is_empty() {
+        return Ok(BTreeMap::new());
+    }
+
+    let menus: HashMap<i32, MenuModel> = select_menus(pool)
+        .await?
+        .into_iter()
+        .filter_map(|menu| Some((menu.id?, menu)))
+        .collect();
+    let menu_ids: Vec<i32> = menus.keys().copied().collect();
+    let mut items_by_menu: HashMap<i32, Vec<MenuItemModel>> = HashMap::new();
+    for item in select_menu_items(pool, &menu_ids).await? {
+        items_by_menu.entry(item.menu_id).or_default().push(item);
+    }
+
+    let mut public_menus = BTreeMap::new();
+    for location in locations {
+        let Some(menu) = menus.get(&location.menu_id) else {
+            continue;
+        };
+        let items = items_by_menu
+            .get(&location.menu_id)
+            .cloned()
+            .unwrap_or_default();
+        public_menus.insert(
+            location.location,
+            PublicMenuDTO {
+                name: menu.name.clone(),
+                slug: menu.slug.clone().unwrap_or_default(),
+                items: build_public_items_tree(&items_by_parent(items), None),
+            },
+        );
+    }
+
+    Ok(public_menus)
+}
+
+/// Item of a tree of items, flattened with the index of its parent.
+struct FlatMenuItem {
+    path: String,
+    parent: Option<usize>,
+    item_type: MenuItemsType,
+    object_id: Option<i32>,
+    url: Option<String>,
+    title: Option<String>,
+    menu_order: i32,
+}
+
+/// Flattens a tree of items, every parent coming before its children.
+fn flatten_items(
+    items: Vec<MenuItemInputDTO>,
+    parent: Option<usize>,
+    path: &str,
+    flat_items: &mut Vec<FlatMenuItem>,
+) {
+    for (index, item) in items.into_iter().enumerate() {
+        let item_path = format!("{}[{}]", path, index);
+        flat_items.push(FlatMenuItem {
+            path: item_path.clone(),
+            parent,
+            item_type: item.item_type,
+            object_id: item.object_id,
+            url: item.url,
+            title: item.title,
+            menu_order: index as i32,
+        });
+        let item_index = flat_items.len() - 1;
+        flatten_items(
+            item.children,
+            Some(item_index),
+            &format!("{}.children", item_path),
+            flat_items,
+        );
+    }
+}
+
+/// Checks every item, and that the content they link to exists.
+async fn validate_items(
+    pool: &PgPool,
+    flat_items: &[FlatMenuItem],
+) -> Result<()> {
+    let mut errors = ValidationErrors::new();
+    let mut add_error = |code: &'static str, item: &FlatMenuItem, reason| {
+        errors.add(
+            "items",
+            menu_error(code, format!("{}: {}", item.path, reason)),
+        );
+    };
+
+    let mut object_ids: HashMap<MenuItemsType, Vec<i32>> = HashMap::new();
+    for item in flat_items {
+        if let Some(ref title) = item.title {
+            let length = title.chars().count();
+            if !(1..=255).contains(&length) {
+                add_error(
+                    "invalid_length",
+                    item,
+                    "Title must be between 1 and 255 characters",
+                );
+            }
+        }
+        match (item.item_type, item.object_id, &item.url) {
+            (MenuItemsType::Custom, None, Some(url)) => {
+                let web_url = (url.starts_with("http://")
+                    || url.starts_with("https://"))
+                    && url.validate_url();
+                let valid = url.len() <= 2048
+                    && (web_url
+                        || (url.starts_with('/') && !url.starts_with("//")));
+                if !valid {
+                    add_error(
+                        "invalid_url",
+                        item,
+                        "URL must be an HTTP URL or a path starting with '/'",
+                    );
+                }
+                if item.title.is_none() {
+                    add_error(
+                        "missing_title",
+                        item,
+                        "A custom item must have a title",
+                    );
+                }
+            }
+            (MenuItemsType::Custom, _, _) => add_error(
+                "invalid_item",
+                item,
+                "A custom item must have a URL and no object ID",
+            ),
+            (item_type, Some(object_id), None) => {
+                object_ids.entry(item_type).or_default().push(object_id);
+            }
+            (_, _, _) => add_error(
+                "invalid_item",
+                item,
+                "An item must have an object ID and no URL, unless custom",
+            ),
+        }
+    }
+
+    for (item_type, ids) in object_ids {
+        let unknown_ids = select_unknown_objects(pool, item_type, &ids).await?;
+        for item in flat_items.iter().filter(|item| {
+            item.item_type == item_type
+                && item.object_id.is_some_and(|id| unknown_ids.contains(&id))
+        }) {
+            add_error(
+                "unknown_object",
+                item,
+                "The object of the item does not exist",
+            );
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors.into());
+    }
+
+    Ok(())
+}
+
+/// Groups the items of a menu by the ID of their parent, keeping their order.
+fn items_by_parent(
+    items: Vec<MenuItemModel>,
+) -> HashMap<Option<i32>, Vec<MenuItemModel>> {
+    let mut items_by_parent: HashMap<Option<i32>, Vec<MenuItemModel>> =
+        HashMap::new();
+    for item in items {
+        items_by_parent
+            .entry(item.parent_id)
+            .or_default()
+            .push(item);
+    }
+
+    items_by_parent
+}
+
+/// Builds the tree of the items under a parent.
+fn build_items_tree(
+    items_by_parent: &HashMap<Option<i32>, Vec<MenuItemModel>>,
+    parent_id: Option<i32>,
+) -> Vec<MenuItemDTO> {
+    items_by_parent
+        .get(&parent_id)
+        .map(|items| {
+            items
+                .iter()
+                .map(|item| MenuItemDTO {
+                    id: item.id,
+                    item_type: item.item_type,
+                    object_id: item.object_id,
+                    url: item.url.clone(),
+                    title: item.title.clone(),
+                    object_title: item.object_title.clone(),
+                    children: build_items_tree(items_by_parent, Some(item.id)),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Builds the tree of the published items under a parent.
+fn build_public_items_tree(
+    items_by_parent: &HashMap<Option<i32>, Vec<MenuItemModel>>,
+    parent_id: Option<i32>,
+) -> Vec<PublicMenuItemDTO> {
+    items_by_parent
+        .get(&parent_id)
+        .map(|items| {
+            items
+                .iter()
+                .filter(|item| is_published(item))
+                .filter_map(|item| {
+                    Some(PublicMenuItemDTO {
+                        title: item
+                            .title
+                            .clone()
+                            .or_else(|| item.object_title.clone())?,
+                        item_type: item.item_type,
+                        slug: item.object_slug.clone(),
+                        url: item.url.clone(),
+                        children: build_public_items_tree(
+                            items_by_parent,
+                            Some(item.id),
+                        ),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Tells whether the content of an item can be shown on the public site.
+fn is_published(item: &MenuItemModel) -> bool {
+    match item.item_type {
+        MenuItemsType::Custom => true,
+        MenuItemsType::Post | MenuItemsType::Page => {
+            item.object_status == Some(PostsStatus::Published)
+        }
+        MenuItemsType::Category | MenuItemsType::Tag => {
+            item.object_slug.is_some()
+        }
+    }
+}
+
+fn menu_error(code: &'static str, message: String) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.message = Some(message.into());
+    error
+}
@@ -1,10 +1,28 @@
+use anyhow::Result;
+use validator::{ValidationError, ValidationErrors};
+
 use crate::dtos::pagination_dto::PaginationInfo;
 
 pub mod auth_service;
+pub mod capabilities_service;
 pub mod categories_service;
+pub mod comments_service;
+pub mod media_service;
+pub mod menus_service;
+pub mod pages_service;
+pub mod post_autosaves_service;
+pub mod post_revisions_service;
 pub mod posts_categories_service;
 pub mod posts_services;
+pub mod posts_tags_service;
+pub mod public_service;
+pub mod redirects_service;
+pub mod roles_service;
+pub mod settings_service;
+pub mod slugs_service;
 pub mod tags_service;
+pub mod themes_service;
+pub mod users_roles_service;
 pub mod users_service;
 
 /// Calculate pagination information based on the total number of items, current page, and limit.
@@ -39,3 +57,35 @@ pub fn calculate_pagination(
         offset,
     }
 }
+
+/// Checks that the parent of an item of a hierarchy is an existing item, and
+/// is neither the item itself nor one of its descendants.
+///
+/// # Arguments
+///
+/// * `kind` - The kind of item, used in the error message.
+/// * `id` - The ID of the item, `None` when it is being created.
+/// * `ancestor_ids` - The IDs of the parent and of all its ancestors.
+///
+/// # Returns
+///
+/// Returns a `ValidationErrors` on `parent_id` otherwise.
+pub fn validate_parent(
+    kind: &str,
+    id: Option<i32>,
+    ancestor_ids: &[i32],
+) -> Result<()> {
+    let message = if ancestor_ids.is_empty() {
+        format!("Unknown {} in parent_id", kind)
+    } else if id.is_some_and(|id| ancestor_ids.contains(&id)) {
+        format!("A {} cannot be its own ancestor", kind)
+    } else {
+        return Ok(());
+    };
+
+    let mut error = ValidationError::new("invalid_parent");
+    error.message = Some(message.into());
+    let mut errors = ValidationErrors::new();
+    errors.add("parent_id", error);
+    Err(errors.into())
+}
@@ -0,0 +1,205 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::dtos::auth_dtos::CurrentUserDTO;
+use crate::dtos::page_dto::{
+    CreatePageDTO, DeletePageIdsDTO, PageDTO, PageFiltersDTO,
+};
+use crate::dtos::pagination_dto::PaginationDTO;
+use crate::models::pages_model::PageModel;
+use crate::models::slugs_model::SlugType;
+use crate::repositories::pages_repository::{
+    count_pages, delete_pages_by_ids, insert_page, lock_page_ancestor_ids,
+    select_page_by_id, select_pages, update_page,
+};
+use crate::repositories::post_autosaves_repository::delete_post_autosave;
+use crate::repositories::post_revisions_repository::insert_post_revision;
+
+use super::posts_services::authorize_post_write;
+use super::slugs_service::{record_slug_change, unique_slug};
+use super::{calculate_pagination, validate_parent};
+
+/// Service to insert a page into the database.
+///
+/// The page and its first revision are saved in a single transaction, which
+/// discards the autosave of the new page. A slug made from the title is
+/// suffixed when another page has it.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user creating the page.
+/// * `create_page_dto` - Data Transfer Object containing the details for the new page.
+///
+/// # Returns
+///
+/// Returns a `PageDTO` object containing the inserted page details.
+pub async fn create_page_service(
+    pool: &PgPool,
+    current_user: &CurrentUserDTO,
+    create_page_dto: CreatePageDTO,
+) -> Result<PageDTO> {
+    authorize_post_write(
+        current_user,
+        create_page_dto.author_id,
+        &create_page_dto.status,
+    )?;
+
+    let explicit_slug = create_page_dto.slug.is_some();
+    let mut page_model: PageModel = create_page_dto.try_into()?;
+
+    let mut tx = pool.begin().await?;
+    if let Some(parent_id) = page_model.parent_id {
+        let ancestor_ids =
+            lock_page_ancestor_ids(&mut tx, None, parent_id).await?;
+        validate_parent("page", None, &ancestor_ids)?;
+    }
+    let slug = page_model.slug.take().unwrap_or_default();
+    page_model.slug = Some(
+        unique_slug(&mut tx, SlugType::Page, &slug, None, explicit_slug)
+            .await?,
+    );
+    let create_page_model = insert_page(&mut tx, page_model).await?;
+    let id = create_page_model.id.ok_or(sqlx::Error::RowNotFound)?;
+    insert_post_revision(&mut tx, id, current_user.id).await?;
+    delete_post_autosave(&mut tx, current_user.id, None).await?;
+    tx.commit().await?;
+
+    Ok(PageDTO::from(create_page_model))
+}
+
+/// Service to update a page by its ID in the database.
+///
+/// The page is saved in a single transaction with a revision of the page,
+/// and the autosave of the user on the page is discarded. A slug made from
+/// the title is suffixed when another page has it, and a former slug is
+/// recorded to redirect the old permalink.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user updating the page.
+/// * `id` - The ID of the page to be updated.
+/// * `update_page_dto` - Data Transfer Object containing the updated details of the page.
+///
+/// # Returns
+///
+/// Returns a `PageDTO` object containing the updated page details.
+pub async fn update_page_service(
+    pool: &PgPool,
+    current_user: &CurrentUserDTO,
+    id: i32,
+    update_page_dto: CreatePageDTO,
+) -> Result<PageDTO> {
+    authorize_post_write(
+        current_user,
+        update_page_dto.author_id,
+        &update_page_dto.status,
+    )?;
+
+    let explicit_slug = update_page_dto.slug.is_some();
+    let mut page_model: PageModel = update_page_dto.try_into()?;
+    page_model.id = Some(id);
+
+    let former_page = select_page_by_id(pool, id).await?;
+    let mut tx = pool.begin().await?;
+    if let Some(parent_id) = page_model.parent_id {
+        let ancestor_ids =
+            lock_page_ancestor_ids(&mut tx, Some(id), parent_id).await?;
+        validate_parent("page", Some(id), &ancestor_ids)?;
+    }
+    let slug = page_model.slug.take().unwrap_or_default();
+    let slug =
+        unique_slug(&mut tx, SlugType::Page, &slug, Some(id), explicit_slug)
+            .await?;
+    let former_slug = former_page.slug.unwrap_or_default();
+    record_slug_change(&mut tx, SlugType::Page, id, &former_slug, &slug)
+        .await?;
+    page_model.slug = Some(slug);
+    let update_page_model = update_page(&mut tx, id, page_model).await?;
+    insert_post_revision(&mut tx, id, current_user.id).await?;
+    delete_post_autosave(&mut tx, current_user.id, Some(id)).await?;
+    tx.commit().await?;
+
+    Ok(PageDTO::from(update_page_model))
+}
+
+/// Service to retrieve all pages from the database with pagination and sorting.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `filters` - The filters the pages must match.
+/// * `page` - The current page number for pagination.
+/// * `limit` - The number of pages per page.
+/// * `sort_column` - The column name to sort by.
+/// * `sort_order` - The order of sorting (either "asc" for ascending or "desc" for descending).
+///
+/// # Returns
+///
+/// Returns a `PaginationDTO<PageDTO>` object containing the paginated list of pages and pagination information.
+pub async fn get_all_pages_service(
+    pool: &PgPool,
+    filters: &PageFiltersDTO,
+    page: i64,
+    limit: i64,
+    sort_column: &str,
+    sort_order: &str,
+) -> Result<PaginationDTO<PageDTO>> {
+    let total_items = count_pages(pool, filters).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let pages_model = select_pages(
+        pool,
+        filters,
+        limit,
+        pagination.offset,
+        sort_column,
+        sort_order,
+    )
+    .await?;
+
+    let pages_dto: Vec<PageDTO> =
+        pages_model.into_iter().map(PageDTO::from).collect();
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: pages_dto,
+    })
+}
+
+/// Service to retrieve a page by its ID from the database.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `id` - The ID of the page to retrieve.
+///
+/// # Returns
+///
+/// Returns a `PageDTO` object containing the details of the page.
+pub async fn get_page_by_id_service(pool: &PgPool, id: i32) -> Result<PageDTO> {
+    let page_model = select_page_by_id(pool, id).await?;
+    Ok(PageDTO::from(page_model))
+}
+
+/// Service to delete pages by their IDs in the database.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `delete_page_ids_dto` - Data Transfer Object containing the list of page IDs to delete.
+///
+/// # Returns
+///
+/// Returns a vector of deleted page IDs.
+pub async fn delete_pages_service(
+    pool: &PgPool,
+    delete_page_ids_dto: DeletePageIdsDTO,
+) -> Result<Vec<i32>> {
+    let deleted_ids =
+        delete_pages_by_ids(pool, delete_page_ids_dto.ids).await?;
+    Ok(deleted_ids)
+}
@@ -0,0 +1,96 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use validator::Validate;
+
+use crate::{
+    dtos::{
+        auth_dtos::CurrentUserDTO,
+        post_autosave_dto::{PostAutosaveDTO, SavePostAutosaveDTO},
+    },
+    repositories::post_autosaves_repository::{
+        delete_post_autosave, select_post_autosave, upsert_post_autosave,
+    },
+};
+
+use super::posts_services::authorize_post_edit;
+
+/// Service to store the unsaved changes of the current user on a post.
+///
+/// Only the latest autosave of a user on a post is kept.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user editing the post.
+/// * `save_dto` - Data Transfer Object containing the unsaved changes.
+///
+/// # Returns
+///
+/// Returns the stored autosave.
+pub async fn save_post_autosave_service(
+    pool: &PgPool,
+    current_user: &CurrentUserDTO,
+    save_dto: SavePostAutosaveDTO,
+) -> Result<PostAutosaveDTO> {
+    save_dto.validate()?;
+    if let Some(post_id) = save_dto.post_id {
+        authorize_post_edit(pool, current_user, post_id).await?;
+    }
+
+    let autosave = upsert_post_autosave(
+        pool,
+        current_user.id,
+        save_dto.post_id,
+        &save_dto.title,
+        &save_dto.content,
+    )
+    .await?;
+
+    Ok(autosave.into())
+}
+
+/// Service to retrieve the unsaved changes of the current user on a post.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user editing the post.
+/// * `post_id` - The ID of the post, `None` for a new post.
+///
+/// # Returns
+///
+/// Returns the autosave, `RowNotFound` if there is none newer than the
+/// saved post.
+pub async fn get_post_autosave_service(
+    pool: &PgPool,
+    current_user: &CurrentUserDTO,
+    post_id: Option<i32>,
+) -> Result<PostAutosaveDTO> {
+    let autosave = select_post_autosave(pool, current_user.id, post_id).await?;
+
+    Ok(autosave.into())
+}
+
+/// Service to discard the unsaved changes of the current user on a post.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user editing the post.
+/// * `post_id` - The ID of the post, `None` for a new post.
+///
+/// # Returns
+///
+/// Returns `RowNotFound` if there was no autosave.
+pub async fn delete_post_autosave_service(
+    pool: &PgPool,
+    current_user: &CurrentUserDTO,
+    post_id: Option<i32>,
+) -> Result<()> {
+    let mut conn = pool.acquire().await?;
+    if delete_post_autosave(&mut conn, current_user.id, post_id).await? == 0 {
+        return Err(sqlx::Error::RowNotFound.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{
+        auth_dtos::CurrentUserDTO,
+        post_dto::PostDTO,
+        post_revision_dto::{
+            DiffLineDTO, PostRevisionDTO, PostRevisionDiffDTO,
+            PostRevisionDiffParamsDTO,
+        },
+    },
+    handlers::diff_handler::diff_lines,
+    repositories::{
+        post_revisions_repository::{
+            insert_post_revision, restore_post_revision,
+            select_post_revision_by_id, select_post_revisions,
+        },
+        posts_repository::select_posts_author_ids,
+    },
+};
+
+use super::posts_services::{authorize_post_edit, get_post_by_id_service};
+
+/// Service to retrieve the revisions of a post, latest first.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `post_id` - The ID of the post.
+///
+/// # Returns
+///
+/// Returns the revisions of the post, `RowNotFound` if the post does not
+/// exist.
+pub async fn get_post_revisions_service(
+    pool: &PgPool,
+    post_id: i32,
+) -> Result<Vec<PostRevisionDTO>> {
+    if select_posts_author_ids(pool, &[post_id]).await?.is_empty() {
+        return Err(sqlx::Error::RowNotFound.into());
+    }
+
+    let revisions = select_post_revisions(pool, post_id)
+        .await?
+        .into_iter()
+        .map(PostRevisionDTO::from)
+        .collect();
+    Ok(revisions)
+}
+
+/// Service to compare two revisions of a post line by line.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `post_id` - The ID of the post.
+/// * `params` - The IDs of the revisions to compare.
+///
+/// # Returns
+///
+/// Returns the differences of the titles and the contents, `RowNotFound` if
+/// a revision does not belong to the post.
+pub async fn get_post_revisions_diff_service(
+    pool: &PgPool,
+    post_id: i32,
+    params: PostRevisionDiffParamsDTO,
+) -> Result<PostRevisionDiffDTO> {
+    let from = select_post_revision_by_id(pool, post_id, params.from).await?;
+    let to = select_post_revision_by_id(pool, post_id, params.to).await?;
+
+    let title = diff_lines(&from.title, &to.title)
+        .into_iter()
+        .map(DiffLineDTO::from)
+        .collect();
+    let content = diff_lines(&from.content, &to.content)
+        .into_iter()
+        .map(DiffLineDTO::from)
+        .collect();
+
+    Ok(PostRevisionDiffDTO {
+        from: from.into(),
+        to: to.into(),
+        title,
+        content,
+    })
+}
+
+/// Service to restore the title and the content of a post from a revision.
+///
+/// The restored post is saved as a new revision in the same transaction.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user restoring the revision.
+/// * `post_id` - The ID of the post.
+/// * `revision_id` - The ID of the revision to restore.
+///
+/// # Returns
+///
+/// Returns the restored post.
+pub async fn restore_post_revision_service(
+    pool: &PgPool,
+    current_user: &CurrentUserDTO,
+    post_id: i32,
+    revision_id: i32,
+) -> Result<PostDTO> {
+    authorize_post_edit(pool, current_user, post_id).await?;
+
+    let mut tx = pool.begin().await?;
+    restore_post_revision(&mut tx, post_id, revision_id).await?;
+    insert_post_revision(&mut tx, post_id, current_user.id).await?;
+    tx.commit().await?;
+
+    get_post_by_id_service(pool, post_id).await
+}
@@ -1,21 +1,42 @@
 use anyhow::Result;
+use chrono::NaiveDateTime;
+use log::info;
 use sqlx::PgPool;
+use validator::Validate;
 
+use crate::dtos::auth_dtos::CurrentUserDTO;
 use crate::dtos::pagination_dto::PaginationDTO;
-use crate::dtos::post_dto::{CreatePostDTO, DeletePostIdsDTO, PostDTO};
-use crate::models::posts_model::PostModel;
+use crate::dtos::post_dto::{
+    CreatePostDTO, DeletePostIdsDTO, PostDTO, PostFiltersDTO, PostSearchDTO,
+    PostSearchParamsDTO,
+};
+use crate::models::capabilities_model::Capability;
+use crate::models::posts_model::{PostModel, PostsStatus};
+use crate::models::slugs_model::SlugType;
+use crate::repositories::post_autosaves_repository::delete_post_autosave;
+use crate::repositories::post_revisions_repository::insert_post_revision;
+use crate::repositories::posts_categories_repository::replace_post_categories;
 use crate::repositories::posts_repository::{
-    count_posts, delete_post_by_id, insert_post, select_post_by_id,
-    select_posts, update_post,
+    count_posts, count_search_posts, delete_post_by_id, insert_post,
+    publish_due_posts, search_posts, select_post_by_id, select_posts,
+    select_posts_author_ids, update_post,
 };
+use crate::repositories::posts_tags_repository::replace_post_tags;
+use crate::repositories::tags_repository::select_tag_by_id;
 
 use super::calculate_pagination;
+use super::slugs_service::{record_slug_change, unique_slug};
 
 /// Service to insert a post into the database.
 ///
+/// The post, its categories, its tags and its first revision are saved in a
+/// single transaction, which discards the autosave of the new post. A slug
+/// made from the title is suffixed when another post has it.
+///
 /// # Arguments
 ///
 /// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user creating the post.
 /// * `create_post_dto` - Data Transfer Object containing the details for the new post.
 ///
 /// # Returns
@@ -23,20 +44,49 @@ use super::calculate_pagination;
 /// Returns a `PostDTO` object containing the inserted post details.
 pub async fn create_post_service(
     pool: &PgPool,
-    create_post_dto: CreatePostDTO,
+    current_user: &CurrentUserDTO,
+    mut create_post_dto: CreatePostDTO,
 ) -> Result<PostDTO> {
-    let post_model: PostModel = create_post_dto.try_into()?;
+    authorize_post_write(
+        current_user,
+        create_post_dto.author_id,
+        &create_post_dto.status,
+    )?;
+
+    let categories_ids = std::mem::take(&mut create_post_dto.categories_ids);
+    let tags_ids = std::mem::take(&mut create_post_dto.tags_ids);
+    let explicit_slug = create_post_dto.slug.is_some();
+    let mut post_model: PostModel = create_post_dto.try_into()?;
 
-    let create_post_model = insert_post(pool, post_model).await?;
-    let result = PostDTO::from(create_post_model);
-    Ok(result)
+    let mut tx = pool.begin().await?;
+    let slug = post_model.slug.take().unwrap_or_default();
+    post_model.slug = Some(
+        unique_slug(&mut tx, SlugType::Post, &slug, None, explicit_slug)
+            .await?,
+    );
+    let create_post_model = insert_post(&mut tx, post_model).await?;
+    let id = create_post_model.id.ok_or(sqlx::Error::RowNotFound)?;
+    replace_post_categories(&mut tx, id, &categories_ids).await?;
+    replace_post_tags(&mut tx, id, &tags_ids).await?;
+    insert_post_revision(&mut tx, id, current_user.id).await?;
+    delete_post_autosave(&mut tx, current_user.id, None).await?;
+    tx.commit().await?;
+
+    get_post_by_id_service(pool, id).await
 }
 
 /// Service to update a post by its ID in the database.
 ///
+/// The post is saved in a single transaction with its categories and tags,
+/// which replace the current ones, and a revision of the post. The autosave
+/// of the user on the post is discarded. A slug made from the title is
+/// suffixed when another post has it, and a former slug is recorded to
+/// redirect the old permalink.
+///
 /// # Arguments
 ///
 /// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user updating the post.
 /// * `id` - The ID of the post to be updated.
 /// * `update_post_dto` - Data Transfer Object containing the updated details of the post.
 ///
@@ -45,15 +95,42 @@ pub async fn create_post_service(
 /// Returns a `PostDTO` object containing the updated post details.
 pub async fn update_post_service(
     pool: &PgPool,
+    current_user: &CurrentUserDTO,
     id: i32,
-    update_post_dto: CreatePostDTO,
+    mut update_post_dto: CreatePostDTO,
 ) -> Result<PostDTO> {
+    // Pages share the posts table, they are updated through their own service
+    let former_post = select_post_by_id(pool, id).await?;
+    authorize_post_edit(pool, current_user, id).await?;
+    authorize_post_write(
+        current_user,
+        update_post_dto.author_id,
+        &update_post_dto.status,
+    )?;
+
+    let categories_ids = std::mem::take(&mut update_post_dto.categories_ids);
+    let tags_ids = std::mem::take(&mut update_post_dto.tags_ids);
+    let explicit_slug = update_post_dto.slug.is_some();
     let mut post_model: PostModel = update_post_dto.try_into()?;
     post_model.id = Some(id);
 
-    let update_post_model = update_post(pool, id, post_model).await?;
-    let result = PostDTO::from(update_post_model);
-    Ok(result)
+    let mut tx = pool.begin().await?;
+    let slug = post_model.slug.take().unwrap_or_default();
+    let slug =
+        unique_slug(&mut tx, SlugType::Post, &slug, Some(id), explicit_slug)
+            .await?;
+    let former_slug = former_post.slug.unwrap_or_default();
+    record_slug_change(&mut tx, SlugType::Post, id, &former_slug, &slug)
+        .await?;
+    post_model.slug = Some(slug);
+    update_post(&mut tx, id, post_model).await?;
+    replace_post_categories(&mut tx, id, &categories_ids).await?;
+    replace_post_tags(&mut tx, id, &tags_ids).await?;
+    insert_post_revision(&mut tx, id, current_user.id).await?;
+    delete_post_autosave(&mut tx, current_user.id, Some(id)).await?;
+    tx.commit().await?;
+
+    get_post_by_id_service(pool, id).await
 }
 
 /// Service to retrieve all posts from the database with pagination and sorting.
@@ -61,6 +138,7 @@ pub async fn update_post_service(
 /// # Arguments
 ///
 /// * `pool` - Reference to the database connection pool.
+/// * `filters` - The filters the posts must match.
 /// * `page` - The current page number for pagination.
 /// * `limit` - The number of posts per page.
 /// * `sort_column` - The column name to sort by.
@@ -71,17 +149,24 @@ pub async fn update_post_service(
 /// Returns a `PaginationDTO<PostDTO>` object containing the paginated list of posts and pagination information.
 pub async fn get_all_posts_service(
     pool: &PgPool,
+    filters: &PostFiltersDTO,
     page: i64,
     limit: i64,
     sort_column: &str,
     sort_order: &str,
 ) -> Result<PaginationDTO<PostDTO>> {
-    let total_items = count_posts(pool).await?;
+    let total_items = count_posts(pool, filters).await?;
     let pagination = calculate_pagination(total_items, page, limit);
 
-    let posts_model =
-        select_posts(pool, limit, pagination.offset, sort_column, sort_order)
-            .await?;
+    let posts_model = select_posts(
+        pool,
+        filters,
+        limit,
+        pagination.offset,
+        sort_column,
+        sort_order,
+    )
+    .await?;
 
     let posts_dto: Vec<PostDTO> =
         posts_model.into_iter().map(PostDTO::from).collect();
@@ -94,6 +179,74 @@ pub async fn get_all_posts_service(
     })
 }
 
+/// Service to retrieve the posts having a tag with pagination and sorting.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `tag_id` - The ID of the tag.
+/// * `page` - The current page number for pagination.
+/// * `limit` - The number of posts per page.
+/// * `sort_column` - The column name to sort by.
+/// * `sort_order` - The order of sorting (either "asc" for ascending or "desc" for descending).
+///
+/// # Returns
+///
+/// Returns a `PaginationDTO<PostDTO>` object containing the paginated list of posts, or a not found error if the tag does not exist.
+pub async fn get_posts_by_tag_service(
+    pool: &PgPool,
+    tag_id: i32,
+    page: i64,
+    limit: i64,
+    sort_column: &str,
+    sort_order: &str,
+) -> Result<PaginationDTO<PostDTO>> {
+    select_tag_by_id(pool, tag_id).await?;
+
+    let filters = PostFiltersDTO {
+        tag_id: Some(tag_id),
+        ..Default::default()
+    };
+    get_all_posts_service(pool, &filters, page, limit, sort_column, sort_order)
+        .await
+}
+
+/// Service to search posts by full text, best matches first.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `params` - The search query and the pagination parameters.
+///
+/// # Returns
+///
+/// Returns a `PaginationDTO<PostSearchDTO>` object containing the paginated list of matching posts with their highlighted snippet.
+pub async fn search_posts_service(
+    pool: &PgPool,
+    params: PostSearchParamsDTO,
+) -> Result<PaginationDTO<PostSearchDTO>> {
+    params.validate()?;
+
+    let query = params.q.trim();
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(25);
+
+    let total_items = count_search_posts(pool, query).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let results = search_posts(pool, query, limit, pagination.offset).await?;
+
+    let results_dto: Vec<PostSearchDTO> =
+        results.into_iter().map(PostSearchDTO::from).collect();
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: results_dto,
+    })
+}
+
 /// Service to retrieve a post by its ID from the database.
 ///
 /// # Arguments
@@ -115,6 +268,7 @@ pub async fn get_post_by_id_service(pool: &PgPool, id: i32) -> Result<PostDTO> {
 /// # Arguments
 ///
 /// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user deleting the posts.
 /// * `delete_post_ids_dto` - Data Transfer Object containing the list of post IDs to delete.
 ///
 /// # Returns
@@ -122,8 +276,100 @@ pub async fn get_post_by_id_service(pool: &PgPool, id: i32) -> Result<PostDTO> {
 /// Returns a vector of deleted post IDs.
 pub async fn delete_post_service(
     pool: &PgPool,
+    current_user: &CurrentUserDTO,
     delete_post_ids_dto: DeletePostIdsDTO,
 ) -> Result<Vec<i32>> {
+    let authors_ids =
+        select_posts_author_ids(pool, &delete_post_ids_dto.ids).await?;
+    if authors_ids
+        .iter()
+        .any(|&author_id| author_id != current_user.id)
+    {
+        current_user.require(Capability::DeleteOthersPosts)?;
+    }
+
     let deleted_ids = delete_post_by_id(pool, delete_post_ids_dto.ids).await?;
     Ok(deleted_ids)
 }
+
+/// Checks that the current user can edit an existing post.
+///
+/// Editing the posts of other authors requires `EditOthersPosts`.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user editing the post.
+/// * `id` - The ID of the post.
+///
+/// # Returns
+///
+/// Returns `RowNotFound` if the post does not exist, a capability error if
+/// the user cannot edit it.
+pub async fn authorize_post_edit(
+    pool: &PgPool,
+    current_user: &CurrentUserDTO,
+    id: i32,
+) -> Result<()> {
+    let authors_ids = select_posts_author_ids(pool, &[id]).await?;
+    let current_author_id =
+        authors_ids.first().ok_or(sqlx::Error::RowNotFound)?;
+    if *current_author_id != current_user.id {
+        current_user.require(Capability::EditOthersPosts)?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the current user may write a post or a page for the given
+/// author and status.
+///
+/// Writing a post for another author requires `edit_others_posts`, making it
+/// public or scheduling it requires `publish_posts`.
+pub fn authorize_post_write(
+    current_user: &CurrentUserDTO,
+    author_id: i32,
+    status: &PostsStatus,
+) -> Result<()> {
+    if author_id != current_user.id {
+        current_user.require(Capability::EditOthersPosts)?;
+    }
+
+    if matches!(status, PostsStatus::Published | PostsStatus::Scheduled) {
+        current_user.require(Capability::PublishPosts)?;
+    }
+
+    Ok(())
+}
+
+/// Service to publish the scheduled posts whose publication date is due.
+///
+/// All due posts are published in a single transaction.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `now` - The current date, compared to the publication dates.
+///
+/// # Returns
+///
+/// Returns the IDs of the published posts.
+pub async fn publish_scheduled_posts_service(
+    pool: &PgPool,
+    now: NaiveDateTime,
+) -> Result<Vec<i32>> {
+    let mut tx = pool.begin().await?;
+    let published_posts = publish_due_posts(&mut tx, now).await?;
+    tx.commit().await?;
+
+    let ids = published_posts
+        .into_iter()
+        .filter_map(|post| {
+            let id = post.id?;
+            info!("Scheduled post {} \"{}\" published", id, post.title);
+            Some(id)
+        })
+        .collect();
+
+    Ok(ids)
+}
@@ -0,0 +1,44 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{
+        auth_dtos::CurrentUserDTO,
+        posts_tags_dto::{PostsTagsDTO, UpdatePostsTagsDTO},
+    },
+    repositories::posts_tags_repository::replace_post_tags,
+};
+
+use super::posts_services::authorize_post_edit;
+
+/// Service to replace the tags of a post.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user editing the post.
+/// * `post_id` - The ID of the post.
+/// * `update_dto` - Data Transfer Object containing the IDs of the tags the post must have.
+///
+/// # Returns
+///
+/// Returns the tags relationships of the post.
+pub async fn update_posts_tags_service(
+    pool: &PgPool,
+    current_user: &CurrentUserDTO,
+    post_id: i32,
+    update_dto: UpdatePostsTagsDTO,
+) -> Result<Vec<PostsTagsDTO>> {
+    authorize_post_edit(pool, current_user, post_id).await?;
+
+    let mut tx = pool.begin().await?;
+    let posts_tags_model =
+        replace_post_tags(&mut tx, post_id, &update_dto.tags_ids).await?;
+    tx.commit().await?;
+
+    let posts_tags = posts_tags_model
+        .into_iter()
+        .map(PostsTagsDTO::from)
+        .collect();
+    Ok(posts_tags)
+}
@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use sqlx::PgPool;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::dtos::category_dto::CategoryDTO;
+use crate::dtos::pagination_dto::PaginationDTO;
+use crate::dtos::post_dto::PostFiltersDTO;
+use crate::dtos::public_dto::{
+    PublicPageDTO, PublicPaginationParamsDTO, PublicPostDTO,
+    PublicPostFiltersDTO, PublicRedirectDTO, PublicSlugDTO,
+};
+use crate::dtos::tag_dto::TagDTO;
+use crate::handlers::archive_range_handler::archive_range;
+use crate::models::posts_model::PostsStatus;
+use crate::models::slugs_model::SlugType;
+use crate::repositories::categories_repository::select_category_by_slug;
+use crate::repositories::pages_repository::select_published_page_by_slug;
+use crate::repositories::posts_repository::{
+    count_posts, select_posts, select_published_post_by_slug,
+};
+use crate::repositories::redirects_repository::hit_redirect;
+use crate::repositories::slugs_repository::select_current_slug;
+use crate::repositories::tags_repository::select_tag_by_slug;
+use crate::repositories::users_repository::{
+    select_author_by_username, select_authors_by_ids,
+};
+use crate::validators::redirect_validator::normalize_redirect_path;
+
+use super::calculate_pagination;
+use super::settings_service::get_integer_setting;
+
+/// Service to retrieve the published posts, latest first, optionally
+/// restricted to a category, a tag, an author or a publication year or month.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `filters` - The archive the posts belong to.
+/// * `params` - The page, and the number of posts per page, the
+///   `posts_per_page` setting if `None`.
+///
+/// # Returns
+///
+/// Returns a `PaginationDTO<PublicPostDTO>` object containing the paginated list of posts, or a not found error if the category, the tag or the author does not exist.
+pub async fn get_public_posts_service(
+    pool: &PgPool,
+    filters: PublicPostFiltersDTO,
+    params: PublicPaginationParamsDTO,
+) -> Result<PaginationDTO<PublicPostDTO>> {
+    params.validate()?;
+    filters.validate()?;
+    let page = params.page.unwrap_or(1);
+    let limit = match params.limit {
+        Some(limit) => limit,
+        None => get_integer_setting(pool, "posts_per_page").await?,
+    };
+
+    let mut posts_filters = PostFiltersDTO {
+        status: Some(PostsStatus::Published),
+        ..Default::default()
+    };
+    if let Some(ref slug) = filters.category {
+        posts_filters.category_id =
+            select_category_by_slug(pool, slug).await?.id;
+    }
+    if let Some(ref slug) = filters.tag {
+        posts_filters.tag_id = select_tag_by_slug(pool, slug).await?.id;
+    }
+    if let Some(ref username) = filters.author {
+        posts_filters.author_id =
+            Some(select_author_by_username(pool, username).await?.id);
+    }
+    match (filters.year, filters.month) {
+        (Some(year), month) => {
+            let (start, end) = archive_range(year, month)
+                .ok_or_else(|| archive_error("year", "Unknown archive date"))?;
+            posts_filters.published_after = Some(start);
+            posts_filters.published_before = Some(end);
+        }
+        (None, Some(_)) => {
+            return Err(archive_error("month", "Month requires a year").into())
+        }
+        (None, None) => {}
+    }
+
+    let total_items = count_posts(pool, &posts_filters).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let posts_model = select_posts(
+        pool,
+        &posts_filters,
+        limit,
+        pagination.offset,
+        "date_published",
+        "desc",
+    )
+    .await?;
+
+    let authors_ids: Vec<i32> =
+        posts_model.iter().map(|post| post.author_id).collect();
+    let authors: HashMap<i32, _> = select_authors_by_ids(pool, &authors_ids)
+        .await?
+        .into_iter()
+        .map(|author| (author.id, author))
+        .collect();
+
+    let posts_dto: Vec<PublicPostDTO> = posts_model
+        .into_iter()
+        .map(|post| {
+            let author = authors.get(&post.author_id).cloned();
+            PublicPostDTO::from((post, author))
+        })
+        .collect();
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: posts_dto,
+    })
+}
+
+/// Service to retrieve a published post by its slug.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `slug` - The slug of the post.
+///
+/// # Returns
+///
+/// Returns a `PublicPostDTO` object, or a not found error if no published post has this slug.
+pub async fn get_public_post_by_slug_service(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<PublicPostDTO> {
+    let post = select_published_post_by_slug(pool, slug).await?;
+    let author = select_authors_by_ids(pool, &[post.author_id]).await?.pop();
+
+    Ok(PublicPostDTO::from((post, author)))
+}
+
+/// Service to retrieve a published page by its slug.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `slug` - The slug of the page.
+///
+/// # Returns
+///
+/// Returns a `PublicPageDTO` object, or a not found error if no published page has this slug.
+pub async fn get_public_page_by_slug_service(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<PublicPageDTO> {
+    let page = select_published_page_by_slug(pool, slug).await?;
+    let author = select_authors_by_ids(pool, &[page.author_id]).await?.pop();
+
+    Ok(PublicPageDTO::from((page, author)))
+}
+
+/// Service to retrieve a category by its slug.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `slug` - The slug of the category.
+///
+/// # Returns
+///
+/// Returns a `CategoryDTO` object containing the details of the category.
+pub async fn get_public_category_by_slug_service(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<CategoryDTO> {
+    let category = select_category_by_slug(pool, slug).await?;
+    Ok(CategoryDTO::from(category))
+}
+
+/// Service to retrieve a tag by its slug.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `slug` - The slug of the tag.
+///
+/// # Returns
+///
+/// Returns a `TagDTO` object containing the details of the tag.
+pub async fn get_public_tag_by_slug_service(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<TagDTO> {
+    let tag = select_tag_by_slug(pool, slug).await?;
+    Ok(TagDTO::from(tag))
+}
+
+/// Service to retrieve the redirect of a path, counting the hit.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `path` - The path requested, a trailing slash being ignored.
+///
+/// # Returns
+///
+/// Returns a `PublicRedirectDTO` with the target and status code.
+pub async fn get_public_redirect_service(
+    pool: &PgPool,
+    path: &str,
+) -> Result<PublicRedirectDTO> {
+    let redirect = hit_redirect(pool, normalize_redirect_path(path)).await?;
+    Ok(PublicRedirectDTO {
+        target: redirect.target,
+        status_code: redirect.status_code,
+    })
+}
+
+/// Service to retrieve the current slug of a content from a former slug.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `slug_type` - The type of the content.
+/// * `slug` - The former slug of the content.
+///
+/// # Returns
+///
+/// Returns a `PublicSlugDTO` with the current slug of the published post or
+/// page, category or tag which last had the slug.
+pub async fn get_public_current_slug_service(
+    pool: &PgPool,
+    slug_type: SlugType,
+    slug: &str,
+) -> Result<PublicSlugDTO> {
+    let slug = select_current_slug(pool, slug_type, slug).await?;
+    Ok(PublicSlugDTO { slug_type, slug })
+}
+
+/// Builds the validation error of an archive date field.
+fn archive_error(
+    field: &'static str,
+    message: &'static str,
+) -> ValidationErrors {
+    let mut error = ValidationError::new("invalid_archive");
+    error.message = Some(message.into());
+    let mut errors = ValidationErrors::new();
+    errors.add(field, error);
+    errors
+}
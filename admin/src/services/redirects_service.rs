@@ -0,0 +1,117 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{
+        pagination_dto::PaginationDTO,
+        redirect_dto::{CreateRedirectDTO, DeleteRedirectIdsDTO, RedirectDTO},
+    },
+    models::redirects_model::RedirectModel,
+    repositories::redirects_repository::{
+        count_redirects, delete_redirects_by_ids, insert_redirect,
+        select_redirects, update_redirect,
+    },
+};
+
+use super::calculate_pagination;
+
+/// Service to create a redirect.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `create_redirect_dto` - The source path, target and status code.
+///
+/// # Returns
+///
+/// * `Result<RedirectDTO>` - The created redirect.
+pub async fn create_redirect_service(
+    pool: &PgPool,
+    create_redirect_dto: CreateRedirectDTO,
+) -> Result<RedirectDTO> {
+    let redirect_model: RedirectModel = create_redirect_dto.try_into()?;
+
+    let redirect_model = insert_redirect(pool, redirect_model).await?;
+    Ok(RedirectDTO::from(redirect_model))
+}
+
+/// Service to update a redirect by its ID, keeping its hit counter.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the redirect to update.
+/// * `update_redirect_dto` - The new source path, target and status code.
+///
+/// # Returns
+///
+/// * `Result<RedirectDTO>` - The updated redirect.
+pub async fn update_redirect_service(
+    pool: &PgPool,
+    id: i32,
+    update_redirect_dto: CreateRedirectDTO,
+) -> Result<RedirectDTO> {
+    let redirect_model: RedirectModel = update_redirect_dto.try_into()?;
+
+    let redirect_model = update_redirect(pool, id, redirect_model).await?;
+    Ok(RedirectDTO::from(redirect_model))
+}
+
+/// Service to retrieve the redirects with pagination and sorting.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `page` - The current page number for pagination.
+/// * `limit` - The number of redirects per page.
+/// * `sort_column` - The column to sort the redirects by.
+/// * `sort_order` - The order of sorting, `asc` or `desc`.
+///
+/// # Returns
+///
+/// * `Result<PaginationDTO<RedirectDTO>>` - The page of redirects.
+pub async fn get_all_redirects_service(
+    pool: &PgPool,
+    page: i64,
+    limit: i64,
+    sort_column: &str,
+    sort_order: &str,
+) -> Result<PaginationDTO<RedirectDTO>> {
+    let total_items = count_redirects(pool).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let redirects = select_redirects(
+        pool,
+        limit,
+        pagination.offset,
+        sort_column,
+        sort_order,
+    )
+    .await?;
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: redirects.into_iter().map(RedirectDTO::from).collect(),
+    })
+}
+
+/// Service to delete redirects by their IDs.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `delete_redirect_ids_dto` - The IDs of the redirects to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the deleted redirects.
+pub async fn delete_redirects_service(
+    pool: &PgPool,
+    delete_redirect_ids_dto: DeleteRedirectIdsDTO,
+) -> Result<Vec<i32>> {
+    let deleted_ids =
+        delete_redirects_by_ids(pool, delete_redirect_ids_dto.ids).await?;
+    Ok(deleted_ids)
+}
@@ -0,0 +1,136 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::dtos::pagination_dto::PaginationDTO;
+use crate::dtos::role_dto::{CreateRoleDTO, DeleteRoleIdsDTO, RoleDTO};
+use crate::models::roles_model::RoleModel;
+use crate::repositories::roles_repository::{
+    count_roles, delete_role_by_id, insert_role, replace_role_capabilities,
+    select_role_by_id, select_roles, update_role,
+};
+
+use super::calculate_pagination;
+
+/// Service to insert a new role and its capabilities into the database, in
+/// one transaction.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `role_dto` - A `CreateRoleDTO` object containing the role data.
+///
+/// # Returns
+///
+/// * `Result<RoleDTO>` - The newly inserted role with its capabilities.
+pub async fn create_role_service(
+    pool: &PgPool,
+    role_dto: CreateRoleDTO,
+) -> Result<RoleDTO> {
+    let capabilities_ids = role_dto.capabilities_ids.clone();
+    let role_model: RoleModel = role_dto.try_into()?;
+
+    let mut tx = pool.begin().await?;
+    let create_role_model = insert_role(&mut tx, role_model).await?;
+    let role_id = create_role_model.id.ok_or(sqlx::Error::RowNotFound)?;
+    replace_role_capabilities(&mut tx, role_id, capabilities_ids).await?;
+    tx.commit().await?;
+
+    get_role_by_id_service(pool, role_id).await
+}
+
+/// Service to update an existing role and its capabilities by its ID, in one
+/// transaction.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the role to update.
+/// * `role_dto` - A `CreateRoleDTO` object containing the updated role data.
+///
+/// # Returns
+///
+/// * `Result<RoleDTO>` - The updated role with its capabilities.
+pub async fn update_role_service(
+    pool: &PgPool,
+    id: i32,
+    role_dto: CreateRoleDTO,
+) -> Result<RoleDTO> {
+    let capabilities_ids = role_dto.capabilities_ids.clone();
+    let mut role_model: RoleModel = role_dto.try_into()?;
+    role_model.id = Some(id);
+
+    let mut tx = pool.begin().await?;
+    update_role(&mut tx, id, role_model).await?;
+    replace_role_capabilities(&mut tx, id, capabilities_ids).await?;
+    tx.commit().await?;
+
+    get_role_by_id_service(pool, id).await
+}
+
+/// Service to retrieve all roles from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<PaginationDTO<RoleDTO>>` - The paginated `RoleDTO` records.
+pub async fn get_all_roles_service(
+    pool: &PgPool,
+    page: i64,
+    limit: i64,
+    sort_column: &str,
+    sort_order: &str,
+) -> Result<PaginationDTO<RoleDTO>> {
+    let total_items = count_roles(pool).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let roles_model =
+        select_roles(pool, limit, pagination.offset, sort_column, sort_order)
+            .await?;
+
+    let roles_dto: Vec<RoleDTO> =
+        roles_model.into_iter().map(RoleDTO::from).collect();
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: roles_dto,
+    })
+}
+
+/// Service to retrieve a role by its ID from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the role to retrieve.
+///
+/// # Returns
+///
+/// * `Result<RoleDTO>` - The `RoleDTO` record for the specified ID.
+pub async fn get_role_by_id_service(pool: &PgPool, id: i32) -> Result<RoleDTO> {
+    let role_model = select_role_by_id(pool, id).await?;
+    let result = RoleDTO::from(role_model);
+    Ok(result)
+}
+
+/// Service to delete roles by their IDs in the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `delete_role_ids_dto` - A `DeleteRoleIdsDTO` containing the list of role IDs to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted roles.
+pub async fn delete_role_by_id_service(
+    pool: &PgPool,
+    delete_role_ids_dto: DeleteRoleIdsDTO,
+) -> Result<Vec<i32>> {
+    let deleted_ids = delete_role_by_id(pool, delete_role_ids_dto.ids).await?;
+    Ok(deleted_ids)
+}
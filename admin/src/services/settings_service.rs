@@ -0,0 +1,280 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::RwLock;
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use sqlx::PgPool;
+use validator::{ValidationError, ValidationErrors};
+
+use crate::{
+    dtos::settings_dto::{
+        PermalinksDTO, PublicSettingsDTO, SettingsDTO, UpdateSettingsDTO,
+    },
+    models::settings_model::{
+        find_setting, SettingDefinition, SettingsGroup, SETTINGS,
+    },
+    repositories::settings_repository::{select_settings, upsert_setting},
+    validators::setting_validator::validate_setting,
+};
+
+/// Values of the settings stored in the database, loaded on first use and
+/// dropped by the updates of the settings.
+static STORED_SETTINGS: RwLock<StoredSettings> = RwLock::new(StoredSettings {
+    generation: 0,
+    values: None,
+});
+
+/// Cache of the settings stored, with the number of updates it has seen.
+struct StoredSettings {
+    generation: u64,
+    values: Option<HashMap<String, Value>>,
+}
+
+/// Returns the values of the settings stored, loading them on first use.
+async fn stored_settings(pool: &PgPool) -> Result<HashMap<String, Value>> {
+    let generation = {
+        let stored = STORED_SETTINGS.read().map_err(|e| anyhow!("{}", e))?;
+        if let Some(values) = &stored.values {
+            return Ok(values.clone());
+        }
+        stored.generation
+    };
+
+    let settings: HashMap<String, Value> = select_settings(pool)
+        .await?
+        .into_iter()
+        .map(|setting| (setting.name, setting.value))
+        .collect();
+
+    // The settings read may miss an update committed during the load
+    let mut stored = STORED_SETTINGS.write().map_err(|e| anyhow!("{}", e))?;
+    if stored.values.is_none() && stored.generation == generation {
+        stored.values = Some(settings.clone());
+    }
+
+    Ok(settings)
+}
+
+/// Value of a setting, its default value unless a valid value is stored.
+fn setting_value(
+    definition: &SettingDefinition,
+    stored: &HashMap<String, Value>,
+) -> Value {
+    stored
+        .get(definition.name)
+        .filter(|value| validate_setting(definition, value).is_ok())
+        .cloned()
+        .unwrap_or_else(|| definition.default.to_json())
+}
+
+/// Retrieves the value of a setting.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `name` - The name of the setting, one of `SETTINGS`.
+///
+/// # Returns
+///
+/// * `Result<Value>` - The value of the setting, its default value if it was
+///   never changed.
+pub async fn get_setting(pool: &PgPool, name: &str) -> Result<Value> {
+    let definition = find_setting(name)
+        .ok_or_else(|| anyhow!("Unknown setting '{}'", name))?;
+
+    Ok(setting_value(definition, &stored_settings(pool).await?))
+}
+
+/// Retrieves the value of a text, URL, slug or choice setting.
+pub async fn get_text_setting(pool: &PgPool, name: &str) -> Result<String> {
+    let value = get_setting(pool, name).await?;
+
+    Ok(value.as_str().unwrap_or_default().to_string())
+}
+
+/// Retrieves the value of an integer setting.
+pub async fn get_integer_setting(pool: &PgPool, name: &str) -> Result<i64> {
+    let value = get_setting(pool, name).await?;
+
+    Ok(value.as_i64().unwrap_or_default())
+}
+
+/// Retrieves the value of a boolean setting.
+pub async fn get_boolean_setting(pool: &PgPool, name: &str) -> Result<bool> {
+    let value = get_setting(pool, name).await?;
+
+    Ok(value.as_bool().unwrap_or_default())
+}
+
+/// Service to retrieve the settings of a group.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `group` - The group of the settings.
+///
+/// # Returns
+///
+/// * `Result<SettingsDTO>` - Every setting of the group, by name.
+pub async fn get_settings_group_service(
+    pool: &PgPool,
+    group: SettingsGroup,
+) -> Result<SettingsDTO> {
+    let stored = stored_settings(pool).await?;
+    let settings = SETTINGS
+        .iter()
+        .filter(|definition| definition.group == group)
+        .map(|definition| {
+            (
+                definition.name.to_string(),
+                setting_value(definition, &stored),
+            )
+        })
+        .collect();
+
+    Ok(SettingsDTO { group, settings })
+}
+
+/// Service to change settings of a group.
+///
+/// Every value is checked against the type of its setting before any of them
+/// is stored, and the permalink bases must stay distinct.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `group` - The group of the settings.
+/// * `update_settings_dto` - The settings to change, by name.
+///
+/// # Returns
+///
+/// * `Result<SettingsDTO>` - Every setting of the group, or a
+///   `ValidationErrors` by setting if a setting is unknown or invalid.
+pub async fn update_settings_group_service(
+    pool: &PgPool,
+    group: SettingsGroup,
+    update_settings_dto: UpdateSettingsDTO,
+) -> Result<SettingsDTO> {
+    let mut errors = ValidationErrors::new();
+    for (name, value) in &update_settings_dto.settings {
+        match find_setting(name).filter(|setting| setting.group == group) {
+            Some(definition) => {
+                if let Err(error) = validate_setting(definition, value) {
+                    errors.add(definition.name, error);
+                }
+            }
+            None => errors.add(
+                "settings",
+                settings_error(
+                    "unknown_setting",
+                    format!(
+                        "Unknown setting '{}' in the {} settings",
+                        name,
+                        group.as_str()
+                    ),
+                ),
+            ),
+        }
+    }
+    if group == SettingsGroup::Permalinks && errors.is_empty() {
+        let current = get_settings_group_service(pool, group).await?.settings;
+        validate_distinct_bases(&current, &update_settings_dto, &mut errors);
+    }
+    if !errors.is_empty() {
+        return Err(errors.into());
+    }
+
+    let mut tx = pool.begin().await?;
+    for (name, value) in &update_settings_dto.settings {
+        upsert_setting(&mut tx, name, value.clone()).await?;
+    }
+    tx.commit().await?;
+
+    {
+        let mut stored =
+            STORED_SETTINGS.write().map_err(|e| anyhow!("{}", e))?;
+        stored.generation += 1;
+        stored.values = None;
+    }
+
+    get_settings_group_service(pool, group).await
+}
+
+/// Service to retrieve the settings the public site is rendered with.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<PublicSettingsDTO>` - The public settings.
+pub async fn get_public_settings_service(
+    pool: &PgPool,
+) -> Result<PublicSettingsDTO> {
+    let stored = stored_settings(pool).await?;
+    let value = |name: &str| {
+        find_setting(name)
+            .map(|definition| setting_value(definition, &stored))
+            .unwrap_or_default()
+    };
+    let text =
+        |name: &str| value(name).as_str().unwrap_or_default().to_string();
+
+    Ok(PublicSettingsDTO {
+        title: text("site_title"),
+        description: text("site_description"),
+        url: text("site_url"),
+        language: text("language"),
+        date_format: text("date_format"),
+        posts_per_page: value("posts_per_page").as_i64().unwrap_or_default(),
+        feed_items: value("feed_items").as_i64().unwrap_or_default(),
+        feed_content: text("feed_content"),
+        comments_enabled: value("comments_enabled")
+            .as_bool()
+            .unwrap_or_default(),
+        permalinks: PermalinksDTO {
+            category_base: text("category_base"),
+            tag_base: text("tag_base"),
+            author_base: text("author_base"),
+        },
+    })
+}
+
+/// Checks that no two permalink bases are the same once updated, the
+/// archives being told apart by their base.
+fn validate_distinct_bases(
+    current: &BTreeMap<String, Value>,
+    update_settings_dto: &UpdateSettingsDTO,
+    errors: &mut ValidationErrors,
+) {
+    let bases: Vec<(&'static str, &Value)> = SETTINGS
+        .iter()
+        .filter(|definition| definition.group == SettingsGroup::Permalinks)
+        .filter_map(|definition| {
+            let value = update_settings_dto
+                .settings
+                .get(definition.name)
+                .or_else(|| current.get(definition.name))?;
+            Some((definition.name, value))
+        })
+        .collect();
+
+    for (index, (name, value)) in bases.iter().enumerate() {
+        if bases[..index].iter().any(|(_, other)| other == value) {
+            errors.add(
+                name,
+                settings_error(
+                    "duplicate_base",
+                    format!("{} is already used by another archive", value),
+                ),
+            );
+        }
+    }
+}
+
+fn settings_error(code: &'static str, message: String) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.message = Some(message.into());
+    error
+}
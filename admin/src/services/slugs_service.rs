@@ -0,0 +1,130 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+use crate::dtos::slug_dto::{SlugCheckDTO, SlugCheckParamsDTO};
+use crate::handlers::error_to_response_handler::ConflictError;
+use crate::handlers::generate_slug_handler::{
+    deduplicate_slug, generate_slug, slug_stem,
+};
+use crate::models::slugs_model::SlugType;
+use crate::repositories::slugs_repository::{
+    delete_slug_history, insert_slug_history, select_slugs_starting_with,
+};
+use crate::validators::slug_validator::validate_slug;
+
+/// Maximum length of the slugs of all the types of content.
+const SLUG_MAX_LENGTH: usize = 200;
+
+/// Service to pick the slug a content is saved with.
+///
+/// It is meant to run in the transaction saving the content, the unique
+/// indexes on the slugs rejecting the saves that raced it.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually a transaction, to run the queries on.
+/// * `slug_type` - The type of the content.
+/// * `slug` - The slug asked for the content.
+/// * `exclude_id` - The ID of the content when it is updated.
+/// * `explicit` - Whether the slug was given by the client rather than made
+///   from the title or name of the content.
+///
+/// # Returns
+///
+/// Returns the slug if it is free. A slug made from the title or name is
+/// otherwise suffixed with `-2`, `-3`..., and a `ConflictError` is returned
+/// for an explicit slug.
+pub async fn unique_slug(
+    conn: &mut PgConnection,
+    slug_type: SlugType,
+    slug: &str,
+    exclude_id: Option<i32>,
+    explicit: bool,
+) -> Result<String> {
+    let taken = select_slugs_starting_with(
+        conn,
+        slug_type,
+        slug_stem(slug, SLUG_MAX_LENGTH),
+        exclude_id,
+    )
+    .await?;
+
+    if explicit && taken.contains(slug) {
+        return Err(ConflictError {
+            message: format!(
+                "The slug '{}' is already used by another {}",
+                slug,
+                slug_type.namespace()
+            ),
+        }
+        .into());
+    }
+
+    Ok(deduplicate_slug(slug, &taken, SLUG_MAX_LENGTH))
+}
+
+/// Service to record the former slug of a content whose slug changes, so
+/// that its old permalink redirects to the new one.
+///
+/// # Arguments
+///
+/// * `conn` - The connection, usually the transaction saving the content.
+/// * `slug_type` - The type of the content.
+/// * `id` - The ID of the content.
+/// * `former_slug` - The slug of the content before the change.
+/// * `slug` - The slug the content is saved with.
+///
+/// # Returns
+///
+/// Returns nothing when the slug is recorded, or did not change.
+pub async fn record_slug_change(
+    conn: &mut PgConnection,
+    slug_type: SlugType,
+    id: i32,
+    former_slug: &str,
+    slug: &str,
+) -> Result<()> {
+    if former_slug == slug {
+        return Ok(());
+    }
+
+    // A content taking back one of its former slugs no longer redirects it
+    delete_slug_history(conn, slug_type, id, slug).await?;
+    insert_slug_history(conn, slug_type, id, former_slug).await?;
+
+    Ok(())
+}
+
+/// Service to check whether a slug is free before saving a content.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `params` - The slug, the type of content and the content being edited.
+///
+/// # Returns
+///
+/// Returns a `SlugCheckDTO` with the slug made from the input, whether it is
+/// free and the slug the content would be saved with.
+pub async fn check_slug_service(
+    pool: &PgPool,
+    params: SlugCheckParamsDTO,
+) -> Result<SlugCheckDTO> {
+    let slug = generate_slug(&params.slug);
+    if let Err(error) = validate_slug(&slug, 1, SLUG_MAX_LENGTH) {
+        let mut errors = validator::ValidationErrors::new();
+        errors.add("slug", error);
+        return Err(errors.into());
+    }
+
+    let mut conn = pool.acquire().await?;
+    let suggestion =
+        unique_slug(&mut conn, params.slug_type, &slug, params.exclude_id, false)
+            .await?;
+
+    Ok(SlugCheckDTO {
+        available: suggestion == slug,
+        slug,
+        suggestion,
+    })
+}
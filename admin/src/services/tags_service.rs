@@ -5,6 +5,7 @@ use validator::Validate;
 use crate::dtos::pagination_dto::PaginationDTO;
 use crate::dtos::tag_dto::{CreateTagDTO, DeleteTagIdsDTO, TagDTO};
 use crate::handlers::generate_slug_handler::generate_slug;
+use crate::models::slugs_model::SlugType;
 use crate::models::tags_model::TagModel;
 use crate::repositories::tags_repository::{
     count_tags, delete_tag_by_id, insert_tag, select_tag_by_id, select_tags,
@@ -12,9 +13,12 @@ use crate::repositories::tags_repository::{
 };
 
 use super::calculate_pagination;
+use super::slugs_service::{record_slug_change, unique_slug};
 
 /// Service to insert a new tag into the database.
 ///
+/// A slug made from the name is suffixed when another tag has it.
+///
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
@@ -27,15 +31,26 @@ pub async fn create_tag_service(
     pool: &PgPool,
     tag_dto: CreateTagDTO,
 ) -> Result<TagDTO> {
-    let tag_model: TagModel = tag_dto.try_into()?;
+    let explicit_slug = tag_dto.slug.is_some();
+    let mut tag_model: TagModel = tag_dto.try_into()?;
+
+    let mut tx = pool.begin().await?;
+    let slug = tag_model.slug.take().unwrap_or_default();
+    tag_model.slug = Some(
+        unique_slug(&mut tx, SlugType::Tag, &slug, None, explicit_slug).await?,
+    );
+    let create_tag_model = insert_tag(&mut tx, tag_model).await?;
+    tx.commit().await?;
 
-    let create_tag_model = insert_tag(pool, tag_model).await?;
     let result = TagDTO::from(create_tag_model);
     Ok(result)
 }
 
 /// Service to update an existing tag by its ID in the database.
 ///
+/// A slug made from the name is suffixed when another tag has it, and a
+/// former slug is recorded to redirect the old permalink.
+///
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
@@ -50,6 +65,7 @@ pub async fn update_tag_service(
     id: i32,
     tag_dto: CreateTagDTO,
 ) -> Result<TagDTO> {
+    let explicit_slug = tag_dto.slug.is_some();
     let mut tag_model: TagModel = tag_dto.try_into()?;
     tag_model.id = Some(id);
 
@@ -58,7 +74,19 @@ pub async fn update_tag_service(
     }
     tag_model.validate()?;
 
-    let update_tag_model = update_tag(pool, id, tag_model).await?;
+    let former_tag = select_tag_by_id(pool, id).await?;
+    let mut tx = pool.begin().await?;
+    let slug = tag_model.slug.take().unwrap_or_default();
+    let slug =
+        unique_slug(&mut tx, SlugType::Tag, &slug, Some(id), explicit_slug)
+            .await?;
+    let former_slug = former_tag.slug.unwrap_or_default();
+    record_slug_change(&mut tx, SlugType::Tag, id, &former_slug, &slug)
+        .await?;
+    tag_model.slug = Some(slug);
+    let update_tag_model = update_tag(&mut tx, id, tag_model).await?;
+    tx.commit().await?;
+
     let result = TagDTO::from(update_tag_model);
     Ok(result)
 }
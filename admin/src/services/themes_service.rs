@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use sqlx::PgPool;
+use validator::Validate;
+
+use crate::{
+    dtos::{settings_dto::UpdateSettingsDTO, theme_dto::ActiveThemeDTO},
+    models::settings_model::SettingsGroup,
+    services::settings_service::{
+        get_text_setting, update_settings_group_service,
+    },
+};
+
+/// Name of the setting holding the active theme.
+const ACTIVE_THEME_SETTING: &str = "active_theme";
+
+/// Service to retrieve the theme rendering the public site.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<ActiveThemeDTO>` - The active theme, the default theme if none
+///   was activated.
+pub async fn get_active_theme_service(pool: &PgPool) -> Result<ActiveThemeDTO> {
+    let name = get_text_setting(pool, ACTIVE_THEME_SETTING).await?;
+
+    Ok(ActiveThemeDTO { name })
+}
+
+/// Service to change the theme rendering the public site.
+///
+/// The front checks the theme when loading it, and falls back to the default
+/// theme when it is missing or invalid.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `theme` - The theme to activate.
+///
+/// # Returns
+///
+/// * `Result<ActiveThemeDTO>` - The active theme.
+pub async fn update_active_theme_service(
+    pool: &PgPool,
+    theme: ActiveThemeDTO,
+) -> Result<ActiveThemeDTO> {
+    theme.validate()?;
+
+    let settings = BTreeMap::from([(
+        ACTIVE_THEME_SETTING.to_string(),
+        serde_json::Value::String(theme.name),
+    )]);
+    update_settings_group_service(
+        pool,
+        SettingsGroup::Appearance,
+        UpdateSettingsDTO { settings },
+    )
+    .await?;
+
+    get_active_theme_service(pool).await
+}
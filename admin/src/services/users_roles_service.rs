@@ -0,0 +1,51 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::{
+    dtos::users_roles_dto::{
+        CreateUsersRolesDTO, DeleteUsersRolesIdsDTO, UsersRolesDTO,
+    },
+    models::users_roles_model::UsersRolesModel,
+    repositories::users_roles_repository::{
+        delete_user_role_by_id, insert_user_role,
+    },
+};
+
+/// Service to assign a role to a user.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `create_dto` - A `CreateUsersRolesDTO` containing the user and role IDs.
+///
+/// # Returns
+///
+/// * `Result<UsersRolesDTO>` - The newly created assignment.
+pub async fn create_user_role_service(
+    pool: &PgPool,
+    create_dto: CreateUsersRolesDTO,
+) -> Result<UsersRolesDTO> {
+    let model: UsersRolesModel = create_dto.try_into()?;
+
+    let create_model = insert_user_role(pool, model).await?;
+    let result = UsersRolesDTO::from(create_model);
+    Ok(result)
+}
+
+/// Service to remove role assignments by their IDs.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `delete_dto` - A `DeleteUsersRolesIdsDTO` containing the assignment IDs to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted assignments.
+pub async fn delete_user_role_service(
+    pool: &PgPool,
+    delete_dto: DeleteUsersRolesIdsDTO,
+) -> Result<Vec<i32>> {
+    let deleted_ids = delete_user_role_by_id(pool, delete_dto.ids).await?;
+    Ok(deleted_ids)
+}
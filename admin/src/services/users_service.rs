@@ -8,7 +8,7 @@ use sqlx::PgPool;
 use crate::{
     dtos::{
         pagination_dto::PaginationDTO,
-        user_dtos::{CreateUserDTO, DeleteUserIdsDTO, UserDTO},
+        user_dtos::{CreateUserDTO, DeleteUserIdsDTO, UserDTO, UserFiltersDTO},
     },
     models::users_models::UserModel,
     repositories::users_repository::{
@@ -64,17 +64,24 @@ pub async fn update_user_service(
 
 pub async fn get_all_users_service(
     pool: &PgPool,
+    filters: &UserFiltersDTO,
     page: i64,
     limit: i64,
     sort_column: &str,
     sort_order: &str,
 ) -> Result<PaginationDTO<UserDTO>> {
-    let total_items = count_users(pool).await?;
+    let total_items = count_users(pool, filters).await?;
     let pagination = calculate_pagination(total_items, page, limit);
 
-    let user_model: Vec<UserModel> =
-        select_users(pool, limit, pagination.offset, sort_column, sort_order)
-            .await?;
+    let user_model: Vec<UserModel> = select_users(
+        pool,
+        filters,
+        limit,
+        pagination.offset,
+        sort_column,
+        sort_order,
+    )
+    .await?;
 
     let user_dto: Vec<UserDTO> =
         user_model.into_iter().map(UserDTO::from).collect();
@@ -0,0 +1,64 @@
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use futures::future::BoxFuture;
+use tokio::fs;
+
+use super::StorageBackend;
+
+/// Stores the files in a directory of the local filesystem, served by the
+/// API under `base_url`.
+pub struct LocalStorage {
+    root: PathBuf,
+    base_url: String,
+}
+
+impl LocalStorage {
+    /// Creates a storage saving the files under `root`.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The directory the files are saved in.
+    /// * `base_url` - The URL the directory is served at.
+    pub fn new(root: impl Into<PathBuf>, base_url: &str) -> Self {
+        LocalStorage {
+            root: root.into(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl StorageBackend for LocalStorage {
+    fn save<'a>(
+        &'a self,
+        key: &'a str,
+        data: &'a [u8],
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let path = self.root.join(key);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).await?;
+            }
+            fs::write(path, data).await?;
+            Ok(())
+        })
+    }
+
+    fn read<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Vec<u8>>> {
+        Box::pin(async move { Ok(fs::read(self.root.join(key)).await?) })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            match fs::remove_file(self.root.join(key)).await {
+                Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+                _ => Ok(()),
+            }
+        })
+    }
+
+    fn url(&self, key: &str) -> String {
+        format!("{}/{}", self.base_url, key)
+    }
+}
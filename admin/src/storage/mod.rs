@@ -0,0 +1,26 @@
+use anyhow::Result;
+use futures::future::BoxFuture;
+
+pub mod local_storage;
+
+/// Backend storing the uploaded files of the media library.
+///
+/// Files are identified by a key, a relative path such as
+/// `2024/10/0b5c0f2e.png`, which is the `path` saved in the `media` table.
+pub trait StorageBackend: Send + Sync {
+    /// Saves a file under the key, replacing any existing file.
+    fn save<'a>(
+        &'a self,
+        key: &'a str,
+        data: &'a [u8],
+    ) -> BoxFuture<'a, Result<()>>;
+
+    /// Reads the file saved under the key.
+    fn read<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Vec<u8>>>;
+
+    /// Deletes the file saved under the key, if any.
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<()>>;
+
+    /// Returns the public URL of the file saved under the key.
+    fn url(&self, key: &str) -> String;
+}
@@ -1,11 +1,15 @@
 #[cfg(test)]
 pub mod setup {
     use std::fmt::Write;
+    use std::sync::Arc;
 
+    use jsonwebtoken::{encode, EncodingKey, Header};
     use sqlx::{Pool, Postgres};
 
     use crate::config::config;
     use crate::db;
+    use crate::dtos::auth_dtos::ClaimsDTO;
+    use crate::storage::{local_storage::LocalStorage, StorageBackend};
 
     /// <summary>
     /// Set up a test database connection pool.
@@ -83,4 +87,71 @@ pub mod setup {
                 err
             })
     }
+
+    /// <summary>
+    /// Create a test user with the given role and sign a token for it.
+    /// </summary>
+    /// <param name="pool">A reference to the database connection pool.</param>
+    /// <param name="role_slug">The slug of the role granted to the test user.</param>
+    /// <returns>The value of the `Authorization` header for the test user.</returns>
+    pub async fn setup_test_auth(
+        pool: &Pool<Postgres>,
+        role_slug: &str,
+    ) -> String {
+        // <remarks>
+        // One account per role, reused across tests.
+        // </remarks>
+        let username = format!("test-{}", role_slug);
+        let (user_id,): (i32,) = sqlx::query_as(
+            "INSERT INTO users (username, password, email, firstname, lastname) \
+            VALUES ($1, 'not-a-hash', $1 || '@rustpress.test', 'Test', 'User') \
+            ON CONFLICT (username) DO UPDATE SET active = TRUE \
+            RETURNING id",
+        )
+        .bind(&username)
+        .fetch_one(pool)
+        .await
+        .expect("Failed to insert test user");
+
+        sqlx::query(
+            "INSERT INTO users_roles (user_id, role_id) \
+            SELECT $1, id FROM roles WHERE slug = $2 \
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(user_id)
+        .bind(role_slug)
+        .execute(pool)
+        .await
+        .expect("Failed to assign test role");
+
+        let claims = ClaimsDTO {
+            sub: user_id.to_string(),
+            exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp()
+                as usize,
+            jti: format!(
+                "test-{}-{}",
+                user_id,
+                chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+            ),
+        };
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(config::get_secret_key().as_ref()),
+        )
+        .expect("Failed to sign test token");
+
+        format!("Bearer {}", token)
+    }
+
+    /// <summary>
+    /// Set up a storage saving the uploaded files in a temporary directory.
+    /// </summary>
+    /// <returns>The storage backend of the test media.</returns>
+    pub fn setup_test_storage() -> Arc<dyn StorageBackend> {
+        Arc::new(LocalStorage::new(
+            std::env::temp_dir().join("rustpress-test-media"),
+            "http://127.0.0.1/uploads",
+        ))
+    }
 }
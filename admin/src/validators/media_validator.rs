@@ -0,0 +1,70 @@
+use validator::ValidationError;
+
+/// MIME types of the images resized on upload.
+pub const IMAGE_MIME_TYPES: &[&str] =
+    &["image/jpeg", "image/png", "image/gif", "image/webp"];
+
+/// MIME types accepted in the media library.
+pub const ALLOWED_MIME_TYPES: &[&str] = &[
+    "image/jpeg",
+    "image/png",
+    "image/gif",
+    "image/webp",
+    "application/pdf",
+];
+
+/// Sniffs the type of a file from its content, the extension and the
+/// `Content-Type` sent by the client being untrusted.
+///
+/// # Returns
+///
+/// Returns the MIME type and the extension of the file, or a
+/// `ValidationError` if the type is unknown or not allowed.
+pub fn validate_media_type(
+    data: &[u8],
+) -> Result<(&'static str, &'static str), ValidationError> {
+    match infer::get(data) {
+        Some(kind) if ALLOWED_MIME_TYPES.contains(&kind.mime_type()) => {
+            Ok((kind.mime_type(), kind.extension()))
+        }
+        _ => {
+            let mut error = ValidationError::new("invalid_mime_type");
+            error.message = Some(
+                format!(
+                    "File type is not allowed, expected one of: {}",
+                    ALLOWED_MIME_TYPES.join(", ")
+                )
+                .into(),
+            );
+            Err(error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_media_type_png() {
+        let data = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        assert_eq!(validate_media_type(data), Ok(("image/png", "png")));
+    }
+
+    #[test]
+    fn test_validate_media_type_pdf() {
+        let data = b"%PDF-1.7\n";
+        assert_eq!(validate_media_type(data), Ok(("application/pdf", "pdf")));
+    }
+
+    #[test]
+    fn test_validate_media_type_not_allowed() {
+        let data = b"MZ\x90\0\x03\0\0\0";
+        assert!(validate_media_type(data).is_err());
+    }
+
+    #[test]
+    fn test_validate_media_type_unknown() {
+        assert!(validate_media_type(b"Hello World").is_err());
+    }
+}
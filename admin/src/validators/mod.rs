@@ -1 +1,4 @@
+pub mod media_validator;
+pub mod redirect_validator;
+pub mod setting_validator;
 pub mod slug_validator;
@@ -0,0 +1,176 @@
+use validator::{ValidateUrl, ValidationError};
+
+use crate::models::{
+    redirects_model::REDIRECT_STATUS_CODES,
+    settings_model::RESERVED_PERMALINK_BASES,
+};
+
+/// Maximum length of the source path and the target of a redirect.
+const REDIRECT_MAX_LENGTH: usize = 2000;
+
+/// Removes the trailing slashes of a path, except for the root.
+pub fn normalize_redirect_path(path: &str) -> &str {
+    let trimmed = path.trim_end_matches('/');
+    if trimmed.is_empty() {
+        "/"
+    } else {
+        trimmed
+    }
+}
+
+/// Validates the path a redirect applies to, without query nor fragment and
+/// outside of the paths reserved by the site.
+pub fn validate_redirect_source(path: &str) -> Result<(), ValidationError> {
+    validate_length(path)?;
+    if !path.starts_with('/') || path.starts_with("//") {
+        return Err(error(
+            "invalid_path",
+            "Path must start with a single '/'".to_string(),
+        ));
+    }
+    if path.contains(['?', '#']) || path.chars().any(char::is_whitespace) {
+        return Err(error(
+            "invalid_path",
+            "Path cannot contain a query, a fragment or spaces".to_string(),
+        ));
+    }
+
+    let first_segment = path[1..].split('/').next().unwrap_or_default();
+    if RESERVED_PERMALINK_BASES.contains(&first_segment) {
+        return Err(error(
+            "reserved_path",
+            format!("'/{}' is reserved by the site", first_segment),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates the target of a redirect, a path of the site or an absolute
+/// URL.
+pub fn validate_redirect_target(target: &str) -> Result<(), ValidationError> {
+    validate_length(target)?;
+    let is_path = target.starts_with('/') && !target.starts_with("//");
+    let is_url = (target.starts_with("http://")
+        || target.starts_with("https://"))
+        && target.validate_url();
+    if !is_path && !is_url {
+        return Err(error(
+            "invalid_target",
+            "Target must be a path starting with '/' or an http(s) URL"
+                .to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates the status code a redirect answers with.
+pub fn validate_redirect_status(
+    status_code: i32,
+) -> Result<(), ValidationError> {
+    if !REDIRECT_STATUS_CODES.contains(&status_code) {
+        let codes: Vec<String> =
+            REDIRECT_STATUS_CODES.iter().map(i32::to_string).collect();
+        return Err(error(
+            "invalid_status_code",
+            format!("Status code must be one of: {}", codes.join(", ")),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_length(value: &str) -> Result<(), ValidationError> {
+    if value.is_empty() || value.len() > REDIRECT_MAX_LENGTH {
+        return Err(error(
+            "invalid_length",
+            format!(
+                "Length must be between 1 and {} characters",
+                REDIRECT_MAX_LENGTH
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn error(code: &'static str, message: String) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.message = Some(message.into());
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_redirect_path() {
+        assert_eq!(normalize_redirect_path("/old-post/"), "/old-post");
+        assert_eq!(normalize_redirect_path("/old/post"), "/old/post");
+        assert_eq!(normalize_redirect_path("/"), "/");
+        assert_eq!(normalize_redirect_path("//"), "/");
+    }
+
+    #[test]
+    fn test_validate_redirect_source_valid() {
+        assert!(validate_redirect_source("/old-post").is_ok());
+        assert!(validate_redirect_source("/2019/05/old-post").is_ok());
+        assert!(validate_redirect_source("/apis").is_ok());
+    }
+
+    #[test]
+    fn test_validate_redirect_source_invalid() {
+        let result = validate_redirect_source("old-post");
+        assert_eq!(result.unwrap_err().code, "invalid_path");
+
+        let result = validate_redirect_source("//example.com");
+        assert_eq!(result.unwrap_err().code, "invalid_path");
+
+        let result = validate_redirect_source("/old-post?page=2");
+        assert_eq!(result.unwrap_err().code, "invalid_path");
+
+        let result = validate_redirect_source("");
+        assert_eq!(result.unwrap_err().code, "invalid_length");
+    }
+
+    #[test]
+    fn test_validate_redirect_source_reserved() {
+        let result = validate_redirect_source("/rs-admin/posts");
+        let err = result.unwrap_err();
+        assert_eq!(err.code, "reserved_path");
+        assert_eq!(err.message.unwrap(), "'/rs-admin' is reserved by the site");
+
+        let result = validate_redirect_source("/api");
+        assert_eq!(result.unwrap_err().code, "reserved_path");
+    }
+
+    #[test]
+    fn test_validate_redirect_target() {
+        assert!(validate_redirect_target("/new-post").is_ok());
+        assert!(validate_redirect_target("https://example.com/post").is_ok());
+
+        let result = validate_redirect_target("new-post");
+        assert_eq!(result.unwrap_err().code, "invalid_target");
+
+        let result = validate_redirect_target("//example.com");
+        assert_eq!(result.unwrap_err().code, "invalid_target");
+
+        let result = validate_redirect_target("ftp://example.com");
+        assert_eq!(result.unwrap_err().code, "invalid_target");
+    }
+
+    #[test]
+    fn test_validate_redirect_status() {
+        assert!(validate_redirect_status(301).is_ok());
+        assert!(validate_redirect_status(308).is_ok());
+
+        let err = validate_redirect_status(303).unwrap_err();
+        assert_eq!(err.code, "invalid_status_code");
+        assert_eq!(
+            err.message.unwrap(),
+            "Status code must be one of: 301, 302, 307, 308"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use serde_json::Value;
+use validator::{ValidateUrl, ValidationError};
+
+use crate::{
+    models::settings_model::{
+        SettingDefinition, SettingType, RESERVED_PERMALINK_BASES,
+    },
+    validators::slug_validator::validate_slug,
+};
+
+/// Validates a new value of a setting against the type of the setting.
+pub fn validate_setting(
+    definition: &SettingDefinition,
+    value: &Value,
+) -> Result<(), ValidationError> {
+    match definition.setting_type {
+        SettingType::Text {
+            min_length,
+            max_length,
+        } => {
+            let text = expect_text(value)?;
+            let length = text.chars().count();
+            if length < min_length || length > max_length {
+                return Err(error(
+                    "invalid_length",
+                    format!(
+                        "Length must be between {} and {} characters",
+                        min_length, max_length
+                    ),
+                ));
+            }
+        }
+        SettingType::Integer { min, max } => {
+            let number = value.as_i64().ok_or_else(|| {
+                error("invalid_type", "Value must be an integer".to_string())
+            })?;
+            if number < min || number > max {
+                return Err(error(
+                    "invalid_range",
+                    format!("Value must be between {} and {}", min, max),
+                ));
+            }
+        }
+        SettingType::Boolean => {
+            if !value.is_boolean() {
+                return Err(error(
+                    "invalid_type",
+                    "Value must be a boolean".to_string(),
+                ));
+            }
+        }
+        SettingType::Url => {
+            if !expect_text(value)?.validate_url() {
+                return Err(error(
+                    "invalid_url",
+                    "Value must be a valid URL".to_string(),
+                ));
+            }
+        }
+        SettingType::Slug => validate_slug(expect_text(value)?, 1, 100)?,
+        SettingType::PermalinkBase => {
+            let base = expect_text(value)?;
+            validate_slug(base, 1, 100)?;
+            if RESERVED_PERMALINK_BASES.contains(&base) {
+                return Err(error(
+                    "reserved_base",
+                    format!("'{}' is reserved by the site", base),
+                ));
+            }
+        }
+        SettingType::Choice(choices) => {
+            if !choices.contains(&expect_text(value)?) {
+                return Err(error(
+                    "invalid_choice",
+                    format!("Value must be one of: {}", choices.join(", ")),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn expect_text(value: &Value) -> Result<&str, ValidationError> {
+    value.as_str().ok_or_else(|| {
+        error("invalid_type", "Value must be a string".to_string())
+    })
+}
+
+fn error(code: &'static str, message: String) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.message = Some(message.into());
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::models::settings_model::find_setting;
+
+    fn setting(name: &str) -> &'static SettingDefinition {
+        find_setting(name).expect("Unknown setting")
+    }
+
+    #[test]
+    fn test_validate_setting_text() {
+        assert!(
+            validate_setting(setting("site_title"), &json!("My blog")).is_ok()
+        );
+
+        let result = validate_setting(setting("site_title"), &json!(""));
+        assert_eq!(result.unwrap_err().code, "invalid_length");
+
+        let result = validate_setting(setting("site_title"), &json!(42));
+        assert_eq!(result.unwrap_err().code, "invalid_type");
+    }
+
+    #[test]
+    fn test_validate_setting_integer() {
+        assert!(validate_setting(setting("posts_per_page"), &json!(20)).is_ok());
+
+        let result = validate_setting(setting("posts_per_page"), &json!(0));
+        assert_eq!(result.unwrap_err().code, "invalid_range");
+
+        let result = validate_setting(setting("posts_per_page"), &json!("20"));
+        assert_eq!(result.unwrap_err().code, "invalid_type");
+    }
+
+    #[test]
+    fn test_validate_setting_boolean() {
+        assert!(validate_setting(setting("comments_enabled"), &json!(false))
+            .is_ok());
+
+        let result =
+            validate_setting(setting("comments_enabled"), &json!("false"));
+        assert_eq!(result.unwrap_err().code, "invalid_type");
+    }
+
+    #[test]
+    fn test_validate_setting_url() {
+        assert!(validate_setting(
+            setting("site_url"),
+            &json!("https://example.com")
+        )
+        .is_ok());
+
+        let result = validate_setting(setting("site_url"), &json!("example"));
+        assert_eq!(result.unwrap_err().code, "invalid_url");
+    }
+
+    #[test]
+    fn test_validate_setting_permalink_base() {
+        assert!(validate_setting(setting("tag_base"), &json!("topic")).is_ok());
+
+        let result = validate_setting(setting("tag_base"), &json!("archives"));
+        assert_eq!(result.unwrap_err().code, "reserved_base");
+
+        let result = validate_setting(setting("tag_base"), &json!("a/b"));
+        assert_eq!(result.unwrap_err().code, "invalid_format");
+    }
+
+    #[test]
+    fn test_validate_setting_choice() {
+        assert!(validate_setting(setting("language"), &json!("fr")).is_ok());
+
+        let result = validate_setting(setting("language"), &json!("xx"));
+        assert_eq!(result.unwrap_err().code, "invalid_choice");
+
+        assert!(validate_setting(setting("feed_content"), &json!("excerpt"))
+            .is_ok());
+
+        let result =
+            validate_setting(setting("feed_content"), &json!("summary"));
+        assert_eq!(result.unwrap_err().code, "invalid_choice");
+    }
+}
@@ -0,0 +1,20 @@
+use chrono::{NaiveDateTime, Utc};
+
+pub mod scheduled_posts_worker;
+
+/// Source of the current date used by the background workers.
+///
+/// Injected so that tests can run a worker at any given date.
+pub trait Clock: Send + Sync {
+    /// Returns the current date, in UTC.
+    fn now(&self) -> NaiveDateTime;
+}
+
+/// Clock reading the system time.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> NaiveDateTime {
+        Utc::now().naive_utc()
+    }
+}
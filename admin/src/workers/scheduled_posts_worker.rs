@@ -0,0 +1,155 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use log::error;
+use sqlx::PgPool;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+use crate::services::posts_services::publish_scheduled_posts_service;
+
+use super::Clock;
+
+/// Publishes the scheduled posts that are due at the date given by the clock.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `clock` - The clock giving the current date.
+///
+/// # Returns
+///
+/// Returns the IDs of the published posts.
+pub async fn run_once(pool: &PgPool, clock: &dyn Clock) -> Result<Vec<i32>> {
+    publish_scheduled_posts_service(pool, clock.now()).await
+}
+
+/// Spawns a background task publishing the due scheduled posts periodically.
+///
+/// A failed run is logged and retried at the next tick.
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool.
+/// * `clock` - The clock giving the current date.
+/// * `interval` - The delay between two runs.
+///
+/// # Returns
+///
+/// Returns the handle of the spawned task.
+pub fn spawn(
+    pool: PgPool,
+    clock: Arc<dyn Clock>,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if let Err(e) = run_once(&pool, clock.as_ref()).await {
+                error!("Scheduled posts publishing failed: {:?}", e);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use super::*;
+    use crate::models::posts_model::PostsStatus;
+    use crate::tests::helpers::setup::{clean_data_test, setup_test_db};
+
+    struct FixedClock(NaiveDateTime);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> NaiveDateTime {
+            self.0
+        }
+    }
+
+    fn date(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2031, 6, 1)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    async fn insert_scheduled_post(pool: &PgPool, slug: &str) -> i32 {
+        let author_id: i32 =
+            sqlx::query_scalar!("SELECT id FROM users ORDER BY id LIMIT 1")
+                .fetch_one(pool)
+                .await
+                .expect("Failed to query test user");
+
+        sqlx::query_scalar!(
+            r#"
+            INSERT INTO posts
+                (title, content, slug, author_id, status, date_published)
+            VALUES ($1, $2, $3, $4, $5::posts_status, $6)
+            RETURNING id
+            "#,
+            "Test Scheduled Post",
+            "Test Scheduled Post Content",
+            slug,
+            author_id,
+            PostsStatus::Scheduled as _,
+            date(12, 0) as _,
+        )
+        .fetch_one(pool)
+        .await
+        .expect("Failed to insert test post")
+    }
+
+    async fn post_status(pool: &PgPool, id: i32) -> PostsStatus {
+        sqlx::query_scalar!(
+            r#"SELECT status AS "status: PostsStatus" FROM posts WHERE id = $1"#,
+            id
+        )
+        .fetch_one(pool)
+        .await
+        .expect("Failed to query test post")
+    }
+
+    #[ntex::test]
+    async fn test_run_once_publishes_due_posts_once() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let post_id =
+            insert_scheduled_post(&pool, "test-scheduled-post-due").await;
+        let clock = FixedClock(date(12, 0));
+
+        // Act
+        let early_ids = run_once(&pool, &FixedClock(date(11, 59)))
+            .await
+            .expect("Failed to run the worker");
+        let early_status = post_status(&pool, post_id).await;
+        let (first, second) =
+            tokio::join!(run_once(&pool, &clock), run_once(&pool, &clock));
+        let again_ids = run_once(&pool, &FixedClock(date(12, 1)))
+            .await
+            .expect("Failed to run the worker");
+
+        // Assert
+        assert!(!early_ids.contains(&post_id));
+        assert_eq!(early_status, PostsStatus::Scheduled);
+        let published = first
+            .expect("Failed to run the worker")
+            .into_iter()
+            .chain(second.expect("Failed to run the worker"))
+            .filter(|&id| id == post_id)
+            .count();
+        assert_eq!(published, 1);
+        assert_eq!(post_status(&pool, post_id).await, PostsStatus::Published);
+        assert!(!again_ids.contains(&post_id));
+
+        // Clean up test data
+        clean_data_test(&pool, "posts", "slug", "test-scheduled-post-due")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
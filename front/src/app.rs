@@ -3,17 +3,28 @@ use leptos_meta::*;
 use leptos_router::*;
 
 use crate::error_template::{AppError, ErrorTemplate};
+use crate::views::admin::comments::comments_index_view::AdminCommentsView;
 use crate::views::admin::dashboard::dashboard_view::AdminDashboardView;
 use crate::views::admin::layout_view::AdminLayoutView;
 use crate::views::admin::login::login_view::AdminLoginView;
+use crate::views::admin::menus::menus_index_view::AdminMenusView;
+use crate::views::admin::pages::page_new_view::AdminPageNewView;
+use crate::views::admin::posts::post_edit_view::AdminPostEditView;
 use crate::views::admin::posts::post_new_view::AdminPostNewView;
 use crate::views::admin::posts::posts_index_view::AdminPostsView;
+use crate::views::admin::redirects::redirects_index_view::AdminRedirectsView;
 use crate::views::admin::tags::tags_index_view::AdminTagsView;
+use crate::views::admin::themes::themes_index_view::AdminThemesView;
+use crate::services::front::settings_service::site_settings;
+use crate::views::front::archive_view::{FrontArchiveView, FrontDateView};
+use crate::views::front::home_view::FrontHomeView;
+use crate::views::front::not_found_view::FrontNotFoundView;
+use crate::views::front::single_view::FrontSingleView;
 
 #[component]
 pub fn App() -> impl IntoView {
     provide_meta_context();
-    let formatter = |text| format!("{text} - RustPress");
+    let settings = create_blocking_resource(|| (), |_| site_settings());
 
     view! {
         <Stylesheet id="leptos" href="/pkg/rustpress_view.css"/>
@@ -30,13 +41,37 @@ pub fn App() -> impl IntoView {
             view! { <ErrorTemplate outside_errors/> }.into_view()
         }>
             <main>
-                <Title formatter/>
-
                 <Meta charset="utf-8"/>
-                <Meta name="title" content="RustPress"/>
-                <Meta name="description" content="A Wordpress clone in Rust!"/>
 
-                <Html lang="en" dir="ltr" attr:data-bs-theme="dark"/>
+                // Site title, description, language and feeds of the settings
+                <Suspense>
+                    {move || {
+                        settings
+                            .get()
+                            .map(|settings| {
+                                let site_title = settings.title.clone();
+                                let formatter = move |text| format!("{text} - {site_title}");
+                                view! {
+                                    <Title formatter/>
+                                    <Meta name="title" content=settings.title.clone()/>
+                                    <Meta name="description" content=settings.description/>
+                                    <Html lang=settings.language dir="ltr" attr:data-bs-theme="dark"/>
+                                    <Link
+                                        rel="alternate"
+                                        type_="application/rss+xml"
+                                        title=format!("{} RSS feed", settings.title)
+                                        href="/feed"
+                                    />
+                                    <Link
+                                        rel="alternate"
+                                        type_="application/atom+xml"
+                                        title=format!("{} Atom feed", settings.title)
+                                        href="/feed/atom"
+                                    />
+                                }
+                            })
+                    }}
+                </Suspense>
 
                 <Routes>
 
@@ -48,15 +83,36 @@ pub fn App() -> impl IntoView {
                         <Route path="posts" view=AdminPostsView/>
                         <Route path="posts/:page" view=AdminPostsView/>
                         <Route path="posts/new" view=AdminPostNewView/>
+                        <Route path="posts/:id/edit" view=AdminPostEditView/>
+
+                        // Pages routes
+                        <Route path="pages/new" view=AdminPageNewView/>
 
                         // Tags routes
                         <Route path="tags" view=AdminTagsView/>
+
+                        // Comments routes
+                        <Route path="comments" view=AdminCommentsView/>
+
+                        // Themes routes
+                        <Route path="themes" view=AdminThemesView/>
+
+                        // Menus routes
+                        <Route path="menus" view=AdminMenusView/>
+
+                        // Redirects routes
+                        <Route path="redirects" view=AdminRedirectsView/>
                     </Route>
 
                     <Route path="/rs-admin/login" view=AdminLoginView/>
 
-                // Front routes
-                // <Route path="/" view=FrontHomeView/>
+                    // Front routes
+                    <Route path="/" view=FrontHomeView/>
+                    <Route path="/archives/:year" view=FrontDateView/>
+                    <Route path="/archives/:year/:month" view=FrontDateView/>
+                    <Route path="/:base/:slug" view=FrontArchiveView/>
+                    <Route path="/:slug" view=FrontSingleView/>
+                    <Route path="/*any" view=FrontNotFoundView/>
 
                 </Routes>
             </main>
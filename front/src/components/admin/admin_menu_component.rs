@@ -18,6 +18,12 @@ pub fn AdminMenu() -> impl IntoView {
                         Posts
                     </A>
                 </li>
+                <li class="nav-item">
+                    <A class="nav-link" href="pages/new">
+                        <i class="bi bi-file-earmark me-2"></i>
+                        Pages
+                    </A>
+                </li>
                 <li class="nav-item">
                     <A class="nav-link" href="categories">
                         <i class="bi bi-bookmarks me-2"></i>
@@ -30,6 +36,30 @@ pub fn AdminMenu() -> impl IntoView {
                         Tags
                     </A>
                 </li>
+                <li class="nav-item">
+                    <A class="nav-link" href="comments">
+                        <i class="bi bi-chat-left-text me-2"></i>
+                        Comments
+                    </A>
+                </li>
+                <li class="nav-item">
+                    <A class="nav-link" href="themes">
+                        <i class="bi bi-palette me-2"></i>
+                        Themes
+                    </A>
+                </li>
+                <li class="nav-item">
+                    <A class="nav-link" href="menus">
+                        <i class="bi bi-menu-button-wide me-2"></i>
+                        Menus
+                    </A>
+                </li>
+                <li class="nav-item">
+                    <A class="nav-link" href="redirects">
+                        <i class="bi bi-signpost-split me-2"></i>
+                        Redirects
+                    </A>
+                </li>
             </ul>
         </nav>
     }
@@ -32,7 +32,19 @@ pub fn DataTable<T: Id + 'static + Clone>(
     sort_order: RwSignal<SortOrder>,
     on_page_change: impl Fn(u32) + Clone + 'static,
     on_items_per_page_change: impl Fn(u32) + Clone + 'static,
+    #[prop(optional, into)] on_search: Option<Callback<String>>,
+    #[prop(optional, into)] filters: Option<ViewFn>,
+    #[prop(optional)] show_filters: Option<RwSignal<bool>>,
 ) -> impl IntoView {
+    // The panel state can be owned by the parent so that it survives a reload of the data
+    let show_filters = show_filters.unwrap_or_else(|| create_rw_signal(false));
+    let has_filters = filters.is_some();
+    let (search, set_search) = create_signal(String::new());
+    let submit_search = move || {
+        if let Some(on_search) = on_search {
+            on_search.call(search.get_untracked().trim().to_string());
+        }
+    };
     let total_pages = create_memo(move |_| {
         let total_items = total_items.get();
         let per_page = items_per_page.get();
@@ -58,15 +70,34 @@ pub fn DataTable<T: Id + 'static + Clone>(
                                 aria-label="Search"
                                 aria-describedby="Search in datas"
                                 style="width: 300px"
+                                prop:value=search
+                                on:input=move |ev| set_search.set(event_target_value(&ev))
+                                on:keydown=move |ev| {
+                                    if ev.key() == "Enter" {
+                                        submit_search();
+                                    }
+                                }
                             />
-                            <button class="btn btn-primary" type="button" id="button-addon2">
+                            <button
+                                class="btn btn-primary"
+                                type="button"
+                                id="button-addon2"
+                                on:click=move |_| submit_search()
+                            >
                                 <i class="bi bi-search"></i>
                             </button>
                         </div>
 
-                        <a class="btn btn-primary me-2" href="#" role="button">
-                            Filters
-                        </a>
+                        <Show when=move || has_filters>
+                            <button
+                                class="btn btn-primary me-2"
+                                type="button"
+                                aria-expanded=move || show_filters.get().to_string()
+                                on:click=move |_| show_filters.update(|show| *show = !*show)
+                            >
+                                Filters
+                            </button>
+                        </Show>
 
                         <select
                             class="form-select me-2"
@@ -98,7 +129,10 @@ pub fn DataTable<T: Id + 'static + Clone>(
                         </select>
 
                         <DataTableVisibilityDropdown columns=columns.into()/>
+                    </div>
 
+                    <div class="w-100 my-2" class:d-none=move || !show_filters.get()>
+                        {filters.map(|filters| filters.run())}
                     </div>
 
                     // Table rendering
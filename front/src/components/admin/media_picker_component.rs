@@ -0,0 +1,171 @@
+use leptos::*;
+use web_sys::HtmlInputElement;
+
+use crate::{
+    components::{
+        admin::modal_component::{Modal, Size},
+        front::loading_component::LoadingComponent,
+    },
+    services::admin::api::media_api::{get_media, get_media_by_id, upload_media},
+};
+
+// Nombre de médias affichés dans la médiathèque
+const MEDIA_PAGE_SIZE: u32 = 24;
+
+#[component]
+pub fn MediaPickerComponent(
+    media_id: Signal<Option<u32>>,          // Signal pour lire l'image mise en avant
+    set_media_id: WriteSignal<Option<u32>>, // Setter pour choisir l'image mise en avant
+) -> impl IntoView {
+    let is_modal_open = create_rw_signal(false);
+    let (library_version, set_library_version) = create_signal(0u32);
+    let (upload_error, set_upload_error) = create_signal(None::<String>);
+
+    let selected_media = create_resource(
+        move || media_id.get(),
+        |media_id| async move {
+            match media_id {
+                Some(media_id) => get_media_by_id(media_id).await.ok(),
+                None => None,
+            }
+        },
+    );
+
+    // Seules les images peuvent être mises en avant
+    let library = create_resource(
+        move || library_version.get(),
+        |_| async { get_media(1, MEDIA_PAGE_SIZE).await.ok() },
+    );
+
+    let on_upload = move |ev: ev::Event| {
+        let input = event_target::<HtmlInputElement>(&ev);
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        input.set_value("");
+        spawn_local(async move {
+            match upload_media(file).await {
+                Ok(media) => {
+                    set_upload_error.set(None);
+                    set_media_id.set(Some(media.id));
+                    set_library_version.update(|version| *version += 1);
+                }
+                Err(e) => set_upload_error.set(Some(e)),
+            }
+        });
+    };
+
+    view! {
+        <div class="media-picker-component mb-3">
+            <h3 class="form-label">"Featured image"</h3>
+            <Suspense fallback=move || {
+                view! { <LoadingComponent/> }
+            }>
+                {move || {
+                    selected_media
+                        .get()
+                        .flatten()
+                        .map(|media| {
+                            view! {
+                                <img src=media.size_url("medium") class="img-fluid rounded mb-2" alt=media.filename/>
+                            }
+                        })
+                }}
+
+            </Suspense>
+            <div class="d-flex gap-2">
+                <button
+                    type="button"
+                    class="btn btn-sm btn-outline-primary"
+                    data-bs-toggle="modal"
+                    data-bs-target="#mediaPickerModal"
+                    on:click=move |_| is_modal_open.set(true)
+                >
+                    {move || {
+                        if media_id.get().is_some() {
+                            "Replace featured image"
+                        } else {
+                            "Set featured image"
+                        }
+                    }}
+
+                </button>
+                <Show when=move || media_id.get().is_some()>
+                    <button
+                        type="button"
+                        class="btn btn-sm btn-outline-danger"
+                        on:click=move |_| set_media_id.set(None)
+                    >
+                        "Remove"
+                    </button>
+                </Show>
+            </div>
+        </div>
+
+        <Modal
+            id="mediaPickerModal".to_string()
+            toggle=is_modal_open
+            title="Featured image".to_string()
+            size=Size::Large
+            body=view! {
+                <div class="mb-3">
+                    <label for="media-upload" class="form-label">"Upload a new image"</label>
+                    <input
+                        type="file"
+                        class="form-control"
+                        id="media-upload"
+                        accept="image/jpeg,image/png,image/gif,image/webp"
+                        on:change=on_upload
+                    />
+                    {move || {
+                        upload_error
+                            .get()
+                            .map(|error| view! { <div class="text-danger small mt-1">{error}</div> })
+                    }}
+
+                </div>
+                <Suspense fallback=move || {
+                    view! { <LoadingComponent/> }
+                }>
+                    {move || match library.get() {
+                        Some(Some(paginated_media)) => {
+                            view! {
+                                <div class="row g-2">
+                                    {paginated_media
+                                        .data
+                                        .into_iter()
+                                        .filter(|media| media.is_image())
+                                        .map(|media| {
+                                            let id = media.id;
+                                            view! {
+                                                <div class="col-3">
+                                                    <button
+                                                        type="button"
+                                                        class="btn p-0 border"
+                                                        class:border-primary=move || media_id.get() == Some(id)
+                                                        data-bs-dismiss="modal"
+                                                        on:click=move |_| {
+                                                            set_media_id.set(Some(id));
+                                                            is_modal_open.set(false);
+                                                        }
+                                                    >
+                                                        <img src=media.size_url("thumbnail") class="img-fluid" alt=media.filename/>
+                                                    </button>
+                                                </div>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            }
+                                .into_view()
+                        }
+                        Some(None) => view! { <div>"No media found."</div> }.into_view(),
+                        None => view! { <LoadingComponent/> }.into_view(),
+                    }}
+
+                </Suspense>
+            }
+            footer=None
+        />
+    }
+}
@@ -0,0 +1,319 @@
+use leptos::*;
+use strum::IntoEnumIterator;
+
+use crate::{
+    components::front::loading_component::LoadingComponent,
+    models::admin::{
+        menus_model::{MenuEditorItem, MenuItemTypeEnum},
+        posts_model::PostFilters,
+    },
+    services::admin::api::{
+        categories_api::get_categories, pages_api::get_pages, posts_api::get_posts, tags_api::get_tags,
+    },
+    utils::menu_items_util::{indent_menu_item, move_menu_item, outdent_menu_item, remove_menu_item},
+};
+
+/// Contents an item of a given type can link to, as their ID and title.
+async fn menu_item_choices(item_type: MenuItemTypeEnum) -> Result<Vec<(u32, String)>, String> {
+    match item_type {
+        MenuItemTypeEnum::Page => Ok(get_pages(1, 100)
+            .await?
+            .data
+            .into_iter()
+            .map(|page| (page.id, page.title))
+            .collect()),
+        MenuItemTypeEnum::Post => Ok(
+            get_posts(1, 100, "date_created".into(), "DESC".into(), PostFilters::default())
+                .await?
+                .data
+                .into_iter()
+                .map(|post| (post.id, post.title))
+                .collect(),
+        ),
+        MenuItemTypeEnum::Category => Ok(get_categories()
+            .await?
+            .into_iter()
+            .map(|category| (category.id, category.name))
+            .collect()),
+        MenuItemTypeEnum::Tag => Ok(get_tags().await?.into_iter().map(|tag| (tag.id, tag.name)).collect()),
+        MenuItemTypeEnum::Custom => Ok(Vec::new()),
+    }
+}
+
+/// Editor of the items of a menu.
+///
+/// The items are listed with their sub-items indented under them. An item is moved, with its sub-items, by
+/// dragging it onto the item it should come before, and nested with the arrow buttons.
+///
+/// # Arguments
+///
+/// * `items` - The items of the menu, every item after its parent.
+#[component]
+pub fn MenuItemsComponent(items: RwSignal<Vec<MenuEditorItem>>) -> impl IntoView {
+    let (dragged, set_dragged) = create_signal(None::<usize>);
+    let (drop_target, set_drop_target) = create_signal(None::<usize>);
+
+    let item_type = create_rw_signal(MenuItemTypeEnum::Page);
+    let object_id = create_rw_signal(None::<u32>);
+    let custom_url = create_rw_signal(String::new());
+    let custom_title = create_rw_signal(String::new());
+    let choices = create_resource(move || item_type.get(), menu_item_choices);
+
+    let drop = move |target: usize| {
+        if let Some(index) = dragged.get_untracked() {
+            items.update(|items| move_menu_item(items, index, target));
+        }
+        set_dragged.set(None);
+        set_drop_target.set(None);
+    };
+
+    let add_item = move |_| {
+        let item_type = item_type.get_untracked();
+        let (object_id, url, title, object_title) = if item_type == MenuItemTypeEnum::Custom {
+            let url = custom_url.get_untracked().trim().to_string();
+            let title = custom_title.get_untracked().trim().to_string();
+            if url.is_empty() || title.is_empty() {
+                return;
+            }
+            (None, Some(url), Some(title), None)
+        } else {
+            let Some(object_id) = object_id.get_untracked() else {
+                return;
+            };
+            let object_title = untrack(|| choices.get())
+                .and_then(Result::ok)
+                .and_then(|choices| choices.into_iter().find(|(id, _)| *id == object_id))
+                .map(|(_, title)| title);
+            (Some(object_id), None, None, object_title)
+        };
+
+        items.update(|items| {
+            items.push(MenuEditorItem {
+                depth: 0,
+                item_type,
+                object_id,
+                url,
+                title,
+                object_title,
+            })
+        });
+        custom_url.set(String::new());
+        custom_title.set(String::new());
+    };
+
+    let item_row = move |index: usize, item: MenuEditorItem| {
+        let placeholder = item.object_title.clone().unwrap_or_else(|| "(deleted)".to_string());
+        let target = item
+            .url
+            .clone()
+            .map(|url| format!("{} - {}", item.item_type, url))
+            .unwrap_or_else(|| format!("{} - {}", item.item_type, placeholder));
+
+        view! {
+            <li
+                class="list-group-item d-flex align-items-center gap-2"
+                class:border-primary=move || drop_target.get() == Some(index)
+                style=format!("margin-left: {}rem", item.depth * 2)
+                draggable="true"
+                on:dragstart=move |ev| {
+                    if let Some(data_transfer) = ev.data_transfer() {
+                        let _ = data_transfer.set_data("text/plain", &index.to_string());
+                    }
+                    set_dragged.set(Some(index));
+                }
+                on:dragover=move |ev| {
+                    ev.prevent_default();
+                    set_drop_target.set(Some(index));
+                }
+                on:dragend=move |_| {
+                    set_dragged.set(None);
+                    set_drop_target.set(None);
+                }
+                on:drop=move |ev| {
+                    ev.prevent_default();
+                    drop(index);
+                }
+            >
+                <i class="bi bi-grip-vertical text-body-secondary" style="cursor: move"></i>
+                <input
+                    type="text"
+                    class="form-control form-control-sm"
+                    placeholder=placeholder
+                    prop:value=item.title.clone().unwrap_or_default()
+                    on:change=move |ev| {
+                        let title = event_target_value(&ev).trim().to_string();
+                        items.update(|items| items[index].title = (!title.is_empty()).then_some(title));
+                    }
+                />
+                <small class="text-body-secondary text-nowrap">{target}</small>
+                <div class="btn-group btn-group-sm">
+                    <button
+                        type="button"
+                        class="btn btn-outline-secondary"
+                        title="Move up one level"
+                        disabled=item.depth == 0
+                        on:click=move |_| items.update(|items| outdent_menu_item(items, index))
+                    >
+                        <i class="bi bi-arrow-left"></i>
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-outline-secondary"
+                        title="Make a sub-item of the item above"
+                        disabled=index == 0
+                        on:click=move |_| items.update(|items| indent_menu_item(items, index))
+                    >
+                        <i class="bi bi-arrow-right"></i>
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-outline-danger"
+                        title="Remove, with the sub-items"
+                        on:click=move |_| items.update(|items| remove_menu_item(items, index))
+                    >
+                        <i class="bi bi-trash"></i>
+                    </button>
+                </div>
+            </li>
+        }
+    };
+
+    view! {
+        <div class="menu-items-component mb-3">
+            <ul class="list-group mb-2">
+                {move || {
+                    items.get().into_iter().enumerate().map(|(index, item)| item_row(index, item)).collect_view()
+                }}
+                <li
+                    class="list-group-item text-body-secondary small"
+                    class:border-primary=move || drop_target.get() == Some(items.get().len())
+                    on:dragover=move |ev| {
+                        ev.prevent_default();
+                        set_drop_target.set(Some(items.get_untracked().len()));
+                    }
+                    on:drop=move |ev| {
+                        ev.prevent_default();
+                        drop(items.get_untracked().len());
+                    }
+                >
+                    {move || {
+                        if items.get().is_empty() {
+                            "The menu has no items yet."
+                        } else {
+                            "Drop an item here to move it to the end of the menu."
+                        }
+                    }}
+                </li>
+            </ul>
+
+            <h3>"Add items"</h3>
+            <div class="row g-2 align-items-end">
+                <div class="col-md-3">
+                    <label for="menu-item-type" class="form-label">"Type"</label>
+                    <select
+                        id="menu-item-type"
+                        class="form-select"
+                        on:change=move |ev| {
+                            if let Some(selected) = MenuItemTypeEnum::iter()
+                                .find(|item_type| item_type.to_string() == event_target_value(&ev))
+                            {
+                                object_id.set(None);
+                                item_type.set(selected);
+                            }
+                        }
+                    >
+                        {MenuItemTypeEnum::iter()
+                            .map(|option| {
+                                view! {
+                                    <option value=option.to_string() selected=move || item_type.get() == option>
+                                        {option.to_string()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+                {move || {
+                    if item_type.get() == MenuItemTypeEnum::Custom {
+                        view! {
+                            <div class="col-md-4">
+                                <label for="menu-item-url" class="form-label">"URL"</label>
+                                <input
+                                    type="text"
+                                    id="menu-item-url"
+                                    class="form-control"
+                                    placeholder="https://example.com or /contact"
+                                    prop:value=move || custom_url.get()
+                                    on:input=move |ev| custom_url.set(event_target_value(&ev))
+                                />
+                            </div>
+                            <div class="col-md-3">
+                                <label for="menu-item-title" class="form-label">"Title"</label>
+                                <input
+                                    type="text"
+                                    id="menu-item-title"
+                                    class="form-control"
+                                    prop:value=move || custom_title.get()
+                                    on:input=move |ev| custom_title.set(event_target_value(&ev))
+                                />
+                            </div>
+                        }
+                            .into_view()
+                    } else {
+                        view! {
+                            <div class="col-md-7">
+                                <label for="menu-item-object" class="form-label">"Content"</label>
+                                <Suspense fallback=move || {
+                                    view! { <LoadingComponent/> }
+                                }>
+                                    {move || {
+                                        choices
+                                            .get()
+                                            .map(|choices| match choices {
+                                                Ok(choices) => {
+                                                    view! {
+                                                        <select
+                                                            id="menu-item-object"
+                                                            class="form-select"
+                                                            on:change=move |ev| {
+                                                                object_id.set(event_target_value(&ev).parse().ok());
+                                                            }
+                                                        >
+                                                            <option value="" selected=move || object_id.get().is_none()>
+                                                                "Choose..."
+                                                            </option>
+                                                            {choices
+                                                                .into_iter()
+                                                                .map(|(id, title)| {
+                                                                    view! {
+                                                                        <option
+                                                                            value=id.to_string()
+                                                                            selected=move || object_id.get() == Some(id)
+                                                                        >
+                                                                            {title}
+                                                                        </option>
+                                                                    }
+                                                                })
+                                                                .collect_view()}
+                                                        </select>
+                                                    }
+                                                        .into_view()
+                                                }
+                                                Err(err) => view! { <p>{format!("Error loading contents: {}", err)}</p> }.into_view(),
+                                            })
+                                    }}
+                                </Suspense>
+                            </div>
+                        }
+                            .into_view()
+                    }
+                }}
+                <div class="col-md-2">
+                    <button type="button" class="btn btn-outline-primary w-100" on:click=add_item>
+                        "Add to menu"
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
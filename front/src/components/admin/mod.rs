@@ -3,7 +3,12 @@ pub mod admin_menu_component;
 pub mod categories_component;
 pub mod data_table;
 pub mod header_content_component;
+pub mod media_picker_component;
+pub mod menu_items_component;
 pub mod modal_component;
 pub mod notification_component;
+pub mod page_attributes_component;
 pub mod publish_component;
+pub mod revisions_component;
+pub mod slug_field_component;
 pub mod tags_component;
@@ -0,0 +1,88 @@
+use leptos::*;
+
+use crate::{
+    components::front::loading_component::LoadingComponent,
+    services::admin::api::pages_api::get_pages,
+};
+
+#[component]
+pub fn PageAttributesComponent(
+    parent_id: RwSignal<Option<u32>>,
+    menu_order: RwSignal<i32>,
+    template: RwSignal<String>,
+) -> impl IntoView {
+    // Pages proposées comme parent
+    let pages = create_resource(|| (), |_| async { get_pages(1, 100).await.ok() });
+
+    view! {
+        <div class="page-attributes-component mb-3">
+            <h3>"Page attributes"</h3>
+
+            <div class="mb-3">
+                <label for="page-parent" class="form-label">"Parent"</label>
+                <Suspense fallback=move || {
+                    view! { <LoadingComponent/> }
+                }>
+                    {move || {
+                        pages
+                            .get()
+                            .map(|pages| {
+                                view! {
+                                    <select
+                                        id="page-parent"
+                                        class="form-select"
+                                        on:change=move |ev| {
+                                            parent_id.set(event_target_value(&ev).parse().ok());
+                                        }
+                                    >
+                                        <option value="" selected=move || parent_id.get().is_none()>
+                                            "(no parent)"
+                                        </option>
+                                        {pages
+                                            .map(|pages| pages.data)
+                                            .unwrap_or_default()
+                                            .into_iter()
+                                            .map(|page| {
+                                                let id = page.id;
+                                                view! {
+                                                    <option
+                                                        value=id.to_string()
+                                                        selected=move || parent_id.get() == Some(id)
+                                                    >
+                                                        {page.title}
+                                                    </option>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </select>
+                                }
+                            })
+                    }}
+                </Suspense>
+            </div>
+
+            <div class="mb-3">
+                <label for="page-menu-order" class="form-label">"Order"</label>
+                <input
+                    type="number"
+                    id="page-menu-order"
+                    class="form-control"
+                    prop:value=move || menu_order.get().to_string()
+                    on:input=move |ev| menu_order.set(event_target_value(&ev).parse().unwrap_or_default())
+                />
+            </div>
+
+            <div class="mb-3">
+                <label for="page-template" class="form-label">"Template"</label>
+                <input
+                    type="text"
+                    id="page-template"
+                    class="form-control"
+                    placeholder="Default template"
+                    prop:value=template
+                    on:input=move |ev| template.set(event_target_value(&ev))
+                />
+            </div>
+        </div>
+    }
+}
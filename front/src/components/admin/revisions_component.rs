@@ -0,0 +1,151 @@
+use leptos::*;
+
+use crate::{
+    components::front::loading_component::LoadingComponent,
+    models::admin::posts_model::{DiffLine, DiffOperationEnum, PostRevisionDiff, PostStruct},
+    services::admin::api::posts_api::{get_post_revisions, get_post_revisions_diff, restore_post_revision},
+};
+
+/// Revisions panel of a post.
+///
+/// Lists the revisions of the post, latest first. Each revision can be compared with the previous one
+/// or restored as the current title and content.
+///
+/// # Arguments
+///
+/// * `post_id` - The ID of the post.
+/// * `version` - Signal to increment when the post is saved, to reload the revisions.
+/// * `on_restore` - Called with the post once a revision is restored.
+#[component]
+pub fn RevisionsComponent(
+    post_id: u32,
+    version: Signal<u32>,
+    #[prop(into)] on_restore: Callback<PostStruct>,
+) -> impl IntoView {
+    let revisions = create_resource(move || version.get(), move |_| async move { get_post_revisions(post_id).await.ok() });
+    let (diff, set_diff) = create_signal(None::<PostRevisionDiff>);
+
+    let compare = move |from: u32, to: u32| {
+        spawn_local(async move {
+            match get_post_revisions_diff(post_id, from, to).await {
+                Ok(revisions_diff) => set_diff.set(Some(revisions_diff)),
+                Err(e) => log::error!("Error comparing revisions: {}", e),
+            }
+        });
+    };
+
+    let restore = move |revision_id: u32| {
+        spawn_local(async move {
+            match restore_post_revision(post_id, revision_id).await {
+                Ok(post) => {
+                    set_diff.set(None);
+                    on_restore.call(post);
+                }
+                Err(e) => log::error!("Error restoring revision: {}", e),
+            }
+        });
+    };
+
+    view! {
+        <div class="revisions-component mb-3">
+            <h3 class="form-label">Revisions</h3>
+            <Suspense fallback=move || {
+                view! { <LoadingComponent/> }
+            }>
+                {move || match revisions.get() {
+                    Some(Some(revisions)) if !revisions.is_empty() => {
+                        let previous_ids: Vec<Option<u32>> = revisions
+                            .iter()
+                            .skip(1)
+                            .map(|revision| Some(revision.id))
+                            .chain(std::iter::once(None))
+                            .collect();
+                        view! {
+                            <ul class="list-group">
+                                {revisions
+                                    .into_iter()
+                                    .zip(previous_ids)
+                                    .map(|(revision, previous_id)| {
+                                        let revision_id = revision.id;
+                                        view! {
+                                            <li class="list-group-item">
+                                                <div class="small">
+                                                    {revision.date_created.format("%Y/%m/%d %-I:%M %P").to_string()}
+                                                    {format!(" - Author {}", revision.author_id)}
+                                                </div>
+                                                <div class="break-word">{revision.title}</div>
+                                                <div class="mt-1">
+                                                    {previous_id
+                                                        .map(|previous_id| {
+                                                            view! {
+                                                                <button
+                                                                    type="button"
+                                                                    class="btn btn-sm btn-outline-secondary me-2"
+                                                                    on:click=move |_| compare(previous_id, revision_id)
+                                                                >
+                                                                    "Compare"
+                                                                </button>
+                                                            }
+                                                        })}
+                                                    <button
+                                                        type="button"
+                                                        class="btn btn-sm btn-outline-primary"
+                                                        on:click=move |_| restore(revision_id)
+                                                    >
+                                                        "Restore"
+                                                    </button>
+                                                </div>
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ul>
+                        }
+                            .into_view()
+                    }
+                    Some(_) => view! { <div>"No revisions yet."</div> }.into_view(),
+                    None => view! { <LoadingComponent/> }.into_view(),
+                }}
+            </Suspense>
+
+            {move || {
+                diff.get()
+                    .map(|diff| {
+                        view! {
+                            <div class="card mt-3">
+                                <div class="card-header d-flex justify-content-between align-items-center">
+                                    {format!("Revision {} → {}", diff.from.id, diff.to.id)}
+                                    <button type="button" class="btn-close" aria-label="Close" on:click=move |_| set_diff.set(None)></button>
+                                </div>
+                                <div class="card-body">
+                                    <h4>Title</h4>
+                                    <DiffLines lines=diff.title/>
+                                    <h4>Content</h4>
+                                    <DiffLines lines=diff.content/>
+                                </div>
+                            </div>
+                        }
+                    })
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn DiffLines(lines: Vec<DiffLine>) -> impl IntoView {
+    view! {
+        <pre class="small mb-3">
+            {lines
+                .into_iter()
+                .map(|diff_line| {
+                    let (prefix, class) = match diff_line.operation {
+                        DiffOperationEnum::Equal => ("  ", ""),
+                        DiffOperationEnum::Insert => ("+ ", "text-success"),
+                        DiffOperationEnum::Delete => ("- ", "text-danger"),
+                    };
+                    view! { <div class=class>{format!("{}{}", prefix, diff_line.line)}</div> }
+                })
+                .collect_view()}
+        </pre>
+    }
+}
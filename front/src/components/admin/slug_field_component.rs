@@ -0,0 +1,84 @@
+use leptos::*;
+
+use crate::{models::admin::slugs_model::SlugCheck, services::admin::api::slugs_api::check_slug};
+
+/// Slug field of a content, telling while typing whether another content of the same type already has the slug.
+///
+/// # Arguments
+///
+/// * `slug` - The slug of the content.
+/// * `slug_type` - The type of the content: `post`, `page`, `category` or `tag`.
+/// * `exclude_id` - The ID of the content being edited, if any.
+#[component]
+pub fn SlugFieldComponent(slug: RwSignal<String>, slug_type: &'static str, exclude_id: Option<u32>) -> impl IntoView {
+    let check = create_rw_signal(None::<SlugCheck>);
+
+    let on_input = move |ev| {
+        let value = event_target_value(&ev);
+        slug.set(value.clone());
+        if value.trim().is_empty() {
+            check.set(None);
+            return;
+        }
+
+        spawn_local(async move {
+            let result = check_slug(slug_type, &value, exclude_id).await;
+            // A later input made the answer stale
+            if slug.get_untracked() != value {
+                return;
+            }
+            match result {
+                Ok(result) => check.set(Some(result)),
+                Err(e) => log::error!("Error checking the slug: {}", e),
+            }
+        });
+    };
+
+    view! {
+        <div class="mb-3">
+            <label for="content-slug" class="form-label">"Slug"</label>
+            <input
+                type="text"
+                id="content-slug"
+                class="form-control"
+                class:is-invalid=move || check.with(|check| check.as_ref().is_some_and(|check| !check.available))
+                prop:value=move || slug.get()
+                on:input=on_input
+            />
+            {move || {
+                check
+                    .get()
+                    .map(|result| {
+                        if result.available {
+                            view! { <div class="form-text">{format!("Saved as {}", result.slug)}</div> }.into_view()
+                        } else {
+                            let suggestion = result.suggestion.clone();
+                            view! {
+                                <div class="invalid-feedback d-block">
+                                    {format!("{} is already used. ", result.slug)}
+                                    <a
+                                        href="#"
+                                        on:click=move |ev| {
+                                            ev.prevent_default();
+                                            slug.set(suggestion.clone());
+                                            check
+                                                .set(
+                                                    Some(SlugCheck {
+                                                        slug: suggestion.clone(),
+                                                        available: true,
+                                                        suggestion: suggestion.clone(),
+                                                    }),
+                                                );
+                                        }
+                                    >
+                                        {format!("Use {}", result.suggestion)}
+                                    </a>
+                                </div>
+                            }
+                                .into_view()
+                        }
+                    })
+            }}
+        </div>
+    }
+}
@@ -1 +1,2 @@
 pub mod loading_component;
+pub mod theme_component;
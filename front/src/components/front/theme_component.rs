@@ -0,0 +1,103 @@
+use leptos::*;
+use leptos_meta::Title;
+
+use crate::{
+    components::front::loading_component::LoadingComponent,
+    models::front::theme_model::{TemplateContext, ThemePage},
+    services::front::theme_service::render_theme_page,
+};
+
+/// Renders a page through the template of the active theme.
+#[component]
+pub fn ThemeComponent(page: ThemePage) -> impl IntoView {
+    if let TemplateContext::Redirect { location, status_code } = page.context {
+        return view! { <ThemeRedirectComponent location status_code/> }.into_view();
+    }
+
+    #[cfg(feature = "ssr")]
+    {
+        use leptos_axum::ResponseOptions;
+
+        if page.context == TemplateContext::NotFound {
+            if let Some(response) = use_context::<ResponseOptions>() {
+                response.set_status(http::StatusCode::NOT_FOUND);
+            }
+        }
+    }
+
+    let title = page.title.clone();
+    let html = create_resource(move || page.clone(), render_theme_page);
+
+    view! {
+        <Title text=title/>
+        <Suspense fallback=move || {
+            view! { <LoadingComponent/> }
+        }>
+            {move || {
+                html.get()
+                    .map(|html| match html {
+                        Ok(html) => view! { <div class="theme" inner_html=html></div> }.into_view(),
+                        Err(err) => view! {
+                            <div>
+                                <p>{format!("Failed to render the theme template: {}", err)}</p>
+                            </div>
+                        }
+                        .into_view(),
+                    })
+            }}
+        </Suspense>
+    }
+    .into_view()
+}
+
+/// Sends the browser to the new location of a moved route: with the status and `Location` header of the response
+/// when rendered on the server, by replacing the page when navigating in the browser.
+#[component]
+fn ThemeRedirectComponent(location: String, status_code: u16) -> impl IntoView {
+    #[cfg(feature = "ssr")]
+    {
+        use leptos_axum::ResponseOptions;
+
+        if let Some(response) = use_context::<ResponseOptions>() {
+            response.set_status(http::StatusCode::from_u16(status_code).unwrap_or(http::StatusCode::MOVED_PERMANENTLY));
+            if let Ok(value) = http::HeaderValue::from_str(&location) {
+                response.insert_header(http::header::LOCATION, value);
+            }
+        }
+    }
+    #[cfg(not(feature = "ssr"))]
+    let _ = status_code;
+
+    let target = location.clone();
+    create_effect(move |_| {
+        let _ = window().location().replace(&target);
+    });
+
+    view! { <p class="theme-redirect">"Moved to " <a href=location.clone()>{location}</a></p> }
+}
+
+/// Renders the page loaded by a front route once it is available.
+#[component]
+pub fn ThemeRouteComponent<S>(page: Resource<S, Result<ThemePage, String>>) -> impl IntoView
+where
+    S: PartialEq + Clone + 'static,
+{
+    view! {
+        <Suspense fallback=move || {
+            view! { <LoadingComponent/> }
+        }>
+            {move || {
+                page.get()
+                    .map(|page| match page {
+                        Ok(page) => view! { <ThemeComponent page/> }.into_view(),
+                        Err(err) => view! {
+                            <div>
+                                <p>{format!("Failed to load the page: {}", err)}</p>
+                            </div>
+                        }
+                        .into_view(),
+                    })
+            }}
+        </Suspense>
+    }
+}
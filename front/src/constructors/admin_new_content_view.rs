@@ -1,21 +1,36 @@
 use crate::components::admin::categories_component::CategoriesComponent;
 use crate::components::admin::header_content_component::HeaderContent;
+use crate::components::admin::media_picker_component::MediaPickerComponent;
+use crate::components::admin::page_attributes_component::PageAttributesComponent;
 use crate::components::admin::publish_component::PublishComponent;
+use crate::components::admin::tags_component::TagsComponent;
+use crate::models::admin::pages_model::PageNewStruct;
 use crate::models::admin::posts_model::{
-    PostNewStruct, PostRequest, PostStatusEnum,
+    PostAutosave, PostAutosaveRequest, PostNewStruct, PostRequest, PostStatusEnum,
 };
-use crate::services::admin::api::posts_api::add_post;
+use crate::services::admin::api::pages_api::add_page;
+use crate::services::admin::api::posts_api::{add_post, delete_autosave, get_autosave, save_autosave};
 use chrono::NaiveDateTime;
 use leptos::*;
+use leptos::leptos_dom::helpers::TimeoutHandle;
+use std::time::Duration;
 use web_sys::SubmitEvent;
 
+// Délai sans saisie avant l'enregistrement automatique
+const AUTOSAVE_DELAY: Duration = Duration::from_secs(3);
+
 pub struct AdminNewContentView {
     pub name: String,
     pub title: RwSignal<String>,
     pub content: RwSignal<String>,
     pub categories_ids: RwSignal<Vec<u32>>,
+    pub tags_ids: RwSignal<Vec<u32>>,
     pub status: RwSignal<PostStatusEnum>,
     pub date_published: RwSignal<Option<NaiveDateTime>>,
+    pub featured_media_id: RwSignal<Option<u32>>,
+    pub parent_id: RwSignal<Option<u32>>,
+    pub menu_order: RwSignal<i32>,
+    pub template: RwSignal<String>,
     pub is_post: bool,
 }
 
@@ -27,8 +42,13 @@ impl AdminNewContentView {
             title: create_rw_signal(String::new()),
             content: create_rw_signal(String::new()),
             categories_ids: create_rw_signal(Vec::new()),
+            tags_ids: create_rw_signal(Vec::new()),
             status: create_rw_signal(PostStatusEnum::Draft),
             date_published: create_rw_signal(None),
+            featured_media_id: create_rw_signal(None),
+            parent_id: create_rw_signal(None),
+            menu_order: create_rw_signal(0),
+            template: create_rw_signal(String::new()),
             is_post: true,
         }
     }
@@ -36,12 +56,17 @@ impl AdminNewContentView {
     // Constructeur pour une page
     pub fn new_page() -> Self {
         Self {
-            name: String::new(),
+            name: "Add new page".to_owned(),
             title: create_rw_signal(String::new()),
             content: create_rw_signal(String::new()),
             categories_ids: create_rw_signal(Vec::new()),
+            tags_ids: create_rw_signal(Vec::new()),
             status: create_rw_signal(PostStatusEnum::Draft),
             date_published: create_rw_signal(None),
+            featured_media_id: create_rw_signal(None),
+            parent_id: create_rw_signal(None),
+            menu_order: create_rw_signal(0),
+            template: create_rw_signal(String::new()),
             is_post: false,
         }
     }
@@ -56,40 +81,58 @@ impl AdminNewContentView {
         let title = self.title.get();
         let content = self.content.get();
         let categories_ids = self.categories_ids.get();
+        let tags_ids = self.tags_ids.get();
         let status = self.status.get();
         let date_published = self.date_published.get();
+        let featured_media_id = self.featured_media_id.get();
+
+        if !self.is_post {
+            let page = PageNewStruct {
+                title: title.clone(),
+                content,
+                slug: None,
+                author_id: 1,
+                status,
+                date_published,
+                featured_media_id,
+                parent_id: self.parent_id.get(),
+                menu_order: self.menu_order.get(),
+                template: Some(self.template.get()).filter(|template| !template.is_empty()),
+            };
+            spawn_local(async move {
+                notify_created(
+                    add_page(page).await.map(|page| (page.title, page.http_code)),
+                    set_notification_message,
+                    set_notification_type,
+                    set_show_toast,
+                );
+            });
+            return;
+        }
 
         let post_request = PostRequest {
             post: PostNewStruct {
                 title: title.clone(),
                 content: content.clone(),
-                slug: title.clone(),
+                slug: None,
                 author_id: 1,
                 status: status.clone(),
                 date_published: date_published.clone(),
+                featured_media_id,
             },
             categories_ids: categories_ids.clone(),
+            tags_ids: tags_ids.clone(),
         };
 
         log::info!("Submitting content: {:?}", &post_request);
 
         spawn_local(async move {
-            match add_post(post_request).await {
-                Ok(created_post) => {
-                    set_notification_message.set(format!(
-                        "Content '{}' created successfully! (HTTP {})",
-                        created_post.title,
-                        created_post.http_code.unwrap_or_default()
-                    ));
-                    set_notification_type.set("success".to_string());
-                    set_show_toast.set(true);
-                }
-                Err(e) => {
-                    set_notification_message.set(format!("Error: {}", e));
-                    set_notification_type.set("error".to_string());
-                    set_show_toast.set(true);
-                }
-            }
+            notify_created(
+                add_post(post_request).await.map(|post| (post.title, post.http_code)),
+                set_notification_message,
+                set_notification_type,
+                set_show_toast,
+            );
         });
     }
 
@@ -99,6 +142,7 @@ impl AdminNewContentView {
         set_title: WriteSignal<String>,
         set_content: WriteSignal<String>,
         set_categories_ids: WriteSignal<Vec<u32>>,
+        set_tags_ids: WriteSignal<Vec<u32>>,
         set_status: WriteSignal<PostStatusEnum>,
         set_date_published: WriteSignal<Option<NaiveDateTime>>,
         set_notification_message: WriteSignal<String>,
@@ -108,11 +152,105 @@ impl AdminNewContentView {
         let title = self.title.clone();
         let content = self.content.clone();
         let categories_ids = self.categories_ids.get_untracked();
+        let tags_ids = self.tags_ids.get_untracked();
         let status = self.status.get_untracked();
+        let featured_media_id = self.featured_media_id;
+        let parent_id = self.parent_id;
+        let menu_order = self.menu_order;
+        let template = self.template;
+        let is_post = self.is_post;
+
+        // Autosave plus récent que le contenu enregistré, proposé à la restauration
+        let pending_autosave = create_rw_signal(None::<PostAutosave>);
+        let (last_autosave, set_last_autosave) = create_signal(None::<NaiveDateTime>);
+        let autosave_timeout = store_value(None::<TimeoutHandle>);
+
+        create_effect(move |_| {
+            spawn_local(async move {
+                match get_autosave(None).await {
+                    Ok(autosave) => pending_autosave.set(autosave),
+                    Err(e) => log::error!("Error loading autosave: {}", e),
+                }
+            });
+        });
+
+        // Enregistre le brouillon après AUTOSAVE_DELAY sans modification
+        create_effect(move |first_run: Option<()>| {
+            let title = title.get();
+            let content = content.get();
+            if first_run.is_none() || pending_autosave.get_untracked().is_some() {
+                return;
+            }
+            if title.is_empty() && content.is_empty() {
+                return;
+            }
+
+            if let Some(handle) = autosave_timeout.get_value() {
+                handle.clear();
+            }
+            let handle = set_timeout_with_handle(
+                move || {
+                    let autosave = PostAutosaveRequest {
+                        post_id: None,
+                        title,
+                        content,
+                    };
+                    spawn_local(async move {
+                        match save_autosave(autosave).await {
+                            Ok(autosave) => set_last_autosave.set(Some(autosave.date_modified)),
+                            Err(e) => log::error!("Error autosaving content: {}", e),
+                        }
+                    });
+                },
+                AUTOSAVE_DELAY,
+            )
+            .ok();
+            autosave_timeout.set_value(handle);
+        });
+
+        let restore_autosave = move |_| {
+            if let Some(autosave) = pending_autosave.get_untracked() {
+                title.set(autosave.title);
+                content.set(autosave.content);
+            }
+            pending_autosave.set(None);
+        };
+
+        let discard_autosave = move |_| {
+            pending_autosave.set(None);
+            spawn_local(async move {
+                if let Err(e) = delete_autosave(None).await {
+                    log::error!("Error discarding autosave: {}", e);
+                }
+            });
+        };
 
         view! {
             <div class="row new-content">
 
+                {move || {
+                    pending_autosave
+                        .get()
+                        .map(|autosave| {
+                            view! {
+                                <div class="alert alert-warning d-flex align-items-center" role="alert">
+                                    <span class="me-auto">
+                                        {format!(
+                                            "There are unsaved changes from {}.",
+                                            autosave.date_modified.format("%Y/%m/%d %-I:%M %P"),
+                                        )}
+                                    </span>
+                                    <button type="button" class="btn btn-sm btn-warning me-2" on:click=restore_autosave>
+                                        "Restore unsaved changes"
+                                    </button>
+                                    <button type="button" class="btn btn-sm btn-outline-secondary" on:click=discard_autosave>
+                                        "Discard"
+                                    </button>
+                                </div>
+                            }
+                        })
+                }}
+
                 <div class="mb-3 d-flex align-items-center justify-content-start">
                     <HeaderContent title=&self.name/>
                     <button
@@ -122,6 +260,13 @@ impl AdminNewContentView {
                     >
                         "Publish"
                     </button>
+                    <span class="small text-body-secondary ms-3">
+                        {move || {
+                            last_autosave
+                                .get()
+                                .map(|date| format!("Draft saved at {}", date.format("%-I:%M:%S %P")))
+                        }}
+                    </span>
                 </div>
 
                 <div class="col-lg-8 col-xl-9">
@@ -130,45 +275,57 @@ impl AdminNewContentView {
                         id=format!("{}-form", &self.name)
                         on:submit=move |ev: SubmitEvent| {
                             ev.prevent_default();
+                            // Le brouillon est supprimé par l'API à la création du contenu
+                            if let Some(handle) = autosave_timeout.get_value() {
+                                handle.clear();
+                            }
                             let title = title.clone();
                             let content = content.clone();
                             let status = status.clone();
                             let categories_ids = categories_ids.clone();
+                            let tags_ids = tags_ids.clone();
                             let set_notification_message = set_notification_message.clone();
                             let set_notification_type = set_notification_type.clone();
                             let set_show_toast = set_show_toast.clone();
                             spawn_local(async move {
-                                let post = PostNewStruct {
-                                    title: title.get(),
-                                    content: content.get(),
-                                    slug: title.get(),
-                                    author_id: 1,
-                                    status,
-                                    date_published: None,
-                                };
-                                let post_request = PostRequest {
-                                    post,
-                                    categories_ids: categories_ids.clone(),
+                                let created = if is_post {
+                                    let post = PostNewStruct {
+                                        title: title.get(),
+                                        content: content.get(),
+                                        slug: None,
+                                        author_id: 1,
+                                        status,
+                                        date_published: None,
+                                        featured_media_id: featured_media_id.get_untracked(),
+                                    };
+                                    let post_request = PostRequest {
+                                        post,
+                                        categories_ids: categories_ids.clone(),
+                                        tags_ids: tags_ids.clone(),
+                                    };
+                                    add_post(post_request).await.map(|post| (post.title, post.http_code))
+                                } else {
+                                    let page = PageNewStruct {
+                                        title: title.get(),
+                                        content: content.get(),
+                                        slug: None,
+                                        author_id: 1,
+                                        status,
+                                        date_published: None,
+                                        featured_media_id: featured_media_id.get_untracked(),
+                                        parent_id: parent_id.get_untracked(),
+                                        menu_order: menu_order.get_untracked(),
+                                        template: Some(template.get_untracked())
+                                            .filter(|template| !template.is_empty()),
+                                    };
+                                    add_page(page).await.map(|page| (page.title, page.http_code))
                                 };
-                                match add_post(post_request).await {
-                                    Ok(created_post) => {
-                                        set_notification_message
-                                            .set(
-                                                format!(
-                                                    "Content '{}' created successfully! (HTTP {})",
-                                                    created_post.title,
-                                                    created_post.http_code.unwrap_or_default(),
-                                                ),
-                                            );
-                                        set_notification_type.set("success".to_string());
-                                        set_show_toast.set(true);
-                                    }
-                                    Err(e) => {
-                                        set_notification_message.set(format!("Error: {}", e));
-                                        set_notification_type.set("error".to_string());
-                                        set_show_toast.set(true);
-                                    }
-                                }
+                                notify_created(
+                                    created,
+                                    set_notification_message,
+                                    set_notification_type,
+                                    set_show_toast,
+                                );
                             });
                         }
                     >
@@ -207,12 +364,60 @@ impl AdminNewContentView {
                         set_date_published=set_date_published
                     />
 
-                    <CategoriesComponent
-                        categories_ids=self.categories_ids.into()
-                        set_categories_ids=set_categories_ids
+                    {if is_post {
+                        view! {
+                            <CategoriesComponent
+                                categories_ids=self.categories_ids.into()
+                                set_categories_ids=set_categories_ids
+                            />
+
+                            <TagsComponent
+                                tags_ids=self.tags_ids.into()
+                                set_tags_ids=set_tags_ids
+                            />
+                        }
+                            .into_view()
+                    } else {
+                        view! {
+                            <PageAttributesComponent
+                                parent_id=parent_id
+                                menu_order=menu_order
+                                template=template
+                            />
+                        }
+                            .into_view()
+                    }}
+
+                    <MediaPickerComponent
+                        media_id=self.featured_media_id.into()
+                        set_media_id=self.featured_media_id.write_only()
                     />
                 </div>
             </div>
         }
     }
 }
+
+// Affiche le résultat de la création d'un post ou d'une page
+fn notify_created(
+    created: Result<(String, Option<u16>), String>,
+    set_notification_message: WriteSignal<String>,
+    set_notification_type: WriteSignal<String>,
+    set_show_toast: WriteSignal<bool>,
+) {
+    match created {
+        Ok((title, http_code)) => {
+            set_notification_message.set(format!(
+                "Content '{}' created successfully! (HTTP {})",
+                title,
+                http_code.unwrap_or_default()
+            ));
+            set_notification_type.set("success".to_string());
+        }
+        Err(e) => {
+            set_notification_message.set(format!("Error: {}", e));
+            set_notification_type.set("error".to_string());
+        }
+    }
+    set_show_toast.set(true);
+}
@@ -0,0 +1,155 @@
+use axum::{
+    extract::Path,
+    http::{
+        header::{CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+        HeaderMap, HeaderValue, StatusCode,
+    },
+    response::{IntoResponse, Response},
+};
+use leptos::logging;
+
+use crate::{
+    models::front::public_model::{PublicPostFilters, PublicPostStruct, PublicSettings},
+    services::front::{
+        api::public_api::{get_public_category, get_public_latest_posts, get_public_tag},
+        feed_service::{
+            feed_etag, feed_last_modified, http_date, is_not_modified, render_feed, FeedChannel, FeedFormat,
+        },
+        settings_service::site_settings,
+    },
+};
+
+/// `/feed`: RSS feed of the latest published posts.
+pub async fn site_feed_handler(headers: HeaderMap) -> Response {
+    feed_response(FeedFormat::Rss, None, &headers).await
+}
+
+/// `/feed/atom`: Atom feed of the latest published posts.
+pub async fn site_atom_feed_handler(headers: HeaderMap) -> Response {
+    feed_response(FeedFormat::Atom, None, &headers).await
+}
+
+/// `/:base/:slug/feed`: RSS feed of the latest posts of a category, a tag or an author.
+pub async fn archive_feed_handler(Path(archive): Path<(String, String)>, headers: HeaderMap) -> Response {
+    feed_response(FeedFormat::Rss, Some(archive), &headers).await
+}
+
+/// `/:base/:slug/feed/atom`: Atom feed of the latest posts of a category, a tag or an author.
+pub async fn archive_atom_feed_handler(Path(archive): Path<(String, String)>, headers: HeaderMap) -> Response {
+    feed_response(FeedFormat::Atom, Some(archive), &headers).await
+}
+
+/// Renders the feed of the site, or of the archive given by its permalink base and slug, answering 304 when the
+/// client already has it.
+async fn feed_response(format: FeedFormat, archive: Option<(String, String)>, headers: &HeaderMap) -> Response {
+    let settings = site_settings().await;
+
+    let (channel, posts) = match load_feed(&settings, format, archive).await {
+        Ok(Some(feed)) => feed,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            logging::warn!("Failed to load the feed: {}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let body = render_feed(
+        format,
+        &channel,
+        &posts,
+        &settings.url,
+        settings.feed_content != "excerpt",
+    );
+    let etag = feed_etag(&body);
+    let last_modified = feed_last_modified(&posts);
+
+    let mut response_headers = HeaderMap::new();
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        response_headers.insert(ETAG, value);
+    }
+    if let Some(value) = last_modified.and_then(|date| HeaderValue::from_str(&http_date(date)).ok()) {
+        response_headers.insert(LAST_MODIFIED, value);
+    }
+
+    let header = |name| headers.get(name).and_then(|value: &HeaderValue| value.to_str().ok());
+    if is_not_modified(header(IF_NONE_MATCH), header(IF_MODIFIED_SINCE), &etag, last_modified) {
+        return (StatusCode::NOT_MODIFIED, response_headers).into_response();
+    }
+
+    response_headers.insert(CONTENT_TYPE, HeaderValue::from_static(format.content_type()));
+    (response_headers, body).into_response()
+}
+
+/// Channel and posts of a feed, `None` when the archive does not exist.
+async fn load_feed(
+    settings: &PublicSettings,
+    format: FeedFormat,
+    archive: Option<(String, String)>,
+) -> Result<Option<(FeedChannel, Vec<PublicPostStruct>)>, String> {
+    let site_url = settings.url.trim_end_matches('/');
+
+    let Some((base, slug)) = archive else {
+        let Some(posts) = get_public_latest_posts(PublicPostFilters::default(), settings.feed_items).await? else {
+            return Ok(None);
+        };
+        let channel = FeedChannel {
+            title: settings.title.clone(),
+            description: settings.description.clone(),
+            link: format!("{}/", site_url),
+            feed_url: format!("{}/{}", site_url, format.path_suffix()),
+            language: settings.language.clone(),
+        };
+        return Ok(Some((channel, posts.data)));
+    };
+
+    let permalinks = &settings.permalinks;
+    let mut filters = PublicPostFilters::default();
+    let (title, description) = if base == permalinks.category_base {
+        let Some(category) = get_public_category(&slug).await? else {
+            return Ok(None);
+        };
+        filters.category = Some(slug.clone());
+        (format!("Category: {}", category.name), category.description)
+    } else if base == permalinks.tag_base {
+        let Some(tag) = get_public_tag(&slug).await? else {
+            return Ok(None);
+        };
+        filters.tag = Some(slug.clone());
+        (format!("Tag: {}", tag.name), tag.description)
+    } else if base == permalinks.author_base {
+        filters.author = Some(slug.clone());
+        (String::new(), None)
+    } else {
+        return Ok(None);
+    };
+
+    // The API answers 404 for an unknown author
+    let Some(posts) = get_public_latest_posts(filters, settings.feed_items).await? else {
+        return Ok(None);
+    };
+
+    let title = if title.is_empty() {
+        let name = posts
+            .data
+            .iter()
+            .find_map(|post| post.author.as_ref())
+            .map(|author| format!("{} {}", author.firstname, author.lastname))
+            .unwrap_or_else(|| slug.clone());
+        format!("Author: {}", name)
+    } else {
+        title
+    };
+
+    let link = format!("{}/{}/{}", site_url, base, slug);
+    let channel = FeedChannel {
+        title: format!("{} - {}", settings.title, title),
+        description: description
+            .filter(|description| !description.is_empty())
+            .unwrap_or_else(|| settings.description.clone()),
+        feed_url: format!("{}/{}", link, format.path_suffix()),
+        link,
+        language: settings.language.clone(),
+    };
+
+    Ok(Some((channel, posts.data)))
+}
@@ -3,6 +3,8 @@ pub mod components;
 pub mod constructors;
 pub mod error_template;
 #[cfg(feature = "ssr")]
+pub mod feeds;
+#[cfg(feature = "ssr")]
 pub mod fileserv;
 pub mod models;
 pub mod services;
@@ -1,12 +1,15 @@
 #[cfg(feature = "ssr")]
 #[tokio::main]
 async fn main() {
-    use axum::Router;
+    use axum::{routing::get, Router};
     use front::app::*;
+    use front::feeds::{archive_atom_feed_handler, archive_feed_handler, site_atom_feed_handler, site_feed_handler};
     use front::fileserv::file_and_error_handler;
+    use front::services::front::theme_service::themes_dir;
     use leptos::*;
-    use leptos_axum::{generate_route_list, LeptosRoutes};
+    use leptos_axum::{generate_route_list_with_exclusions, LeptosRoutes};
     use tower_http::cors::CorsLayer;
+    use tower_http::services::ServeDir;
 
     // Setting get_configuration(None) means we'll be using cargo-leptos's env values
     // For deployment these variables are:
@@ -16,11 +19,20 @@ async fn main() {
     let conf = get_configuration(None).await.unwrap();
     let leptos_options = conf.leptos_options;
     let addr = leptos_options.site_addr;
-    let routes = generate_route_list(App);
+    // The catch-all route of the 404 page conflicts with the front routes in axum, the paths matching no route are
+    // rendered by the fallback instead
+    let routes = generate_route_list_with_exclusions(App, Some(vec!["/*any".to_string()]));
 
     // build our application with a route
     let app = Router::new()
+        // RSS and Atom feeds of the site and of its category, tag and author archives
+        .route("/feed", get(site_feed_handler))
+        .route("/feed/atom", get(site_atom_feed_handler))
+        .route("/:base/:slug/feed", get(archive_feed_handler))
+        .route("/:base/:slug/feed/atom", get(archive_atom_feed_handler))
         .leptos_routes(&leptos_options, routes, App)
+        // Assets of the themes, e.g. /themes/Default/assets/style.css
+        .nest_service("/themes", ServeDir::new(themes_dir()))
         .layer(CorsLayer::permissive())
         .fallback(file_and_error_handler)
         .with_state(leptos_options);
@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter};
+
+use super::posts_model::Id;
+
+#[derive(Serialize, Deserialize, Clone, Debug, EnumIter, Display, PartialEq, Eq, Hash)]
+pub enum CommentStatusEnum {
+    Pending,
+    Approved,
+    Spam,
+    Trash,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct CommentStruct {
+    pub id: u32,
+    pub post_id: u32,
+    pub parent_id: Option<u32>,
+    pub user_id: Option<u32>,
+    pub author_name: String,
+    pub author_email: String,
+    pub content: String,
+    pub status: CommentStatusEnum,
+    pub date_created: NaiveDateTime,
+}
+
+impl Id for CommentStruct {
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct CommentFilters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CommentStatusEnum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_id: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommentsIds {
+    pub ids: HashSet<u32>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct CommentsStatusUpdate {
+    pub ids: HashSet<u32>,
+    pub status: CommentStatusEnum,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginatedComments {
+    pub data: Vec<CommentStruct>,
+    pub current_page: u32,
+    pub total_pages: u32,
+    pub total_items: u32,
+}
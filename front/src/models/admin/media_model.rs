@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct MediaStruct {
+    pub id: u32,
+    pub author_id: u32,
+    pub filename: String,
+    pub mime_type: String,
+    pub size: u64,
+    pub url: String,
+    #[serde(default)]
+    pub width: Option<u32>,
+    #[serde(default)]
+    pub height: Option<u32>,
+    #[serde(default)]
+    pub sizes: BTreeMap<String, MediaSizeStruct>,
+    pub date_created: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct MediaSizeStruct {
+    pub url: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl MediaStruct {
+    pub fn is_image(&self) -> bool {
+        self.mime_type.starts_with("image/")
+    }
+
+    /// URL of the given size of an image, the original when it was not resized
+    pub fn size_url(&self, size: &str) -> String {
+        self.sizes
+            .get(size)
+            .map_or_else(|| self.url.clone(), |size| size.url.clone())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PaginatedMedia {
+    pub current_page: u32,
+    pub total_pages: u32,
+    pub total_items: u32,
+    pub data: Vec<MediaStruct>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MediaIds {
+    pub ids: Vec<u32>,
+}
@@ -0,0 +1,88 @@
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, EnumIter, Display, PartialEq, Eq, Hash)]
+pub enum MenuItemTypeEnum {
+    Page,
+    Post,
+    Category,
+    Tag,
+    Custom,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct MenuStruct {
+    pub id: u32,
+    pub name: String,
+    pub slug: String,
+    pub date_created: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MenuNewStruct {
+    pub name: String,
+    pub slug: Option<String>,
+}
+
+/// Menu with the tree of its items.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct MenuTree {
+    pub id: u32,
+    pub name: String,
+    pub slug: String,
+    pub items: Vec<MenuItem>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct MenuItem {
+    pub id: u32,
+    pub item_type: MenuItemTypeEnum,
+    pub object_id: Option<u32>,
+    pub url: Option<String>,
+    /// Label of the item, the title of its content if `None`
+    pub title: Option<String>,
+    /// Title or name of the content, `None` if it was deleted
+    pub object_title: Option<String>,
+    pub children: Vec<MenuItem>,
+}
+
+/// Item sent to replace the items of a menu.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct MenuItemInput {
+    pub item_type: MenuItemTypeEnum,
+    pub object_id: Option<u32>,
+    pub url: Option<String>,
+    pub title: Option<String>,
+    pub children: Vec<MenuItemInput>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MenuItemsUpdate {
+    pub items: Vec<MenuItemInput>,
+}
+
+/// ID of the menu of every theme location which has one.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct MenuLocations {
+    pub locations: BTreeMap<String, u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MenusIds {
+    pub ids: HashSet<u32>,
+}
+
+/// Item of the menu editor, the tree of items being edited as a list where every item is indented by its depth.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MenuEditorItem {
+    pub depth: usize,
+    pub item_type: MenuItemTypeEnum,
+    pub object_id: Option<u32>,
+    pub url: Option<String>,
+    pub title: Option<String>,
+    /// Title or name of the content, shown when the item has no title
+    pub object_title: Option<String>,
+}
@@ -1,3 +1,9 @@
 pub mod categories_model;
+pub mod comments_model;
+pub mod media_model;
+pub mod menus_model;
+pub mod pages_model;
 pub mod posts_model;
+pub mod redirects_model;
+pub mod slugs_model;
 pub mod tags_model;
@@ -0,0 +1,45 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use super::posts_model::PostStatusEnum;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PageStruct {
+    #[serde(default)]
+    pub http_code: Option<u16>,
+    pub id: u32,
+    pub title: String,
+    pub content: String,
+    pub slug: String,
+    pub author_id: u32,
+    pub status: PostStatusEnum,
+    pub date_published: Option<NaiveDateTime>,
+    pub featured_media_id: Option<u32>,
+    pub parent_id: Option<u32>,
+    pub menu_order: i32,
+    pub template: Option<String>,
+    pub date_created: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PageNewStruct {
+    pub title: String,
+    pub content: String,
+    /// Made from the title, and made unique, when `None`
+    pub slug: Option<String>,
+    pub author_id: u32,
+    pub status: PostStatusEnum,
+    pub date_published: Option<NaiveDateTime>,
+    pub featured_media_id: Option<u32>,
+    pub parent_id: Option<u32>,
+    pub menu_order: i32,
+    pub template: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginatedPages {
+    pub data: Vec<PageStruct>,
+    pub current_page: u32,
+    pub total_pages: u32,
+    pub total_items: u32,
+}
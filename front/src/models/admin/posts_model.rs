@@ -33,24 +33,34 @@ pub struct PostStruct {
     pub author_id: u32,
     pub status: PostStatusEnum,
     pub date_published: Option<NaiveDateTime>,
+    #[serde(default)]
+    pub featured_media_id: Option<u32>,
     pub date_created: NaiveDateTime,
     pub categories: Vec<Category>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    #[serde(default)]
+    pub comment_count: u32,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PostNewStruct {
     pub title: String,
     pub content: String,
-    pub slug: String,
+    /// Made from the title, and made unique, when `None`
+    pub slug: Option<String>,
     pub author_id: u32,
     pub status: PostStatusEnum,
     pub date_published: Option<NaiveDateTime>,
+    pub featured_media_id: Option<u32>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PostRequest {
+    #[serde(flatten)]
     pub post: PostNewStruct,
     pub categories_ids: Vec<u32>,
+    pub tags_ids: Vec<u32>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Debug)]
@@ -60,6 +70,78 @@ pub struct Category {
     pub description: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Tag {
+    pub id: u32,
+    pub name: String,
+    pub slug: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct PostFilters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatusEnum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_after: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_before: Option<NaiveDateTime>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PostRevision {
+    pub id: u32,
+    pub post_id: u32,
+    pub author_id: u32,
+    pub title: String,
+    pub content: String,
+    pub status: PostStatusEnum,
+    pub date_created: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum DiffOperationEnum {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DiffLine {
+    pub operation: DiffOperationEnum,
+    pub line: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PostRevisionDiff {
+    pub from: PostRevision,
+    pub to: PostRevision,
+    pub title: Vec<DiffLine>,
+    pub content: Vec<DiffLine>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PostAutosave {
+    pub id: u32,
+    pub post_id: Option<u32>,
+    pub author_id: u32,
+    pub title: String,
+    pub content: String,
+    pub date_modified: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PostAutosaveRequest {
+    pub post_id: Option<u32>,
+    pub title: String,
+    pub content: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PostsIds {
     pub ids: HashSet<u32>,
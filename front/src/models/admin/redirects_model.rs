@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// Status codes a redirect can answer with, with their label in the admin.
+pub const REDIRECT_STATUS_CODES: &[(u16, &str)] = &[
+    (301, "301 Moved Permanently"),
+    (302, "302 Found"),
+    (307, "307 Temporary Redirect"),
+    (308, "308 Permanent Redirect"),
+];
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RedirectStruct {
+    pub id: u32,
+    pub source_path: String,
+    pub target: String,
+    pub status_code: u16,
+    pub hits: u32,
+    pub last_hit: Option<NaiveDateTime>,
+    pub date_created: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaginatedRedirects {
+    pub data: Vec<RedirectStruct>,
+    pub current_page: u32,
+    pub total_pages: u32,
+    pub total_items: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RedirectNewStruct {
+    pub source_path: String,
+    pub target: String,
+    pub status_code: Option<u16>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RedirectsIds {
+    pub ids: HashSet<u32>,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Result of the check of a slug before saving a content.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct SlugCheck {
+    /// The slug checked, as it would be saved
+    pub slug: String,
+    pub available: bool,
+    /// The slug the content gets when saved without an explicit slug
+    pub suggestion: String,
+}
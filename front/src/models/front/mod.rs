@@ -0,0 +1,2 @@
+pub mod public_model;
+pub mod theme_model;
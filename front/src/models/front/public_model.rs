@@ -0,0 +1,156 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PublicAuthorStruct {
+    pub id: u32,
+    pub username: String,
+    pub firstname: String,
+    pub lastname: String,
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PublicPostStruct {
+    pub id: u32,
+    pub title: String,
+    pub content: String,
+    pub slug: String,
+    pub author: Option<PublicAuthorStruct>,
+    pub date_published: Option<NaiveDateTime>,
+    pub featured_media_id: Option<u32>,
+    pub categories: Option<Value>,
+    pub tags: Option<Value>,
+    pub comment_count: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PublicPageStruct {
+    pub id: u32,
+    pub title: String,
+    pub content: String,
+    pub slug: String,
+    pub author: Option<PublicAuthorStruct>,
+    pub date_published: Option<NaiveDateTime>,
+    pub featured_media_id: Option<u32>,
+    pub parent_id: Option<u32>,
+    pub menu_order: i32,
+    pub template: Option<String>,
+}
+
+/// A category or a tag, as returned by the public API.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PublicTermStruct {
+    pub id: u32,
+    pub name: String,
+    pub slug: String,
+    pub description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct PublicPostFilters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub month: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginatedPublicPosts {
+    pub data: Vec<PublicPostStruct>,
+    pub current_page: u32,
+    pub total_pages: u32,
+    pub total_items: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveTheme {
+    pub name: String,
+}
+
+/// Redirect of a path which matches no content, managed in the admin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicRedirect {
+    /// Path of the site or absolute URL
+    pub target: String,
+    pub status_code: u16,
+}
+
+/// Current slug of a content, found from one of its former slugs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicSlug {
+    pub slug: String,
+}
+
+/// Settings the public site is rendered with, as returned by the public API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicSettings {
+    pub title: String,
+    pub description: String,
+    pub url: String,
+    pub language: String,
+    pub date_format: String,
+    pub posts_per_page: u32,
+    pub feed_items: u32,
+    /// What the feeds show of a post, `full` content or `excerpt`
+    pub feed_content: String,
+    pub comments_enabled: bool,
+    pub permalinks: PublicPermalinks,
+}
+
+/// First segment of the URLs of the archives.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicPermalinks {
+    pub category_base: String,
+    pub tag_base: String,
+    pub author_base: String,
+}
+
+/// The default settings of the API, used while it cannot be reached.
+impl Default for PublicSettings {
+    fn default() -> Self {
+        PublicSettings {
+            title: "RustPress".to_string(),
+            description: "A Wordpress clone in Rust!".to_string(),
+            url: "http://127.0.0.1:3000".to_string(),
+            language: "en".to_string(),
+            date_format: String::new(),
+            posts_per_page: 10,
+            feed_items: 10,
+            feed_content: "full".to_string(),
+            comments_enabled: true,
+            permalinks: PublicPermalinks {
+                category_base: "category".to_string(),
+                tag_base: "tag".to_string(),
+                author_base: "author".to_string(),
+            },
+        }
+    }
+}
+
+/// Menu shown at a location of the theme, without the items of unpublished content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicMenu {
+    pub name: String,
+    pub slug: String,
+    pub items: Vec<PublicMenuItem>,
+}
+
+/// Item of a menu, linking to a content by its slug or to the URL of a `Custom` item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicMenuItem {
+    pub title: String,
+    /// `Post`, `Page`, `Category`, `Tag` or `Custom`
+    pub item_type: String,
+    pub slug: Option<String>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub children: Vec<PublicMenuItem>,
+}
@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// What a front route displays, to pick the template rendering it.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum TemplateContext {
+    Home,
+    Single,
+    Page { template: Option<String> },
+    Category { slug: String },
+    Tag { slug: String },
+    Author { username: String },
+    Date,
+    NotFound,
+    /// The content moved, the route answers with a redirect instead of a template
+    Redirect { location: String, status_code: u16 },
+}
+
+impl TemplateContext {
+    /// Candidate templates, from the most specific to `index`.
+    pub fn hierarchy(&self) -> Vec<String> {
+        let candidates: Vec<String> = match self {
+            TemplateContext::Home => vec!["home".to_string()],
+            TemplateContext::Single => vec!["single".to_string()],
+            TemplateContext::Page { template } => template.iter().cloned().chain(["page".to_string()]).collect(),
+            TemplateContext::Category { slug } => {
+                vec![format!("category-{}", slug), "category".to_string(), "archive".to_string()]
+            }
+            TemplateContext::Tag { slug } => vec![format!("tag-{}", slug), "tag".to_string(), "archive".to_string()],
+            TemplateContext::Author { username } => {
+                vec![format!("author-{}", username), "author".to_string(), "archive".to_string()]
+            }
+            TemplateContext::Date => vec!["date".to_string(), "archive".to_string()],
+            TemplateContext::NotFound => vec!["404".to_string()],
+            TemplateContext::Redirect { .. } => vec![],
+        };
+
+        candidates.into_iter().chain(["index".to_string()]).collect()
+    }
+
+    /// Class of the page wrapper, for the theme stylesheets.
+    pub fn body_class(&self) -> &'static str {
+        match self {
+            TemplateContext::Home => "home",
+            TemplateContext::Single => "single",
+            TemplateContext::Page { .. } => "page",
+            TemplateContext::Category { .. } => "archive category",
+            TemplateContext::Tag { .. } => "archive tag",
+            TemplateContext::Author { .. } => "archive author",
+            TemplateContext::Date => "archive date",
+            TemplateContext::NotFound => "error404",
+            TemplateContext::Redirect { .. } => "",
+        }
+    }
+}
+
+/// A front route, ready to be rendered by the active theme.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ThemePage {
+    pub context: TemplateContext,
+    pub title: String,
+    pub data: Value,
+}
+
+impl ThemePage {
+    pub fn not_found() -> Self {
+        ThemePage {
+            context: TemplateContext::NotFound,
+            title: "Page not found".to_string(),
+            data: Value::Object(Default::default()),
+        }
+    }
+
+    pub fn redirect(location: String, status_code: u16) -> Self {
+        ThemePage {
+            context: TemplateContext::Redirect { location, status_code },
+            title: "Moved".to_string(),
+            data: Value::Object(Default::default()),
+        }
+    }
+}
+
+/// The `theme.toml` manifest of a theme.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ThemeManifest {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Templates the theme provides, checked when loading it
+    #[serde(default)]
+    pub templates: Vec<String>,
+    /// Features the theme supports, e.g. `pagination`
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// Locations where the theme shows a menu, with their description
+    #[serde(default)]
+    pub menu_locations: BTreeMap<String, String>,
+}
+
+/// A theme found in the themes directory.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ThemeInfo {
+    /// Name of the theme directory, used to activate it
+    pub directory: String,
+    pub manifest: Option<ThemeManifest>,
+    pub active: bool,
+    /// Why the theme cannot be loaded, empty for a valid theme
+    pub problems: Vec<String>,
+}
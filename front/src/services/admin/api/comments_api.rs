@@ -0,0 +1,66 @@
+use reqwest::{Client, Response};
+
+use crate::models::admin::comments_model::{CommentFilters, CommentsIds, CommentsStatusUpdate, PaginatedComments};
+
+const BASE_URL: &str = "http://127.0.0.1:6988/api/v1/comments";
+
+async fn handle_response<T>(response: Response) -> Result<T, String>
+where
+    T: serde::de::DeserializeOwned,
+{
+    response.json::<T>().await.map_err(|e| e.to_string())
+}
+
+pub async fn get_comments(
+    page: u32,
+    limit: u32,
+    sort_column: String,
+    sort_order: String,
+    filters: CommentFilters,
+) -> Result<PaginatedComments, String> {
+    let client = Client::new();
+
+    let response = client
+        .get(BASE_URL)
+        .query(&[("page", page), ("limit", limit)])
+        .query(&[("sort_column", sort_column), ("sort_order", sort_order)])
+        .query(&filters)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+/// Approves, holds or discards comments, returning the IDs of the updated comments.
+pub async fn update_comments_status(update: CommentsStatusUpdate) -> Result<Vec<u32>, String> {
+    let client = Client::new();
+
+    let response = client
+        .put(format!("{BASE_URL}/status"))
+        .json(&update)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn delete_comments(comments_ids: CommentsIds) -> Result<Vec<u32>, String> {
+    let client = Client::new();
+
+    let response = client
+        .delete(BASE_URL)
+        .json(&comments_ids)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
@@ -0,0 +1,64 @@
+use gloo_net::http::Request;
+use reqwest::{Client, Response};
+use web_sys::{File, FormData};
+
+use crate::models::admin::media_model::{MediaStruct, PaginatedMedia};
+
+const BASE_URL: &str = "http://127.0.0.1:6988/api/v1/media";
+
+async fn handle_response<T>(response: Response) -> Result<T, String>
+where
+    T: serde::de::DeserializeOwned,
+{
+    response.json::<T>().await.map_err(|e| e.to_string())
+}
+
+pub async fn get_media(page: u32, limit: u32) -> Result<PaginatedMedia, String> {
+    let client = Client::new();
+
+    let response = client
+        .get(BASE_URL)
+        .query(&[("page", page), ("limit", limit)])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn get_media_by_id(media_id: u32) -> Result<MediaStruct, String> {
+    let client = Client::new();
+
+    let response = client
+        .get(format!("{BASE_URL}/{media_id}"))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+/// Uploads a file chosen in the browser, sent as `multipart/form-data` in a `file` field.
+pub async fn upload_media(file: File) -> Result<MediaStruct, String> {
+    let form_data = FormData::new().map_err(|e| format!("{:?}", e))?;
+    form_data
+        .append_with_blob_and_filename("file", &file, &file.name())
+        .map_err(|e| format!("{:?}", e))?;
+
+    let response = Request::post(BASE_URL)
+        .body(form_data)
+        .map_err(|e| e.to_string())?
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !response.ok() {
+        return Err(format!("Failed to upload file. HTTP Status: {}", response.status()));
+    }
+
+    response.json::<MediaStruct>().await.map_err(|e| e.to_string())
+}
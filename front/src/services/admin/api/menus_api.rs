@@ -0,0 +1,134 @@
+use reqwest::{Client, Response};
+
+use crate::models::admin::menus_model::{
+    MenuItemsUpdate, MenuLocations, MenuNewStruct, MenuStruct, MenuTree, MenusIds,
+};
+
+const BASE_URL: &str = "http://127.0.0.1:6988/api/v1/menus";
+
+async fn handle_response<T>(response: Response) -> Result<T, String>
+where
+    T: serde::de::DeserializeOwned,
+{
+    response.json::<T>().await.map_err(|e| e.to_string())
+}
+
+pub async fn get_menus() -> Result<Vec<MenuStruct>, String> {
+    let client = Client::new();
+
+    let response = client
+        .get(BASE_URL)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+/// Retrieves a menu with the tree of its items.
+pub async fn get_menu_by_id(menu_id: u32) -> Result<MenuTree, String> {
+    let client = Client::new();
+
+    let response = client
+        .get(format!("{BASE_URL}/{menu_id}"))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn add_menu(menu: MenuNewStruct) -> Result<MenuStruct, String> {
+    let client = Client::new();
+
+    let response = client
+        .post(BASE_URL)
+        .json(&menu)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn update_menu(menu_id: u32, menu: MenuNewStruct) -> Result<MenuStruct, String> {
+    let client = Client::new();
+
+    let response = client
+        .put(format!("{BASE_URL}/{menu_id}"))
+        .json(&menu)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+/// Replaces the items of a menu, returning the menu with its new items.
+pub async fn update_menu_items(menu_id: u32, items: MenuItemsUpdate) -> Result<MenuTree, String> {
+    let client = Client::new();
+
+    let response = client
+        .put(format!("{BASE_URL}/{menu_id}/items"))
+        .json(&items)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn delete_menus(menus_ids: MenusIds) -> Result<Vec<u32>, String> {
+    let client = Client::new();
+
+    let response = client
+        .delete(BASE_URL)
+        .json(&menus_ids)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn get_menu_locations() -> Result<MenuLocations, String> {
+    let client = Client::new();
+
+    let response = client
+        .get(format!("{BASE_URL}/locations"))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+/// Assigns the menus to the theme locations, the missing locations being left without a menu.
+pub async fn update_menu_locations(locations: MenuLocations) -> Result<MenuLocations, String> {
+    let client = Client::new();
+
+    let response = client
+        .put(format!("{BASE_URL}/locations"))
+        .json(&locations)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
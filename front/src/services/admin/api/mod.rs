@@ -1,3 +1,10 @@
 pub mod categories_api;
+pub mod comments_api;
+pub mod media_api;
+pub mod menus_api;
+pub mod pages_api;
 pub mod posts_api;
+pub mod redirects_api;
+pub mod slugs_api;
 pub mod tags_api;
+pub mod themes_api;
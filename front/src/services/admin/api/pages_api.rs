@@ -0,0 +1,54 @@
+use reqwest::{Client, Response};
+
+use crate::models::admin::pages_model::{PageNewStruct, PageStruct, PaginatedPages};
+
+const BASE_URL: &str = "http://127.0.0.1:6988/api/v1/pages";
+
+async fn handle_response<T>(response: Response) -> Result<T, String>
+where
+    T: serde::de::DeserializeOwned,
+{
+    response.json::<T>().await.map_err(|e| e.to_string())
+}
+
+/// Lists the pages by menu order, to choose the parent of a page.
+pub async fn get_pages(page: u32, limit: u32) -> Result<PaginatedPages, String> {
+    let client = Client::new();
+
+    let response = client
+        .get(BASE_URL)
+        .query(&[("page", page), ("limit", limit)])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn add_page(page: PageNewStruct) -> Result<PageStruct, String> {
+    let client = Client::new();
+
+    let response = client
+        .post(BASE_URL)
+        .json(&page)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    let http_code = response.status().as_u16();
+
+    if response.status().is_success() {
+        let mut created_page: PageStruct = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        created_page.http_code = Some(http_code);
+
+        Ok(created_page)
+    } else {
+        Err(format!("Failed to create page. HTTP Status: {}", http_code))
+    }
+}
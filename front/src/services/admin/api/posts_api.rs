@@ -1,9 +1,13 @@
-use reqwest::{Client, Response};
+use reqwest::{Client, Response, StatusCode};
 use web_sys::console;
 
-use crate::models::admin::posts_model::{PaginatedPosts, PostNewStruct, PostRequest, PostStruct, PostsIds};
+use crate::models::admin::posts_model::{
+    PaginatedPosts, PostAutosave, PostAutosaveRequest, PostFilters, PostRequest, PostRevision, PostRevisionDiff,
+    PostStruct, PostsIds,
+};
 
 const BASE_URL: &str = "http://127.0.0.1:6988/api/v1/posts";
+const AUTOSAVES_URL: &str = "http://127.0.0.1:6988/api/v1/autosaves";
 
 async fn handle_response<T>(response: Response) -> Result<T, String>
 where
@@ -17,6 +21,7 @@ pub async fn get_posts(
     limit: u32,
     sort_column: String,
     sort_order: String,
+    filters: PostFilters,
 ) -> Result<PaginatedPosts, String> {
     console::log_1(
         &format!(
@@ -35,6 +40,25 @@ pub async fn get_posts(
 
     let response = client
         .get(&url)
+        .query(&filters)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    let paginated_posts = response.json::<PaginatedPosts>().await.map_err(|e| e.to_string())?;
+
+    Ok(paginated_posts)
+}
+
+pub async fn search_posts(query: String, page: u32, limit: u32) -> Result<PaginatedPosts, String> {
+    let client = Client::new();
+
+    let response = client
+        .get(format!("{BASE_URL}/search"))
+        .query(&[("q", query)])
+        .query(&[("page", page), ("limit", limit)])
         .send()
         .await
         .map_err(|e| e.to_string())?
@@ -92,7 +116,7 @@ pub async fn add_post(post: PostRequest) -> Result<PostStruct, String> {
     }
 }
 
-pub async fn update_post(post_id: u32, post: PostNewStruct) -> Result<PostNewStruct, String> {
+pub async fn update_post(post_id: u32, post: PostRequest) -> Result<PostStruct, String> {
     let client = Client::new();
     let url = format!("{}/{}", BASE_URL, post_id);
 
@@ -128,3 +152,95 @@ pub async fn delete_posts(posts_ids: PostsIds) -> Result<PostsIds, String> {
         Err(format!("API returned an error: {}", response.status()))
     }
 }
+
+pub async fn get_post_revisions(post_id: u32) -> Result<Vec<PostRevision>, String> {
+    let client = Client::new();
+
+    let response = client
+        .get(format!("{BASE_URL}/{post_id}/revisions"))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn get_post_revisions_diff(post_id: u32, from: u32, to: u32) -> Result<PostRevisionDiff, String> {
+    let client = Client::new();
+
+    let response = client
+        .get(format!("{BASE_URL}/{post_id}/revisions/diff"))
+        .query(&[("from", from), ("to", to)])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn restore_post_revision(post_id: u32, revision_id: u32) -> Result<PostStruct, String> {
+    let client = Client::new();
+
+    let response = client
+        .post(format!("{BASE_URL}/{post_id}/revisions/{revision_id}/restore"))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+/// Returns the autosave of a post newer than its saved version, `post_id` being `None` for a new post.
+pub async fn get_autosave(post_id: Option<u32>) -> Result<Option<PostAutosave>, String> {
+    let client = Client::new();
+
+    let response = client
+        .get(AUTOSAVES_URL)
+        .query(&[("post_id", post_id)])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    let response = response.error_for_status().map_err(|e| e.to_string())?;
+    handle_response(response).await.map(Some)
+}
+
+pub async fn save_autosave(autosave: PostAutosaveRequest) -> Result<PostAutosave, String> {
+    let client = Client::new();
+
+    let response = client
+        .put(AUTOSAVES_URL)
+        .json(&autosave)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn delete_autosave(post_id: Option<u32>) -> Result<(), String> {
+    let client = Client::new();
+
+    client
+        .delete(AUTOSAVES_URL)
+        .query(&[("post_id", post_id)])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
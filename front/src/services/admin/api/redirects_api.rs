@@ -0,0 +1,73 @@
+use reqwest::{Client, Response};
+
+use crate::models::admin::redirects_model::{PaginatedRedirects, RedirectNewStruct, RedirectStruct, RedirectsIds};
+
+const BASE_URL: &str = "http://127.0.0.1:6988/api/v1/redirects";
+
+async fn handle_response<T>(response: Response) -> Result<T, String>
+where
+    T: serde::de::DeserializeOwned,
+{
+    response.json::<T>().await.map_err(|e| e.to_string())
+}
+
+/// Retrieves a page of redirects, sorted by source path.
+pub async fn get_redirects(page: u32, limit: u32) -> Result<PaginatedRedirects, String> {
+    let client = Client::new();
+
+    let response = client
+        .get(BASE_URL)
+        .query(&[("page", page), ("limit", limit)])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn add_redirect(redirect: RedirectNewStruct) -> Result<RedirectStruct, String> {
+    let client = Client::new();
+
+    let response = client
+        .post(BASE_URL)
+        .json(&redirect)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn update_redirect(redirect_id: u32, redirect: RedirectNewStruct) -> Result<RedirectStruct, String> {
+    let client = Client::new();
+
+    let response = client
+        .put(format!("{BASE_URL}/{redirect_id}"))
+        .json(&redirect)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+pub async fn delete_redirects(redirects_ids: RedirectsIds) -> Result<Vec<u32>, String> {
+    let client = Client::new();
+
+    let response = client
+        .delete(BASE_URL)
+        .json(&redirects_ids)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
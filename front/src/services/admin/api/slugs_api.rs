@@ -0,0 +1,28 @@
+use reqwest::Client;
+
+use crate::models::admin::slugs_model::SlugCheck;
+
+const BASE_URL: &str = "http://127.0.0.1:6988/api/v1/slugs";
+
+/// Checks whether a slug is free among the contents of a type (`post`, `page`, `category` or `tag`), leaving out
+/// the content being edited.
+pub async fn check_slug(slug_type: &str, slug: &str, exclude_id: Option<u32>) -> Result<SlugCheck, String> {
+    let client = Client::new();
+
+    let mut query = vec![("type", slug_type.to_string()), ("slug", slug.to_string())];
+    if let Some(exclude_id) = exclude_id {
+        query.push(("exclude_id", exclude_id.to_string()));
+    }
+
+    client
+        .get(format!("{BASE_URL}/check"))
+        .query(&query)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?
+        .json::<SlugCheck>()
+        .await
+        .map_err(|e| e.to_string())
+}
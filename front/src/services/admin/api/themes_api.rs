@@ -0,0 +1,28 @@
+use reqwest::{Client, Response};
+
+use crate::models::front::public_model::ActiveTheme;
+
+const BASE_URL: &str = "http://127.0.0.1:6988/api/v1/themes";
+
+async fn handle_response<T>(response: Response) -> Result<T, String>
+where
+    T: serde::de::DeserializeOwned,
+{
+    response.json::<T>().await.map_err(|e| e.to_string())
+}
+
+/// Makes a theme of the themes directory render the public site.
+pub async fn activate_theme(directory: String) -> Result<ActiveTheme, String> {
+    let client = Client::new();
+
+    let response = client
+        .put(format!("{BASE_URL}/active"))
+        .json(&ActiveTheme { name: directory })
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
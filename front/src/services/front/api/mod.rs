@@ -0,0 +1 @@
+pub mod public_api;
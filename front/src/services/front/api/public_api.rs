@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+
+use reqwest::{Client, Response, StatusCode};
+
+use crate::models::front::public_model::{
+    ActiveTheme, PaginatedPublicPosts, PublicMenu, PublicPageStruct, PublicPostFilters, PublicPostStruct,
+    PublicRedirect, PublicSettings, PublicSlug, PublicTermStruct,
+};
+
+const BASE_URL: &str = "http://127.0.0.1:6988/api/v1/public";
+
+async fn handle_response<T>(response: Response) -> Result<T, String>
+where
+    T: serde::de::DeserializeOwned,
+{
+    response.json::<T>().await.map_err(|e| e.to_string())
+}
+
+/// Fetches a single resource of the public API, `None` when it does not exist.
+async fn get_by_slug<T>(url: String) -> Result<Option<T>, String>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let response = Client::new().get(url).send().await.map_err(|e| e.to_string())?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    let response = response.error_for_status().map_err(|e| e.to_string())?;
+
+    handle_response(response).await.map(Some)
+}
+
+/// Lists the published posts, latest first, by pages of the `posts_per_page` setting.
+///
+/// Returns `None` when the category, tag or author of the filters does not exist.
+pub async fn get_public_posts(filters: PublicPostFilters, page: u32) -> Result<Option<PaginatedPublicPosts>, String> {
+    let response = Client::new()
+        .get(format!("{}/posts", BASE_URL))
+        .query(&[("page", page)])
+        .query(&filters)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    let response = response.error_for_status().map_err(|e| e.to_string())?;
+
+    handle_response(response).await.map(Some)
+}
+
+/// The `limit` latest published posts matching the filters, `None` when the filtered archive does not exist.
+pub async fn get_public_latest_posts(
+    filters: PublicPostFilters,
+    limit: u32,
+) -> Result<Option<PaginatedPublicPosts>, String> {
+    let response = Client::new()
+        .get(format!("{}/posts", BASE_URL))
+        .query(&[("page", 1), ("limit", limit)])
+        .query(&filters)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    let response = response.error_for_status().map_err(|e| e.to_string())?;
+
+    handle_response(response).await.map(Some)
+}
+
+pub async fn get_public_post(slug: &str) -> Result<Option<PublicPostStruct>, String> {
+    get_by_slug(format!("{}/posts/{}", BASE_URL, slug)).await
+}
+
+pub async fn get_public_page(slug: &str) -> Result<Option<PublicPageStruct>, String> {
+    get_by_slug(format!("{}/pages/{}", BASE_URL, slug)).await
+}
+
+pub async fn get_public_category(slug: &str) -> Result<Option<PublicTermStruct>, String> {
+    get_by_slug(format!("{}/categories/{}", BASE_URL, slug)).await
+}
+
+pub async fn get_public_tag(slug: &str) -> Result<Option<PublicTermStruct>, String> {
+    get_by_slug(format!("{}/tags/{}", BASE_URL, slug)).await
+}
+
+/// Redirect of a path matching no content, `None` when the path is not redirected.
+pub async fn get_public_redirect(path: &str) -> Result<Option<PublicRedirect>, String> {
+    let response = Client::new()
+        .get(format!("{}/redirects", BASE_URL))
+        .query(&[("path", path)])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    let response = response.error_for_status().map_err(|e| e.to_string())?;
+
+    handle_response(response).await.map(Some)
+}
+
+/// Current slug of the `post`, `page`, `category` or `tag` which had `slug`, `None` when none had it.
+pub async fn get_public_current_slug(slug_type: &str, slug: &str) -> Result<Option<PublicSlug>, String> {
+    get_by_slug(format!("{}/slugs/{}/{}", BASE_URL, slug_type, slug)).await
+}
+
+/// Name of the theme directory rendering the site.
+pub async fn get_active_theme() -> Result<String, String> {
+    let response = Client::new()
+        .get(format!("{}/theme", BASE_URL))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response::<ActiveTheme>(response).await.map(|theme| theme.name)
+}
+
+/// Settings the site is rendered with.
+pub async fn get_public_settings() -> Result<PublicSettings, String> {
+    let response = Client::new()
+        .get(format!("{}/settings", BASE_URL))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
+
+/// Menu of every location of the theme which has one.
+pub async fn get_public_menus() -> Result<BTreeMap<String, PublicMenu>, String> {
+    let response = Client::new()
+        .get(format!("{}/menus", BASE_URL))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+
+    handle_response(response).await
+}
@@ -0,0 +1,443 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use chrono::{NaiveDateTime, SecondsFormat, TimeZone, Utc};
+use serde_json::Value;
+
+use crate::{
+    models::front::public_model::PublicPostStruct,
+    services::front::theme_helpers::{excerpt, DEFAULT_EXCERPT_LENGTH},
+};
+
+/// Format of the dates of the `Last-Modified` and `If-Modified-Since` headers.
+const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+const GENERATOR: &str = "RustPress";
+
+/// Formats a feed is rendered in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+}
+
+impl FeedFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+        }
+    }
+
+    /// End of the path of the feed, after the path of the page it syndicates.
+    pub fn path_suffix(&self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "feed",
+            FeedFormat::Atom => "feed/atom",
+        }
+    }
+}
+
+/// Description of a feed, its links being absolute URLs.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FeedChannel {
+    pub title: String,
+    pub description: String,
+    /// Page of the site listing the posts of the feed
+    pub link: String,
+    /// URL of the feed itself
+    pub feed_url: String,
+    pub language: String,
+}
+
+/// Escapes a text for an XML element or attribute, without the control characters XML does not allow.
+pub fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Date of the most recently published post, `None` for an empty feed.
+pub fn feed_last_modified(posts: &[PublicPostStruct]) -> Option<NaiveDateTime> {
+    posts.iter().filter_map(|post| post.date_published).max()
+}
+
+/// Entity tag of the body of a feed.
+pub fn feed_etag(body: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    format!("\"{:016x}\"", hasher.finish())
+}
+
+/// Date of a `Last-Modified` header.
+pub fn http_date(date: NaiveDateTime) -> String {
+    date.format(HTTP_DATE_FORMAT).to_string()
+}
+
+/// Whether the client already has the feed, by its `If-None-Match` header or else its `If-Modified-Since` header.
+pub fn is_not_modified(
+    if_none_match: Option<&str>,
+    if_modified_since: Option<&str>,
+    etag: &str,
+    last_modified: Option<NaiveDateTime>,
+) -> bool {
+    if let Some(if_none_match) = if_none_match {
+        return if_none_match
+            .split(',')
+            .map(|tag| tag.trim().trim_start_matches("W/"))
+            .any(|tag| tag == etag || tag == "*");
+    }
+
+    match (if_modified_since, last_modified) {
+        (Some(if_modified_since), Some(last_modified)) => {
+            NaiveDateTime::parse_from_str(if_modified_since, HTTP_DATE_FORMAT)
+                .is_ok_and(|since| last_modified.and_utc().timestamp() <= since.and_utc().timestamp())
+        }
+        _ => false,
+    }
+}
+
+/// Renders the XML of a feed of posts, linking them to their permalink on `site_url`.
+///
+/// Every post comes with an excerpt of its content, and its full content too when `full_content` is set.
+pub fn render_feed(
+    format: FeedFormat,
+    channel: &FeedChannel,
+    posts: &[PublicPostStruct],
+    site_url: &str,
+    full_content: bool,
+) -> String {
+    let site_url = site_url.trim_end_matches('/');
+
+    match format {
+        FeedFormat::Rss => render_rss(channel, posts, site_url, full_content),
+        FeedFormat::Atom => render_atom(channel, posts, site_url, full_content),
+    }
+}
+
+fn render_rss(channel: &FeedChannel, posts: &[PublicPostStruct], site_url: &str, full_content: bool) -> String {
+    let mut xml = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\" ",
+        "xmlns:content=\"http://purl.org/rss/1.0/modules/content/\" ",
+        "xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n",
+        "<channel>\n"
+    ));
+    xml.push_str(&format!("<title>{}</title>\n", xml_escape(&channel.title)));
+    xml.push_str(&format!("<link>{}</link>\n", xml_escape(&channel.link)));
+    xml.push_str(&format!(
+        "<description>{}</description>\n",
+        xml_escape(&channel.description)
+    ));
+    xml.push_str(&format!("<language>{}</language>\n", xml_escape(&channel.language)));
+    xml.push_str(&format!(
+        "<atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>\n",
+        xml_escape(&channel.feed_url)
+    ));
+    if let Some(last_modified) = feed_last_modified(posts) {
+        xml.push_str(&format!("<lastBuildDate>{}</lastBuildDate>\n", rfc2822(last_modified)));
+    }
+    xml.push_str(&format!("<generator>{}</generator>\n", GENERATOR));
+
+    for post in posts {
+        let link = format!("{}/{}", site_url, post.slug);
+
+        xml.push_str("<item>\n");
+        xml.push_str(&format!("<title>{}</title>\n", xml_escape(&post.title)));
+        xml.push_str(&format!("<link>{}</link>\n", xml_escape(&link)));
+        xml.push_str(&format!("<guid isPermaLink=\"true\">{}</guid>\n", xml_escape(&link)));
+        if let Some(date_published) = post.date_published {
+            xml.push_str(&format!("<pubDate>{}</pubDate>\n", rfc2822(date_published)));
+        }
+        if let Some(author) = author_name(post) {
+            xml.push_str(&format!("<dc:creator>{}</dc:creator>\n", xml_escape(&author)));
+        }
+        for (_, name) in post_terms(post) {
+            xml.push_str(&format!("<category>{}</category>\n", xml_escape(&name)));
+        }
+        xml.push_str(&format!(
+            "<description>{}</description>\n",
+            xml_escape(&excerpt(&post.content, DEFAULT_EXCERPT_LENGTH as usize))
+        ));
+        if full_content {
+            xml.push_str(&format!(
+                "<content:encoded>{}</content:encoded>\n",
+                xml_escape(&ammonia::clean(&post.content))
+            ));
+        }
+        xml.push_str("</item>\n");
+    }
+
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+}
+
+fn render_atom(channel: &FeedChannel, posts: &[PublicPostStruct], site_url: &str, full_content: bool) -> String {
+    let updated = feed_last_modified(posts).unwrap_or_default();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"{}\">\n",
+        xml_escape(&channel.language)
+    ));
+    xml.push_str(&format!(
+        "<title type=\"text\">{}</title>\n",
+        xml_escape(&channel.title)
+    ));
+    xml.push_str(&format!(
+        "<subtitle type=\"text\">{}</subtitle>\n",
+        xml_escape(&channel.description)
+    ));
+    xml.push_str(&format!(
+        "<link href=\"{}\" rel=\"alternate\" type=\"text/html\"/>\n",
+        xml_escape(&channel.link)
+    ));
+    xml.push_str(&format!(
+        "<link href=\"{}\" rel=\"self\" type=\"application/atom+xml\"/>\n",
+        xml_escape(&channel.feed_url)
+    ));
+    xml.push_str(&format!("<id>{}</id>\n", xml_escape(&channel.feed_url)));
+    xml.push_str(&format!("<updated>{}</updated>\n", rfc3339(updated)));
+    // Entries without an author take the one of the feed
+    xml.push_str(&format!(
+        "<author><name>{}</name></author>\n",
+        xml_escape(&channel.title)
+    ));
+    xml.push_str(&format!("<generator>{}</generator>\n", GENERATOR));
+
+    for post in posts {
+        let link = format!("{}/{}", site_url, post.slug);
+        let date_published = rfc3339(post.date_published.unwrap_or_default());
+
+        xml.push_str("<entry>\n");
+        xml.push_str(&format!("<title type=\"text\">{}</title>\n", xml_escape(&post.title)));
+        xml.push_str(&format!(
+            "<link href=\"{}\" rel=\"alternate\" type=\"text/html\"/>\n",
+            xml_escape(&link)
+        ));
+        xml.push_str(&format!("<id>{}</id>\n", xml_escape(&link)));
+        xml.push_str(&format!("<published>{}</published>\n", date_published));
+        xml.push_str(&format!("<updated>{}</updated>\n", date_published));
+        if let Some(author) = author_name(post) {
+            xml.push_str(&format!("<author><name>{}</name></author>\n", xml_escape(&author)));
+        }
+        for (slug, name) in post_terms(post) {
+            xml.push_str(&format!(
+                "<category term=\"{}\" label=\"{}\"/>\n",
+                xml_escape(&slug),
+                xml_escape(&name)
+            ));
+        }
+        xml.push_str(&format!(
+            "<summary type=\"html\">{}</summary>\n",
+            xml_escape(&excerpt(&post.content, DEFAULT_EXCERPT_LENGTH as usize))
+        ));
+        if full_content {
+            xml.push_str(&format!(
+                "<content type=\"html\">{}</content>\n",
+                xml_escape(&ammonia::clean(&post.content))
+            ));
+        }
+        xml.push_str("</entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+fn rfc2822(date: NaiveDateTime) -> String {
+    Utc.from_utc_datetime(&date).to_rfc2822()
+}
+
+fn rfc3339(date: NaiveDateTime) -> String {
+    Utc.from_utc_datetime(&date).to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn author_name(post: &PublicPostStruct) -> Option<String> {
+    post.author
+        .as_ref()
+        .map(|author| format!("{} {}", author.firstname, author.lastname).trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Slugs and names of the categories, then of the tags, of a post.
+fn post_terms(post: &PublicPostStruct) -> Vec<(String, String)> {
+    [&post.categories, &post.tags]
+        .into_iter()
+        .filter_map(|terms| terms.as_ref().and_then(Value::as_array))
+        .flatten()
+        .filter_map(|term| {
+            Some((
+                term.get("slug")?.as_str()?.to_string(),
+                term.get("name")?.as_str()?.to_string(),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    use super::*;
+    use crate::models::front::public_model::PublicAuthorStruct;
+
+    fn date(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, day)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap()
+    }
+
+    fn post(slug: &str, day: u32) -> PublicPostStruct {
+        PublicPostStruct {
+            id: day,
+            title: "Fish & <Chips>".to_string(),
+            content: "<p>Hello <b>world</b></p><script>alert(1)</script>".to_string(),
+            slug: slug.to_string(),
+            author: Some(PublicAuthorStruct {
+                id: 1,
+                username: "admin".to_string(),
+                firstname: "Ada".to_string(),
+                lastname: "Lovelace".to_string(),
+                url: None,
+            }),
+            date_published: Some(date(day)),
+            featured_media_id: None,
+            categories: Some(json!([{ "id": 1, "name": "News & Events", "slug": "news" }])),
+            tags: Some(json!([{ "id": 2, "name": "Rust", "slug": "rust" }])),
+            comment_count: 0,
+        }
+    }
+
+    fn channel() -> FeedChannel {
+        FeedChannel {
+            title: "My \"blog\"".to_string(),
+            description: "Posts".to_string(),
+            link: "http://example.com/".to_string(),
+            feed_url: "http://example.com/feed".to_string(),
+            language: "en".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(
+            xml_escape("a & b < c > \"d\" 'e'"),
+            "a &amp; b &lt; c &gt; &quot;d&quot; &apos;e&apos;"
+        );
+        assert_eq!(xml_escape("line\nbreak\u{0}\u{1b}"), "line\nbreak");
+    }
+
+    #[test]
+    fn test_render_rss() {
+        let posts = vec![post("second", 2), post("first", 1)];
+
+        let xml = render_feed(FeedFormat::Rss, &channel(), &posts, "http://example.com/", true);
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\""));
+        assert!(xml.contains("<title>My &quot;blog&quot;</title>"));
+        assert!(xml.contains("<lastBuildDate>Sat, 2 Mar 2024 09:30:00 +0000</lastBuildDate>"));
+        assert!(xml.contains("<title>Fish &amp; &lt;Chips&gt;</title>"));
+        assert!(xml.contains("<link>http://example.com/second</link>"));
+        assert!(xml.contains("<guid isPermaLink=\"true\">http://example.com/first</guid>"));
+        assert!(xml.contains("<pubDate>Fri, 1 Mar 2024 09:30:00 +0000</pubDate>"));
+        assert!(xml.contains("<dc:creator>Ada Lovelace</dc:creator>"));
+        assert!(xml.contains("<category>News &amp; Events</category>\n<category>Rust</category>"));
+        assert!(xml.contains("<content:encoded>&lt;p&gt;Hello &lt;b&gt;world&lt;/b&gt;&lt;/p&gt;</content:encoded>"));
+        assert!(!xml.contains("alert"));
+        assert!(xml.ends_with("</channel>\n</rss>\n"));
+    }
+
+    #[test]
+    fn test_render_rss_excerpt() {
+        let xml = render_feed(
+            FeedFormat::Rss,
+            &channel(),
+            &[post("first", 1)],
+            "http://example.com",
+            false,
+        );
+
+        assert!(xml.contains("<description>Hello world</description>"));
+        assert!(!xml.contains("<content:encoded>"));
+    }
+
+    #[test]
+    fn test_render_atom() {
+        let posts = vec![post("second", 2), post("first", 1)];
+
+        let xml = render_feed(FeedFormat::Atom, &channel(), &posts, "http://example.com", false);
+
+        assert!(xml.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"en\">"));
+        assert!(xml.contains("<link href=\"http://example.com/feed\" rel=\"self\" type=\"application/atom+xml\"/>"));
+        assert!(xml.contains("<updated>2024-03-02T09:30:00Z</updated>"));
+        assert!(xml.contains("<id>http://example.com/first</id>"));
+        assert!(xml.contains("<published>2024-03-01T09:30:00Z</published>"));
+        assert!(xml.contains("<category term=\"news\" label=\"News &amp; Events\"/>"));
+        assert!(xml.contains("<summary type=\"html\">Hello world</summary>"));
+        assert!(!xml.contains("<content"));
+        assert!(xml.ends_with("</feed>\n"));
+    }
+
+    #[test]
+    fn test_render_atom_empty() {
+        let xml = render_feed(FeedFormat::Atom, &channel(), &[], "http://example.com", true);
+
+        assert!(xml.contains("<updated>1970-01-01T00:00:00Z</updated>"));
+        assert!(!xml.contains("<entry>"));
+    }
+
+    #[test]
+    fn test_is_not_modified() {
+        let etag = feed_etag("<rss/>");
+        let last_modified = Some(date(2));
+
+        assert!(is_not_modified(Some(&etag), None, &etag, last_modified));
+        assert!(is_not_modified(
+            Some(&format!("\"other\", W/{}", etag)),
+            None,
+            &etag,
+            last_modified
+        ));
+        assert!(!is_not_modified(
+            Some("\"other\""),
+            Some(&http_date(date(2))),
+            &etag,
+            last_modified
+        ));
+        assert!(is_not_modified(
+            None,
+            Some("Sat, 02 Mar 2024 09:30:00 GMT"),
+            &etag,
+            last_modified
+        ));
+        assert!(!is_not_modified(
+            None,
+            Some("Fri, 01 Mar 2024 09:30:00 GMT"),
+            &etag,
+            last_modified
+        ));
+        assert!(!is_not_modified(None, Some("yesterday"), &etag, last_modified));
+        assert!(!is_not_modified(None, None, &etag, last_modified));
+    }
+
+    #[test]
+    fn test_http_date() {
+        assert_eq!(http_date(date(2)), "Sat, 02 Mar 2024 09:30:00 GMT");
+    }
+}
@@ -0,0 +1,13 @@
+use std::collections::BTreeMap;
+
+use leptos::logging;
+
+use crate::{models::front::public_model::PublicMenu, services::front::api::public_api::get_public_menus};
+
+/// Menu of every location of the theme, none when the API cannot be reached so the site still renders.
+pub async fn site_menus() -> BTreeMap<String, PublicMenu> {
+    get_public_menus().await.unwrap_or_else(|e| {
+        logging::warn!("Failed to get the menus: {}", e);
+        BTreeMap::new()
+    })
+}
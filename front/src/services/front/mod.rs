@@ -0,0 +1,7 @@
+pub mod api;
+pub mod feed_service;
+pub mod menus_service;
+pub mod redirects_service;
+pub mod settings_service;
+pub mod theme_helpers;
+pub mod theme_service;
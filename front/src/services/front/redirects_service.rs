@@ -0,0 +1,62 @@
+use leptos::logging;
+
+use crate::{
+    models::front::{public_model::PublicPermalinks, theme_model::ThemePage},
+    services::front::{
+        api::public_api::{get_public_current_slug, get_public_redirect},
+        settings_service::site_settings,
+    },
+};
+
+/// Permalink of the `post`, `page`, `category` or `tag` of a slug.
+pub fn content_permalink(slug_type: &str, slug: &str, permalinks: &PublicPermalinks) -> String {
+    match slug_type {
+        "category" => format!("/{}/{}", permalinks.category_base, slug),
+        "tag" => format!("/{}/{}", permalinks.tag_base, slug),
+        _ => format!("/{}", slug),
+    }
+}
+
+/// Page of a path matching no content: the redirect of the path managed in the admin, then a permanent redirect to
+/// the current permalink of a content of `slug_types` which had `slug`, else the 404 page.
+///
+/// Errors of the API fall back to the 404 page, so the site still renders.
+pub async fn not_found_page(path: &str, slug_types: &[&str], slug: &str) -> ThemePage {
+    match get_public_redirect(path).await {
+        Ok(Some(redirect)) => return ThemePage::redirect(redirect.target, redirect.status_code),
+        Ok(None) => {}
+        Err(e) => logging::warn!("Failed to get the redirect of '{}': {}", path, e),
+    }
+
+    for slug_type in slug_types {
+        match get_public_current_slug(slug_type, slug).await {
+            Ok(Some(current)) => {
+                let permalinks = site_settings().await.permalinks;
+                return ThemePage::redirect(content_permalink(slug_type, &current.slug, &permalinks), 301);
+            }
+            Ok(None) => {}
+            Err(e) => logging::warn!("Failed to get the current slug of '{}': {}", slug, e),
+        }
+    }
+
+    ThemePage::not_found()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_permalink() {
+        let permalinks = PublicPermalinks {
+            category_base: "topics".to_string(),
+            tag_base: "tag".to_string(),
+            author_base: "author".to_string(),
+        };
+
+        assert_eq!(content_permalink("post", "hello-world", &permalinks), "/hello-world");
+        assert_eq!(content_permalink("page", "about", &permalinks), "/about");
+        assert_eq!(content_permalink("category", "news", &permalinks), "/topics/news");
+        assert_eq!(content_permalink("tag", "rust", &permalinks), "/tag/rust");
+    }
+}
@@ -0,0 +1,11 @@
+use leptos::logging;
+
+use crate::{models::front::public_model::PublicSettings, services::front::api::public_api::get_public_settings};
+
+/// Settings of the site, the default settings when the API cannot be reached so the site still renders.
+pub async fn site_settings() -> PublicSettings {
+    get_public_settings().await.unwrap_or_else(|e| {
+        logging::warn!("Failed to get the site settings: {}", e);
+        PublicSettings::default()
+    })
+}
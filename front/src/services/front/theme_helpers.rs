@@ -0,0 +1,746 @@
+use std::{fs, path::PathBuf, time::UNIX_EPOCH};
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime};
+use handlebars::{
+    html_escape, Context, Handlebars, Helper, HelperDef, HelperResult, Output, RenderContext, RenderErrorReason,
+};
+use serde_json::Value;
+
+use crate::utils::theme_data_util::page_url;
+
+/// Locale of the dates when neither the template nor the site settings give one.
+const DEFAULT_LOCALE: &str = "en";
+
+/// Words kept by `excerpt` when the template does not give a length.
+pub const DEFAULT_EXCERPT_LENGTH: u64 = 55;
+
+const ELLIPSIS: &str = "…";
+
+/// Month and weekday names of a locale, weekdays starting on Monday.
+struct LocaleNames {
+    date_format: &'static str,
+    months: [&'static str; 12],
+    short_months: [&'static str; 12],
+    weekdays: [&'static str; 7],
+    short_weekdays: [&'static str; 7],
+}
+
+const EN: LocaleNames = LocaleNames {
+    date_format: "%B %-d, %Y",
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    short_months: ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+    weekdays: ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"],
+    short_weekdays: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+};
+
+const FR: LocaleNames = LocaleNames {
+    date_format: "%-d %B %Y",
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    short_months: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc.",
+    ],
+    weekdays: ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"],
+    short_weekdays: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+};
+
+const DE: LocaleNames = LocaleNames {
+    date_format: "%-d. %B %Y",
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    short_months: ["Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sep.", "Okt.", "Nov.", "Dez."],
+    weekdays: ["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag"],
+    short_weekdays: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+};
+
+const ES: LocaleNames = LocaleNames {
+    date_format: "%-d de %B de %Y",
+    months: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    short_months: ["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"],
+    weekdays: ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"],
+    short_weekdays: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+};
+
+/// Registers the helpers available to every template of a theme.
+///
+/// # Arguments
+///
+/// * `registry` - The Handlebars registry of the theme.
+/// * `theme_name` - Name of the theme directory, for the asset URLs.
+/// * `assets_directory` - Directory of the theme assets, to version their URLs.
+pub fn register_helpers(registry: &mut Handlebars, theme_name: &str, assets_directory: PathBuf) {
+    registry.register_helper("format_date", Box::new(format_date_helper));
+    registry.register_helper("excerpt", Box::new(excerpt_helper));
+    registry.register_helper("truncate", Box::new(truncate_helper));
+    registry.register_helper("permalink", Box::new(permalink_helper));
+    registry.register_helper("category_list", Box::new(category_list_helper));
+    registry.register_helper("tag_list", Box::new(tag_list_helper));
+    registry.register_helper("pagination_links", Box::new(pagination_links_helper));
+    registry.register_helper("safe_html", Box::new(safe_html_helper));
+    registry.register_helper("menu", Box::new(menu_helper));
+    registry.register_helper(
+        "asset_url",
+        Box::new(AssetUrlHelper {
+            base_url: format!("/themes/{}/assets", theme_name),
+            directory: assets_directory,
+        }),
+    );
+}
+
+/// Escapes a URL for an HTML attribute, keeping its query readable.
+fn escape_url(url: &str) -> String {
+    url.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#x27;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// String value of a parameter, `None` when it is missing or null.
+fn param_str<'a>(h: &'a Helper, index: usize) -> Option<&'a str> {
+    h.param(index).and_then(|param| param.value().as_str())
+}
+
+fn hash_str<'a>(h: &'a Helper, name: &str) -> Option<&'a str> {
+    h.hash_get(name).and_then(|value| value.value().as_str())
+}
+
+fn hash_u64(h: &Helper, name: &str) -> Option<u64> {
+    h.hash_get(name).and_then(|value| value.value().as_u64())
+}
+
+fn locale_names(locale: &str) -> &'static LocaleNames {
+    let language = locale.split(['_', '-']).next().unwrap_or_default();
+
+    match language.to_ascii_lowercase().as_str() {
+        "fr" => &FR,
+        "de" => &DE,
+        "es" => &ES,
+        _ => &EN,
+    }
+}
+
+/// Setting of the site given to every template under `site`, `None` when it is missing or empty.
+fn site_setting<'a>(ctx: &'a Context, path: &str) -> Option<&'a str> {
+    ctx.data()
+        .pointer(&format!("/site/{}", path))
+        .and_then(Value::as_str)
+        .filter(|value| !value.is_empty())
+}
+
+/// Parses the dates of the API, with or without a time.
+fn parse_date(date: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(date, "%Y-%m-%dT%H:%M:%S%.f")
+        .ok()
+        .or_else(|| DateTime::parse_from_rfc3339(date).ok().map(|date| date.naive_utc()))
+        .or_else(|| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+}
+
+/// Formats a date with the month and weekday names of a locale.
+///
+/// `%B`, `%b`, `%A` and `%a` are replaced by the names of the locale, the other specifiers are left to chrono.
+fn format_localized(date: NaiveDateTime, format: &str, names: &LocaleNames) -> String {
+    let month = date.month0() as usize;
+    let weekday = date.weekday().num_days_from_monday() as usize;
+    let mut localized = String::with_capacity(format.len());
+    let mut chars = format.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            localized.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('B') => localized.push_str(names.months[month]),
+            Some('b') => localized.push_str(names.short_months[month]),
+            Some('A') => localized.push_str(names.weekdays[weekday]),
+            Some('a') => localized.push_str(names.short_weekdays[weekday]),
+            Some(specifier) => {
+                localized.push('%');
+                localized.push(specifier);
+            }
+            None => localized.push('%'),
+        }
+    }
+
+    date.format(&localized).to_string()
+}
+
+/// `{{format_date date format="%d/%m/%Y" locale="fr"}}`: a date, in the language and date format of the site
+/// settings by default.
+fn format_date_helper(
+    h: &Helper,
+    _: &Handlebars,
+    ctx: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let Some(date) = param_str(h, 0).and_then(parse_date) else {
+        return Ok(());
+    };
+    let locale = hash_str(h, "locale")
+        .or_else(|| site_setting(ctx, "language"))
+        .unwrap_or(DEFAULT_LOCALE);
+    let names = locale_names(locale);
+    let format = hash_str(h, "format")
+        .or_else(|| site_setting(ctx, "date_format"))
+        .unwrap_or(names.date_format);
+
+    out.write(&html_escape(&format_localized(date, format, names)))?;
+    Ok(())
+}
+
+/// Keeps the first `length` words of a text, followed by an ellipsis when it is cut.
+fn truncate_words(text: &str, length: usize) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+
+    if words.len() <= length {
+        words.join(" ")
+    } else {
+        format!("{}{}", words[..length].join(" "), ELLIPSIS)
+    }
+}
+
+/// The first `length` words of some HTML content, without its tags and HTML escaped.
+pub fn excerpt(content: &str, length: usize) -> String {
+    // Without any allowed tag, ammonia keeps the text, escaped. The tags are
+    // spaced out first so the words of two paragraphs are not joined.
+    let text = ammonia::Builder::empty().clean(&content.replace('<', " <")).to_string();
+
+    truncate_words(&text, length)
+}
+
+/// `{{excerpt post.content length=20}}`: the first words of some HTML content, without its tags.
+fn excerpt_helper(h: &Helper, _: &Handlebars, _: &Context, _: &mut RenderContext, out: &mut dyn Output) -> HelperResult {
+    let content = param_str(h, 0).unwrap_or_default();
+    let length = hash_u64(h, "length").unwrap_or(DEFAULT_EXCERPT_LENGTH) as usize;
+
+    out.write(&excerpt(content, length))?;
+    Ok(())
+}
+
+/// `{{truncate post.title 30}}`: the first characters of a text, followed by an ellipsis when it is cut.
+fn truncate_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let text = param_str(h, 0).unwrap_or_default();
+    let length = h
+        .param(1)
+        .and_then(|param| param.value().as_u64())
+        .ok_or(RenderErrorReason::ParamNotFoundForIndex("truncate", 1))? as usize;
+    let suffix = hash_str(h, "suffix").unwrap_or(ELLIPSIS);
+
+    let truncated = if text.chars().count() > length {
+        format!("{}{}", text.chars().take(length).collect::<String>().trim_end(), suffix)
+    } else {
+        text.to_string()
+    };
+
+    out.write(&html_escape(&truncated))?;
+    Ok(())
+}
+
+/// URL of a content of the site, by its kind, the archives of the terms and authors under the permalink bases
+/// of the site settings.
+fn permalink(ctx: &Context, kind: &str, slug: &str) -> Option<String> {
+    match kind {
+        "post" | "page" => Some(format!("/{}", slug)),
+        "category" | "tag" | "author" => {
+            let base = site_setting(ctx, &format!("permalinks/{}_base", kind)).unwrap_or(kind);
+            Some(format!("/{}/{}", base, slug))
+        }
+        "date" => Some(format!("/archives/{}", slug)),
+        _ => None,
+    }
+}
+
+/// `{{permalink "category" slug}}`: the URL of a post, page, category, tag, author or date archive.
+fn permalink_helper(
+    h: &Helper,
+    _: &Handlebars,
+    ctx: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let kind = param_str(h, 0).ok_or(RenderErrorReason::ParamNotFoundForIndex("permalink", 0))?;
+    let slug = match h.param(1).map(|param| param.value()) {
+        Some(Value::String(slug)) => slug.clone(),
+        Some(Value::Number(number)) => number.to_string(),
+        _ => return Err(RenderErrorReason::ParamNotFoundForIndex("permalink", 1).into()),
+    };
+    let url = permalink(ctx, kind, &slug)
+        .ok_or_else(|| RenderErrorReason::Other(format!("Unknown permalink kind '{}'", kind)))?;
+
+    out.write(&escape_url(&url))?;
+    Ok(())
+}
+
+/// Links to the archives of some terms, joined by `separator`.
+fn term_links(ctx: &Context, terms: Option<&Value>, kind: &str, separator: &str) -> String {
+    terms
+        .and_then(Value::as_array)
+        .map(|terms| {
+            terms
+                .iter()
+                .filter_map(|term| {
+                    let name = term.get("name")?.as_str()?;
+                    let slug = term.get("slug")?.as_str()?;
+                    Some(format!(
+                        "<a href=\"{}\" rel=\"{}\">{}</a>",
+                        escape_url(&permalink(ctx, kind, slug)?),
+                        if kind == "category" { "category tag" } else { "tag" },
+                        html_escape(name)
+                    ))
+                })
+                .collect::<Vec<String>>()
+                .join(separator)
+        })
+        .unwrap_or_default()
+}
+
+/// `{{category_list post.categories separator=" | "}}`: links to the categories of a post.
+fn category_list_helper(
+    h: &Helper,
+    _: &Handlebars,
+    ctx: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let separator = hash_str(h, "separator").unwrap_or(", ");
+
+    out.write(&term_links(ctx, h.param(0).map(|param| param.value()), "category", separator))?;
+    Ok(())
+}
+
+/// `{{tag_list post.tags separator=" | "}}`: links to the tags of a post.
+fn tag_list_helper(
+    h: &Helper,
+    _: &Handlebars,
+    ctx: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let separator = hash_str(h, "separator").unwrap_or(", ");
+
+    out.write(&term_links(ctx, h.param(0).map(|param| param.value()), "tag", separator))?;
+    Ok(())
+}
+
+/// `{{pagination_links pagination}}`: links to the pages of a list of posts, nothing when it has a single page.
+fn pagination_links_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let Some(pagination) = h.param(0).map(|param| param.value()) else {
+        return Ok(());
+    };
+    let current_page = pagination.get("current_page").and_then(Value::as_u64).unwrap_or(1) as u32;
+    let total_pages = pagination.get("total_pages").and_then(Value::as_u64).unwrap_or(1) as u32;
+    let base_url = pagination.get("base_url").and_then(Value::as_str).unwrap_or("/");
+
+    if total_pages <= 1 {
+        return Ok(());
+    }
+
+    let link = |page: u32, label: &str| {
+        format!(
+            "<li class=\"page-item\"><a class=\"page-link\" href=\"{}\">{}</a></li>",
+            escape_url(&page_url(base_url, page)),
+            label
+        )
+    };
+
+    let mut links = Vec::new();
+    if current_page > 1 {
+        links.push(link(current_page - 1, "&laquo;"));
+    }
+    for page in 1..=total_pages {
+        if page == current_page {
+            links.push(format!(
+                "<li class=\"page-item active\" aria-current=\"page\"><span class=\"page-link\">{}</span></li>",
+                page
+            ));
+        } else {
+            links.push(link(page, &page.to_string()));
+        }
+    }
+    if current_page < total_pages {
+        links.push(link(current_page + 1, "&raquo;"));
+    }
+
+    out.write(&format!(
+        "<nav aria-label=\"Pagination\"><ul class=\"pagination\">{}</ul></nav>",
+        links.concat()
+    ))?;
+    Ok(())
+}
+
+/// `{{safe_html post.content}}`: the HTML of a content, without scripts, event handlers or unsafe URLs.
+fn safe_html_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let content = param_str(h, 0).unwrap_or_default();
+
+    out.write(&ammonia::clean(content))?;
+    Ok(())
+}
+
+/// Nested list of the items of a menu, each item linking to its content by its permalink or to its URL.
+fn menu_items_html(ctx: &Context, items: &[Value], class: Option<&str>) -> String {
+    let items: String = items
+        .iter()
+        .filter_map(|item| {
+            let title = item.get("title")?.as_str()?;
+            let url = match item.get("item_type")?.as_str()? {
+                "Custom" => item.get("url")?.as_str()?.to_string(),
+                kind => permalink(ctx, &kind.to_ascii_lowercase(), item.get("slug")?.as_str()?)?,
+            };
+            let children = item
+                .get("children")
+                .and_then(Value::as_array)
+                .filter(|children| !children.is_empty())
+                .map(|children| menu_items_html(ctx, children, Some("sub-menu")))
+                .unwrap_or_default();
+
+            Some(format!(
+                "<li class=\"menu-item{}\"><a href=\"{}\">{}</a>{}</li>",
+                if children.is_empty() { "" } else { " menu-item-has-children" },
+                escape_url(&url),
+                html_escape(title),
+                children
+            ))
+        })
+        .collect();
+
+    match class {
+        Some(class) => format!("<ul class=\"{}\">{}</ul>", html_escape(class), items),
+        None => format!("<ul>{}</ul>", items),
+    }
+}
+
+/// `{{menu "primary" class="nav"}}`: the menu of a location of the theme, as nested lists, nothing when the
+/// location has no menu.
+fn menu_helper(h: &Helper, _: &Handlebars, ctx: &Context, _: &mut RenderContext, out: &mut dyn Output) -> HelperResult {
+    let location = param_str(h, 0).ok_or(RenderErrorReason::ParamNotFoundForIndex("menu", 0))?;
+    let Some(items) = ctx
+        .data()
+        .get("menus")
+        .and_then(|menus| menus.get(location))
+        .and_then(|menu| menu.get("items"))
+        .and_then(Value::as_array)
+        .filter(|items| !items.is_empty())
+    else {
+        return Ok(());
+    };
+
+    out.write(&menu_items_html(ctx, items, Some(hash_str(h, "class").unwrap_or("menu"))))?;
+    Ok(())
+}
+
+/// `{{asset_url "css/style.css"}}`: the URL of an asset of the theme, versioned by its modification time so
+/// browsers load it again when it changes.
+struct AssetUrlHelper {
+    base_url: String,
+    directory: PathBuf,
+}
+
+impl HelperDef for AssetUrlHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let path = param_str(h, 0).ok_or(RenderErrorReason::ParamNotFoundForIndex("asset_url", 0))?;
+        let path = path.trim_start_matches('/');
+        let version = fs::metadata(self.directory.join(path))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|modified| modified.as_secs());
+
+        let url = match version {
+            Some(version) => format!("{}/{}?v={}", self.base_url, path, version),
+            None => format!("{}/{}", self.base_url, path),
+        };
+
+        out.write(&escape_url(&url))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn render(template: &str, data: &Value) -> String {
+        let mut registry = Handlebars::new();
+        register_helpers(&mut registry, "Test", std::env::temp_dir().join("rustpress-theme-helpers"));
+
+        registry.render_template(template, data).expect("Failed to render the template")
+    }
+
+    #[test]
+    fn test_format_date_default_locale() {
+        let data = json!({ "date": "2024-03-05T10:30:00" });
+
+        assert_eq!(render("{{format_date date}}", &data), "March 5, 2024");
+    }
+
+    #[test]
+    fn test_format_date_with_locale_and_format() {
+        let data = json!({ "date": "2024-03-05T10:30:00" });
+
+        assert_eq!(render("{{format_date date locale=\"fr_FR\"}}", &data), "5 mars 2024");
+        assert_eq!(
+            render("{{format_date date format=\"%A %d %b %Y, %H:%M\" locale=\"de\"}}", &data),
+            "Dienstag 05 März 2024, 10:30"
+        );
+    }
+
+    #[test]
+    fn test_format_date_site_settings() {
+        let data = json!({
+            "date": "2024-03-05T10:30:00",
+            "site": { "language": "es", "date_format": "" },
+        });
+
+        assert_eq!(render("{{format_date date}}", &data), "5 de marzo de 2024");
+
+        let data = json!({
+            "posts": [{ "date_published": "2024-03-05T10:30:00" }],
+            "site": { "language": "fr", "date_format": "%a %d/%m/%Y" },
+        });
+
+        assert_eq!(
+            render("{{#each posts}}{{format_date date_published}}{{/each}}", &data),
+            "mar. 05/03/2024"
+        );
+    }
+
+    #[test]
+    fn test_format_date_missing_date() {
+        let data = json!({ "date": null });
+
+        assert_eq!(render("[{{format_date date}}]", &data), "[]");
+    }
+
+    #[test]
+    fn test_excerpt_strips_tags_and_cuts_words() {
+        let data = json!({ "content": "<p>One <b>two</b> three</p><script>alert(1)</script><p>four &amp; five</p>" });
+
+        assert_eq!(render("{{excerpt content length=3}}", &data), "One two three…");
+        assert_eq!(render("{{excerpt content}}", &data), "One two three four &amp; five");
+    }
+
+    #[test]
+    fn test_truncate() {
+        let data = json!({ "title": "Hello <world> of Rust" });
+
+        assert_eq!(render("{{truncate title 13}}", &data), "Hello &lt;world&gt;…");
+        assert_eq!(render("{{truncate title 50}}", &data), "Hello &lt;world&gt; of Rust");
+        assert_eq!(render("{{truncate title 5 suffix=\"...\"}}", &data), "Hello...");
+    }
+
+    #[test]
+    fn test_permalink() {
+        let data = json!({ "slug": "hello-world", "year": 2024 });
+
+        assert_eq!(render("{{permalink \"post\" slug}}", &data), "/hello-world");
+        assert_eq!(render("{{permalink \"category\" slug}}", &data), "/category/hello-world");
+        assert_eq!(render("{{permalink \"author\" \"admin\"}}", &data), "/author/admin");
+        assert_eq!(render("{{permalink \"date\" year}}", &data), "/archives/2024");
+    }
+
+    #[test]
+    fn test_permalink_site_bases() {
+        let data = json!({
+            "site": { "permalinks": { "category_base": "topic", "tag_base": "", "author_base": "writer" } },
+            "tags": [{ "name": "Leptos", "slug": "leptos" }],
+        });
+
+        assert_eq!(render("{{permalink \"category\" \"rust\"}}", &data), "/topic/rust");
+        assert_eq!(render("{{permalink \"author\" \"admin\"}}", &data), "/writer/admin");
+        assert_eq!(
+            render("{{tag_list tags}}", &data),
+            "<a href=\"/tag/leptos\" rel=\"tag\">Leptos</a>"
+        );
+    }
+
+    #[test]
+    fn test_permalink_unknown_kind() {
+        let mut registry = Handlebars::new();
+        register_helpers(&mut registry, "Test", PathBuf::new());
+
+        let result = registry.render_template("{{permalink \"comment\" slug}}", &json!({ "slug": "a" }));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_category_and_tag_lists() {
+        let data = json!({
+            "categories": [{ "name": "News & Events", "slug": "news" }, { "name": "Rust", "slug": "rust" }],
+            "tags": [{ "name": "Leptos", "slug": "leptos" }],
+        });
+
+        assert_eq!(
+            render("{{category_list categories}}", &data),
+            "<a href=\"/category/news\" rel=\"category tag\">News &amp; Events</a>, \
+            <a href=\"/category/rust\" rel=\"category tag\">Rust</a>"
+        );
+        assert_eq!(
+            render("{{tag_list tags separator=\" | \"}}", &data),
+            "<a href=\"/tag/leptos\" rel=\"tag\">Leptos</a>"
+        );
+        assert_eq!(render("{{tag_list missing}}", &data), "");
+    }
+
+    #[test]
+    fn test_pagination_links() {
+        let data = json!({ "pagination": { "current_page": 2, "total_pages": 3, "base_url": "/tag/rust" } });
+
+        let html = render("{{pagination_links pagination}}", &data);
+
+        assert!(html.contains("<a class=\"page-link\" href=\"/tag/rust\">&laquo;</a>"));
+        assert!(html.contains("<a class=\"page-link\" href=\"/tag/rust\">1</a>"));
+        assert!(html.contains("<span class=\"page-link\">2</span>"));
+        assert!(html.contains("<a class=\"page-link\" href=\"/tag/rust?page=3\">&raquo;</a>"));
+    }
+
+    #[test]
+    fn test_pagination_links_single_page() {
+        let data = json!({ "pagination": { "current_page": 1, "total_pages": 1, "base_url": "/" } });
+
+        assert_eq!(render("{{pagination_links pagination}}", &data), "");
+    }
+
+    #[test]
+    fn test_safe_html() {
+        let data = json!({
+            "content": "<p onclick=\"steal()\">Hello <a href=\"javascript:alert(1)\">link</a></p><script>alert(1)</script>"
+        });
+
+        let html = render("{{safe_html content}}", &data);
+
+        assert!(html.starts_with("<p>Hello <a"));
+        assert!(!html.contains("onclick"));
+        assert!(!html.contains("javascript"));
+        assert!(!html.contains("<script>"));
+    }
+
+    #[test]
+    fn test_menu() {
+        let data = json!({
+            "site": { "permalinks": { "category_base": "topic" } },
+            "menus": {
+                "primary": {
+                    "name": "Main",
+                    "slug": "main",
+                    "items": [
+                        { "title": "About", "item_type": "Page", "slug": "about", "url": null, "children": [
+                            { "title": "Rust & Leptos", "item_type": "Category", "slug": "rust", "url": null, "children": [] },
+                        ] },
+                        { "title": "Source", "item_type": "Custom", "slug": null, "url": "https://example.com/?a=1&b=2", "children": [] },
+                    ],
+                },
+            },
+        });
+
+        assert_eq!(
+            render("{{menu \"primary\" class=\"nav\"}}", &data),
+            "<ul class=\"nav\"><li class=\"menu-item menu-item-has-children\"><a href=\"/about\">About</a>\
+            <ul class=\"sub-menu\"><li class=\"menu-item\"><a href=\"/topic/rust\">Rust &amp; Leptos</a></li></ul></li>\
+            <li class=\"menu-item\"><a href=\"https://example.com/?a=1&amp;b=2\">Source</a></li></ul>"
+        );
+    }
+
+    #[test]
+    fn test_menu_missing_location() {
+        let data = json!({ "menus": {} });
+
+        assert_eq!(render("{{menu \"footer\"}}", &data), "");
+    }
+
+    #[test]
+    fn test_asset_url() {
+        let directory = std::env::temp_dir().join("rustpress-theme-helpers");
+        fs::create_dir_all(&directory).expect("Failed to create the assets directory");
+        fs::write(directory.join("style.css"), "body {}").expect("Failed to write the asset");
+
+        let data = json!({});
+
+        assert!(render("{{asset_url \"style.css\"}}", &data).starts_with("/themes/Test/assets/style.css?v="));
+        assert_eq!(render("{{asset_url \"missing.js\"}}", &data), "/themes/Test/assets/missing.js");
+    }
+}
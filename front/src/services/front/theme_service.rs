@@ -0,0 +1,273 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+
+use handlebars::Handlebars;
+use leptos::{logging, server, ServerFnError};
+use serde_json::{json, Value};
+
+use crate::{
+    models::front::{
+        public_model::{PublicMenu, PublicSettings},
+        theme_model::{ThemeInfo, ThemeManifest, ThemePage},
+    },
+    services::front::{api::public_api::get_active_theme, theme_helpers::register_helpers},
+};
+
+/// Theme used when the active theme cannot be loaded.
+pub const DEFAULT_THEME: &str = "Default";
+
+/// Templates every theme must provide.
+const REQUIRED_TEMPLATES: &[&str] = &["index"];
+
+const MANIFEST_FILE: &str = "theme.toml";
+const PARTIALS_DIR: &str = "partials";
+const ASSETS_DIR: &str = "assets";
+const TEMPLATE_EXTENSION: &str = "hbs";
+
+/// The last theme loaded, reused while it stays active.
+static LOADED_THEME: Mutex<Option<LoadedTheme>> = Mutex::new(None);
+
+struct LoadedTheme {
+    directory: String,
+    fingerprint: Option<SystemTime>,
+    theme: Arc<Theme>,
+}
+
+/// A theme: its manifest, and its templates and partials registered with Handlebars.
+pub struct Theme {
+    pub manifest: ThemeManifest,
+    registry: Handlebars<'static>,
+}
+
+impl Theme {
+    /// Loads the theme of a directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The theme directory, holding `theme.toml`, the templates, and the `partials` and `assets`
+    ///   directories.
+    ///
+    /// # Returns
+    ///
+    /// The theme, or every problem preventing to load it: an invalid manifest, a template which does not
+    /// compile, a required template or a template of the manifest which is missing.
+    pub fn load(directory: &Path) -> Result<Self, Vec<String>> {
+        let mut problems = Vec::new();
+        let manifest = read_manifest(directory).map_err(|e| vec![e])?;
+        let mut registry = Handlebars::new();
+        let name = directory.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+        register_helpers(&mut registry, name, directory.join(ASSETS_DIR));
+
+        for (name, path) in template_files(&directory.join(PARTIALS_DIR)) {
+            let registered = fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|source| registry.register_partial(&name, source).map_err(|e| e.to_string()));
+            if let Err(e) = registered {
+                problems.push(format!("Partial '{}': {}", name, e));
+            }
+        }
+
+        let templates = template_files(directory);
+        for (name, path) in &templates {
+            if let Err(e) = registry.register_template_file(name, path) {
+                problems.push(format!("Template '{}': {}", name, e));
+            }
+        }
+
+        let expected = REQUIRED_TEMPLATES
+            .iter()
+            .map(|name| name.to_string())
+            .chain(manifest.templates.iter().cloned());
+        for name in expected {
+            if !templates.iter().any(|(template, _)| *template == name) {
+                problems.push(format!("Missing template '{}'", name));
+            }
+        }
+
+        if !problems.is_empty() {
+            return Err(problems);
+        }
+
+        Ok(Theme { manifest, registry })
+    }
+
+    /// First template of the hierarchy of `page` provided by the theme.
+    pub fn resolve(&self, page: &ThemePage) -> Option<String> {
+        page.context
+            .hierarchy()
+            .into_iter()
+            .find(|name| self.registry.has_template(name))
+    }
+
+    /// Renders a page, with the settings of the site shared by every template under `site`, and the menus of
+    /// the theme locations under `menus`.
+    pub fn render(
+        &self,
+        page: &ThemePage,
+        settings: &PublicSettings,
+        menus: &BTreeMap<String, PublicMenu>,
+    ) -> Result<String, String> {
+        let template = self
+            .resolve(page)
+            .ok_or_else(|| format!("No template found for {:?}", page.context))?;
+
+        let mut data = page.data.clone();
+        if let Value::Object(data) = &mut data {
+            data.insert("site".to_string(), json!(settings));
+            data.insert("menus".to_string(), json!(menus));
+            data.insert("title".to_string(), Value::String(page.title.clone()));
+            data.insert("body_class".to_string(), Value::String(page.context.body_class().to_string()));
+        }
+
+        self.registry.render(&template, &data).map_err(|e| e.to_string())
+    }
+}
+
+/// Directory holding the themes, `THEMES_DIR` or `themes`.
+pub fn themes_dir() -> PathBuf {
+    std::env::var("THEMES_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("themes"))
+}
+
+/// Whether the templates are reloaded when they change, in development only.
+fn hot_reload() -> bool {
+    std::env::var("LEPTOS_ENV").is_ok_and(|env| env.eq_ignore_ascii_case("DEV"))
+}
+
+fn read_manifest(directory: &Path) -> Result<ThemeManifest, String> {
+    let source = fs::read_to_string(directory.join(MANIFEST_FILE)).map_err(|e| format!("{}: {}", MANIFEST_FILE, e))?;
+
+    toml::from_str(&source).map_err(|e| format!("{}: {}", MANIFEST_FILE, e))
+}
+
+/// Templates of a directory, by name, without its subdirectories.
+fn template_files(directory: &Path) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return Vec::new();
+    };
+
+    let mut templates: Vec<(String, PathBuf)> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|extension| extension == TEMPLATE_EXTENSION))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_string();
+            Some((name, path))
+        })
+        .collect();
+    templates.sort();
+
+    templates
+}
+
+/// Latest modification of the files of a theme, to reload it when it changes.
+fn fingerprint(directory: &Path) -> Option<SystemTime> {
+    let manifest = directory.join(MANIFEST_FILE);
+
+    template_files(directory)
+        .into_iter()
+        .chain(template_files(&directory.join(PARTIALS_DIR)))
+        .map(|(_, path)| path)
+        .chain([manifest])
+        .filter_map(|path| fs::metadata(path).and_then(|metadata| metadata.modified()).ok())
+        .max()
+}
+
+/// Returns the theme of a directory, loading it unless it is the last theme loaded.
+fn load_theme(directory: &str) -> Result<Arc<Theme>, String> {
+    let path = themes_dir().join(directory);
+    let fingerprint = if hot_reload() { fingerprint(&path) } else { None };
+    let mut loaded = LOADED_THEME.lock().map_err(|e| e.to_string())?;
+
+    if let Some(loaded) = loaded.as_ref() {
+        if loaded.directory == directory && loaded.fingerprint == fingerprint {
+            return Ok(loaded.theme.clone());
+        }
+    }
+
+    let theme = Arc::new(Theme::load(&path).map_err(|problems| problems.join(", "))?);
+    *loaded = Some(LoadedTheme {
+        directory: directory.to_string(),
+        fingerprint,
+        theme: theme.clone(),
+    });
+
+    Ok(theme)
+}
+
+/// The theme rendering the public site, the default theme if it cannot be loaded.
+pub async fn active_theme() -> Result<Arc<Theme>, String> {
+    let directory = get_active_theme()
+        .await
+        .unwrap_or_else(|e| {
+            logging::warn!("Failed to get the active theme: {}", e);
+            DEFAULT_THEME.to_string()
+        });
+
+    load_theme(&directory).or_else(|e| {
+        logging::warn!("Failed to load the theme '{}': {}", directory, e);
+        load_theme(DEFAULT_THEME)
+    })
+}
+
+/// Lists the themes of the themes directory, with the problems of the invalid ones.
+///
+/// # Arguments
+///
+/// * `active` - Directory of the active theme.
+pub fn discover_themes(active: &str) -> Vec<ThemeInfo> {
+    let Ok(entries) = fs::read_dir(themes_dir()) else {
+        return Vec::new();
+    };
+
+    let mut directories: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_dir())
+        .collect();
+    directories.sort();
+
+    directories
+        .into_iter()
+        .filter_map(|path| {
+            let directory = path.file_name()?.to_str()?.to_string();
+            let (manifest, problems) = match Theme::load(&path) {
+                Ok(theme) => (Some(theme.manifest), Vec::new()),
+                Err(problems) => (read_manifest(&path).ok(), problems),
+            };
+
+            Some(ThemeInfo {
+                active: directory == active,
+                directory,
+                manifest,
+                problems,
+            })
+        })
+        .collect()
+}
+
+/// Renders a page of the public site through the active theme.
+#[server(RenderThemePage, "/api")]
+pub async fn render_theme_page(page: ThemePage) -> Result<String, ServerFnError> {
+    use crate::services::front::{menus_service::site_menus, settings_service::site_settings};
+
+    let theme = active_theme().await.map_err(ServerFnError::new)?;
+    let settings = site_settings().await;
+    let menus = site_menus().await;
+
+    theme.render(&page, &settings, &menus).map_err(ServerFnError::new)
+}
+
+/// Lists the installed themes, for the theme switcher.
+#[server(GetThemes, "/api")]
+pub async fn get_themes() -> Result<Vec<ThemeInfo>, ServerFnError> {
+    let active = get_active_theme()
+        .await
+        .map_err(ServerFnError::new)?;
+
+    Ok(discover_themes(&active))
+}
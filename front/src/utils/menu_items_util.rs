@@ -0,0 +1,211 @@
+use crate::models::admin::menus_model::{MenuEditorItem, MenuItem, MenuItemInput};
+
+/// Lists the items of a menu tree for the editor, every item after its parent and indented under it.
+pub fn flatten_menu_items(items: &[MenuItem]) -> Vec<MenuEditorItem> {
+    fn flatten(items: &[MenuItem], depth: usize, editor_items: &mut Vec<MenuEditorItem>) {
+        for item in items {
+            editor_items.push(MenuEditorItem {
+                depth,
+                item_type: item.item_type,
+                object_id: item.object_id,
+                url: item.url.clone(),
+                title: item.title.clone(),
+                object_title: item.object_title.clone(),
+            });
+            flatten(&item.children, depth + 1, editor_items);
+        }
+    }
+
+    let mut editor_items = Vec::new();
+    flatten(items, 0, &mut editor_items);
+    editor_items
+}
+
+/// Builds the tree of items to save from the items of the editor.
+pub fn menu_items_tree(items: &[MenuEditorItem]) -> Vec<MenuItemInput> {
+    fn children(items: &[MenuEditorItem], index: &mut usize, depth: usize) -> Vec<MenuItemInput> {
+        let mut tree = Vec::new();
+        while let Some(item) = items.get(*index).filter(|item| item.depth >= depth) {
+            *index += 1;
+            tree.push(MenuItemInput {
+                item_type: item.item_type,
+                object_id: item.object_id,
+                url: item.url.clone(),
+                title: item.title.clone(),
+                children: children(items, index, item.depth + 1),
+            });
+        }
+        tree
+    }
+
+    children(items, &mut 0, 0)
+}
+
+/// Index after the last sub-item of an item.
+fn subtree_end(items: &[MenuEditorItem], index: usize) -> usize {
+    let depth = items[index].depth;
+
+    items[index + 1..]
+        .iter()
+        .position(|item| item.depth <= depth)
+        .map_or(items.len(), |position| index + 1 + position)
+}
+
+/// Moves an item, with its sub-items, before the item at `target`, or at the end of the menu when `target` is
+/// the number of items. The item takes the depth of the item it is dropped before.
+pub fn move_menu_item(items: &mut Vec<MenuEditorItem>, index: usize, target: usize) {
+    let end = subtree_end(items, index);
+    if target >= index && target <= end {
+        return;
+    }
+
+    let depth = items.get(target).map_or(0, |item| item.depth);
+    let mut moved: Vec<MenuEditorItem> = items.drain(index..end).collect();
+    let root_depth = moved[0].depth;
+    for item in &mut moved {
+        item.depth = item.depth - root_depth + depth;
+    }
+
+    let target = if target > index { target - moved.len() } else { target };
+    items.splice(target..target, moved);
+}
+
+/// Makes an item, with its sub-items, a sub-item of the item above it.
+pub fn indent_menu_item(items: &mut [MenuEditorItem], index: usize) {
+    if index == 0 || items[index - 1].depth < items[index].depth {
+        return;
+    }
+
+    let end = subtree_end(items, index);
+    for item in &mut items[index..end] {
+        item.depth += 1;
+    }
+}
+
+/// Moves an item, with its sub-items, one level up.
+pub fn outdent_menu_item(items: &mut [MenuEditorItem], index: usize) {
+    if items[index].depth == 0 {
+        return;
+    }
+
+    let end = subtree_end(items, index);
+    for item in &mut items[index..end] {
+        item.depth -= 1;
+    }
+}
+
+/// Removes an item with its sub-items.
+pub fn remove_menu_item(items: &mut Vec<MenuEditorItem>, index: usize) {
+    let end = subtree_end(items, index);
+    items.drain(index..end);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::admin::menus_model::MenuItemTypeEnum;
+
+    fn item(key: u32, depth: usize) -> MenuEditorItem {
+        MenuEditorItem {
+            depth,
+            item_type: MenuItemTypeEnum::Custom,
+            object_id: None,
+            url: Some(format!("/{}", key)),
+            title: Some(key.to_string()),
+            object_title: None,
+        }
+    }
+
+    fn layout(items: &[MenuEditorItem]) -> Vec<(String, usize)> {
+        items
+            .iter()
+            .map(|item| (item.title.clone().unwrap_or_default(), item.depth))
+            .collect()
+    }
+
+    #[test]
+    fn test_menu_items_tree() {
+        let items = vec![item(1, 0), item(2, 1), item(3, 2), item(4, 1), item(5, 0)];
+
+        let tree = menu_items_tree(&items);
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[0].children.len(), 2);
+        assert_eq!(tree[0].children[0].children[0].url.as_deref(), Some("/3"));
+        assert_eq!(tree[1].url.as_deref(), Some("/5"));
+    }
+
+    #[test]
+    fn test_flatten_menu_items_round_trip() {
+        let items = vec![item(1, 0), item(2, 1), item(3, 2), item(4, 0)];
+        let menu_items: Vec<MenuItem> = menu_items_tree(&items)
+            .into_iter()
+            .map(|input| {
+                fn to_item(input: MenuItemInput) -> MenuItem {
+                    MenuItem {
+                        id: 0,
+                        item_type: input.item_type,
+                        object_id: input.object_id,
+                        url: input.url,
+                        title: input.title,
+                        object_title: None,
+                        children: input.children.into_iter().map(to_item).collect(),
+                    }
+                }
+                to_item(input)
+            })
+            .collect();
+
+        assert_eq!(flatten_menu_items(&menu_items), items);
+    }
+
+    #[test]
+    fn test_move_menu_item_with_sub_items() {
+        let mut items = vec![item(1, 0), item(2, 1), item(3, 0), item(4, 1)];
+
+        move_menu_item(&mut items, 0, 4);
+        assert_eq!(
+            layout(&items),
+            vec![("3".into(), 0), ("4".into(), 1), ("1".into(), 0), ("2".into(), 1)]
+        );
+
+        move_menu_item(&mut items, 2, 1);
+        assert_eq!(
+            layout(&items),
+            vec![("3".into(), 0), ("1".into(), 1), ("2".into(), 2), ("4".into(), 1)]
+        );
+
+        // Dropping an item on its own sub-items does nothing
+        move_menu_item(&mut items, 1, 2);
+        assert_eq!(
+            layout(&items),
+            vec![("3".into(), 0), ("1".into(), 1), ("2".into(), 2), ("4".into(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_indent_and_outdent_menu_item() {
+        let mut items = vec![item(1, 0), item(2, 0), item(3, 1)];
+
+        indent_menu_item(&mut items, 0);
+        indent_menu_item(&mut items, 1);
+        assert_eq!(layout(&items), vec![("1".into(), 0), ("2".into(), 1), ("3".into(), 2)]);
+
+        // An item cannot be nested deeper than under the item above it
+        indent_menu_item(&mut items, 1);
+        assert_eq!(layout(&items), vec![("1".into(), 0), ("2".into(), 1), ("3".into(), 2)]);
+
+        outdent_menu_item(&mut items, 1);
+        outdent_menu_item(&mut items, 0);
+        assert_eq!(layout(&items), vec![("1".into(), 0), ("2".into(), 0), ("3".into(), 1)]);
+    }
+
+    #[test]
+    fn test_remove_menu_item() {
+        let mut items = vec![item(1, 0), item(2, 1), item(3, 2), item(4, 0)];
+
+        remove_menu_item(&mut items, 1);
+
+        assert_eq!(layout(&items), vec![("1".into(), 0), ("4".into(), 0)]);
+    }
+}
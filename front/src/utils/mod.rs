@@ -1 +1,3 @@
 pub mod add_class_util;
+pub mod menu_items_util;
+pub mod theme_data_util;
@@ -0,0 +1,48 @@
+use leptos_router::ParamsMap;
+use serde_json::{json, Value};
+
+use crate::models::front::public_model::PaginatedPublicPosts;
+
+/// Builds the template data of a list of posts, with the links to the neighbouring pages.
+///
+/// # Arguments
+///
+/// * `posts` - The page of posts to display.
+/// * `base_url` - The URL of the route listing the posts, without the page.
+///
+/// # Returns
+///
+/// The `posts` and `pagination` entries of the template data.
+pub fn posts_list_data(posts: &PaginatedPublicPosts, base_url: &str) -> Value {
+    let previous_url = (posts.current_page > 1).then(|| page_url(base_url, posts.current_page - 1));
+    let next_url = (posts.current_page < posts.total_pages).then(|| page_url(base_url, posts.current_page + 1));
+
+    json!({
+        "posts": posts.data,
+        "pagination": {
+            "current_page": posts.current_page,
+            "total_pages": posts.total_pages,
+            "base_url": base_url,
+            "previous_url": previous_url,
+            "next_url": next_url,
+        },
+    })
+}
+
+/// Page number of a list of posts, from the `page` query parameter.
+pub fn page_param(query: &ParamsMap) -> u32 {
+    query
+        .get("page")
+        .and_then(|page| page.parse().ok())
+        .filter(|page| *page > 0)
+        .unwrap_or(1)
+}
+
+/// URL of a page of a list of posts, the first page being the list itself.
+pub fn page_url(base_url: &str, page: u32) -> String {
+    if page <= 1 {
+        base_url.to_string()
+    } else {
+        format!("{}?page={}", base_url, page)
+    }
+}
@@ -0,0 +1,376 @@
+use std::{collections::HashSet, sync::Arc};
+
+use leptos::*;
+
+use crate::{
+    components::{
+        admin::{
+            data_table::{
+                data_table_component::{DataTable, TableColumn},
+                sort::SortOrder,
+            },
+            header_content_component::HeaderContent,
+            modal_component::*,
+        },
+        front::loading_component::LoadingComponent,
+    },
+    models::admin::comments_model::{
+        CommentFilters, CommentStatusEnum, CommentStruct, CommentsIds, CommentsStatusUpdate,
+    },
+    services::admin::api::comments_api::{delete_comments, get_comments, update_comments_status},
+    utils::add_class_util::add_class,
+};
+use strum::IntoEnumIterator;
+
+#[component]
+pub fn AdminCommentsView() -> impl IntoView {
+    add_class("body", "comments");
+
+    let current_page = create_rw_signal(1);
+    let items_per_page = create_rw_signal(20);
+    let sort_column: RwSignal<Option<usize>> = create_rw_signal(None);
+    let sort_order = create_rw_signal(SortOrder::Descending);
+    let (total_items_signal, set_total_items_signal) = create_signal(0);
+    // The moderation queue is shown first
+    let filters = create_rw_signal(CommentFilters {
+        status: Some(CommentStatusEnum::Pending),
+        ..Default::default()
+    });
+    let show_filters = create_rw_signal(true);
+
+    let on_page_change = move |new_page: u32| {
+        current_page.set(new_page);
+    };
+
+    let on_sort_change = move |column: Option<usize>, order: SortOrder| {
+        sort_column.set(column);
+        sort_order.set(order);
+    };
+
+    let comments = create_resource(
+        move || {
+            let sort_column_string = match sort_column.get() {
+                Some(0) => "author_name".to_string(),
+                Some(2) => "post_id".to_string(),
+                Some(3) => "status".to_string(),
+                Some(4) => "date_created".to_string(),
+                _ => "id".to_string(),
+            };
+
+            let sort_order_string = match sort_order.get() {
+                SortOrder::Ascending => "asc".to_string(),
+                SortOrder::Descending => "desc".to_string(),
+            };
+
+            (
+                current_page.get(),
+                items_per_page.get(),
+                sort_column_string,
+                sort_order_string,
+                filters.get(),
+            )
+        },
+        move |(page, limit, sort_column, sort_order, filters)| async move {
+            get_comments(page, limit, sort_column, sort_order, filters).await
+        },
+    );
+
+    let filters_view = move || {
+        view! {
+            <div class="row g-2">
+                <div class="col-md-4">
+                    <label class="form-label" for="filter-status">
+                        "Status"
+                    </label>
+                    <select
+                        id="filter-status"
+                        class="form-select"
+                        prop:value=move || filters.with(|filters| filters.status.as_ref().map(|status| status.to_string()).unwrap_or_default())
+                        on:change=move |ev| {
+                            let value = event_target_value(&ev);
+                            let status = CommentStatusEnum::iter().find(|status| status.to_string() == value);
+                            current_page.set(1);
+                            filters.update(|filters| filters.status = status);
+                        }
+                    >
+                        <option value="">"All"</option>
+                        {CommentStatusEnum::iter()
+                            .map(|status| view! { <option value=status.to_string()>{status.to_string()}</option> })
+                            .collect_view()}
+                    </select>
+                </div>
+                <div class="col-md-4">
+                    <label class="form-label" for="filter-post-id">
+                        "Post ID"
+                    </label>
+                    <input
+                        id="filter-post-id"
+                        class="form-control"
+                        type="number"
+                        min="1"
+                        prop:value=move || filters.with(|filters| filters.post_id.map(|id| id.to_string()).unwrap_or_default())
+                        on:change=move |ev| {
+                            let post_id = event_target_value(&ev).parse::<u32>().ok();
+                            current_page.set(1);
+                            filters.update(|filters| filters.post_id = post_id);
+                        }
+                    />
+                </div>
+            </div>
+        }
+    };
+
+    let (loaded_comments, set_loaded_comments) = create_signal(Vec::<CommentStruct>::new());
+    let selected_comments = create_rw_signal(HashSet::<u32>::new());
+    let is_modal_open = create_rw_signal(false);
+
+    let moderate = move |status: CommentStatusEnum| {
+        let update = CommentsStatusUpdate {
+            ids: selected_comments.get_untracked(),
+            status,
+        };
+        spawn_local(async move {
+            match update_comments_status(update).await {
+                Ok(_) => {
+                    selected_comments.set(HashSet::new());
+                    comments.refetch();
+                }
+                Err(err) => log::error!("Error moderating comments: {}", err),
+            }
+        });
+    };
+
+    let confirm_delete = move || {
+        let comments_ids = CommentsIds {
+            ids: selected_comments.get_untracked(),
+        };
+        spawn_local(async move {
+            match delete_comments(comments_ids).await {
+                Ok(deleted_ids) => {
+                    set_loaded_comments.update(|comments| {
+                        comments.retain(|comment| !deleted_ids.contains(&comment.id));
+                    });
+                    selected_comments.set(HashSet::new());
+                }
+                Err(err) => log::error!("Error deleting comments: {}", err),
+            }
+        });
+    };
+
+    view! {
+        <HeaderContent title="Comments"/>
+
+        <nav class="toolbar navbar sticky-top bg-body-tertiary border-bottom mb-3">
+            <div class="container-fluid">
+                <div class="d-flex justify-content-between align-items-center w-100 my-2">
+                    <div class="dropdown">
+                        <button
+                            class="btn btn-secondary dropdown-toggle"
+                            type="button"
+                            data-bs-toggle="dropdown"
+                            aria-expanded="false"
+                            disabled=move || selected_comments.get().is_empty()
+                        >
+                            Actions
+                        </button>
+                        <ul class="dropdown-menu">
+                            <li>
+                                <button
+                                    type="button"
+                                    class="dropdown-item"
+                                    on:click=move |_| moderate(CommentStatusEnum::Approved)
+                                >
+                                    <i class="bi bi-check-lg me-2"></i>
+                                    "Approve"
+                                </button>
+                            </li>
+                            <li>
+                                <button
+                                    type="button"
+                                    class="dropdown-item"
+                                    on:click=move |_| moderate(CommentStatusEnum::Pending)
+                                >
+                                    <i class="bi bi-hourglass me-2"></i>
+                                    "Unapprove"
+                                </button>
+                            </li>
+                            <li>
+                                <button
+                                    type="button"
+                                    class="dropdown-item"
+                                    on:click=move |_| moderate(CommentStatusEnum::Spam)
+                                >
+                                    <i class="bi bi-slash-circle me-2"></i>
+                                    "Mark as spam"
+                                </button>
+                            </li>
+                            <li>
+                                <button
+                                    type="button"
+                                    class="dropdown-item"
+                                    on:click=move |_| moderate(CommentStatusEnum::Trash)
+                                >
+                                    <i class="bi bi-trash me-2"></i>
+                                    "Move to trash"
+                                </button>
+                            </li>
+                            <li>
+                                <hr class="dropdown-divider"/>
+                            </li>
+                            <li>
+                                <button
+                                    type="button"
+                                    class="dropdown-item"
+                                    data-bs-toggle="modal"
+                                    data-bs-target="#deleteModal"
+                                    on:click=move |_| is_modal_open.set(true)
+                                >
+                                    <i class="bi bi-trash-fill me-2"></i>
+                                    "Delete permanently"
+                                </button>
+                            </li>
+                        </ul>
+                    </div>
+                </div>
+            </div>
+        </nav>
+
+        <Modal
+            id="deleteModal".to_string()
+            toggle=is_modal_open
+            title="Delete comments".to_string()
+            body=view! { <p>"Are you sure you want to delete the selected comments and their replies?"</p> }
+            footer=Some(
+                view! {
+                    <button
+                        type="button"
+                        class="btn btn-outline-secondary"
+                        data-bs-dismiss="modal"
+                        on:click=move |_| is_modal_open.set(false)
+                    >
+                        "Cancel"
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-danger"
+                        data-bs-dismiss="modal"
+                        on:click=move |_| {
+                            confirm_delete();
+                            is_modal_open.set(false)
+                        }
+                    >
+
+                        "Delete"
+                    </button>
+                }
+                    .into(),
+            )
+        />
+
+        <Suspense fallback=move || {
+            view! { <LoadingComponent/> }
+        }>
+            {move || {
+                if let Some(Ok(paginated_comments)) = comments.get() {
+                    set_loaded_comments.set(paginated_comments.data.clone());
+                    set_total_items_signal.set(paginated_comments.total_items);
+                    let (columns, _) = create_signal(
+                        vec![
+                            TableColumn {
+                                title: "Author",
+                                value_fn: Arc::new(|comment: &CommentStruct| {
+                                    view! {
+                                        <>
+                                            {&comment.author_name}
+                                            <div class="small break-word">{&comment.author_email}</div>
+                                        </>
+                                    }
+                                        .into()
+                                }),
+                                visible: create_rw_signal(true),
+                            },
+                            TableColumn {
+                                title: "Comment",
+                                value_fn: Arc::new(|comment: &CommentStruct| {
+                                    view! {
+                                        <>
+                                            {comment
+                                                .parent_id
+                                                .map(|parent_id| {
+                                                    view! {
+                                                        <div class="small text-body-secondary">
+                                                            {format!("In reply to #{}", parent_id)}
+                                                        </div>
+                                                    }
+                                                })}
+                                            <div class="break-word">{&comment.content}</div>
+                                        </>
+                                    }
+                                        .into()
+                                }),
+                                visible: create_rw_signal(true),
+                            },
+                            TableColumn {
+                                title: "Post",
+                                value_fn: Arc::new(|comment: &CommentStruct| {
+                                    view! {
+                                        <>
+                                            <a href=format!(
+                                                "/rs-admin/posts/{}/edit",
+                                                comment.post_id,
+                                            )>{format!("#{}", comment.post_id)}</a>
+                                        </>
+                                    }
+                                        .into()
+                                }),
+                                visible: create_rw_signal(true),
+                            },
+                            TableColumn {
+                                title: "Status",
+                                value_fn: Arc::new(|comment: &CommentStruct| {
+                                    view! { <>{comment.status.to_string()}</> }.into()
+                                }),
+                                visible: create_rw_signal(true),
+                            },
+                            TableColumn {
+                                title: "Date Created",
+                                value_fn: Arc::new(|comment: &CommentStruct| {
+                                    view! {
+                                        <>
+                                            {comment.date_created.format("%Y/%m/%d").to_string()} <br/>
+                                            {comment.date_created.format("%-I:%M %P").to_string()}
+                                        </>
+                                    }
+                                        .into()
+                                }),
+                                visible: create_rw_signal(true),
+                            },
+                        ],
+                    );
+                    view! {
+                        <DataTable
+                            data=loaded_comments.into()
+                            columns=columns.into()
+                            selected_datas=selected_comments
+                            total_items=total_items_signal.into()
+                            items_per_page=items_per_page
+                            page=current_page
+                            on_sort_change=on_sort_change
+                            sort_column=sort_column
+                            sort_order=sort_order
+                            on_page_change=on_page_change
+                            on_items_per_page_change=move |new_items_per_page| {
+                                items_per_page.set(new_items_per_page);
+                            }
+                            filters=filters_view
+                            show_filters=show_filters
+                        />
+                    }
+                } else {
+                    view! { <LoadingComponent/> }
+                }
+            }}
+
+        </Suspense>
+    }
+}
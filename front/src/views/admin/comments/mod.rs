@@ -0,0 +1 @@
+pub mod comments_index_view;
@@ -0,0 +1,327 @@
+use std::collections::{BTreeMap, HashSet};
+
+use leptos::*;
+
+use crate::{
+    components::{
+        admin::{header_content_component::HeaderContent, menu_items_component::MenuItemsComponent},
+        front::loading_component::LoadingComponent,
+    },
+    models::admin::menus_model::{MenuEditorItem, MenuItemsUpdate, MenuLocations, MenuNewStruct, MenusIds},
+    services::{
+        admin::api::menus_api::{
+            add_menu, delete_menus, get_menu_by_id, get_menu_locations, get_menus, update_menu, update_menu_items,
+            update_menu_locations,
+        },
+        front::theme_service::get_themes,
+    },
+    utils::{
+        add_class_util::add_class,
+        menu_items_util::{flatten_menu_items, menu_items_tree},
+    },
+};
+
+/// Creates and edits the navigation menus, and assigns them to the locations of the active theme.
+#[component]
+pub fn AdminMenusView() -> impl IntoView {
+    add_class("body", "menus");
+
+    let menus = create_resource(|| (), |_| get_menus());
+    let themes = create_resource(|| (), |_| get_themes());
+    let locations = create_rw_signal(MenuLocations::default());
+    let selected_menu = create_rw_signal(None::<u32>);
+    let menu_name = create_rw_signal(String::new());
+    let new_menu_name = create_rw_signal(String::new());
+    let items = create_rw_signal(Vec::<MenuEditorItem>::new());
+    let (message, set_message) = create_signal(None::<String>);
+
+    spawn_local(async move {
+        match get_menu_locations().await {
+            Ok(menu_locations) => locations.set(menu_locations),
+            Err(e) => log::error!("Error loading menu locations: {}", e),
+        }
+    });
+
+    let load_menu = move |menu_id: Option<u32>| {
+        selected_menu.set(menu_id);
+        set_message.set(None);
+        let Some(menu_id) = menu_id else {
+            menu_name.set(String::new());
+            items.set(Vec::new());
+            return;
+        };
+
+        spawn_local(async move {
+            match get_menu_by_id(menu_id).await {
+                Ok(menu) => {
+                    menu_name.set(menu.name);
+                    items.set(flatten_menu_items(&menu.items));
+                }
+                Err(e) => log::error!("Error loading menu: {}", e),
+            }
+        });
+    };
+
+    let create_menu = move |_| {
+        let name = new_menu_name.get_untracked().trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+
+        spawn_local(async move {
+            match add_menu(MenuNewStruct { name, slug: None }).await {
+                Ok(menu) => {
+                    new_menu_name.set(String::new());
+                    menus.refetch();
+                    load_menu(Some(menu.id));
+                }
+                Err(e) => set_message.set(Some(format!("Error creating the menu: {}", e))),
+            }
+        });
+    };
+
+    let save_menu = move |_| {
+        let Some(menu_id) = selected_menu.get_untracked() else {
+            return;
+        };
+        let name = menu_name.get_untracked().trim().to_string();
+        let tree = menu_items_tree(&items.get_untracked());
+
+        spawn_local(async move {
+            let saved = async {
+                update_menu(menu_id, MenuNewStruct { name, slug: None }).await?;
+                update_menu_items(menu_id, MenuItemsUpdate { items: tree }).await
+            };
+            match saved.await {
+                Ok(menu) => {
+                    items.set(flatten_menu_items(&menu.items));
+                    menus.refetch();
+                    set_message.set(Some("Menu saved.".to_string()));
+                }
+                Err(e) => set_message.set(Some(format!("Error saving the menu: {}", e))),
+            }
+        });
+    };
+
+    let delete_menu = move |_| {
+        let Some(menu_id) = selected_menu.get_untracked() else {
+            return;
+        };
+
+        spawn_local(async move {
+            match delete_menus(MenusIds {
+                ids: HashSet::from([menu_id]),
+            })
+            .await
+            {
+                Ok(_) => {
+                    locations.update(|menu_locations| menu_locations.locations.retain(|_, id| *id != menu_id));
+                    menus.refetch();
+                    load_menu(None);
+                }
+                Err(e) => set_message.set(Some(format!("Error deleting the menu: {}", e))),
+            }
+        });
+    };
+
+    let save_locations = move |_| {
+        spawn_local(async move {
+            match update_menu_locations(locations.get_untracked()).await {
+                Ok(menu_locations) => {
+                    locations.set(menu_locations);
+                    set_message.set(Some("Locations saved.".to_string()));
+                }
+                Err(e) => set_message.set(Some(format!("Error saving the locations: {}", e))),
+            }
+        });
+    };
+
+    // Locations of the active theme, with their description
+    let theme_locations = move || {
+        themes
+            .get()
+            .and_then(Result::ok)
+            .and_then(|themes| themes.into_iter().find(|theme| theme.active))
+            .and_then(|theme| theme.manifest)
+            .map(|manifest| manifest.menu_locations)
+            .unwrap_or_default()
+    };
+
+    view! {
+        <HeaderContent title="Menus"/>
+
+        {move || message.get().map(|message| view! { <div class="alert alert-info">{message}</div> })}
+
+        <div class="row g-4">
+            <div class="col-lg-4">
+                <div class="mb-3">
+                    <label for="menu-select" class="form-label">"Menu to edit"</label>
+                    <Suspense fallback=move || {
+                        view! { <LoadingComponent/> }
+                    }>
+                        {move || {
+                            menus
+                                .get()
+                                .map(|menus| match menus {
+                                    Ok(menus) => {
+                                        view! {
+                                            <select
+                                                id="menu-select"
+                                                class="form-select"
+                                                on:change=move |ev| load_menu(event_target_value(&ev).parse().ok())
+                                            >
+                                                <option value="" selected=move || selected_menu.get().is_none()>
+                                                    "Choose..."
+                                                </option>
+                                                {menus
+                                                    .into_iter()
+                                                    .map(|menu| {
+                                                        let id = menu.id;
+                                                        view! {
+                                                            <option
+                                                                value=id.to_string()
+                                                                selected=move || selected_menu.get() == Some(id)
+                                                            >
+                                                                {menu.name}
+                                                            </option>
+                                                        }
+                                                    })
+                                                    .collect_view()}
+                                            </select>
+                                        }
+                                            .into_view()
+                                    }
+                                    Err(err) => view! { <p>{format!("Error loading menus: {}", err)}</p> }.into_view(),
+                                })
+                        }}
+                    </Suspense>
+                </div>
+
+                <div class="mb-4">
+                    <label for="menu-new-name" class="form-label">"New menu"</label>
+                    <div class="input-group">
+                        <input
+                            type="text"
+                            id="menu-new-name"
+                            class="form-control"
+                            placeholder="Name"
+                            prop:value=move || new_menu_name.get()
+                            on:input=move |ev| new_menu_name.set(event_target_value(&ev))
+                        />
+                        <button type="button" class="btn btn-outline-primary" on:click=create_menu>
+                            "Create"
+                        </button>
+                    </div>
+                </div>
+
+                <h3>"Theme locations"</h3>
+                <Suspense fallback=move || {
+                    view! { <LoadingComponent/> }
+                }>
+                    {move || {
+                        let theme_locations: BTreeMap<String, String> = theme_locations();
+                        if theme_locations.is_empty() {
+                            return view! { <p>"The active theme has no menu locations."</p> }.into_view();
+                        }
+                        let menus = menus.get().and_then(Result::ok).unwrap_or_default();
+
+                        view! {
+                            {theme_locations
+                                .into_iter()
+                                .map(|(location, description)| {
+                                    let id = format!("menu-location-{}", location);
+                                    let selected_location = location.clone();
+                                    let change_location = location.clone();
+                                    view! {
+                                        <div class="mb-3">
+                                            <label for=id.clone() class="form-label">{location}</label>
+                                            <select
+                                                id=id
+                                                class="form-select"
+                                                on:change=move |ev| {
+                                                    let menu_id: Option<u32> = event_target_value(&ev).parse().ok();
+                                                    locations
+                                                        .update(|menu_locations| match menu_id {
+                                                            Some(menu_id) => {
+                                                                menu_locations
+                                                                    .locations
+                                                                    .insert(change_location.clone(), menu_id);
+                                                            }
+                                                            None => {
+                                                                menu_locations.locations.remove(&change_location);
+                                                            }
+                                                        });
+                                                }
+                                            >
+                                                <option value="">"(no menu)"</option>
+                                                {menus
+                                                    .iter()
+                                                    .map(|menu| {
+                                                        let menu_id = menu.id;
+                                                        let selected_location = selected_location.clone();
+                                                        view! {
+                                                            <option
+                                                                value=menu_id.to_string()
+                                                                selected=move || {
+                                                                    locations
+                                                                        .with(|menu_locations| {
+                                                                            menu_locations.locations.get(&selected_location)
+                                                                                == Some(&menu_id)
+                                                                        })
+                                                                }
+                                                            >
+                                                                {menu.name.clone()}
+                                                            </option>
+                                                        }
+                                                    })
+                                                    .collect_view()}
+                                            </select>
+                                            <div class="form-text">{description}</div>
+                                        </div>
+                                    }
+                                })
+                                .collect_view()}
+                            <button type="button" class="btn btn-primary" on:click=save_locations>
+                                "Save locations"
+                            </button>
+                        }
+                            .into_view()
+                    }}
+                </Suspense>
+            </div>
+
+            <div class="col-lg-8">
+                {move || {
+                    if selected_menu.get().is_none() {
+                        return view! { <p>"Choose a menu to edit, or create a new one."</p> }.into_view();
+                    }
+
+                    view! {
+                        <div class="mb-3">
+                            <label for="menu-name" class="form-label">"Menu name"</label>
+                            <input
+                                type="text"
+                                id="menu-name"
+                                class="form-control"
+                                prop:value=move || menu_name.get()
+                                on:input=move |ev| menu_name.set(event_target_value(&ev))
+                            />
+                        </div>
+
+                        <MenuItemsComponent items=items/>
+
+                        <div class="d-flex gap-2">
+                            <button type="button" class="btn btn-primary" on:click=save_menu>
+                                "Save menu"
+                            </button>
+                            <button type="button" class="btn btn-outline-danger" on:click=delete_menu>
+                                "Delete menu"
+                            </button>
+                        </div>
+                    }
+                        .into_view()
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1 @@
+pub mod menus_index_view;
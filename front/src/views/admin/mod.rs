@@ -1,5 +1,10 @@
+pub mod comments;
 pub mod dashboard;
 pub mod layout_view;
 pub mod login;
+pub mod menus;
+pub mod pages;
 pub mod posts;
+pub mod redirects;
 pub mod tags;
+pub mod themes;
@@ -0,0 +1 @@
+pub mod page_new_view;
@@ -1,3 +1,3 @@
-//pub mod post_edit_view;
+pub mod post_edit_view;
 pub mod post_new_view;
 pub mod posts_index_view;
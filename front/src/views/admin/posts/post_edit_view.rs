@@ -1,103 +1,151 @@
-use ev::SubmitEvent;
 use leptos::*;
 use leptos_router::use_params_map;
-
-use crate::components::admin::header_content::HeaderContent;
-use crate::services::admin::api::posts::{get_post_by_id, update_post};
-use crate::structs::admin::posts::{PostNewStruct, PostStatusEnum, PostStruct};
-use crate::utils::add_class::add_class;
+use web_sys::SubmitEvent;
+
+use crate::{
+    components::admin::{
+        header_content_component::HeaderContent, media_picker_component::MediaPickerComponent,
+        notification_component::ToastComponent, revisions_component::RevisionsComponent,
+        slug_field_component::SlugFieldComponent,
+    },
+    models::admin::posts_model::{PostNewStruct, PostRequest, PostStruct},
+    services::admin::api::posts_api::{get_post_by_id, update_post},
+    utils::add_class_util::add_class,
+};
 
 #[component]
-pub fn PostEdit() -> impl IntoView {
+pub fn AdminPostEditView() -> impl IntoView {
     add_class("body", "post-edit");
 
     let params = use_params_map();
-    let id = move || {
-        params.with(|params| params.get("id").cloned().unwrap_or_default())
+    let post_id: u32 = params.with_untracked(|params| params.get("id").and_then(|id| id.parse().ok()).unwrap_or(0));
+
+    let post = create_rw_signal(None::<PostStruct>);
+    let title = create_rw_signal(String::new());
+    let content = create_rw_signal(String::new());
+    let slug = create_rw_signal(String::new());
+    let featured_media_id = create_rw_signal(None::<u32>);
+    // Incrémenté à chaque enregistrement pour recharger les révisions
+    let version = create_rw_signal(0u32);
+
+    // Signaux pour les notifications (gérés localement)
+    let (notification_message, set_notification_message) = create_signal(String::new());
+    let (notification_type, set_notification_type) = create_signal(String::new());
+    let (show_toast, set_show_toast) = create_signal(false);
+
+    let load_post = move |loaded_post: PostStruct| {
+        title.set(loaded_post.title.clone());
+        content.set(loaded_post.content.clone());
+        slug.set(loaded_post.slug.clone());
+        featured_media_id.set(loaded_post.featured_media_id);
+        post.set(Some(loaded_post));
     };
-    let post_id: u32 = id().parse().unwrap_or(0);
-
-    let (post, set_post) = create_signal(PostStruct {
-        id: 0,
-        title: String::new(),
-        content: String::new(),
-        author_id: 0,
-        status: PostStatusEnum::Draft,
-    });
-
-    create_effect(move |_| {
-        let post_id = post_id.clone();
 
-        spawn_local(async move {
-            let post_data =
-                get_post_by_id(post_id).await.unwrap_or(PostStruct {
-                    id: 0,
-                    title: String::new(),
-                    content: String::new(),
-                    slug: "test-123".to_string(),
-                    author_id: 0,
-                    status: PostStatusEnum::Draft,
-                    date_published: date_published.get().clone(),
-                    categories: categories.get().clone(),
-                });
-
-            set_post.set(post_data);
-        });
+    spawn_local(async move {
+        match get_post_by_id(post_id).await {
+            Ok(loaded_post) => load_post(loaded_post),
+            Err(e) => log::error!("Error loading post: {}", e),
+        }
     });
 
-    let on_submit = move |event: SubmitEvent| {
-        event.prevent_default();
+    let on_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        let Some(current_post) = post.get_untracked() else {
+            return;
+        };
 
-        let updated_post = PostNewStruct {
-            title: post.get().title.clone(),
-            content: post.get().content.clone(),
-            author_id: post.get().author_id,
-            status: post.get().status.clone(),
+        let post_request = PostRequest {
+            post: PostNewStruct {
+                title: title.get_untracked(),
+                content: content.get_untracked(),
+                slug: Some(slug.get_untracked()).filter(|slug| !slug.trim().is_empty()),
+                author_id: current_post.author_id,
+                status: current_post.status.clone(),
+                date_published: current_post.date_published,
+                featured_media_id: featured_media_id.get_untracked(),
+            },
+            categories_ids: current_post.categories.iter().map(|category| category.id).collect(),
+            tags_ids: current_post.tags.iter().map(|tag| tag.id).collect(),
         };
 
         spawn_local(async move {
-            let _ = update_post(post_id, updated_post).await;
+            match update_post(post_id, post_request).await {
+                Ok(updated_post) => {
+                    set_notification_message.set(format!("Content '{}' updated successfully!", updated_post.title));
+                    set_notification_type.set("success".to_string());
+                    load_post(updated_post);
+                    version.update(|version| *version += 1);
+                }
+                Err(e) => {
+                    set_notification_message.set(format!("Error: {}", e));
+                    set_notification_type.set("error".to_string());
+                }
+            }
+            set_show_toast.set(true);
         });
     };
 
-    view! {
-        <HeaderContent title="Edit post"/>
-
-        <form on:submit=on_submit>
-            <input type="hidden" id="author-id" value=move || post.with(|p| p.author_id.clone())/>
-
-            <div class="mb-3">
-                <label for="post-title" class="form-label">
-                    Title
-                </label>
-                <input
-                    type="text"
-                    class="form-control"
-                    id="post-title"
-                    prop:value=move || post.with(|p| p.title.clone())
-                    on:input=move |e| set_post.update(|p| p.title = event_target_value(&e))
-                />
+    let on_restore = move |restored_post: PostStruct| {
+        set_notification_message.set(format!("Revision of '{}' restored!", restored_post.title));
+        set_notification_type.set("success".to_string());
+        set_show_toast.set(true);
+        load_post(restored_post);
+        version.update(|version| *version += 1);
+    };
 
+    view! {
+        <ToastComponent
+            message=notification_message.into()
+            toast_type=notification_type.into()
+            show=show_toast.into()
+            set_show=set_show_toast
+        />
+
+        <div class="row edit-content">
+
+            <div class="mb-3 d-flex align-items-center justify-content-start">
+                <HeaderContent title="Edit post"/>
+                <button type="submit" form="edit-post-form" class="btn btn-primary ms-3">
+                    "Update"
+                </button>
             </div>
 
-            <div class="mb-3">
-                <label for="post-content" class="form-label">
-                    Content
-                </label>
-                <textarea
-                    class="form-control"
-                    id="post-content"
-                    rows="8"
-                    prop:value=move || post.with(|p| p.content.clone())
-                    on:input=move |e| set_post.update(|p| p.content = event_target_value(&e))
-                >
-                    {post.with_untracked(|p| p.content.clone())}
-                </textarea>
+            <div class="col-lg-8 col-xl-9">
+                <form id="edit-post-form" on:submit=on_submit>
+
+                    <div class="form-floating mb-3">
+                        <input
+                            type="text"
+                            on:input=move |ev| title.set(event_target_value(&ev))
+                            prop:value=title
+                            class="form-control"
+                            id="post-title"
+                            placeholder="Add title"
+                        />
+                        <label for="post-title">"Title"</label>
+                    </div>
+
+                    <SlugFieldComponent slug=slug slug_type="post" exclude_id=Some(post_id)/>
+
+                    <div class="form-floating mb-3">
+                        <textarea
+                            on:input=move |ev| content.set(event_target_value(&ev))
+                            prop:value=content
+                            class="form-control"
+                            id="post-content"
+                            placeholder="Add content"
+                            style="height: 200px"
+                        ></textarea>
+                        <label for="post-content">"Content"</label>
+                    </div>
+
+                </form>
             </div>
 
-            <button type="submit" class="btn btn-primary">
-                Submit
-            </button>
-        </form>
+            <div class="col-lg-4 col-xl-3">
+                <MediaPickerComponent media_id=featured_media_id.into() set_media_id=featured_media_id.write_only()/>
+                <RevisionsComponent post_id=post_id version=version.into() on_restore=on_restore/>
+            </div>
+        </div>
     }
 }
@@ -33,6 +33,7 @@ pub fn AdminPostNewView() -> impl IntoView {
                 content_view.title.write_only(),
                 content_view.content.write_only(),
                 content_view.categories_ids.write_only(),
+                content_view.tags_ids.write_only(),
                 content_view.status.write_only(),
                 content_view.date_published.write_only(),
                 set_notification_message,
@@ -1,5 +1,6 @@
 use std::{collections::HashSet, sync::Arc};
 
+use chrono::NaiveDate;
 use leptos::*;
 use leptos_router::{use_params_map, A};
 
@@ -15,10 +16,11 @@ use crate::{
         },
         front::loading_component::LoadingComponent,
     },
-    models::admin::posts_model::{PostStruct, PostsIds},
-    services::admin::api::posts_api::{delete_posts, get_posts},
+    models::admin::posts_model::{PostFilters, PostStatusEnum, PostStruct, PostsIds},
+    services::admin::api::posts_api::{delete_posts, get_posts, search_posts},
     utils::add_class_util::add_class,
 };
+use strum::IntoEnumIterator;
 
 #[component]
 pub fn AdminPostsView() -> impl IntoView {
@@ -31,6 +33,9 @@ pub fn AdminPostsView() -> impl IntoView {
     let sort_column: RwSignal<Option<usize>> = create_rw_signal(None);
     let sort_order = create_rw_signal(SortOrder::Descending);
     let (total_items_signal, set_total_items_signal) = create_signal(0);
+    let search_query = create_rw_signal(String::new());
+    let filters = create_rw_signal(PostFilters::default());
+    let show_filters = create_rw_signal(false);
 
     let on_page_change = move |new_page: u32| {
         current_page.set(new_page);
@@ -41,6 +46,11 @@ pub fn AdminPostsView() -> impl IntoView {
         sort_order.set(order);
     };
 
+    let on_search = move |query: String| {
+        current_page.set(1);
+        search_query.set(query);
+    };
+
     let posts = create_resource(
         move || {
             let sort_column_string = match sort_column.get() {
@@ -60,11 +70,84 @@ pub fn AdminPostsView() -> impl IntoView {
                 items_per_page.get(),
                 sort_column_string, // Conversion en String
                 sort_order_string,  // Conversion en String
+                search_query.get(),
+                filters.get(),
             )
         },
-        move |(page, limit, sort_column, sort_order)| async move { get_posts(page, limit, sort_column, sort_order).await },
+        move |(page, limit, sort_column, sort_order, query, filters)| async move {
+            // Les résultats de recherche sont triés par pertinence
+            if query.is_empty() {
+                get_posts(page, limit, sort_column, sort_order, filters).await
+            } else {
+                search_posts(query, page, limit).await
+            }
+        },
     );
 
+    let update_filters = move |update: &dyn Fn(&mut PostFilters)| {
+        current_page.set(1);
+        filters.update(|filters| update(filters));
+    };
+
+    let filters_view = move || {
+        view! {
+            <div class="row g-2">
+                <div class="col-md-4">
+                    <label class="form-label" for="filter-status">
+                        "Status"
+                    </label>
+                    <select
+                        id="filter-status"
+                        class="form-select"
+                        prop:value=move || filters.with(|filters| filters.status.as_ref().map(|status| status.to_string()).unwrap_or_default())
+                        on:change=move |ev| {
+                            let value = event_target_value(&ev);
+                            let status = PostStatusEnum::iter().find(|status| status.to_string() == value);
+                            update_filters(&|filters| filters.status = status.clone());
+                        }
+                    >
+                        <option value="">"All"</option>
+                        {PostStatusEnum::iter()
+                            .map(|status| view! { <option value=status.to_string()>{status.to_string()}</option> })
+                            .collect_view()}
+                    </select>
+                </div>
+                <div class="col-md-4">
+                    <label class="form-label" for="filter-published-after">
+                        "Published after"
+                    </label>
+                    <input
+                        id="filter-published-after"
+                        class="form-control"
+                        type="date"
+                        prop:value=move || filters.with(|filters| filters.published_after.map(|date| date.format("%Y-%m-%d").to_string()).unwrap_or_default())
+                        on:change=move |ev| {
+                            let date = NaiveDate::parse_from_str(&event_target_value(&ev), "%Y-%m-%d").ok();
+                            let published_after = date.and_then(|date| date.and_hms_opt(0, 0, 0));
+                            update_filters(&|filters| filters.published_after = published_after);
+                        }
+                    />
+                </div>
+                <div class="col-md-4">
+                    <label class="form-label" for="filter-published-before">
+                        "Published before"
+                    </label>
+                    <input
+                        id="filter-published-before"
+                        class="form-control"
+                        type="date"
+                        prop:value=move || filters.with(|filters| filters.published_before.map(|date| date.format("%Y-%m-%d").to_string()).unwrap_or_default())
+                        on:change=move |ev| {
+                            let date = NaiveDate::parse_from_str(&event_target_value(&ev), "%Y-%m-%d").ok();
+                            let published_before = date.and_then(|date| date.and_hms_opt(23, 59, 59));
+                            update_filters(&|filters| filters.published_before = published_before);
+                        }
+                    />
+                </div>
+            </div>
+        }
+    };
+
     let (loaded_posts, set_loaded_posts) = create_signal(Vec::<PostStruct>::new());
     let selected_posts = create_rw_signal(HashSet::<u32>::new());
     let is_modal_open = create_rw_signal(false);
@@ -257,6 +340,13 @@ pub fn AdminPostsView() -> impl IntoView {
                                 }),
                                 visible: create_rw_signal(true),
                             },
+                            TableColumn {
+                                title: "Comments",
+                                value_fn: Arc::new(|post: &PostStruct| {
+                                    view! { <>{post.comment_count}</> }.into()
+                                }),
+                                visible: create_rw_signal(true),
+                            },
                             TableColumn {
                                 title: "ID",
                                 value_fn: Arc::new(|post: &PostStruct| {
@@ -281,6 +371,9 @@ pub fn AdminPostsView() -> impl IntoView {
                             on_items_per_page_change=move |new_items_per_page| {
                                 items_per_page.set(new_items_per_page.try_into().unwrap());
                             }
+                            on_search=on_search
+                            filters=filters_view
+                            show_filters=show_filters
                         />
                     }
                 } else {
@@ -0,0 +1 @@
+pub mod redirects_index_view;
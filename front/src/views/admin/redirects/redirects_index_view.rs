@@ -0,0 +1,263 @@
+use std::collections::HashSet;
+
+use leptos::*;
+
+use crate::{
+    components::{admin::header_content_component::HeaderContent, front::loading_component::LoadingComponent},
+    models::admin::redirects_model::{RedirectNewStruct, RedirectsIds, REDIRECT_STATUS_CODES},
+    services::admin::api::redirects_api::{add_redirect, delete_redirects, get_redirects, update_redirect},
+    utils::add_class_util::add_class,
+};
+
+/// Number of redirects listed per page.
+const REDIRECTS_PAGE_SIZE: u32 = 25;
+
+/// Lists the redirects of the site with their hit counter, and adds, edits or deletes them.
+#[component]
+pub fn AdminRedirectsView() -> impl IntoView {
+    add_class("body", "redirects");
+
+    let page = create_rw_signal(1u32);
+    let redirects = create_resource(move || page.get(), |page| get_redirects(page, REDIRECTS_PAGE_SIZE));
+    let editing = create_rw_signal(None::<u32>);
+    let source_path = create_rw_signal(String::new());
+    let target = create_rw_signal(String::new());
+    let status_code = create_rw_signal(301u16);
+    let (message, set_message) = create_signal(None::<String>);
+
+    let reset_form = move || {
+        editing.set(None);
+        source_path.set(String::new());
+        target.set(String::new());
+        status_code.set(301);
+    };
+
+    let save_redirect = move |_| {
+        let redirect = RedirectNewStruct {
+            source_path: source_path.get_untracked().trim().to_string(),
+            target: target.get_untracked().trim().to_string(),
+            status_code: Some(status_code.get_untracked()),
+        };
+        let redirect_id = editing.get_untracked();
+
+        spawn_local(async move {
+            let saved = match redirect_id {
+                Some(redirect_id) => update_redirect(redirect_id, redirect).await,
+                None => add_redirect(redirect).await,
+            };
+            match saved {
+                Ok(redirect) => {
+                    reset_form();
+                    redirects.refetch();
+                    set_message.set(Some(format!("Redirect of {} saved.", redirect.source_path)));
+                }
+                Err(e) => set_message.set(Some(format!("Error saving the redirect: {}", e))),
+            }
+        });
+    };
+
+    let delete_redirect = move |redirect_id: u32| {
+        spawn_local(async move {
+            match delete_redirects(RedirectsIds {
+                ids: HashSet::from([redirect_id]),
+            })
+            .await
+            {
+                Ok(_) => {
+                    if editing.get_untracked() == Some(redirect_id) {
+                        reset_form();
+                    }
+                    redirects.refetch();
+                }
+                Err(e) => set_message.set(Some(format!("Error deleting the redirect: {}", e))),
+            }
+        });
+    };
+
+    view! {
+        <HeaderContent title="Redirects"/>
+
+        <p class="text-body-secondary">
+            "A redirect applies to a path of the site which matches no content. The former slugs of the posts, pages, "
+            "categories and tags are redirected to their current permalink without a redirect."
+        </p>
+
+        {move || message.get().map(|message| view! { <div class="alert alert-info">{message}</div> })}
+
+        <div class="row g-2 align-items-end mb-4">
+            <div class="col-md-4">
+                <label for="redirect-source" class="form-label">"Source path"</label>
+                <input
+                    type="text"
+                    id="redirect-source"
+                    class="form-control"
+                    placeholder="/old-post"
+                    prop:value=move || source_path.get()
+                    on:input=move |ev| source_path.set(event_target_value(&ev))
+                />
+            </div>
+            <div class="col-md-4">
+                <label for="redirect-target" class="form-label">"Target"</label>
+                <input
+                    type="text"
+                    id="redirect-target"
+                    class="form-control"
+                    placeholder="/new-post or https://example.com/"
+                    prop:value=move || target.get()
+                    on:input=move |ev| target.set(event_target_value(&ev))
+                />
+            </div>
+            <div class="col-md-2">
+                <label for="redirect-status" class="form-label">"Status"</label>
+                <select
+                    id="redirect-status"
+                    class="form-select"
+                    on:change=move |ev| {
+                        if let Ok(code) = event_target_value(&ev).parse() {
+                            status_code.set(code);
+                        }
+                    }
+                >
+                    {REDIRECT_STATUS_CODES
+                        .iter()
+                        .map(|(code, label)| {
+                            let code = *code;
+                            view! {
+                                <option value=code.to_string() selected=move || status_code.get() == code>
+                                    {*label}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </div>
+            <div class="col-md-2 d-flex gap-2">
+                <button type="button" class="btn btn-primary" on:click=save_redirect>
+                    {move || if editing.get().is_some() { "Update" } else { "Add" }}
+                </button>
+                <Show when=move || editing.get().is_some()>
+                    <button type="button" class="btn btn-outline-secondary" on:click=move |_| reset_form()>
+                        "Cancel"
+                    </button>
+                </Show>
+            </div>
+        </div>
+
+        <Suspense fallback=move || {
+            view! { <LoadingComponent/> }
+        }>
+            <table class="table">
+                <thead>
+                    <tr>
+                        <th scope="col">"Source path"</th>
+                        <th scope="col">"Target"</th>
+                        <th scope="col">"Status"</th>
+                        <th scope="col">"Hits"</th>
+                        <th scope="col">"Last hit"</th>
+                        <th scope="col"></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {move || {
+                        redirects
+                            .get()
+                            .map(|redirects| match redirects {
+                                Ok(redirects) if redirects.data.is_empty() => {
+                                    view! {
+                                        <tr>
+                                            <td colspan="6">"No redirects yet."</td>
+                                        </tr>
+                                    }
+                                        .into_view()
+                                }
+                                Ok(redirects) => {
+                                    redirects
+                                        .data
+                                        .into_iter()
+                                        .map(|redirect| {
+                                            let redirect_id = redirect.id;
+                                            let edited = redirect.clone();
+                                            view! {
+                                                <tr>
+                                                    <td>{redirect.source_path}</td>
+                                                    <td>{redirect.target}</td>
+                                                    <td>{redirect.status_code}</td>
+                                                    <td>{redirect.hits}</td>
+                                                    <td>
+                                                        {redirect
+                                                            .last_hit
+                                                            .map(|last_hit| last_hit.format("%Y-%m-%d %H:%M").to_string())
+                                                            .unwrap_or_else(|| "Never".to_string())}
+                                                    </td>
+                                                    <td class="text-end">
+                                                        <button
+                                                            type="button"
+                                                            class="btn btn-sm btn-outline-primary me-2"
+                                                            on:click=move |_| {
+                                                                editing.set(Some(redirect_id));
+                                                                source_path.set(edited.source_path.clone());
+                                                                target.set(edited.target.clone());
+                                                                status_code.set(edited.status_code);
+                                                            }
+                                                        >
+                                                            "Edit"
+                                                        </button>
+                                                        <button
+                                                            type="button"
+                                                            class="btn btn-sm btn-outline-danger"
+                                                            on:click=move |_| delete_redirect(redirect_id)
+                                                        >
+                                                            "Delete"
+                                                        </button>
+                                                    </td>
+                                                </tr>
+                                            }
+                                        })
+                                        .collect_view()
+                                }
+                                Err(err) => {
+                                    view! {
+                                        <tr>
+                                            <td colspan="6">{format!("Failed to load redirects: {}", err)}</td>
+                                        </tr>
+                                    }
+                                        .into_view()
+                                }
+                            })
+                    }}
+                </tbody>
+            </table>
+            {move || {
+                redirects
+                    .get()
+                    .and_then(Result::ok)
+                    .filter(|redirects| redirects.total_pages > 1)
+                    .map(|redirects| {
+                        let current_page = redirects.current_page;
+                        let total_pages = redirects.total_pages;
+                        view! {
+                            <div class="d-flex justify-content-center align-items-center gap-3">
+                                <button
+                                    type="button"
+                                    class="btn btn-sm btn-outline-secondary"
+                                    disabled=current_page <= 1
+                                    on:click=move |_| page.set(current_page - 1)
+                                >
+                                    "Previous"
+                                </button>
+                                <span>{format!("Page {} of {}", current_page, total_pages)}</span>
+                                <button
+                                    type="button"
+                                    class="btn btn-sm btn-outline-secondary"
+                                    disabled=current_page >= total_pages
+                                    on:click=move |_| page.set(current_page + 1)
+                                >
+                                    "Next"
+                                </button>
+                            </div>
+                        }
+                    })
+            }}
+        </Suspense>
+    }
+}
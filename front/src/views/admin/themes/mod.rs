@@ -0,0 +1 @@
+pub mod themes_index_view;
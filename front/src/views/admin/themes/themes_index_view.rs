@@ -0,0 +1,94 @@
+use leptos::*;
+
+use crate::{
+    components::{admin::header_content_component::HeaderContent, front::loading_component::LoadingComponent},
+    models::front::theme_model::ThemeInfo,
+    services::{admin::api::themes_api::activate_theme, front::theme_service::get_themes},
+    utils::add_class_util::add_class,
+};
+
+/// Lists the installed themes and activates one of them.
+#[component]
+pub fn AdminThemesView() -> impl IntoView {
+    add_class("body", "themes");
+
+    let themes = create_resource(|| (), |_| get_themes());
+
+    let activate = move |directory: String| {
+        spawn_local(async move {
+            match activate_theme(directory).await {
+                Ok(_) => themes.refetch(),
+                Err(err) => log::error!("Error activating theme: {}", err),
+            }
+        });
+    };
+
+    let theme_card = move |theme: ThemeInfo| {
+        let directory = theme.directory.clone();
+        let (name, version, description) = match &theme.manifest {
+            Some(manifest) => (manifest.name.clone(), manifest.version.clone(), manifest.description.clone()),
+            None => (theme.directory.clone(), String::new(), None),
+        };
+        let features = theme.manifest.map(|manifest| manifest.features).unwrap_or_default();
+        let is_valid = theme.problems.is_empty();
+
+        view! {
+            <div class="col">
+                <div class="card h-100" class:border-primary=theme.active>
+                    <div class="card-body">
+                        <h5 class="card-title">
+                            {name} " " <small class="text-body-secondary">{version}</small>
+                        </h5>
+                        <p class="card-text">{description}</p>
+                        <div>
+                            {features
+                                .into_iter()
+                                .map(|feature| view! { <span class="badge text-bg-secondary me-1">{feature}</span> })
+                                .collect_view()}
+                        </div>
+                        <ul class="list-unstyled text-danger mt-2 mb-0">
+                            {theme.problems.into_iter().map(|problem| view! { <li>{problem}</li> }).collect_view()}
+                        </ul>
+                    </div>
+                    <div class="card-footer">
+                        {if theme.active {
+                            view! { <span class="badge text-bg-primary">"Active"</span> }.into_view()
+                        } else {
+                            view! {
+                                <button
+                                    class="btn btn-sm btn-primary"
+                                    disabled=!is_valid
+                                    on:click=move |_| activate(directory.clone())
+                                >
+                                    "Activate"
+                                </button>
+                            }
+                            .into_view()
+                        }}
+                    </div>
+                </div>
+            </div>
+        }
+    };
+
+    view! {
+        <HeaderContent title="Themes"/>
+
+        <Suspense fallback=move || {
+            view! { <LoadingComponent/> }
+        }>
+            {move || {
+                themes.get()
+                    .map(|themes| match themes {
+                        Ok(themes) => view! {
+                            <div class="row row-cols-1 row-cols-md-3 g-3">
+                                {themes.into_iter().map(theme_card).collect_view()}
+                            </div>
+                        }
+                        .into_view(),
+                        Err(err) => view! { <p>{format!("Error loading themes: {}", err)}</p> }.into_view(),
+                    })
+            }}
+        </Suspense>
+    }
+}
@@ -0,0 +1,208 @@
+use chrono::Month;
+use leptos::*;
+use leptos_router::{use_location, use_params_map, use_query_map};
+use serde_json::{json, Value};
+
+use crate::{
+    components::front::theme_component::ThemeRouteComponent,
+    models::front::{
+        public_model::{PaginatedPublicPosts, PublicPostFilters},
+        theme_model::{TemplateContext, ThemePage},
+    },
+    services::front::{
+        api::public_api::{get_public_category, get_public_posts, get_public_tag},
+        redirects_service::not_found_page,
+        settings_service::site_settings,
+    },
+    utils::theme_data_util::{page_param, posts_list_data},
+};
+
+/// Builds an archive page listing `posts`, described by `archive`.
+fn archive_page(
+    context: TemplateContext,
+    title: String,
+    archive: Value,
+    posts: PaginatedPublicPosts,
+    base_url: &str,
+) -> ThemePage {
+    let mut data = posts_list_data(&posts, base_url);
+    data["archive"] = archive;
+
+    ThemePage { context, title, data }
+}
+
+/// Archive of a category, a tag or an author, told apart by the permalink bases of the site settings.
+#[component]
+pub fn FrontArchiveView() -> impl IntoView {
+    let location = use_location();
+    let params = use_params_map();
+    let query = use_query_map();
+    let page = create_blocking_resource(
+        move || {
+            (
+                location.pathname.get(),
+                params.with(|params| params.get("base").cloned().unwrap_or_default()),
+                params.with(|params| params.get("slug").cloned().unwrap_or_default()),
+                query.with(page_param),
+            )
+        },
+        |(path, base, slug, page)| async move { load_archive(path, base, slug, page).await },
+    );
+
+    view! { <ThemeRouteComponent page/> }
+}
+
+async fn load_archive(path: String, base: String, slug: String, page: u32) -> Result<ThemePage, String> {
+    let permalinks = site_settings().await.permalinks;
+    let base_url = format!("/{}/{}", base, slug);
+
+    if base == permalinks.category_base {
+        load_category(&path, slug, page, &base_url).await
+    } else if base == permalinks.tag_base {
+        load_tag(&path, slug, page, &base_url).await
+    } else if base == permalinks.author_base {
+        load_author(&path, slug, page, &base_url).await
+    } else {
+        Ok(not_found_page(&path, &[], "").await)
+    }
+}
+
+async fn load_category(path: &str, slug: String, page: u32, base_url: &str) -> Result<ThemePage, String> {
+    let Some(category) = get_public_category(&slug).await? else {
+        return Ok(not_found_page(path, &["category"], &slug).await);
+    };
+
+    let filters = PublicPostFilters {
+        category: Some(slug.clone()),
+        ..Default::default()
+    };
+    let Some(posts) = get_public_posts(filters, page).await? else {
+        return Ok(ThemePage::not_found());
+    };
+
+    let title = format!("Category: {}", category.name);
+    let archive = json!({
+        "type": "category",
+        "title": title,
+        "description": category.description,
+        "term": category,
+    });
+
+    Ok(archive_page(
+        TemplateContext::Category { slug },
+        title,
+        archive,
+        posts,
+        base_url,
+    ))
+}
+
+async fn load_tag(path: &str, slug: String, page: u32, base_url: &str) -> Result<ThemePage, String> {
+    let Some(tag) = get_public_tag(&slug).await? else {
+        return Ok(not_found_page(path, &["tag"], &slug).await);
+    };
+
+    let filters = PublicPostFilters {
+        tag: Some(slug.clone()),
+        ..Default::default()
+    };
+    let Some(posts) = get_public_posts(filters, page).await? else {
+        return Ok(ThemePage::not_found());
+    };
+
+    let title = format!("Tag: {}", tag.name);
+    let archive = json!({
+        "type": "tag",
+        "title": title,
+        "description": tag.description,
+        "term": tag,
+    });
+
+    Ok(archive_page(
+        TemplateContext::Tag { slug },
+        title,
+        archive,
+        posts,
+        base_url,
+    ))
+}
+
+async fn load_author(path: &str, username: String, page: u32, base_url: &str) -> Result<ThemePage, String> {
+    let filters = PublicPostFilters {
+        author: Some(username.clone()),
+        ..Default::default()
+    };
+    // The API answers 404 for an unknown author
+    let Some(posts) = get_public_posts(filters, page).await? else {
+        return Ok(not_found_page(path, &[], "").await);
+    };
+
+    let author = posts.data.iter().find_map(|post| post.author.clone());
+    let name = author
+        .as_ref()
+        .map(|author| format!("{} {}", author.firstname, author.lastname))
+        .unwrap_or_else(|| username.clone());
+    let title = format!("Author: {}", name);
+    let archive = json!({
+        "type": "author",
+        "title": title,
+        "author": author,
+    });
+
+    Ok(archive_page(TemplateContext::Author { username }, title, archive, posts, base_url))
+}
+
+/// Archive of the posts published in a year, or in a month of a year.
+#[component]
+pub fn FrontDateView() -> impl IntoView {
+    let params = use_params_map();
+    let query = use_query_map();
+    let page = create_resource(
+        move || {
+            (
+                params.with(|params| params.get("year").cloned().unwrap_or_default()),
+                params.with(|params| params.get("month").cloned()),
+                query.with(page_param),
+            )
+        },
+        |(year, month, page)| async move { load_date(year, month, page).await },
+    );
+
+    view! { <ThemeRouteComponent page/> }
+}
+
+async fn load_date(year: String, month: Option<String>, page: u32) -> Result<ThemePage, String> {
+    let Ok(year) = year.parse::<i32>() else {
+        return Ok(ThemePage::not_found());
+    };
+    let month = match month.map(|month| month.parse::<u8>().ok().and_then(|month| Month::try_from(month).ok())) {
+        None => None,
+        Some(Some(month)) => Some(month),
+        Some(None) => return Ok(ThemePage::not_found()),
+    };
+
+    let filters = PublicPostFilters {
+        year: Some(year),
+        month: month.map(|month| month.number_from_month()),
+        ..Default::default()
+    };
+    let Some(posts) = get_public_posts(filters, page).await? else {
+        return Ok(ThemePage::not_found());
+    };
+
+    let (title, base_url) = match month {
+        Some(month) => (
+            format!("{} {}", month.name(), year),
+            format!("/archives/{}/{:02}", year, month.number_from_month()),
+        ),
+        None => (year.to_string(), format!("/archives/{}", year)),
+    };
+    let archive = json!({
+        "type": "date",
+        "title": title,
+        "year": year,
+        "month": month.map(|month| month.number_from_month()),
+    });
+
+    Ok(archive_page(TemplateContext::Date, title, archive, posts, &base_url))
+}
@@ -1,86 +1,33 @@
-use handlebars::Handlebars;
 use leptos::*;
-use serde_json::Value;
-use std::collections::HashMap;
+use leptos_router::use_query_map;
 
 use crate::{
-    components::front::loading_component::LoadingComponent, services::admin::api::posts_api::get_posts,
-    utils::add_class_util::add_class,
+    components::front::theme_component::ThemeRouteComponent,
+    models::front::{
+        public_model::PublicPostFilters,
+        theme_model::{TemplateContext, ThemePage},
+    },
+    services::front::api::public_api::get_public_posts,
+    utils::theme_data_util::{page_param, posts_list_data},
 };
 
 #[component]
 pub fn FrontHomeView() -> impl IntoView {
-    add_class("body", "home");
-    let posts = create_resource(|| (), |_| async { get_posts().await });
+    let query = use_query_map();
+    let page = create_resource(move || query.with(page_param), load_home);
 
-    view! {
-        <Suspense fallback=move || {
-            view! { <LoadingComponent/> }
-        }>
-            {move || {
-                match posts.get() {
-                    Some(Ok(posts)) => {
-                        let mut handlebars = Handlebars::new();
-                        let template = include_str!("../../themes/Default/home.hbs");
-                        handlebars
-                            .register_template_string("home", template)
-                            .expect("Template string could not be loaded");
-                        let mut data = HashMap::new();
-                        data.insert(
-                            "title".to_string(),
-                            Value::String("Bienvenue sur la Homepage".to_string()),
-                        );
-                        let posts_for_template: Vec<HashMap<String, String>> = posts
-                            .into_iter()
-                            .map(|post| {
-                                let mut post_map = HashMap::new();
-                                post_map.insert("title".to_string(), post.title);
-                                post_map.insert("content".to_string(), post.content);
-                                post_map
-                            })
-                            .collect();
-                        let posts_json = match serde_json::to_value(&posts_for_template) {
-                            Ok(json) => json,
-                            Err(e) => {
-                                return view! {
-                                    <div>
-                                        <p>
-                                            {format!("Erreur de conversion des posts en JSON: {}", e)}
-                                        </p>
-                                    </div>
-                                };
-                            }
-                        };
-                        data.insert("posts".to_string(), posts_json);
-                        let html = match handlebars.render("home", &data) {
-                            Ok(html) => html,
-                            Err(e) => {
-                                return view! {
-                                    <div>
-                                        <p>{format!("Erreur lors du rendu du template: {}", e)}</p>
-                                    </div>
-                                };
-                            }
-                        };
-                        view! { <div inner_html=html></div> }
-                    }
-                    Some(Err(err)) => {
-                        view! {
-                            <div>
-                                <p>{format!("Erreur lors du chargement des posts: {}", err)}</p>
-                            </div>
-                        }
-                    }
-                    None => {
-                        view! {
-                            <div>
-                                <p>"Chargement..."</p>
-                            </div>
-                        }
-                    }
-                }
-            }}
+    view! { <ThemeRouteComponent page/> }
+}
+
+async fn load_home(page: u32) -> Result<ThemePage, String> {
+    let posts = get_public_posts(PublicPostFilters::default(), page).await?;
 
-        </Suspense>
-    }
+    Ok(match posts {
+        Some(posts) => ThemePage {
+            context: TemplateContext::Home,
+            title: "Home".to_string(),
+            data: posts_list_data(&posts, "/"),
+        },
+        None => ThemePage::not_found(),
+    })
 }
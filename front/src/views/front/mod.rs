@@ -1 +1,4 @@
-//pub mod home_view;
+pub mod archive_view;
+pub mod home_view;
+pub mod not_found_view;
+pub mod single_view;
@@ -0,0 +1,18 @@
+use leptos::*;
+use leptos_router::use_location;
+
+use crate::{
+    components::front::theme_component::ThemeRouteComponent, services::front::redirects_service::not_found_page,
+};
+
+/// Displays the 404 page, unless the path is redirected in the admin.
+#[component]
+pub fn FrontNotFoundView() -> impl IntoView {
+    let location = use_location();
+    let page = create_blocking_resource(
+        move || location.pathname.get(),
+        |path| async move { Ok(not_found_page(&path, &[], "").await) },
+    );
+
+    view! { <ThemeRouteComponent page/> }
+}
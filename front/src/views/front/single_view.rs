@@ -0,0 +1,52 @@
+use leptos::*;
+use leptos_router::{use_location, use_params_map};
+use serde_json::json;
+
+use crate::{
+    components::front::theme_component::ThemeRouteComponent,
+    models::front::theme_model::{TemplateContext, ThemePage},
+    services::front::{
+        api::public_api::{get_public_page, get_public_post},
+        redirects_service::not_found_page,
+    },
+};
+
+/// Displays the published post or page of the slug, or redirects a former slug to the current permalink.
+#[component]
+pub fn FrontSingleView() -> impl IntoView {
+    let location = use_location();
+    let params = use_params_map();
+    let page = create_blocking_resource(
+        move || {
+            (
+                location.pathname.get(),
+                params.with(|params| params.get("slug").cloned().unwrap_or_default()),
+            )
+        },
+        |(path, slug)| async move { load_single(&path, &slug).await },
+    );
+
+    view! { <ThemeRouteComponent page/> }
+}
+
+async fn load_single(path: &str, slug: &str) -> Result<ThemePage, String> {
+    if let Some(post) = get_public_post(slug).await? {
+        return Ok(ThemePage {
+            context: TemplateContext::Single,
+            title: post.title.clone(),
+            data: json!({ "post": post }),
+        });
+    }
+
+    if let Some(page) = get_public_page(slug).await? {
+        return Ok(ThemePage {
+            context: TemplateContext::Page {
+                template: page.template.clone(),
+            },
+            title: page.title.clone(),
+            data: json!({ "page": page }),
+        });
+    }
+
+    Ok(not_found_page(path, &["post", "page"], slug).await)
+}